@@ -0,0 +1,77 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compare the scalar and SIMD expansion of the plain values of a nullable page.
+//!
+//! cargo bench --features simd_null_expansion --bench null_expansion
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bolt_parquet_reader::utils::plain_values::{
+    expand_non_null_values_scalar, expand_non_null_values_simd,
+};
+use rand::Rng;
+
+const NUM_VALUES: usize = 1 << 20;
+const ITERATIONS: usize = 50;
+
+fn bench<F: Fn() -> usize>(name: &str, f: F) -> Duration {
+    // Warm up.
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / ITERATIONS as u32;
+    println!("{:<40} {:>10.3} ms", name, elapsed.as_secs_f64() * 1000.0);
+    elapsed
+}
+
+fn create_validity(null_ratio: f64) -> Vec<bool> {
+    let mut rng = rand::thread_rng();
+    let mut validity = Vec::with_capacity(NUM_VALUES);
+    // The nulls come in runs, as in most of the real data.
+    while validity.len() < NUM_VALUES {
+        let run = rng.gen_range(1..512).min(NUM_VALUES - validity.len());
+        let valid = !rng.gen_bool(null_ratio);
+        validity.resize(validity.len() + run, valid);
+    }
+    validity
+}
+
+fn main() {
+    for null_ratio in [0.0, 0.1, 0.5] {
+        let validity = create_validity(null_ratio);
+        let num_non_null = validity.iter().filter(|valid| **valid).count();
+        let int64_values: Vec<i64> = (0..num_non_null as i64).collect();
+        let int32_values: Vec<i32> = (0..num_non_null as i32).collect();
+
+        println!("null ratio: {}", null_ratio);
+        bench("INT64 scalar", || {
+            expand_non_null_values_scalar(&int64_values, &validity).len()
+        });
+        bench("INT64 simd", || {
+            expand_non_null_values_simd(&int64_values, &validity).len()
+        });
+        bench("INT32 scalar", || {
+            expand_non_null_values_scalar(&int32_values, &validity).len()
+        });
+        bench("INT32 simd", || {
+            expand_non_null_values_simd(&int32_values, &validity).len()
+        });
+    }
+}
@@ -14,11 +14,11 @@
 // limitations under the License.
 
 use std::fmt::Formatter;
-use std::intrinsics::unlikely;
 use std::string::String;
 
 use crate::bridge::bridge_base::Bridge;
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
 use crate::utils::row_range_set::RowRangeSet;
 
 // Currently, we display 10 pieces of data only
@@ -144,6 +144,16 @@ impl<T: Clone + Default + Copy> RawBridge<T> {
         Ok(())
     }
 
+    /// The values in the appended order, the values at the null positions are the default.
+    pub fn get_raw_result(&self) -> &[T] {
+        &self.raw_result
+    }
+
+    /// The validity might be shorter than the values, see the comment of RawBridge.
+    pub fn get_raw_validity(&self) -> &[bool] {
+        &self.raw_validity
+    }
+
     /// Late materialize the validity vector when necessary.
     /// The may_have_null flag is set to be true.
     #[inline(always)]
@@ -641,4 +651,17 @@ mod tests {
         assert_eq!(other_raw_bridge.may_has_null(), false);
         assert_eq!(other_raw_bridge.raw_result, other_values);
     }
+
+    #[test]
+    fn test_get_raw_result_and_validity() {
+        let mut raw_bridge = create_non_null_raw_bridge(true, 5, 3);
+        assert_eq!(raw_bridge.get_raw_result(), &[1, 2, 3]);
+        assert!(raw_bridge.get_raw_validity().is_empty());
+
+        // The validity of the non-null values is materialized before appending a null.
+        raw_bridge.append_result(false, 0);
+        raw_bridge.append_non_null_result(5);
+        assert_eq!(raw_bridge.get_raw_result(), &[1, 2, 3, 0, 5]);
+        assert_eq!(raw_bridge.get_raw_validity(), &[true, true, true, false]);
+    }
 }
@@ -18,3 +18,5 @@ pub mod filter;
 pub mod fixed_length_filter;
 pub mod float_point_range_filter;
 pub mod integer_range_filter;
+pub mod page_pruner;
+pub mod predicate;
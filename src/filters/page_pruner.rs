@@ -0,0 +1,466 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::metadata::column_index::{read_column_index, ColumnIndex};
+use crate::metadata::offset_index::{read_offset_index, OffsetIndex};
+use crate::metadata::parquet_metadata_thrift::Type;
+use crate::metadata::row_group::ColumnChunkMetaData;
+use crate::metadata::statistics::Statistics;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+/// Select the pages of a column chunk that may have rows satisfying the filter, with the page
+/// min/max values in the ColumnIndex and the page locations in the OffsetIndex.
+///
+/// The returned RowRangeSet has offset 0, its ranges are the rows of the selected pages in the
+/// row group, and the contiguous pages are coalesced. A null page is selected only if the filter
+/// allows null. The pages of a physical type the filter can not check are always selected.
+pub fn prune_pages(
+    column_index: &ColumnIndex,
+    offset_index: &OffsetIndex,
+    physical_type: Type,
+    num_rows: usize,
+    filter: &dyn FixedLengthRangeFilter,
+) -> Result<RowRangeSet, BoltReaderError> {
+    if unlikely(column_index.num_pages() != offset_index.num_pages()) {
+        return Err(BoltReaderError::MetadataError(format!(
+            "Column index number of pages: {} is different from offset index number of pages: {}",
+            column_index.num_pages(),
+            offset_index.num_pages()
+        )));
+    }
+
+    let mut row_ranges: Vec<RowRange> = Vec::new();
+    for i in 0..column_index.num_pages() {
+        if !check_page(column_index, i, physical_type, filter)? {
+            continue;
+        }
+
+        let page_range = offset_index.get_page_row_range(i, num_rows)?;
+        match row_ranges.last_mut() {
+            Some(last) if last.end == page_range.begin => last.end = page_range.end,
+            _ => row_ranges.push(page_range),
+        }
+    }
+
+    let mut result = RowRangeSet::new(0);
+    for range in row_ranges {
+        if range.begin < range.end {
+            result.add_row_ranges(range.begin, range.end);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Select the rows of a column chunk that may satisfy the filter. No row is selected if the
+/// column chunk statistics exclude the filter, otherwise the pages are pruned with the page
+/// index, see prune_pages.
+///
+/// The statistics and the page index are optional, e.g. the older writers omit them. A column
+/// chunk without the statistics is never skipped, and all the rows are selected without the page
+/// index.
+pub fn prune_column_chunk(
+    file: &dyn LoadFile,
+    column: &ColumnChunkMetaData,
+    num_rows: usize,
+    filter: &dyn FixedLengthRangeFilter,
+) -> Result<RowRangeSet, BoltReaderError> {
+    let physical_type = column.physical_type();
+    if let Some(statistics) = column.statistics() {
+        if !check_statistics(statistics, physical_type, column.num_values(), filter) {
+            return Ok(RowRangeSet::new(0));
+        }
+    }
+
+    match (
+        column.column_index_offset(),
+        column.column_index_length(),
+        column.offset_index_offset(),
+        column.offset_index_length(),
+    ) {
+        (
+            Some(column_index_offset),
+            Some(column_index_length),
+            Some(offset_index_offset),
+            Some(offset_index_length),
+        ) => {
+            let column_index = read_column_index(file, column_index_offset, column_index_length)?;
+            let offset_index = read_offset_index(file, offset_index_offset, offset_index_length)?;
+            prune_pages(
+                &column_index,
+                &offset_index,
+                physical_type,
+                num_rows,
+                filter,
+            )
+        }
+        _ => {
+            let mut result = RowRangeSet::new(0);
+            if num_rows > 0 {
+                result.add_row_ranges(0, num_rows);
+            }
+            Ok(result)
+        }
+    }
+}
+
+// Return false only if the statistics exclude the filter. The statistics without the min or max
+// value, or can not be decoded as the physical type, can not exclude any value.
+fn check_statistics(
+    statistics: &Statistics,
+    physical_type: Type,
+    num_values: usize,
+    filter: &dyn FixedLengthRangeFilter,
+) -> bool {
+    let null_count = statistics.null_count();
+    if null_count.is_some_and(|null_count| num_values > 0 && null_count as usize == num_values) {
+        return filter.get_null_allowed();
+    }
+
+    // Without the null count, the column chunk may have nulls.
+    let has_null = null_count.is_none_or(|null_count| null_count > 0);
+    match physical_type {
+        Type::INT32 => check_range(statistics.min_i32(), statistics.max_i32(), |min, max| {
+            filter.check_range_i32(min, max, has_null)
+        }),
+        Type::INT64 => check_range(statistics.min_i64(), statistics.max_i64(), |min, max| {
+            filter.check_range_i64(min, max, has_null)
+        }),
+        Type::FLOAT => check_range(statistics.min_f32(), statistics.max_f32(), |min, max| {
+            filter.check_range_f32(min, max, has_null)
+        }),
+        Type::DOUBLE => check_range(statistics.min_f64(), statistics.max_f64(), |min, max| {
+            filter.check_range_f64(min, max, has_null)
+        }),
+        _ => true,
+    }
+}
+
+fn check_range<T>(
+    min: Result<Option<T>, BoltReaderError>,
+    max: Result<Option<T>, BoltReaderError>,
+    check: impl Fn(T, T) -> bool,
+) -> bool {
+    match (min, max) {
+        (Ok(Some(min)), Ok(Some(max))) => check(min, max),
+        _ => true,
+    }
+}
+
+fn check_page(
+    column_index: &ColumnIndex,
+    page_index: usize,
+    physical_type: Type,
+    filter: &dyn FixedLengthRangeFilter,
+) -> Result<bool, BoltReaderError> {
+    if column_index.null_pages()[page_index] {
+        return Ok(filter.get_null_allowed());
+    }
+
+    // Without the null counts, the page may have nulls.
+    let has_null = column_index
+        .null_counts()
+        .is_none_or(|null_counts| null_counts[page_index] > 0);
+    let min = &column_index.min_values()[page_index];
+    let max = &column_index.max_values()[page_index];
+    // The page without the min or max value can not be pruned.
+    if min.is_empty() || max.is_empty() {
+        return Ok(true);
+    }
+
+    match physical_type {
+        Type::INT32 => Ok(filter.check_range_i32(
+            decode(min, i32::from_le_bytes)?,
+            decode(max, i32::from_le_bytes)?,
+            has_null,
+        )),
+        Type::INT64 => Ok(filter.check_range_i64(
+            decode(min, i64::from_le_bytes)?,
+            decode(max, i64::from_le_bytes)?,
+            has_null,
+        )),
+        Type::FLOAT => Ok(filter.check_range_f32(
+            decode(min, f32::from_le_bytes)?,
+            decode(max, f32::from_le_bytes)?,
+            has_null,
+        )),
+        Type::DOUBLE => Ok(filter.check_range_f64(
+            decode(min, f64::from_le_bytes)?,
+            decode(max, f64::from_le_bytes)?,
+            has_null,
+        )),
+        _ => Ok(true),
+    }
+}
+
+fn decode<T, const N: usize>(
+    bytes: &[u8],
+    from_le_bytes: fn([u8; N]) -> T,
+) -> Result<T, BoltReaderError> {
+    match <[u8; N]>::try_from(bytes) {
+        Ok(bytes) => Ok(from_le_bytes(bytes)),
+        Err(_) => Err(BoltReaderError::MetadataError(format!(
+            "Invalid column index value size: {} bytes, expected: {} bytes",
+            bytes.len(),
+            N
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::filters::float_point_range_filter::FloatPointRangeFilter;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::filters::page_pruner::{prune_column_chunk, prune_pages};
+    use crate::metadata::column_index::{read_column_index, ColumnIndex};
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::offset_index::{read_offset_index, OffsetIndex};
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::metadata::parquet_metadata_thrift::{
+        BoundaryOrder, ColumnChunk, PageLocation, Type,
+    };
+    use crate::metadata::row_group::ColumnChunkMetaData;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::RowRangeSet;
+
+    fn load_page_index() -> (ColumnIndex, OffsetIndex, usize) {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let column = row_group.column(0).unwrap();
+
+        let column_index = read_column_index(
+            &file,
+            column.column_index_offset().unwrap(),
+            column.column_index_length().unwrap(),
+        )
+        .unwrap();
+        let offset_index = read_offset_index(
+            &file,
+            column.offset_index_offset().unwrap(),
+            column.offset_index_length().unwrap(),
+        )
+        .unwrap();
+
+        (column_index, offset_index, row_group.num_rows() as usize)
+    }
+
+    fn get_ranges(row_range_set: &RowRangeSet) -> Vec<(usize, usize)> {
+        assert_eq!(row_range_set.get_offset(), 0);
+        row_range_set
+            .get_row_ranges()
+            .iter()
+            .map(|range| (range.begin, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_prune_pages_greater_than() {
+        // The pages are [0, 498], [1001, 1249] with nulls, all nulls and [-249, 0].
+        let (column_index, offset_index, num_rows) = load_page_index();
+
+        // col > 100, the first page overlaps the predicate and the last page is below it.
+        let filter = IntegerRangeFilter::new(101, i128::MAX, false);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, num_rows, &filter);
+        assert!(res.is_ok());
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 500)]);
+
+        // col > 498, the first page is entirely below the threshold.
+        let filter = IntegerRangeFilter::new(499, i128::MAX, false);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, num_rows, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(250, 500)]);
+
+        // col > 1249 selects no page.
+        let filter = IntegerRangeFilter::new(1250, i128::MAX, false);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, num_rows, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![]);
+
+        // The pages with nulls are selected if null is allowed.
+        let filter = IntegerRangeFilter::new(1250, i128::MAX, true);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, num_rows, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(250, 750)]);
+
+        // col <= 0 selects the first and the last pages.
+        let filter = IntegerRangeFilter::new(i128::MIN, 0, false);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, num_rows, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 250), (750, 1000)]);
+    }
+
+    #[test]
+    fn test_prune_double_pages() {
+        let values: Vec<(f64, f64)> = vec![(0.0, 10.0), (10.0, 20.0), (20.0, 30.0)];
+        let column_index = ColumnIndex::from_thrift(parquet_metadata_thrift::ColumnIndex::new(
+            vec![false; 3],
+            values.iter().map(|v| v.0.to_le_bytes().to_vec()).collect(),
+            values.iter().map(|v| v.1.to_le_bytes().to_vec()).collect(),
+            BoundaryOrder::ASCENDING,
+            None,
+        ))
+        .unwrap();
+        let offset_index = OffsetIndex::from_thrift(parquet_metadata_thrift::OffsetIndex::new(
+            (0..3)
+                .map(|i| PageLocation::new(i * 100, 100, i * 10))
+                .collect(),
+        ))
+        .unwrap();
+
+        // col > 10.0, the page with max value 10.0 can not satisfy the predicate.
+        let filter = FloatPointRangeFilter::new(10.0, 0.0, true, false, false, false, false);
+        let res = prune_pages(&column_index, &offset_index, Type::DOUBLE, 25, &filter);
+        assert!(res.is_ok());
+        assert_eq!(get_ranges(&res.unwrap()), vec![(10, 25)]);
+
+        // The pages of BYTE_ARRAY are not checked.
+        let res = prune_pages(&column_index, &offset_index, Type::BYTE_ARRAY, 25, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 25)]);
+
+        // The min and max values of an INT32 column should be 4 bytes.
+        let res = prune_pages(&column_index, &offset_index, Type::INT32, 25, &filter);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Invalid column index value size: 8 bytes, expected: 4 bytes\n"
+        );
+
+        let offset_index =
+            OffsetIndex::from_thrift(parquet_metadata_thrift::OffsetIndex::new(vec![
+                PageLocation::new(0, 100, 0),
+            ]))
+            .unwrap();
+        let res = prune_pages(&column_index, &offset_index, Type::DOUBLE, 25, &filter);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_prune_column_chunk_without_statistics() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        assert!(column.statistics().is_some());
+
+        // Rebuild the column chunk with the statistics, and with or without the page index.
+        let create_column = |statistics: Option<parquet_metadata_thrift::Statistics>,
+                             with_page_index: bool| {
+            let mut metadata = column.get_thrift_metadata().clone();
+            metadata.statistics = statistics;
+            let page_index = |offset: Option<usize>| offset.filter(|_| with_page_index);
+            ColumnChunkMetaData::from_thrift(ColumnChunk::new(
+                None::<String>,
+                column.file_offset() as i64,
+                metadata,
+                page_index(column.offset_index_offset()).map(|v| v as i64),
+                page_index(column.offset_index_length()).map(|v| v as i32),
+                page_index(column.column_index_offset()).map(|v| v as i64),
+                page_index(column.column_index_length()).map(|v| v as i32),
+                None,
+                None::<Vec<u8>>,
+            ))
+            .unwrap()
+        };
+
+        // col > 1249, no row can satisfy the filter according to the statistics.
+        let filter = IntegerRangeFilter::new(1250, i128::MAX, false);
+        let res = prune_column_chunk(&file, column, 1000, &filter);
+        assert!(res.is_ok());
+        assert_eq!(get_ranges(&res.unwrap()), vec![]);
+
+        // Without the statistics and the page index, all the rows are selected.
+        let res = prune_column_chunk(&file, &create_column(None, false), 1000, &filter);
+        assert!(res.is_ok());
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 1000)]);
+
+        // Without the statistics, the pages are still pruned with the page index.
+        let res = prune_column_chunk(&file, &create_column(None, true), 1000, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![]);
+        let filter = IntegerRangeFilter::new(499, i128::MAX, false);
+        let res = prune_column_chunk(&file, &create_column(None, true), 1000, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(250, 500)]);
+
+        // The statistics without the min and max values, or of the wrong size, skip nothing.
+        let filter = IntegerRangeFilter::new(1250, i128::MAX, false);
+        let statistics =
+            parquet_metadata_thrift::Statistics::new(None, None, Some(300), None, None, None);
+        let res = prune_column_chunk(
+            &file,
+            &create_column(Some(statistics), false),
+            1000,
+            &filter,
+        );
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 1000)]);
+        let statistics = parquet_metadata_thrift::Statistics::new(
+            None,
+            None,
+            Some(300),
+            None,
+            vec![0u8; 4],
+            vec![0u8; 4],
+        );
+        let res = prune_column_chunk(
+            &file,
+            &create_column(Some(statistics), false),
+            1000,
+            &filter,
+        );
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 1000)]);
+
+        // All the values are null.
+        let statistics =
+            parquet_metadata_thrift::Statistics::new(None, None, Some(1000), None, None, None);
+        let column = create_column(Some(statistics), false);
+        let res = prune_column_chunk(&file, &column, 1000, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![]);
+        let filter = IntegerRangeFilter::new(1250, i128::MAX, true);
+        let res = prune_column_chunk(&file, &column, 1000, &filter);
+        assert_eq!(get_ranges(&res.unwrap()), vec![(0, 1000)]);
+    }
+
+    #[test]
+    fn test_prune_pages_without_min_max() {
+        // The second page has no min and max values.
+        let column_index = ColumnIndex::from_thrift(parquet_metadata_thrift::ColumnIndex::new(
+            vec![false; 3],
+            vec![
+                0i64.to_le_bytes().to_vec(),
+                vec![],
+                20i64.to_le_bytes().to_vec(),
+            ],
+            vec![
+                9i64.to_le_bytes().to_vec(),
+                vec![],
+                29i64.to_le_bytes().to_vec(),
+            ],
+            BoundaryOrder::UNORDERED,
+            None,
+        ))
+        .unwrap();
+        let offset_index = OffsetIndex::from_thrift(parquet_metadata_thrift::OffsetIndex::new(
+            (0..3)
+                .map(|i| PageLocation::new(i * 100, 100, i * 10))
+                .collect(),
+        ))
+        .unwrap();
+
+        let filter = IntegerRangeFilter::new(100, i128::MAX, false);
+        let res = prune_pages(&column_index, &offset_index, Type::INT64, 30, &filter);
+        assert!(res.is_ok());
+        assert_eq!(get_ranges(&res.unwrap()), vec![(10, 20)]);
+    }
+}
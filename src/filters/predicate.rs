@@ -0,0 +1,333 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::statistics::Statistics;
+use crate::utils::exceptions::BoltReaderError;
+
+/// The typed literal of a comparison, which should match the physical type of the column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Literal {
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Int32(value) => write!(f, "{}", value),
+            Literal::Int64(value) => write!(f, "{}", value),
+            Literal::Float(value) => write!(f, "{}", value),
+            Literal::Double(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The predicate to skip the row groups or the pages with the min/max statistics. The usize of
+/// a comparison is the index of the column in the schema.
+pub enum Predicate {
+    Eq(usize, Literal),
+    Lt(usize, Literal),
+    Gt(usize, Literal),
+    LtEq(usize, Literal),
+    GtEq(usize, Literal),
+    IsNull(usize),
+    IsNotNull(usize),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Predicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Predicate::Eq(column, value) => write!(f, "column {} = {}", column, value),
+            Predicate::Lt(column, value) => write!(f, "column {} < {}", column, value),
+            Predicate::Gt(column, value) => write!(f, "column {} > {}", column, value),
+            Predicate::LtEq(column, value) => write!(f, "column {} <= {}", column, value),
+            Predicate::GtEq(column, value) => write!(f, "column {} >= {}", column, value),
+            Predicate::IsNull(column) => write!(f, "column {} IS NULL", column),
+            Predicate::IsNotNull(column) => write!(f, "column {} IS NOT NULL", column),
+            Predicate::And(left, right) => write!(f, "({} AND {})", left, right),
+            Predicate::Or(left, right) => write!(f, "({} OR {})", left, right),
+        }
+    }
+}
+
+impl Predicate {
+    /// Return true if none of the num_rows rows can satisfy the predicate, according to the
+    /// statistics of each column, where statistics[i] is the statistics of column i.
+    ///
+    /// It is conservative, the comparison is not skipped if the statistics of the column are
+    /// missing, have no min or max value, or can not be decoded as the type of the literal. The
+    /// null checks are not skipped without the null count.
+    pub fn can_skip(&self, statistics: &[Option<&Statistics>], num_rows: usize) -> bool {
+        match self {
+            Predicate::And(left, right) => {
+                left.can_skip(statistics, num_rows) || right.can_skip(statistics, num_rows)
+            }
+            Predicate::Or(left, right) => {
+                left.can_skip(statistics, num_rows) && right.can_skip(statistics, num_rows)
+            }
+            Predicate::IsNull(column) => {
+                get_null_count(statistics, *column).is_some_and(|null_count| null_count == 0)
+            }
+            Predicate::IsNotNull(column) => get_null_count(statistics, *column)
+                .is_some_and(|null_count| null_count as usize == num_rows),
+            Predicate::Eq(column, value)
+            | Predicate::Lt(column, value)
+            | Predicate::Gt(column, value)
+            | Predicate::LtEq(column, value)
+            | Predicate::GtEq(column, value) => match statistics.get(*column) {
+                Some(Some(column_statistics)) => self
+                    .can_skip_comparison(column_statistics, value)
+                    .unwrap_or(false),
+                _ => false,
+            },
+        }
+    }
+
+    fn can_skip_comparison(
+        &self,
+        statistics: &Statistics,
+        value: &Literal,
+    ) -> Result<bool, BoltReaderError> {
+        let res = match value {
+            Literal::Int32(value) => {
+                self.can_skip_range(statistics.min_i32()?, statistics.max_i32()?, *value)
+            }
+            Literal::Int64(value) => {
+                self.can_skip_range(statistics.min_i64()?, statistics.max_i64()?, *value)
+            }
+            Literal::Float(value) => {
+                self.can_skip_range(statistics.min_f32()?, statistics.max_f32()?, *value)
+            }
+            Literal::Double(value) => {
+                self.can_skip_range(statistics.min_f64()?, statistics.max_f64()?, *value)
+            }
+        };
+
+        Ok(res)
+    }
+
+    // The comparisons with NaN are false, so a NaN min, max or literal never skips.
+    fn can_skip_range<T: PartialOrd>(&self, min: Option<T>, max: Option<T>, value: T) -> bool {
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return false,
+        };
+
+        match self {
+            Predicate::Eq(..) => value < min || value > max,
+            Predicate::Lt(..) => min >= value,
+            Predicate::Gt(..) => max <= value,
+            Predicate::LtEq(..) => min > value,
+            Predicate::GtEq(..) => max < value,
+            _ => false,
+        }
+    }
+}
+
+fn get_null_count(statistics: &[Option<&Statistics>], column: usize) -> Option<i64> {
+    match statistics.get(column) {
+        Some(Some(column_statistics)) => column_statistics.null_count(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::filters::predicate::{Literal, Predicate};
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::metadata::parquet_metadata_thrift::Type;
+    use crate::metadata::statistics::Statistics;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    fn create_statistics(min: i64, max: i64) -> Statistics {
+        let thrift_statistics = parquet_metadata_thrift::Statistics::new(
+            None,
+            None,
+            Some(0),
+            None,
+            Some(max.to_le_bytes().to_vec()),
+            Some(min.to_le_bytes().to_vec()),
+        );
+        Statistics::from_thrift(&thrift_statistics, Type::INT64)
+    }
+
+    #[test]
+    fn test_comparison_can_skip() {
+        let statistics = create_statistics(10, 20);
+        let statistics = vec![Some(&statistics)];
+
+        let can_skip = |predicate: Predicate| predicate.can_skip(&statistics, 100);
+        assert!(can_skip(Predicate::Eq(0, Literal::Int64(9))));
+        assert!(!can_skip(Predicate::Eq(0, Literal::Int64(10))));
+        assert!(!can_skip(Predicate::Eq(0, Literal::Int64(20))));
+        assert!(can_skip(Predicate::Eq(0, Literal::Int64(21))));
+
+        assert!(can_skip(Predicate::Lt(0, Literal::Int64(10))));
+        assert!(!can_skip(Predicate::Lt(0, Literal::Int64(11))));
+        assert!(can_skip(Predicate::LtEq(0, Literal::Int64(9))));
+        assert!(!can_skip(Predicate::LtEq(0, Literal::Int64(10))));
+
+        assert!(can_skip(Predicate::Gt(0, Literal::Int64(20))));
+        assert!(!can_skip(Predicate::Gt(0, Literal::Int64(19))));
+        assert!(can_skip(Predicate::GtEq(0, Literal::Int64(21))));
+        assert!(!can_skip(Predicate::GtEq(0, Literal::Int64(20))));
+
+        // The statistics are missing or can not be decoded as the literal type.
+        assert!(!can_skip(Predicate::Gt(1, Literal::Int64(100))));
+        assert!(!can_skip(Predicate::Gt(0, Literal::Int32(100))));
+        assert!(!Predicate::Gt(0, Literal::Int64(100)).can_skip(&[None], 100));
+
+        let thrift_statistics =
+            parquet_metadata_thrift::Statistics::new(None, None, Some(5), None, None, None);
+        let statistics = Statistics::from_thrift(&thrift_statistics, Type::INT64);
+        assert!(!Predicate::Gt(0, Literal::Int64(100)).can_skip(&[Some(&statistics)], 100));
+    }
+
+    #[test]
+    fn test_compound_predicate_can_skip() {
+        let first = create_statistics(10, 20);
+        let second = create_statistics(100, 200);
+        let statistics = vec![Some(&first), Some(&second)];
+
+        // column 0 > 15 AND column 1 < 100
+        let predicate = Predicate::And(
+            Box::new(Predicate::Gt(0, Literal::Int64(15))),
+            Box::new(Predicate::Lt(1, Literal::Int64(100))),
+        );
+        assert!(predicate.can_skip(&statistics, 100));
+        assert_eq!(predicate.to_string(), "(column 0 > 15 AND column 1 < 100)");
+
+        // column 0 > 15 AND column 1 <= 100
+        let predicate = Predicate::And(
+            Box::new(Predicate::Gt(0, Literal::Int64(15))),
+            Box::new(Predicate::LtEq(1, Literal::Int64(100))),
+        );
+        assert!(!predicate.can_skip(&statistics, 100));
+
+        // column 0 = 5 OR column 1 >= 300
+        let predicate = Predicate::Or(
+            Box::new(Predicate::Eq(0, Literal::Int64(5))),
+            Box::new(Predicate::GtEq(1, Literal::Int64(300))),
+        );
+        assert!(predicate.can_skip(&statistics, 100));
+
+        // (column 0 = 5 OR column 1 >= 300) OR column 2 = 0, column 2 has no statistics.
+        let predicate = Predicate::Or(
+            Box::new(predicate),
+            Box::new(Predicate::Eq(2, Literal::Int64(0))),
+        );
+        assert!(!predicate.can_skip(&statistics, 100));
+
+        // (column 0 = 5 OR column 1 >= 300) AND column 2 = 0
+        let predicate = Predicate::And(
+            Box::new(Predicate::Or(
+                Box::new(Predicate::Eq(0, Literal::Int64(5))),
+                Box::new(Predicate::GtEq(1, Literal::Int64(300))),
+            )),
+            Box::new(Predicate::Eq(2, Literal::Int64(0))),
+        );
+        assert!(predicate.can_skip(&statistics, 100));
+    }
+
+    #[test]
+    fn test_null_predicate_can_skip() {
+        // The all-null column chunk has no min and max values.
+        let thrift_statistics =
+            parquet_metadata_thrift::Statistics::new(None, None, Some(100), None, None, None);
+        let all_null = Statistics::from_thrift(&thrift_statistics, Type::INT64);
+        let all_present = create_statistics(10, 20);
+        let statistics = vec![Some(&all_null), Some(&all_present)];
+
+        assert!(Predicate::IsNotNull(0).can_skip(&statistics, 100));
+        assert!(!Predicate::IsNull(0).can_skip(&statistics, 100));
+        assert!(!Predicate::IsNotNull(1).can_skip(&statistics, 100));
+        assert!(Predicate::IsNull(1).can_skip(&statistics, 100));
+
+        // Some of the rows are not null.
+        assert!(!Predicate::IsNotNull(0).can_skip(&statistics, 101));
+
+        // Without the null count, the null checks are not skipped.
+        let thrift_statistics = parquet_metadata_thrift::Statistics::new(
+            None,
+            None,
+            None,
+            None,
+            Some(20i64.to_le_bytes().to_vec()),
+            Some(10i64.to_le_bytes().to_vec()),
+        );
+        let unknown_nulls = Statistics::from_thrift(&thrift_statistics, Type::INT64);
+        assert!(!Predicate::IsNull(0).can_skip(&[Some(&unknown_nulls)], 100));
+        assert!(!Predicate::IsNotNull(0).can_skip(&[Some(&unknown_nulls)], 100));
+        assert!(!Predicate::IsNull(0).can_skip(&[None], 100));
+
+        // column 0 IS NULL AND column 1 IS NULL
+        let predicate = Predicate::And(
+            Box::new(Predicate::IsNull(0)),
+            Box::new(Predicate::IsNull(1)),
+        );
+        assert!(predicate.can_skip(&statistics, 100));
+        assert_eq!(
+            predicate.to_string(),
+            "(column 0 IS NULL AND column 1 IS NULL)"
+        );
+
+        // column 0 IS NOT NULL OR column 1 > 15
+        let predicate = Predicate::Or(
+            Box::new(Predicate::IsNotNull(0)),
+            Box::new(Predicate::Gt(1, Literal::Int64(15))),
+        );
+        assert!(!predicate.can_skip(&statistics, 100));
+    }
+
+    #[test]
+    fn test_predicate_can_skip_row_group() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let statistics: Vec<Option<&Statistics>> = (0..row_group.num_columns())
+            .map(|i| row_group.column(i).unwrap().statistics())
+            .collect();
+        let num_rows = row_group.num_rows() as usize;
+
+        // The quantity is in [1.0, 50.0] and the linenumber is in [1, 197].
+        let predicate = Predicate::Or(
+            Box::new(Predicate::Gt(4, Literal::Double(50.0))),
+            Box::new(Predicate::Lt(3, Literal::Int32(1))),
+        );
+        assert!(predicate.can_skip(&statistics, num_rows));
+
+        let predicate = Predicate::And(
+            Box::new(Predicate::GtEq(4, Literal::Double(50.0))),
+            Box::new(Predicate::Eq(3, Literal::Int32(197))),
+        );
+        assert!(!predicate.can_skip(&statistics, num_rows));
+
+        assert!(!Predicate::Gt(4, Literal::Double(f64::NAN)).can_skip(&statistics, num_rows));
+
+        // The quantity has no null.
+        assert!(Predicate::IsNull(4).can_skip(&statistics, num_rows));
+        assert!(!Predicate::IsNotNull(4).can_skip(&statistics, num_rows));
+    }
+}
@@ -15,9 +15,9 @@
 
 #![deny(warnings)]
 #![feature(portable_simd)]
-#![feature(core_intrinsics)]
 pub mod bridge;
 pub mod filters;
 pub mod metadata;
 pub mod page_reader;
+pub mod reader;
 pub mod utils;
@@ -0,0 +1,142 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_ipc::convert::fb_to_schema;
+use arrow_ipc::root_as_message;
+use arrow_schema::Schema;
+
+use crate::metadata::file_metadata::FileMetaData;
+use crate::utils::encoding::base64::decode_base64;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The key of the Arrow schema in the key-value metadata, written by the Arrow writers.
+pub const ARROW_SCHEMA_KEY: &str = "ARROW:schema";
+
+// The IPC message starts with the continuation marker and the message length, while the legacy
+// format before Arrow 0.15 has the message length only.
+const IPC_CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+const IPC_MESSAGE_LENGTH_SIZE: usize = 4;
+
+impl FileMetaData {
+    /// Decode the Arrow schema embedded by the Arrow writers, or None if the footer has no
+    /// ARROW:schema. The schema keeps the original Arrow types, e.g. Int8 for an INT32 column,
+    /// or the dictionary types, which can not be told from the Parquet logical types.
+    pub fn get_arrow_schema(&self) -> Result<Option<Schema>, BoltReaderError> {
+        match self.get_key_value(ARROW_SCHEMA_KEY) {
+            Some(encoded) => decode_arrow_schema(encoded).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Decode the base64 encoded Arrow IPC schema message.
+pub fn decode_arrow_schema(encoded: &str) -> Result<Schema, BoltReaderError> {
+    let bytes = decode_base64(encoded)?;
+    let begin = if bytes.starts_with(&IPC_CONTINUATION_MARKER) {
+        IPC_CONTINUATION_MARKER.len() + IPC_MESSAGE_LENGTH_SIZE
+    } else {
+        IPC_MESSAGE_LENGTH_SIZE
+    };
+    if unlikely(bytes.len() <= begin) {
+        return Err(BoltReaderError::FileMetadataError(format!(
+            "Arrow schema message of {} bytes is truncated",
+            bytes.len()
+        )));
+    }
+
+    let message = root_as_message(&bytes[begin..]).map_err(|err| {
+        BoltReaderError::FileMetadataError(format!(
+            "Unable to parse the Arrow schema message: {}",
+            err
+        ))
+    })?;
+    match message.header_as_schema() {
+        Some(schema) => Ok(fb_to_schema(schema)),
+        None => Err(BoltReaderError::FileMetadataError(String::from(
+            "The Arrow IPC message of ARROW:schema is not a schema",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::DataType;
+
+    use crate::metadata::arrow_schema::decode_arrow_schema;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_arrow_schema() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+
+        let res = file_metadata.get_arrow_schema();
+        assert!(res.is_ok());
+        let schema = res.unwrap().unwrap();
+        assert_eq!(schema.fields().len(), 22);
+
+        let expected = [
+            ("orderkey", DataType::Int64),
+            ("partkey", DataType::Int64),
+            ("suppkey", DataType::Int64),
+            ("linenumber", DataType::Int32),
+            ("quantity", DataType::Float64),
+            ("extendedprice", DataType::Float64),
+            ("discount", DataType::Float64),
+            ("tax", DataType::Float64),
+            ("returnflag", DataType::Utf8),
+            ("linestatus", DataType::Utf8),
+            ("shipdate", DataType::Utf8),
+            ("commitdate", DataType::Utf8),
+            ("receiptdate", DataType::Utf8),
+            ("shipinstruct", DataType::Utf8),
+            ("shipmode", DataType::Utf8),
+            ("comment", DataType::Utf8),
+            ("is_open", DataType::Boolean),
+            ("is_returned", DataType::Boolean),
+            ("tax_as_real", DataType::Float32),
+            ("discount_as_real", DataType::Float32),
+            // The INT32 columns of the Parquet schema.
+            ("linenumber_as_smallint", DataType::Int16),
+            ("linenumber_as_tinyint", DataType::Int8),
+        ];
+        for (field, (name, data_type)) in schema.fields().iter().zip(expected.iter()) {
+            assert_eq!(field.name(), name);
+            assert_eq!(field.data_type(), data_type);
+        }
+
+        // The files written without Arrow have no Arrow schema.
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        assert!(file_metadata.get_arrow_schema().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_invalid_arrow_schema() {
+        let res = decode_arrow_schema("/////w==");
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "File Metadata Error: Arrow schema message of 4 bytes is truncated\n"
+        );
+
+        assert!(decode_arrow_schema("not base64").is_err());
+        assert!(decode_arrow_schema("/////wgAAAAAAAAAAAAAAA==").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::min;
+use std::fmt::Formatter;
+
+use thrift::protocol::{TCompactInputProtocol, TSerializable};
+use xxhash_rust::xxh64::xxh64;
+
+use crate::metadata::parquet_metadata_thrift::BloomFilterHeader;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+// The Thrift BloomFilterHeader is a few bytes only, it is loaded together with the beginning of
+// the bitset, so that the header size does not need to be known in advance.
+const BLOOM_FILTER_HEADER_SIZE_ESTIMATE: usize = 32;
+const BLOOM_FILTER_BLOCK_SIZE: usize = 32;
+const BLOOM_FILTER_HASH_SEED: u64 = 0;
+const BLOOM_FILTER_SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// The split block Bloom filter of a column chunk. Each block has 256 bits, as 8 little-endian
+/// u32 words, and a value sets 1 bit in each word of the block selected by its hash.
+pub struct BloomFilter {
+    bitset: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for BloomFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Bloom Filter: num_bytes {}, num_blocks {}",
+            self.bitset.len(),
+            self.bitset.len() / BLOOM_FILTER_BLOCK_SIZE
+        )
+    }
+}
+
+impl BloomFilter {
+    pub fn new(bitset: Vec<u8>) -> Result<BloomFilter, BoltReaderError> {
+        if unlikely(bitset.is_empty() || !bitset.len().is_multiple_of(BLOOM_FILTER_BLOCK_SIZE)) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Bloom filter size: {} bytes should be a positive multiple of the block size: {} bytes",
+                bitset.len(),
+                BLOOM_FILTER_BLOCK_SIZE
+            )));
+        }
+
+        Ok(BloomFilter { bitset })
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        self.bitset.len()
+    }
+
+    /// Return false if the value of the hash is definitely not in the column chunk. The hash is
+    /// computed by hash_bytes on the PLAIN encoded value.
+    pub fn check(&self, hash: u64) -> bool {
+        let num_blocks = (self.bitset.len() / BLOOM_FILTER_BLOCK_SIZE) as u64;
+        let block_index = (((hash >> 32) * num_blocks) >> 32) as usize;
+        let block = &self.bitset
+            [block_index * BLOOM_FILTER_BLOCK_SIZE..(block_index + 1) * BLOOM_FILTER_BLOCK_SIZE];
+
+        let key = hash as u32;
+        for (i, salt) in BLOOM_FILTER_SALT.iter().enumerate() {
+            let word = u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+            let mask = 1u32 << (key.wrapping_mul(*salt) >> 27);
+            if word & mask == 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// The XXH64 hash of the PLAIN encoded value, e.g. the 8 little-endian bytes of an INT64 or the
+/// bytes of a BYTE_ARRAY without the length prefix.
+pub fn hash_bytes(value: &[u8]) -> u64 {
+    xxh64(value, BLOOM_FILTER_HASH_SEED)
+}
+
+/// Load the BloomFilterHeader and the bitset at the bloom_filter_offset of the column chunk
+/// metadata.
+pub fn read_bloom_filter(
+    file: &dyn LoadFile,
+    bloom_filter_offset: usize,
+) -> Result<BloomFilter, BoltReaderError> {
+    let file_size = file.get_file_size();
+    if unlikely(bloom_filter_offset >= file_size) {
+        return Err(BoltReaderError::MetadataError(format!(
+            "Bloom filter offset: {} exceeds the file size: {} bytes",
+            bloom_filter_offset, file_size
+        )));
+    }
+
+    let buffer = file.load_file_to_buffer(
+        bloom_filter_offset,
+        min(
+            BLOOM_FILTER_HEADER_SIZE_ESTIMATE,
+            file_size - bloom_filter_offset,
+        ),
+    )?;
+    let bytes = buffer.as_bytes();
+
+    let mut remaining = bytes;
+    let header = {
+        let mut protocol = TCompactInputProtocol::new(&mut remaining);
+        BloomFilterHeader::read_from_in_protocol(&mut protocol).map_err(|err| {
+            BoltReaderError::MetadataError(format!(
+                "Unable to deserialize Thrift BloomFilterHeader: {}",
+                err
+            ))
+        })?
+    };
+    let header_size = bytes.len() - remaining.len();
+
+    if unlikely(header.num_bytes < 0) {
+        return Err(BoltReaderError::MetadataError(format!(
+            "Bloom filter has negative size: {} bytes",
+            header.num_bytes
+        )));
+    }
+
+    let num_bytes = header.num_bytes as usize;
+    let bitset = if remaining.len() >= num_bytes {
+        remaining[..num_bytes].to_vec()
+    } else {
+        file.load_file_to_buffer(bloom_filter_offset + header_size, num_bytes)?
+            .into_vec()
+    };
+
+    BloomFilter::new(bitset)
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::bloom_filter::{hash_native, insert};
+    use thrift::protocol::{TCompactOutputProtocol, TSerializable};
+
+    use crate::metadata::bloom_filter::{hash_bytes, read_bloom_filter, BloomFilter};
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift::{
+        BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+        SplitBlockAlgorithm, Uncompressed, XxHash,
+    };
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::test_utils::InMemoryFile;
+
+    // The bloom filter is written by parquet2 after 100 bytes of padding.
+    fn create_bloom_filter_file(values: &[i64], num_bytes: usize) -> InMemoryFile {
+        let mut bitset = vec![0u8; num_bytes];
+        for value in values {
+            insert(&mut bitset, hash_native(*value));
+        }
+
+        let header = BloomFilterHeader::new(
+            num_bytes as i32,
+            BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm::new()),
+            BloomFilterHash::XXHASH(XxHash::new()),
+            BloomFilterCompression::UNCOMPRESSED(Uncompressed::new()),
+        );
+        let mut data = vec![0u8; 100];
+        let mut protocol = TCompactOutputProtocol::new(&mut data);
+        header.write_to_out_protocol(&mut protocol).unwrap();
+        data.extend_from_slice(&bitset);
+
+        InMemoryFile {
+            path: String::from("bloom_filter"),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_read_bloom_filter() {
+        let values: Vec<i64> = (0..1000).map(|i| i * 3).collect();
+        let file = create_bloom_filter_file(&values, 1024);
+
+        let res = read_bloom_filter(&file, 100);
+        assert!(res.is_ok());
+        let bloom_filter = res.unwrap();
+        assert_eq!(bloom_filter.num_bytes(), 1024);
+        assert_eq!(
+            bloom_filter.to_string(),
+            "Bloom Filter: num_bytes 1024, num_blocks 32\n"
+        );
+
+        // No false negatives.
+        for value in &values {
+            assert!(bloom_filter.check(hash_bytes(&value.to_le_bytes())));
+        }
+
+        // 1000 values in 8192 bits, most of the absent values are filtered out.
+        let false_positives = (0..1000i64)
+            .map(|i| i * 3 + 1)
+            .filter(|value| bloom_filter.check(hash_bytes(&value.to_le_bytes())))
+            .count();
+        assert!(false_positives < 100);
+        assert!(!bloom_filter.check(hash_bytes(&(-1i64).to_le_bytes())));
+        assert!(!bloom_filter.check(hash_bytes(&1_000_000i64.to_le_bytes())));
+    }
+
+    #[test]
+    fn test_read_single_value_bloom_filter() {
+        // The bitset is smaller than the header size estimate.
+        let file = create_bloom_filter_file(&[42], 32);
+        let bloom_filter = read_bloom_filter(&file, 100).unwrap();
+        assert_eq!(bloom_filter.num_bytes(), 32);
+        assert!(bloom_filter.check(hash_bytes(&42i64.to_le_bytes())));
+        assert!(!bloom_filter.check(hash_bytes(&43i64.to_le_bytes())));
+        assert!(!bloom_filter.check(hash_bytes(&0i64.to_le_bytes())));
+    }
+
+    #[test]
+    fn test_invalid_bloom_filter() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        assert_eq!(column.bloom_filter_offset(), None);
+
+        // The magic code is not a valid BloomFilterHeader.
+        assert!(read_bloom_filter(&file, 0).is_err());
+
+        let res = read_bloom_filter(&file, file.get_file_size());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Bloom filter offset: 2065982 exceeds the file size: 2065982 bytes\n"
+        );
+
+        let res = BloomFilter::new(vec![0u8; 48]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Bloom filter size: 48 bytes should be a positive multiple of the block size: 32 bytes\n"
+        );
+    }
+}
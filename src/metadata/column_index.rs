@@ -0,0 +1,206 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use thrift::protocol::{TCompactInputProtocol, TSerializable};
+
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::BoundaryOrder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+/// The page level statistics of a column chunk. The i-th entry of each list refers to the i-th
+/// data page. The min and max values of a null page are empty and should not be used.
+pub struct ColumnIndex {
+    null_pages: Vec<bool>,
+    min_values: Vec<Vec<u8>>,
+    max_values: Vec<Vec<u8>>,
+    boundary_order: BoundaryOrder,
+    null_counts: Option<Vec<i64>>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ColumnIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Column Index: num_pages {}, null_pages {}, boundary_order {}",
+            self.num_pages(),
+            self.null_pages
+                .iter()
+                .filter(|null_page| **null_page)
+                .count(),
+            self.boundary_order.0
+        )
+    }
+}
+
+impl ColumnIndex {
+    pub fn from_thrift(
+        column_index: parquet_metadata_thrift::ColumnIndex,
+    ) -> Result<ColumnIndex, BoltReaderError> {
+        let num_pages = column_index.null_pages.len();
+        let null_counts_len = column_index
+            .null_counts
+            .as_ref()
+            .map_or(num_pages, |null_counts| null_counts.len());
+        if unlikely(
+            column_index.min_values.len() != num_pages
+                || column_index.max_values.len() != num_pages
+                || null_counts_len != num_pages,
+        ) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Column index has inconsistent number of pages, null_pages: {}, min_values: {}, max_values: {}, null_counts: {}",
+                num_pages,
+                column_index.min_values.len(),
+                column_index.max_values.len(),
+                null_counts_len
+            )));
+        }
+
+        Ok(ColumnIndex {
+            null_pages: column_index.null_pages,
+            min_values: column_index.min_values,
+            max_values: column_index.max_values,
+            boundary_order: column_index.boundary_order,
+            null_counts: column_index.null_counts,
+        })
+    }
+
+    pub fn num_pages(&self) -> usize {
+        self.null_pages.len()
+    }
+
+    pub fn null_pages(&self) -> &Vec<bool> {
+        &self.null_pages
+    }
+
+    pub fn min_values(&self) -> &Vec<Vec<u8>> {
+        &self.min_values
+    }
+
+    pub fn max_values(&self) -> &Vec<Vec<u8>> {
+        &self.max_values
+    }
+
+    pub fn boundary_order(&self) -> BoundaryOrder {
+        self.boundary_order
+    }
+
+    pub fn null_counts(&self) -> Option<&Vec<i64>> {
+        self.null_counts.as_ref()
+    }
+}
+
+/// Load and deserialize the Thrift ColumnIndex located by column_index_offset and
+/// column_index_length of the column chunk metadata.
+pub fn read_column_index(
+    file: &dyn LoadFile,
+    column_index_offset: usize,
+    column_index_length: usize,
+) -> Result<ColumnIndex, BoltReaderError> {
+    let buffer = file.load_file_to_buffer(column_index_offset, column_index_length)?;
+
+    let mut protocol = TCompactInputProtocol::new(buffer.as_bytes());
+    let column_index = parquet_metadata_thrift::ColumnIndex::read_from_in_protocol(&mut protocol)
+        .map_err(|err| {
+        BoltReaderError::MetadataError(format!("Unable to deserialize Thrift ColumnIndex: {}", err))
+    })?;
+
+    ColumnIndex::from_thrift(column_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::column_index::{read_column_index, ColumnIndex};
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::metadata::parquet_metadata_thrift::BoundaryOrder;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_column_index() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        let res = read_column_index(
+            &file,
+            column.column_index_offset().unwrap(),
+            column.column_index_length().unwrap(),
+        );
+        assert!(res.is_ok());
+        let column_index = res.unwrap();
+
+        // The column chunk has 4 data pages with 250 values each, the third page is all nulls.
+        assert_eq!(column_index.num_pages(), 4);
+        assert_eq!(column_index.null_pages(), &vec![false, false, true, false]);
+        assert_eq!(column_index.null_counts(), Some(&vec![0, 50, 250, 0]));
+        assert_eq!(column_index.boundary_order(), BoundaryOrder::UNORDERED);
+
+        let min_values: Vec<i64> = column_index
+            .min_values()
+            .iter()
+            .zip(column_index.null_pages())
+            .filter(|(_, null_page)| !**null_page)
+            .map(|(value, _)| i64::from_le_bytes(value[..].try_into().unwrap()))
+            .collect();
+        let max_values: Vec<i64> = column_index
+            .max_values()
+            .iter()
+            .zip(column_index.null_pages())
+            .filter(|(_, null_page)| !**null_page)
+            .map(|(value, _)| i64::from_le_bytes(value[..].try_into().unwrap()))
+            .collect();
+        assert_eq!(min_values, vec![0, 1001, -249]);
+        assert_eq!(max_values, vec![498, 1249, 0]);
+
+        assert_eq!(
+            column_index.to_string(),
+            "Column Index: num_pages 4, null_pages 1, boundary_order 0\n"
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_column_index() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        assert_eq!(column.column_index_offset(), None);
+        assert_eq!(column.column_index_length(), None);
+
+        // The magic code is not a valid ColumnIndex.
+        let res = read_column_index(&file, 0, 4);
+        assert!(res.is_err());
+
+        let column_index = parquet_metadata_thrift::ColumnIndex::new(
+            vec![false, false],
+            vec![vec![0], vec![1]],
+            vec![vec![1]],
+            BoundaryOrder::ASCENDING,
+            None,
+        );
+        let res = ColumnIndex::from_thrift(column_index);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Column index has inconsistent number of pages, null_pages: 2, min_values: 2, max_values: 1, null_counts: 2\n"
+        );
+    }
+}
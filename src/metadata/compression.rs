@@ -0,0 +1,113 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::parquet_metadata_thrift;
+
+/// The compression codec of a column chunk. The codecs unknown to the reader keep the raw Thrift
+/// value, so the newer files can still be loaded and rejected by the decompression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Uncompressed,
+    Snappy,
+    Gzip,
+    Lzo,
+    Brotli,
+    Lz4,
+    Zstd,
+    Lz4Raw,
+    Unknown(i32),
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compression::Uncompressed => write!(f, "UNCOMPRESSED"),
+            Compression::Snappy => write!(f, "SNAPPY"),
+            Compression::Gzip => write!(f, "GZIP"),
+            Compression::Lzo => write!(f, "LZO"),
+            Compression::Brotli => write!(f, "BROTLI"),
+            Compression::Lz4 => write!(f, "LZ4"),
+            Compression::Zstd => write!(f, "ZSTD"),
+            Compression::Lz4Raw => write!(f, "LZ4_RAW"),
+            Compression::Unknown(value) => write!(f, "UNKNOWN({})", value),
+        }
+    }
+}
+
+impl From<parquet_metadata_thrift::CompressionCodec> for Compression {
+    fn from(codec: parquet_metadata_thrift::CompressionCodec) -> Self {
+        match codec {
+            parquet_metadata_thrift::CompressionCodec::UNCOMPRESSED => Compression::Uncompressed,
+            parquet_metadata_thrift::CompressionCodec::SNAPPY => Compression::Snappy,
+            parquet_metadata_thrift::CompressionCodec::GZIP => Compression::Gzip,
+            parquet_metadata_thrift::CompressionCodec::LZO => Compression::Lzo,
+            parquet_metadata_thrift::CompressionCodec::BROTLI => Compression::Brotli,
+            parquet_metadata_thrift::CompressionCodec::LZ4 => Compression::Lz4,
+            parquet_metadata_thrift::CompressionCodec::ZSTD => Compression::Zstd,
+            parquet_metadata_thrift::CompressionCodec::LZ4_RAW => Compression::Lz4Raw,
+            codec => Compression::Unknown(codec.0),
+        }
+    }
+}
+
+impl From<Compression> for parquet_metadata_thrift::CompressionCodec {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::Uncompressed => parquet_metadata_thrift::CompressionCodec::UNCOMPRESSED,
+            Compression::Snappy => parquet_metadata_thrift::CompressionCodec::SNAPPY,
+            Compression::Gzip => parquet_metadata_thrift::CompressionCodec::GZIP,
+            Compression::Lzo => parquet_metadata_thrift::CompressionCodec::LZO,
+            Compression::Brotli => parquet_metadata_thrift::CompressionCodec::BROTLI,
+            Compression::Lz4 => parquet_metadata_thrift::CompressionCodec::LZ4,
+            Compression::Zstd => parquet_metadata_thrift::CompressionCodec::ZSTD,
+            Compression::Lz4Raw => parquet_metadata_thrift::CompressionCodec::LZ4_RAW,
+            Compression::Unknown(value) => parquet_metadata_thrift::CompressionCodec(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::compression::Compression;
+    use crate::metadata::parquet_metadata_thrift::CompressionCodec;
+
+    #[test]
+    fn test_convert_compression() {
+        let codecs = [
+            Compression::Uncompressed,
+            Compression::Snappy,
+            Compression::Gzip,
+            Compression::Lzo,
+            Compression::Brotli,
+            Compression::Lz4,
+            Compression::Zstd,
+            Compression::Lz4Raw,
+        ];
+        for (value, compression) in codecs.iter().enumerate() {
+            let codec = CompressionCodec(value as i32);
+            assert_eq!(Compression::from(codec), *compression);
+            assert_eq!(CompressionCodec::from(*compression), codec);
+        }
+
+        let compression = Compression::from(CompressionCodec(-1));
+        assert_eq!(compression, Compression::Unknown(-1));
+        assert_eq!(compression.to_string(), "UNKNOWN(-1)");
+        assert_eq!(CompressionCodec::from(compression), CompressionCodec(-1));
+        assert_eq!(Compression::Lz4Raw.to_string(), "LZ4_RAW");
+    }
+}
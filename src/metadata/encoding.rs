@@ -0,0 +1,130 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::parquet_metadata_thrift;
+
+/// The encoding of the values or the levels in a page. The encodings unknown to the reader keep
+/// the raw Thrift value, so the newer files can still be loaded and rejected by the page reader.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Plain,
+    PlainDictionary,
+    Rle,
+    BitPacked,
+    DeltaBinaryPacked,
+    DeltaLengthByteArray,
+    DeltaByteArray,
+    RleDictionary,
+    ByteStreamSplit,
+    Unknown(i32),
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Encoding::Plain => write!(f, "PLAIN"),
+            Encoding::PlainDictionary => write!(f, "PLAIN_DICTIONARY"),
+            Encoding::Rle => write!(f, "RLE"),
+            Encoding::BitPacked => write!(f, "BIT_PACKED"),
+            Encoding::DeltaBinaryPacked => write!(f, "DELTA_BINARY_PACKED"),
+            Encoding::DeltaLengthByteArray => write!(f, "DELTA_LENGTH_BYTE_ARRAY"),
+            Encoding::DeltaByteArray => write!(f, "DELTA_BYTE_ARRAY"),
+            Encoding::RleDictionary => write!(f, "RLE_DICTIONARY"),
+            Encoding::ByteStreamSplit => write!(f, "BYTE_STREAM_SPLIT"),
+            Encoding::Unknown(value) => write!(f, "UNKNOWN({})", value),
+        }
+    }
+}
+
+impl From<parquet_metadata_thrift::Encoding> for Encoding {
+    fn from(encoding: parquet_metadata_thrift::Encoding) -> Self {
+        match encoding {
+            parquet_metadata_thrift::Encoding::PLAIN => Encoding::Plain,
+            parquet_metadata_thrift::Encoding::PLAIN_DICTIONARY => Encoding::PlainDictionary,
+            parquet_metadata_thrift::Encoding::RLE => Encoding::Rle,
+            parquet_metadata_thrift::Encoding::BIT_PACKED => Encoding::BitPacked,
+            parquet_metadata_thrift::Encoding::DELTA_BINARY_PACKED => Encoding::DeltaBinaryPacked,
+            parquet_metadata_thrift::Encoding::DELTA_LENGTH_BYTE_ARRAY => {
+                Encoding::DeltaLengthByteArray
+            }
+            parquet_metadata_thrift::Encoding::DELTA_BYTE_ARRAY => Encoding::DeltaByteArray,
+            parquet_metadata_thrift::Encoding::RLE_DICTIONARY => Encoding::RleDictionary,
+            parquet_metadata_thrift::Encoding::BYTE_STREAM_SPLIT => Encoding::ByteStreamSplit,
+            encoding => Encoding::Unknown(encoding.0),
+        }
+    }
+}
+
+impl From<Encoding> for parquet_metadata_thrift::Encoding {
+    fn from(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Plain => parquet_metadata_thrift::Encoding::PLAIN,
+            Encoding::PlainDictionary => parquet_metadata_thrift::Encoding::PLAIN_DICTIONARY,
+            Encoding::Rle => parquet_metadata_thrift::Encoding::RLE,
+            Encoding::BitPacked => parquet_metadata_thrift::Encoding::BIT_PACKED,
+            Encoding::DeltaBinaryPacked => parquet_metadata_thrift::Encoding::DELTA_BINARY_PACKED,
+            Encoding::DeltaLengthByteArray => {
+                parquet_metadata_thrift::Encoding::DELTA_LENGTH_BYTE_ARRAY
+            }
+            Encoding::DeltaByteArray => parquet_metadata_thrift::Encoding::DELTA_BYTE_ARRAY,
+            Encoding::RleDictionary => parquet_metadata_thrift::Encoding::RLE_DICTIONARY,
+            Encoding::ByteStreamSplit => parquet_metadata_thrift::Encoding::BYTE_STREAM_SPLIT,
+            Encoding::Unknown(value) => parquet_metadata_thrift::Encoding(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::encoding::Encoding;
+    use crate::metadata::parquet_metadata_thrift;
+
+    #[test]
+    fn test_convert_encoding() {
+        let encodings = [
+            Encoding::Plain,
+            Encoding::PlainDictionary,
+            Encoding::Rle,
+            Encoding::BitPacked,
+            Encoding::DeltaBinaryPacked,
+            Encoding::DeltaLengthByteArray,
+            Encoding::DeltaByteArray,
+            Encoding::RleDictionary,
+            Encoding::ByteStreamSplit,
+        ];
+        // The Thrift value 1 is the removed GROUP_VAR_INT.
+        let values = [0, 2, 3, 4, 5, 6, 7, 8, 9];
+        for (encoding, value) in encodings.iter().zip(values) {
+            let thrift_encoding = parquet_metadata_thrift::Encoding(value);
+            assert_eq!(Encoding::from(thrift_encoding), *encoding);
+            assert_eq!(
+                parquet_metadata_thrift::Encoding::from(*encoding),
+                thrift_encoding
+            );
+        }
+
+        let encoding = Encoding::from(parquet_metadata_thrift::Encoding(42));
+        assert_eq!(encoding, Encoding::Unknown(42));
+        assert_eq!(encoding.to_string(), "UNKNOWN(42)");
+        assert_eq!(
+            parquet_metadata_thrift::Encoding::from(encoding),
+            parquet_metadata_thrift::Encoding(42)
+        );
+        assert_eq!(Encoding::RleDictionary.to_string(), "RLE_DICTIONARY");
+    }
+}
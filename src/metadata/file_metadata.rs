@@ -0,0 +1,468 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use thrift::protocol::{TCompactInputProtocol, TSerializable};
+
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::SchemaElement;
+use crate::metadata::row_group::RowGroupMetaData;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+const PARQUET_MAGIC_CODE: [u8; 4] = *b"PAR1";
+const PARQUET_MAGIC_CODE_LENGTH: usize = 4;
+const PARQUET_FOOTER_LENGTH_SIZE: usize = 4;
+const PARQUET_FOOTER_TAIL_SIZE: usize = PARQUET_FOOTER_LENGTH_SIZE + PARQUET_MAGIC_CODE_LENGTH;
+
+/// The footers of a newer major format version are rejected instead of being misparsed.
+// todo: Create config module to handle the default const values.
+pub const MAX_SUPPORTED_FORMAT_VERSION: i32 = 2;
+
+/// The default prefetch size of read_file_metadata_with_prefetch, which holds the footer of most
+/// files.
+pub const DEFAULT_FOOTER_PREFETCH_SIZE: usize = 64 * 1024;
+
+/// The file level metadata deserialized from the Parquet footer.
+pub struct FileMetaData {
+    version: i32,
+    num_rows: i64,
+    schema: Vec<SchemaElement>,
+    row_groups: Vec<RowGroupMetaData>,
+    created_by: Option<String>,
+    key_value_metadata: Vec<(String, Option<String>)>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for FileMetaData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "File Metadata: version {}, num_rows {}, num_schema_elements {}, num_row_groups {}, created_by {}",
+            self.version,
+            self.num_rows,
+            self.schema.len(),
+            self.row_groups.len(),
+            self.created_by.as_deref().unwrap_or("unknown")
+        )
+    }
+}
+
+impl FileMetaData {
+    pub fn from_thrift(
+        file_metadata: parquet_metadata_thrift::FileMetaData,
+    ) -> Result<FileMetaData, BoltReaderError> {
+        let mut row_groups = Vec::with_capacity(file_metadata.row_groups.len());
+        for row_group in file_metadata.row_groups {
+            row_groups.push(RowGroupMetaData::from_thrift(row_group)?);
+        }
+
+        Ok(FileMetaData {
+            version: file_metadata.version,
+            num_rows: file_metadata.num_rows,
+            schema: file_metadata.schema,
+            row_groups,
+            created_by: file_metadata.created_by,
+            key_value_metadata: file_metadata
+                .key_value_metadata
+                .unwrap_or_default()
+                .into_iter()
+                .map(|key_value| (key_value.key, key_value.value))
+                .collect(),
+        })
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn num_rows(&self) -> i64 {
+        self.num_rows
+    }
+
+    pub fn schema(&self) -> &Vec<SchemaElement> {
+        &self.schema
+    }
+
+    pub fn row_groups(&self) -> &Vec<RowGroupMetaData> {
+        &self.row_groups
+    }
+
+    pub fn row_group(&self, index: usize) -> Result<&RowGroupMetaData, BoltReaderError> {
+        if unlikely(index >= self.row_groups.len()) {
+            return Err(BoltReaderError::FileMetadataError(format!(
+                "Row group index: {} is out of bound, number of row groups: {}",
+                index,
+                self.row_groups.len()
+            )));
+        }
+
+        Ok(&self.row_groups[index])
+    }
+
+    pub fn created_by(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    /// The application defined key-value pairs in the footer order, e.g. the ARROW:schema of the
+    /// files written by Arrow. It is empty if the footer has no key-value metadata.
+    pub fn key_value_metadata(&self) -> &[(String, Option<String>)] {
+        &self.key_value_metadata
+    }
+
+    /// The value of the first pair with the key, None if the key is missing or has no value.
+    pub fn get_key_value(&self, key: &str) -> Option<&str> {
+        self.key_value_metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
+/// Read the trailing 8 bytes (footer length and the PAR1 magic code), then load and deserialize
+/// the Thrift FileMetaData.
+pub fn read_file_metadata(file: &dyn LoadFile) -> Result<FileMetaData, BoltReaderError> {
+    let mut file_metadata = read_footer(file)?;
+    for row_group in &mut file_metadata.row_groups {
+        row_group.infer_dictionary_page_offsets(file)?;
+    }
+
+    Ok(file_metadata)
+}
+
+/// Like read_file_metadata, but the last prefetch_size bytes of the file are fetched in one
+/// request, so the footer is parsed without the second request as long as it fits in them. The
+/// footer larger than the prefetch size is loaded by a second request.
+pub fn read_file_metadata_with_prefetch(
+    file: &dyn LoadFile,
+    prefetch_size: usize,
+) -> Result<FileMetaData, BoltReaderError> {
+    let mut file_metadata = read_footer_with_prefetch(file, prefetch_size)?;
+    for row_group in &mut file_metadata.row_groups {
+        row_group.infer_dictionary_page_offsets(file)?;
+    }
+
+    Ok(file_metadata)
+}
+
+/// Read the footer only, so only the footer bytes are loaded from the file. Unlike
+/// read_file_metadata, the missing dictionary page offsets are not inferred, see
+/// RowGroupMetaData::infer_dictionary_page_offsets.
+pub fn read_footer(file: &dyn LoadFile) -> Result<FileMetaData, BoltReaderError> {
+    read_footer_with_prefetch(file, PARQUET_FOOTER_TAIL_SIZE)
+}
+
+fn read_footer_with_prefetch(
+    file: &dyn LoadFile,
+    prefetch_size: usize,
+) -> Result<FileMetaData, BoltReaderError> {
+    let file_size = file.get_file_size();
+    if unlikely(file_size < PARQUET_MAGIC_CODE_LENGTH + PARQUET_FOOTER_TAIL_SIZE) {
+        return Err(BoltReaderError::FileMetadataError(format!(
+            "File size: {} bytes is too small for a Parquet file",
+            file_size
+        )));
+    }
+
+    let prefetch_size = prefetch_size.clamp(PARQUET_FOOTER_TAIL_SIZE, file_size);
+    let prefetch_buffer = file.load_file_to_buffer(file_size - prefetch_size, prefetch_size)?;
+    let prefetch = prefetch_buffer.as_bytes();
+    let tail = &prefetch[prefetch_size - PARQUET_FOOTER_TAIL_SIZE..];
+
+    if unlikely(tail[PARQUET_FOOTER_LENGTH_SIZE..] != PARQUET_MAGIC_CODE) {
+        return Err(BoltReaderError::FileMetadataError(String::from(
+            "Not a Parquet File. Missing footer Magic code: PAR1",
+        )));
+    }
+
+    let footer_size = u32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]) as usize;
+    if unlikely(footer_size + PARQUET_MAGIC_CODE_LENGTH + PARQUET_FOOTER_TAIL_SIZE > file_size) {
+        return Err(BoltReaderError::FileMetadataError(format!(
+            "Truncated footer, footer size: {} bytes, file size: {} bytes",
+            footer_size, file_size
+        )));
+    }
+
+    if footer_size + PARQUET_FOOTER_TAIL_SIZE <= prefetch_size {
+        let footer_end = prefetch_size - PARQUET_FOOTER_TAIL_SIZE;
+        return deserialize_footer(&prefetch[footer_end - footer_size..footer_end]);
+    }
+
+    let footer_buffer = file.load_file_to_buffer(
+        file_size - PARQUET_FOOTER_TAIL_SIZE - footer_size,
+        footer_size,
+    )?;
+    deserialize_footer(footer_buffer.as_bytes())
+}
+
+fn deserialize_footer(footer: &[u8]) -> Result<FileMetaData, BoltReaderError> {
+    let mut protocol = TCompactInputProtocol::new(footer);
+    let file_metadata = parquet_metadata_thrift::FileMetaData::read_from_in_protocol(&mut protocol)
+        .map_err(|err| {
+            BoltReaderError::FileMetadataError(format!(
+                "Unable to deserialize Thrift FileMetaData: {}",
+                err
+            ))
+        })?;
+
+    if unlikely(file_metadata.version > MAX_SUPPORTED_FORMAT_VERSION) {
+        return Err(BoltReaderError::UnsupportedFormatVersion {
+            version: file_metadata.version,
+            max_version: MAX_SUPPORTED_FORMAT_VERSION,
+        });
+    }
+
+    FileMetaData::from_thrift(file_metadata)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol, TSerializable};
+
+    use crate::metadata::file_metadata::{
+        read_file_metadata, read_file_metadata_with_prefetch, DEFAULT_FOOTER_PREFETCH_SIZE,
+    };
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::test_utils::{InMemoryFile, RecordingFileLoader};
+
+    fn load_sample_file() -> Vec<u8> {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let buffer = file.load_file_to_buffer(0, file.get_file_size()).unwrap();
+        buffer.as_bytes().to_vec()
+    }
+
+    #[test]
+    fn test_read_file_metadata() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+
+        let res = read_file_metadata(&file);
+        assert!(res.is_ok());
+        let file_metadata = res.unwrap();
+
+        assert_eq!(file_metadata.num_rows(), 11212);
+        assert_eq!(file_metadata.version(), 2);
+        assert_eq!(file_metadata.row_groups().len(), 1);
+        assert_eq!(file_metadata.row_group(0).unwrap().num_rows(), 11212);
+        assert!(file_metadata.row_group(1).is_err());
+        assert_eq!(file_metadata.schema().len(), 23);
+        assert_eq!(file_metadata.schema()[1].name, "orderkey");
+        assert_eq!(
+            file_metadata.created_by(),
+            Some("parquet-cpp-arrow version 11.0.0")
+        );
+        assert_eq!(file_metadata.to_string(), "File Metadata: version 2, num_rows 11212, num_schema_elements 23, num_row_groups 1, created_by parquet-cpp-arrow version 11.0.0\n");
+    }
+
+    #[test]
+    fn test_read_key_value_metadata() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+
+        // The file is written by pandas with pyarrow.
+        let keys: Vec<&str> = file_metadata
+            .key_value_metadata()
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["pandas", "ARROW:schema"]);
+        assert!(file_metadata
+            .get_key_value("pandas")
+            .unwrap()
+            .contains("orderkey"));
+        assert!(!file_metadata
+            .get_key_value("ARROW:schema")
+            .unwrap()
+            .is_empty());
+        assert_eq!(file_metadata.get_key_value("missing"), None);
+
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        assert!(file_metadata.key_value_metadata().is_empty());
+        assert_eq!(file_metadata.get_key_value("ARROW:schema"), None);
+    }
+
+    // Rewrite the footer of the sample file with the given version, the pages are kept.
+    fn create_file_with_version(version: i32) -> InMemoryFile {
+        let mut data = load_sample_file();
+        let len = data.len();
+        let footer_size = u32::from_le_bytes(data[len - 8..len - 4].try_into().unwrap()) as usize;
+        let footer_begin = len - 8 - footer_size;
+        let mut file_metadata = parquet_metadata_thrift::FileMetaData::read_from_in_protocol(
+            &mut TCompactInputProtocol::new(&data[footer_begin..len - 8]),
+        )
+        .unwrap();
+        file_metadata.version = version;
+
+        let mut footer = Vec::new();
+        file_metadata
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))
+            .unwrap();
+        data.truncate(footer_begin);
+        data.extend_from_slice(&footer);
+        data.extend_from_slice(&(footer.len() as u32).to_le_bytes());
+        data.extend_from_slice(b"PAR1");
+
+        InMemoryFile {
+            path: format!("version_{}", version),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_read_file_metadata_format_version() {
+        for version in [1, 2] {
+            let res = read_file_metadata(&create_file_with_version(version));
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap().version(), version);
+        }
+
+        let res = read_file_metadata(&create_file_with_version(99));
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::UnsupportedFormatVersion {
+                version: 99,
+                max_version: 2
+            })
+        ));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Unsupported Format Version: version 99 is newer than the max supported version 2\n"
+        );
+    }
+
+    #[test]
+    fn test_read_file_metadata_bad_magic() {
+        let mut data = load_sample_file();
+        let len = data.len();
+        data[len - 1] = b'2';
+        let file = InMemoryFile {
+            path: String::from("bad_magic"),
+            data,
+        };
+
+        let res = read_file_metadata(&file);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "File Metadata Error: Not a Parquet File. Missing footer Magic code: PAR1\n"
+        );
+    }
+
+    #[test]
+    fn test_read_file_metadata_truncated_footer() {
+        let data = load_sample_file();
+        let file = InMemoryFile {
+            path: String::from("truncated"),
+            data: data[data.len() - 1000..].to_vec(),
+        };
+
+        let res = read_file_metadata(&file);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "File Metadata Error: Truncated footer, footer size: 11135 bytes, file size: 1000 bytes\n"
+        );
+
+        let file = InMemoryFile {
+            path: String::from("too_small"),
+            data: data[data.len() - 8..].to_vec(),
+        };
+        let res = read_file_metadata(&file);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_read_file_metadata_corrupt_footer() {
+        let mut data = load_sample_file();
+        let len = data.len();
+        // Keep the footer length and magic code, but corrupt the Thrift struct.
+        for byte in data[len - 8 - 11135..len - 8].iter_mut() {
+            *byte = 0xff;
+        }
+        let file = InMemoryFile {
+            path: String::from("corrupt"),
+            data,
+        };
+
+        let res = read_file_metadata(&file);
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("File Metadata Error: Unable to deserialize Thrift FileMetaData"));
+    }
+
+    #[test]
+    fn test_read_file_metadata_with_prefetch() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = RecordingFileLoader::new(LocalFileLoader::new(&path).unwrap());
+        let file_size = file.get_file_size();
+
+        // The footer fits in the prefetched bytes, so it is loaded in one request.
+        let res = read_file_metadata_with_prefetch(&file, DEFAULT_FOOTER_PREFETCH_SIZE);
+        assert!(res.is_ok());
+        let file_metadata = res.unwrap();
+        assert_eq!(file_metadata.num_rows(), 11212);
+        assert_eq!(file_metadata.schema().len(), 23);
+        assert_eq!(
+            file.get_ranges(),
+            vec![(
+                file_size - DEFAULT_FOOTER_PREFETCH_SIZE,
+                DEFAULT_FOOTER_PREFETCH_SIZE
+            )]
+        );
+    }
+
+    #[test]
+    fn test_read_file_metadata_with_small_prefetch() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = RecordingFileLoader::new(LocalFileLoader::new(&path).unwrap());
+        let file_size = file.get_file_size();
+        let expected = read_file_metadata(file.get_loader()).unwrap();
+
+        // The footer is larger than the prefetched bytes, so it is loaded by a second request.
+        let res = read_file_metadata_with_prefetch(&file, 1024);
+        assert!(res.is_ok());
+        let file_metadata = res.unwrap();
+        assert_eq!(file_metadata.to_string(), expected.to_string());
+
+        let ranges = file.get_ranges();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0], (file_size - 1024, 1024));
+        assert!(ranges[1].1 > 1024 - 8);
+        assert_eq!(ranges[1].0 + ranges[1].1, file_size - 8);
+
+        // The prefetch size larger than the file loads the whole file.
+        let small_path = String::from("src/sample_files/list_int32.parquet");
+        let small_file = RecordingFileLoader::new(LocalFileLoader::new(&small_path).unwrap());
+        assert!(
+            read_file_metadata_with_prefetch(&small_file, DEFAULT_FOOTER_PREFETCH_SIZE).is_ok()
+        );
+        assert_eq!(small_file.get_ranges(), vec![(0, 243)]);
+    }
+}
@@ -13,6 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arrow")]
+pub mod arrow_schema;
+pub mod bloom_filter;
+pub mod column_index;
+pub mod compression;
+pub mod encoding;
+pub mod file_metadata;
+pub mod offset_index;
 pub mod page_header;
 pub mod parquet_footer;
 pub mod parquet_metadata_thrift;
+pub mod projection_mask;
+pub mod row_group;
+pub mod schema;
+pub mod statistics;
@@ -0,0 +1,244 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use thrift::protocol::{TCompactInputProtocol, TSerializable};
+
+use crate::metadata::parquet_metadata_thrift;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::RowRange;
+
+/// The location of a data page.
+/// offset: the offset of the page header in the file
+/// compressed_page_size: the size of the page, including the page header
+/// first_row_index: the index of the first row of the page in the row group
+pub struct PageLocation {
+    pub offset: usize,
+    pub compressed_page_size: usize,
+    pub first_row_index: usize,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for PageLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PageLocation: offset {}, compressed_page_size {}, first_row_index {}",
+            self.offset, self.compressed_page_size, self.first_row_index
+        )
+    }
+}
+
+/// The locations of the data pages of a column chunk, ordered by first_row_index.
+pub struct OffsetIndex {
+    page_locations: Vec<PageLocation>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for OffsetIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Offset Index: num_pages {}", self.num_pages())
+    }
+}
+
+impl OffsetIndex {
+    pub fn from_thrift(
+        offset_index: parquet_metadata_thrift::OffsetIndex,
+    ) -> Result<OffsetIndex, BoltReaderError> {
+        let mut page_locations: Vec<PageLocation> =
+            Vec::with_capacity(offset_index.page_locations.len());
+        for (i, location) in offset_index.page_locations.iter().enumerate() {
+            if unlikely(
+                location.offset < 0
+                    || location.compressed_page_size < 0
+                    || location.first_row_index < 0,
+            ) {
+                return Err(BoltReaderError::MetadataError(format!(
+                    "Page: {} has negative offset, size or first row index",
+                    i
+                )));
+            }
+
+            let first_row_index = location.first_row_index as usize;
+            if let Some(previous) = page_locations.last() {
+                if unlikely(first_row_index < previous.first_row_index) {
+                    return Err(BoltReaderError::MetadataError(format!(
+                        "Page: {} first row index: {} is smaller than the previous page first row index: {}",
+                        i, first_row_index, previous.first_row_index
+                    )));
+                }
+            }
+
+            page_locations.push(PageLocation {
+                offset: location.offset as usize,
+                compressed_page_size: location.compressed_page_size as usize,
+                first_row_index,
+            });
+        }
+
+        Ok(OffsetIndex { page_locations })
+    }
+
+    pub fn num_pages(&self) -> usize {
+        self.page_locations.len()
+    }
+
+    pub fn page_locations(&self) -> &Vec<PageLocation> {
+        &self.page_locations
+    }
+
+    /// Get the rows [begin, end) of the page in the row group. The last page ends at the number
+    /// of rows of the row group.
+    pub fn get_page_row_range(
+        &self,
+        page_index: usize,
+        num_rows: usize,
+    ) -> Result<RowRange, BoltReaderError> {
+        if unlikely(page_index >= self.page_locations.len()) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Page index: {} is out of bound, number of pages: {}",
+                page_index,
+                self.page_locations.len()
+            )));
+        }
+
+        let begin = self.page_locations[page_index].first_row_index;
+        let end = match self.page_locations.get(page_index + 1) {
+            Some(next) => next.first_row_index,
+            None => num_rows,
+        };
+        if unlikely(begin > end) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Page: {} first row index: {} exceeds the number of rows: {}",
+                page_index, begin, end
+            )));
+        }
+
+        Ok(RowRange::new(begin, end))
+    }
+}
+
+/// Load and deserialize the Thrift OffsetIndex located by offset_index_offset and
+/// offset_index_length of the column chunk metadata.
+pub fn read_offset_index(
+    file: &dyn LoadFile,
+    offset_index_offset: usize,
+    offset_index_length: usize,
+) -> Result<OffsetIndex, BoltReaderError> {
+    let buffer = file.load_file_to_buffer(offset_index_offset, offset_index_length)?;
+
+    let mut protocol = TCompactInputProtocol::new(buffer.as_bytes());
+    let offset_index = parquet_metadata_thrift::OffsetIndex::read_from_in_protocol(&mut protocol)
+        .map_err(|err| {
+        BoltReaderError::MetadataError(format!("Unable to deserialize Thrift OffsetIndex: {}", err))
+    })?;
+
+    OffsetIndex::from_thrift(offset_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::offset_index::{read_offset_index, OffsetIndex};
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::metadata::parquet_metadata_thrift::PageLocation;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_offset_index() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let column = row_group.column(0).unwrap();
+
+        let res = read_offset_index(
+            &file,
+            column.offset_index_offset().unwrap(),
+            column.offset_index_length().unwrap(),
+        );
+        assert!(res.is_ok());
+        let offset_index = res.unwrap();
+        assert_eq!(offset_index.num_pages(), 4);
+
+        let locations = offset_index.page_locations();
+        assert_eq!(locations[0].offset, column.data_page_offset());
+        for i in 1..locations.len() {
+            assert_eq!(
+                locations[i].offset,
+                locations[i - 1].offset + locations[i - 1].compressed_page_size
+            );
+        }
+        let last = &locations[locations.len() - 1];
+        assert_eq!(
+            last.offset + last.compressed_page_size,
+            column.column_chunk_offset() + column.total_compressed_size()
+        );
+
+        // The page row ranges are contiguous and cover all the rows.
+        let num_rows = row_group.num_rows() as usize;
+        assert_eq!(num_rows, 1000);
+        let mut expected_begin = 0;
+        for i in 0..offset_index.num_pages() {
+            let row_range = offset_index.get_page_row_range(i, num_rows).unwrap();
+            assert_eq!(row_range.begin, expected_begin);
+            assert_eq!(row_range.end - row_range.begin, 250);
+            expected_begin = row_range.end;
+        }
+        assert_eq!(expected_begin, num_rows);
+
+        let res = offset_index.get_page_row_range(4, num_rows);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Page index: 4 is out of bound, number of pages: 4\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_offset_index() {
+        let offset_index = parquet_metadata_thrift::OffsetIndex::new(vec![
+            PageLocation::new(4, 100, 0),
+            PageLocation::new(104, 100, 50),
+            PageLocation::new(204, 100, 20),
+        ]);
+        let res = OffsetIndex::from_thrift(offset_index);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Page: 2 first row index: 20 is smaller than the previous page first row index: 50\n"
+        );
+
+        let offset_index =
+            parquet_metadata_thrift::OffsetIndex::new(vec![PageLocation::new(4, -1, 0)]);
+        assert!(OffsetIndex::from_thrift(offset_index).is_err());
+
+        let offset_index = parquet_metadata_thrift::OffsetIndex::new(vec![
+            PageLocation::new(4, 100, 0),
+            PageLocation::new(104, 100, 50),
+        ]);
+        let offset_index = OffsetIndex::from_thrift(offset_index).unwrap();
+        let row_range = offset_index.get_page_row_range(0, 80).unwrap();
+        assert_eq!((row_range.begin, row_range.end), (0, 50));
+        let row_range = offset_index.get_page_row_range(1, 80).unwrap();
+        assert_eq!((row_range.begin, row_range.end), (50, 80));
+
+        // The number of rows is smaller than the first row index of the last page.
+        assert!(offset_index.get_page_row_range(1, 40).is_err());
+    }
+}
@@ -13,10 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::max;
+
 use thrift::protocol::{TCompactInputProtocol, TSerializable};
 
+use crate::metadata::encoding::Encoding;
 use crate::metadata::parquet_metadata_thrift::PageHeader;
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The default max size of a page, compressed or uncompressed.
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 256 * 1024 * 1024;
 
 pub fn read_page_header<T: std::io::Read>(buffer: &mut T) -> Result<PageHeader, BoltReaderError> {
     let mut protocol = TCompactInputProtocol::new(buffer);
@@ -30,10 +37,71 @@ pub fn read_page_header<T: std::io::Read>(buffer: &mut T) -> Result<PageHeader,
     }
 }
 
+/// Get the encoding of the values of a data page V1, data page V2 or dictionary page.
+pub fn get_page_encoding(page_header: &PageHeader) -> Result<Encoding, BoltReaderError> {
+    let encoding = if let Some(header) = &page_header.data_page_header {
+        header.encoding
+    } else if let Some(header) = &page_header.data_page_header_v2 {
+        header.encoding
+    } else if let Some(header) = &page_header.dictionary_page_header {
+        header.encoding
+    } else {
+        return Err(BoltReaderError::MetadataError(format!(
+            "Page of type: {} has no encoding",
+            page_header.type_.0
+        )));
+    };
+
+    Ok(Encoding::from(encoding))
+}
+
+/// Check the page sizes declared in the header against the max page size, so the page of a
+/// corrupt header is rejected before its buffer is loaded or decompressed. The negative sizes are
+/// left to the callers.
+pub fn check_page_size(
+    page_header: &PageHeader,
+    max_page_size: usize,
+) -> Result<(), BoltReaderError> {
+    let page_size = max(
+        page_header.compressed_page_size,
+        page_header.uncompressed_page_size,
+    );
+    if unlikely(page_size > 0 && page_size as usize > max_page_size) {
+        return Err(BoltReaderError::PageTooLarge {
+            page_size: page_size as usize,
+            max_page_size,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verify the CRC32 of the page, i.e. the bytes after the page header as stored in the file.
+/// The pages without crc in the header are not verified.
+pub fn verify_page_crc(page_header: &PageHeader, page: &[u8]) -> Result<(), BoltReaderError> {
+    if let Some(crc) = page_header.crc {
+        let expected = crc as u32;
+        let computed = crc32fast::hash(page);
+        if unlikely(expected != computed) {
+            return Err(BoltReaderError::CrcMismatch { expected, computed });
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::encoding;
+    use crate::metadata::page_header::{
+        check_page_size, get_page_encoding, read_page_header, verify_page_crc,
+        DEFAULT_MAX_PAGE_SIZE,
+    };
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, DataPageHeaderV2, Encoding, PageHeader, PageType,
+    };
     use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
     use crate::utils::file_streaming_byte_buffer::{FileStreamingBuffer, StreamingByteBuffer};
     use crate::utils::local_file_loader::LocalFileLoader;
 
@@ -114,4 +182,120 @@ mod tests {
         let page_header = read_page_header(&mut buf);
         assert!(page_header.is_err());
     }
+
+    #[test]
+    fn test_get_page_encoding() {
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        // The dictionary page and the dictionary encoded data page of the first column chunk.
+        let mut buf = DirectByteBuffer::from_file(&file, 4, 100).unwrap();
+        let page_header = read_page_header(&mut buf).unwrap();
+        assert_eq!(page_header.type_, PageType::DICTIONARY_PAGE);
+        assert_eq!(
+            get_page_encoding(&page_header).unwrap(),
+            encoding::Encoding::Plain
+        );
+
+        let offset = 4 + buf.get_rpos() + page_header.compressed_page_size as usize;
+        let mut buf = DirectByteBuffer::from_file(&file, offset, 100).unwrap();
+        let page_header = read_page_header(&mut buf).unwrap();
+        assert_eq!(page_header.type_, PageType::DATA_PAGE);
+        assert_eq!(
+            get_page_encoding(&page_header).unwrap(),
+            encoding::Encoding::RleDictionary
+        );
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE_V2,
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            DataPageHeaderV2::new(0, 0, 0, Encoding(42), 0, 0, false, None),
+        );
+        assert_eq!(
+            get_page_encoding(&page_header).unwrap(),
+            encoding::Encoding::Unknown(42)
+        );
+
+        let page_header = PageHeader::new(PageType::INDEX_PAGE, 0, 0, None, None, None, None, None);
+        let res = get_page_encoding(&page_header);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet Metadata Error: Page of type: 1 has no encoding\n"
+        );
+    }
+
+    #[test]
+    fn test_verify_page_crc() {
+        let page = b"bolt parquet reader";
+        let mut page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page.len() as i32,
+            page.len() as i32,
+            None,
+            DataPageHeader::new(1, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        // The page without crc is not verified.
+        assert!(verify_page_crc(&page_header, page).is_ok());
+
+        page_header.crc = Some(crc32fast::hash(page) as i32);
+        assert!(verify_page_crc(&page_header, page).is_ok());
+
+        let res = verify_page_crc(&page_header, b"bolt parquet readers");
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            format!(
+                "CRC Mismatch: expected {:#010x}, computed {:#010x}\n",
+                crc32fast::hash(page),
+                crc32fast::hash(b"bolt parquet readers")
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_page_size() {
+        // The i32 page sizes can claim up to 2GB.
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            i32::MAX,
+            100,
+            None,
+            DataPageHeader::new(10, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let res = check_page_size(&page_header, DEFAULT_MAX_PAGE_SIZE);
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::PageTooLarge {
+                page_size: 2147483647,
+                max_page_size: DEFAULT_MAX_PAGE_SIZE
+            })
+        ));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Page Too Large: page size 2147483647 bytes exceeds the max page size 268435456 bytes\n"
+        );
+
+        let mut page_header = page_header;
+        page_header.uncompressed_page_size = 100;
+        assert!(check_page_size(&page_header, DEFAULT_MAX_PAGE_SIZE).is_ok());
+        assert!(check_page_size(&page_header, 100).is_ok());
+        assert!(check_page_size(&page_header, 99).is_err());
+
+        // The negative sizes are not checked here.
+        page_header.uncompressed_page_size = -1;
+        page_header.compressed_page_size = -1;
+        assert!(check_page_size(&page_header, DEFAULT_MAX_PAGE_SIZE).is_ok());
+    }
 }
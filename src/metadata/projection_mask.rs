@@ -0,0 +1,159 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::schema::SchemaTree;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The leaf columns to read, indexed by the column index of the ColumnDescriptors, which is also
+/// the index of the column chunk in the row group.
+pub struct ProjectionMask {
+    mask: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ProjectionMask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Projection Mask: num_columns {}, projected columns {:?}",
+            self.mask.len(),
+            self.get_column_indices()
+        )
+    }
+}
+
+impl ProjectionMask {
+    pub fn new(
+        schema_tree: &SchemaTree,
+        column_indices: &[usize],
+    ) -> Result<ProjectionMask, BoltReaderError> {
+        let num_columns = schema_tree.get_num_columns();
+        let mut mask = vec![false; num_columns];
+        for &index in column_indices {
+            if unlikely(index >= num_columns) {
+                return Err(BoltReaderError::MetadataError(format!(
+                    "Projected column index: {} is out of bound, number of columns: {}",
+                    index, num_columns
+                )));
+            }
+            mask[index] = true;
+        }
+
+        Ok(ProjectionMask { mask })
+    }
+
+    /// Resolve the dot separated column paths, e.g. "info.tags", to the leaf column indices.
+    pub fn from_paths(
+        schema_tree: &SchemaTree,
+        paths: &[&str],
+    ) -> Result<ProjectionMask, BoltReaderError> {
+        let columns = schema_tree.get_column_descriptors();
+        let mut column_indices = Vec::with_capacity(paths.len());
+        for path in paths {
+            match columns
+                .iter()
+                .find(|column| column.get_path_string() == *path)
+            {
+                Some(column) => column_indices.push(column.get_column_index()),
+                None => {
+                    return Err(BoltReaderError::MetadataError(format!(
+                        "Projected column: {} is not found in the schema",
+                        path
+                    )))
+                }
+            }
+        }
+
+        Self::new(schema_tree, &column_indices)
+    }
+
+    pub fn all(schema_tree: &SchemaTree) -> ProjectionMask {
+        ProjectionMask {
+            mask: vec![true; schema_tree.get_num_columns()],
+        }
+    }
+
+    pub fn get_num_columns(&self) -> usize {
+        self.mask.len()
+    }
+
+    pub fn is_projected(&self, column_index: usize) -> bool {
+        column_index < self.mask.len() && self.mask[column_index]
+    }
+
+    /// The projected column indices in ascending order.
+    pub fn get_column_indices(&self) -> Vec<usize> {
+        self.mask
+            .iter()
+            .enumerate()
+            .filter(|(_, projected)| **projected)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::projection_mask::ProjectionMask;
+    use crate::metadata::schema::SchemaTree;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_projection_mask() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+
+        let res = ProjectionMask::new(&schema_tree, &[15, 0, 15]);
+        assert!(res.is_ok());
+        let mask = res.unwrap();
+        assert_eq!(mask.get_num_columns(), 22);
+        assert_eq!(mask.get_column_indices(), vec![0, 15]);
+        assert!(mask.is_projected(0));
+        assert!(!mask.is_projected(1));
+        assert!(!mask.is_projected(22));
+        assert_eq!(
+            mask.to_string(),
+            "Projection Mask: num_columns 22, projected columns [0, 15]\n"
+        );
+
+        let mask = ProjectionMask::from_paths(&schema_tree, &["comment", "orderkey"]).unwrap();
+        assert_eq!(mask.get_column_indices(), vec![0, 15]);
+
+        assert_eq!(
+            ProjectionMask::all(&schema_tree).get_column_indices().len(),
+            22
+        );
+
+        let res = ProjectionMask::new(&schema_tree, &[22]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Projected column index: 22 is out of bound, number of columns: 22\n"
+        );
+
+        let res = ProjectionMask::from_paths(&schema_tree, &["not_existing"]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Projected column: not_existing is not found in the schema\n"
+        );
+    }
+}
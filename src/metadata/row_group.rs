@@ -0,0 +1,497 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::compression::Compression;
+use crate::metadata::page_header::read_page_header;
+use crate::metadata::parquet_metadata_thrift::{
+    ColumnChunk, ColumnMetaData, CompressionCodec, Encoding, PageType, RowGroup, Type,
+};
+use crate::metadata::statistics::Statistics;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+// A dictionary page header has no statistics, so it fits in a few dozen bytes.
+const MAX_DICTIONARY_PAGE_HEADER_SIZE: usize = 256;
+
+/// The column chunk metadata. The offsets are validated to be non-negative, so they can be used
+/// to load the column chunk via DirectByteBuffer::from_file directly.
+#[derive(Clone)]
+pub struct ColumnChunkMetaData {
+    file_path: Option<String>,
+    file_offset: usize,
+    dictionary_page_offset: Option<usize>,
+    column_index_offset: Option<usize>,
+    column_index_length: Option<usize>,
+    offset_index_offset: Option<usize>,
+    offset_index_length: Option<usize>,
+    statistics: Option<Statistics>,
+    metadata: ColumnMetaData,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ColumnChunkMetaData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Column Chunk Metadata: path {}, file_offset {}, data_page_offset {}, dictionary_page_offset {:?}, compression {}, num_values {}",
+            self.metadata.path_in_schema.join("."),
+            self.file_offset,
+            self.data_page_offset(),
+            self.dictionary_page_offset(),
+            self.metadata.codec.0,
+            self.metadata.num_values
+        )
+    }
+}
+
+impl ColumnChunkMetaData {
+    pub fn from_thrift(column_chunk: ColumnChunk) -> Result<ColumnChunkMetaData, BoltReaderError> {
+        let metadata = match column_chunk.meta_data {
+            Some(metadata) => metadata,
+            None => {
+                return Err(BoltReaderError::MetadataError(String::from(
+                    "Column chunk metadata is missing",
+                )))
+            }
+        };
+
+        let offsets = [
+            Some(column_chunk.file_offset),
+            Some(metadata.data_page_offset),
+            metadata.dictionary_page_offset,
+            metadata.bloom_filter_offset,
+            Some(metadata.total_compressed_size),
+            Some(metadata.total_uncompressed_size),
+            Some(metadata.num_values),
+            column_chunk.column_index_offset,
+            column_chunk.column_index_length.map(|length| length as i64),
+            column_chunk.offset_index_offset,
+            column_chunk.offset_index_length.map(|length| length as i64),
+        ];
+        if unlikely(offsets.iter().flatten().any(|offset| *offset < 0)) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Column chunk: {} has negative offsets or sizes",
+                metadata.path_in_schema.join(".")
+            )));
+        }
+
+        let statistics = metadata
+            .statistics
+            .as_ref()
+            .map(|statistics| Statistics::from_thrift(statistics, metadata.type_));
+
+        Ok(ColumnChunkMetaData {
+            file_path: column_chunk.file_path,
+            file_offset: column_chunk.file_offset as usize,
+            // The offset 0 is the PAR1 magic code, so it is treated as absent.
+            dictionary_page_offset: metadata
+                .dictionary_page_offset
+                .filter(|offset| *offset > 0)
+                .map(|offset| offset as usize),
+            column_index_offset: column_chunk
+                .column_index_offset
+                .map(|offset| offset as usize),
+            column_index_length: column_chunk
+                .column_index_length
+                .map(|length| length as usize),
+            offset_index_offset: column_chunk
+                .offset_index_offset
+                .map(|offset| offset as usize),
+            offset_index_length: column_chunk
+                .offset_index_length
+                .map(|length| length as usize),
+            statistics,
+            metadata,
+        })
+    }
+
+    /// The file containing the column chunk, None if it is in the same file as the footer.
+    pub fn file_path(&self) -> Option<&str> {
+        self.file_path.as_deref()
+    }
+
+    pub fn file_offset(&self) -> usize {
+        self.file_offset
+    }
+
+    pub fn data_page_offset(&self) -> usize {
+        self.metadata.data_page_offset as usize
+    }
+
+    pub fn dictionary_page_offset(&self) -> Option<usize> {
+        self.dictionary_page_offset
+    }
+
+    /// Some writers omit the dictionary_page_offset, or set it to 0, even when the column chunk
+    /// starts with a dictionary page. If the offset is absent and file_offset is before the
+    /// data pages, the page header at file_offset is loaded, and file_offset is used as the
+    /// dictionary page offset if it is a dictionary page.
+    pub fn infer_dictionary_page_offset(
+        &mut self,
+        file: &dyn LoadFile,
+    ) -> Result<Option<usize>, BoltReaderError> {
+        if self.dictionary_page_offset.is_some() || self.file_offset >= self.data_page_offset() {
+            return Ok(self.dictionary_page_offset);
+        }
+
+        let length =
+            MAX_DICTIONARY_PAGE_HEADER_SIZE.min(self.data_page_offset() - self.file_offset);
+        let buffer = file.load_file_to_buffer(self.file_offset, length)?;
+        // The bytes at file_offset which are not a page header are not a dictionary page either.
+        if let Ok(page_header) = read_page_header(&mut buffer.as_bytes()) {
+            if page_header.type_ == PageType::DICTIONARY_PAGE {
+                self.dictionary_page_offset = Some(self.file_offset);
+            }
+        }
+
+        Ok(self.dictionary_page_offset)
+    }
+
+    pub fn bloom_filter_offset(&self) -> Option<usize> {
+        self.metadata
+            .bloom_filter_offset
+            .map(|offset| offset as usize)
+    }
+
+    /// The offset of the first page, which is the dictionary page if there is one.
+    pub fn column_chunk_offset(&self) -> usize {
+        match self.dictionary_page_offset() {
+            Some(offset) => offset,
+            None => self.data_page_offset(),
+        }
+    }
+
+    pub fn compression(&self) -> CompressionCodec {
+        self.metadata.codec
+    }
+
+    /// The compression codec of the column chunk, as the reader's Compression.
+    pub fn codec(&self) -> Compression {
+        Compression::from(self.metadata.codec)
+    }
+
+    pub fn physical_type(&self) -> Type {
+        self.metadata.type_
+    }
+
+    pub fn encodings(&self) -> &Vec<Encoding> {
+        &self.metadata.encodings
+    }
+
+    pub fn path_in_schema(&self) -> &Vec<String> {
+        &self.metadata.path_in_schema
+    }
+
+    pub fn num_values(&self) -> usize {
+        self.metadata.num_values as usize
+    }
+
+    pub fn total_compressed_size(&self) -> usize {
+        self.metadata.total_compressed_size as usize
+    }
+
+    pub fn total_uncompressed_size(&self) -> usize {
+        self.metadata.total_uncompressed_size as usize
+    }
+
+    /// The location of the ColumnIndex, None if the page index is not written.
+    pub fn column_index_offset(&self) -> Option<usize> {
+        self.column_index_offset
+    }
+
+    pub fn column_index_length(&self) -> Option<usize> {
+        self.column_index_length
+    }
+
+    /// The location of the OffsetIndex, None if the page index is not written.
+    pub fn offset_index_offset(&self) -> Option<usize> {
+        self.offset_index_offset
+    }
+
+    pub fn offset_index_length(&self) -> Option<usize> {
+        self.offset_index_length
+    }
+
+    /// The column chunk statistics, None if they are not written.
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    pub fn get_thrift_metadata(&self) -> &ColumnMetaData {
+        &self.metadata
+    }
+}
+
+/// The row group metadata, wrapping the Thrift row group and its column chunks.
+#[derive(Clone)]
+pub struct RowGroupMetaData {
+    num_rows: i64,
+    total_byte_size: i64,
+    columns: Vec<ColumnChunkMetaData>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for RowGroupMetaData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Row Group Metadata: num_rows {}, total_byte_size {}, num_columns {}",
+            self.num_rows,
+            self.total_byte_size,
+            self.columns.len()
+        )
+    }
+}
+
+impl RowGroupMetaData {
+    pub fn from_thrift(row_group: RowGroup) -> Result<RowGroupMetaData, BoltReaderError> {
+        let mut columns = Vec::with_capacity(row_group.columns.len());
+        for column_chunk in row_group.columns {
+            columns.push(ColumnChunkMetaData::from_thrift(column_chunk)?);
+        }
+
+        Ok(RowGroupMetaData {
+            num_rows: row_group.num_rows,
+            total_byte_size: row_group.total_byte_size,
+            columns,
+        })
+    }
+
+    pub fn num_rows(&self) -> i64 {
+        self.num_rows
+    }
+
+    pub fn total_byte_size(&self) -> i64 {
+        self.total_byte_size
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn columns(&self) -> &Vec<ColumnChunkMetaData> {
+        &self.columns
+    }
+
+    /// Infer the missing dictionary page offsets of the column chunks, see
+    /// ColumnChunkMetaData::infer_dictionary_page_offset.
+    pub fn infer_dictionary_page_offsets(
+        &mut self,
+        file: &dyn LoadFile,
+    ) -> Result<(), BoltReaderError> {
+        for column in &mut self.columns {
+            column.infer_dictionary_page_offset(file)?;
+        }
+        Ok(())
+    }
+
+    pub fn column(&self, index: usize) -> Result<&ColumnChunkMetaData, BoltReaderError> {
+        if unlikely(index >= self.columns.len()) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Column index: {} is out of bound, number of columns: {}",
+                index,
+                self.columns.len()
+            )));
+        }
+
+        Ok(&self.columns[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::compression::Compression;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift::{
+        ColumnChunk, ColumnMetaData, CompressionCodec, Encoding, Type,
+    };
+    use crate::metadata::row_group::ColumnChunkMetaData;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_row_group_metadata() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+
+        let row_group = &file_metadata.row_groups()[0];
+        assert_eq!(row_group.num_rows(), 11212);
+        assert_eq!(row_group.total_byte_size(), 2052953);
+        assert_eq!(row_group.num_columns(), 22);
+        assert_eq!(
+            row_group.to_string(),
+            "Row Group Metadata: num_rows 11212, total_byte_size 2052953, num_columns 22\n"
+        );
+
+        let res = row_group.column(0);
+        assert!(res.is_ok());
+        let column = res.unwrap();
+        assert_eq!(column.file_path(), None);
+        assert_eq!(column.file_offset(), 89775);
+        assert_eq!(column.data_page_offset(), 4);
+        assert_eq!(column.dictionary_page_offset(), None);
+        assert_eq!(column.column_chunk_offset(), 4);
+        assert_eq!(column.compression(), CompressionCodec::UNCOMPRESSED);
+        assert_eq!(column.codec(), Compression::Uncompressed);
+        assert_eq!(column.physical_type(), Type::INT64);
+        assert_eq!(column.num_values(), 11212);
+        assert_eq!(column.total_compressed_size(), 89771);
+        assert_eq!(column.path_in_schema(), &vec![String::from("orderkey")]);
+
+        // The offsets can be used to load the column chunk.
+        let res = DirectByteBuffer::from_file(
+            &file,
+            column.column_chunk_offset(),
+            column.total_compressed_size(),
+        );
+        assert!(res.is_ok());
+
+        let res = row_group.column(22);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Column index: 22 is out of bound, number of columns: 22\n"
+        );
+    }
+
+    #[test]
+    fn test_dictionary_page_offset() {
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+
+        let column = file_metadata.row_groups()[0].column(0).unwrap();
+        assert_eq!(column.data_page_offset(), 23391);
+        assert_eq!(column.dictionary_page_offset(), Some(4));
+        assert_eq!(column.column_chunk_offset(), 4);
+        assert_eq!(column.to_string(), "Column Chunk Metadata: path orderkey, file_offset 40314, data_page_offset 23391, dictionary_page_offset Some(4), compression 0, num_values 11212\n");
+    }
+
+    #[test]
+    fn test_infer_dictionary_page_offset() {
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let metadata = file_metadata.row_groups()[0]
+            .column(0)
+            .unwrap()
+            .get_thrift_metadata()
+            .clone();
+
+        let create_column = |file_offset: i64, dictionary_page_offset: Option<i64>| {
+            let mut metadata = metadata.clone();
+            metadata.dictionary_page_offset = dictionary_page_offset;
+            let column_chunk = ColumnChunk::new(
+                None,
+                file_offset,
+                metadata,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            ColumnChunkMetaData::from_thrift(column_chunk).unwrap()
+        };
+
+        // The dictionary page offset is present.
+        let mut column = create_column(40314, Some(4));
+        assert_eq!(column.infer_dictionary_page_offset(&file).unwrap(), Some(4));
+        assert_eq!(column.column_chunk_offset(), 4);
+
+        // The dictionary page offset is omitted or 0, the dictionary page is at file_offset.
+        for dictionary_page_offset in [None, Some(0)] {
+            let mut column = create_column(4, dictionary_page_offset);
+            assert_eq!(column.dictionary_page_offset(), None);
+            assert_eq!(column.column_chunk_offset(), 23391);
+            assert_eq!(column.infer_dictionary_page_offset(&file).unwrap(), Some(4));
+            assert_eq!(column.dictionary_page_offset(), Some(4));
+            assert_eq!(column.column_chunk_offset(), 4);
+        }
+
+        // file_offset is after the data pages.
+        let mut column = create_column(40314, None);
+        assert_eq!(column.infer_dictionary_page_offset(&file).unwrap(), None);
+        assert_eq!(column.column_chunk_offset(), 23391);
+
+        // The page at file_offset is a data page.
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let plain_file = LocalFileLoader::new(&path).unwrap();
+        let plain_metadata = read_file_metadata(&plain_file).unwrap();
+        let mut metadata = plain_metadata.row_groups()[0]
+            .column(1)
+            .unwrap()
+            .get_thrift_metadata()
+            .clone();
+        let data_page_offset = metadata.data_page_offset;
+        metadata.data_page_offset += 100;
+        let column_chunk = ColumnChunk::new(
+            None,
+            data_page_offset,
+            metadata,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut column = ColumnChunkMetaData::from_thrift(column_chunk).unwrap();
+        assert_eq!(
+            column.infer_dictionary_page_offset(&plain_file).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_column_chunk() {
+        let column_chunk = ColumnChunk::new(None, 0, None, None, None, None, None, None, None);
+        let res = ColumnChunkMetaData::from_thrift(column_chunk);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Column chunk metadata is missing\n"
+        );
+
+        let metadata = ColumnMetaData::new(
+            Type::INT32,
+            vec![Encoding::PLAIN],
+            vec![String::from("foo")],
+            CompressionCodec::SNAPPY,
+            10,
+            40,
+            40,
+            None,
+            -1,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let column_chunk = ColumnChunk::new(None, 0, metadata, None, None, None, None, None, None);
+        let res = ColumnChunkMetaData::from_thrift(column_chunk);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Column chunk: foo has negative offsets or sizes\n"
+        );
+    }
+}
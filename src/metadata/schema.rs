@@ -0,0 +1,496 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::parquet_metadata_thrift::{
+    ConvertedType, FieldRepetitionType, LogicalType, SchemaElement, TimeUnit, Type,
+};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::timestamp::{TimeType, TimestampType, TimestampUnit};
+
+/// A node of the schema tree. Group nodes have children, leaf nodes have a physical type.
+pub struct SchemaNode {
+    name: String,
+    schema_element_index: usize,
+    repetition_type: Option<FieldRepetitionType>,
+    physical_type: Option<Type>,
+    children: Vec<SchemaNode>,
+}
+
+impl SchemaNode {
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_schema_element_index(&self) -> usize {
+        self.schema_element_index
+    }
+
+    pub fn get_repetition_type(&self) -> Option<FieldRepetitionType> {
+        self.repetition_type
+    }
+
+    pub fn get_physical_type(&self) -> Option<Type> {
+        self.physical_type
+    }
+
+    pub fn get_children(&self) -> &Vec<SchemaNode> {
+        &self.children
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty() && self.physical_type.is_some()
+    }
+}
+
+/// The leaf column resolved from the schema tree.
+/// column_index: the index of the column chunk in the row group
+/// path: the names from the top level field to the leaf, the root is excluded
+pub struct ColumnDescriptor {
+    column_index: usize,
+    schema_element_index: usize,
+    path: Vec<String>,
+    physical_type: Type,
+    type_length: Option<i32>,
+    repetition_type: Option<FieldRepetitionType>,
+    decimal_precision: Option<i32>,
+    decimal_scale: Option<i32>,
+    is_date: bool,
+    timestamp_type: Option<TimestampType>,
+    time_type: Option<TimeType>,
+    max_definition_level: i16,
+    max_repetition_level: i16,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ColumnDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Column Descriptor: column_index {}, path {}, physical_type {}, max_definition_level {}, max_repetition_level {}",
+            self.column_index,
+            self.get_path_string(),
+            self.physical_type.0,
+            self.max_definition_level,
+            self.max_repetition_level
+        )
+    }
+}
+
+impl ColumnDescriptor {
+    pub fn get_column_index(&self) -> usize {
+        self.column_index
+    }
+
+    pub fn get_schema_element_index(&self) -> usize {
+        self.schema_element_index
+    }
+
+    pub fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    pub fn get_path_string(&self) -> String {
+        self.path.join(".")
+    }
+
+    pub fn get_physical_type(&self) -> Type {
+        self.physical_type
+    }
+
+    pub fn get_type_length(&self) -> Option<i32> {
+        self.type_length
+    }
+
+    pub fn get_repetition_type(&self) -> Option<FieldRepetitionType> {
+        self.repetition_type
+    }
+
+    /// The precision of the DECIMAL column, from the logical type or the legacy converted type.
+    pub fn get_decimal_precision(&self) -> Option<i32> {
+        self.decimal_precision
+    }
+
+    /// The scale of the DECIMAL column, from the logical type or the legacy converted type.
+    pub fn get_decimal_scale(&self) -> Option<i32> {
+        self.decimal_scale
+    }
+
+    /// The INT32 column holding the days since the Unix epoch, from the logical type or the
+    /// legacy converted type.
+    pub fn is_date(&self) -> bool {
+        self.is_date
+    }
+
+    /// The unit and the UTC flag of the INT64 TIMESTAMP column. The legacy converted types
+    /// TIMESTAMP_MILLIS and TIMESTAMP_MICROS are adjusted to UTC.
+    pub fn get_timestamp_type(&self) -> Option<TimestampType> {
+        self.timestamp_type
+    }
+
+    /// The unit and the UTC flag of the INT64 TIME column. The legacy converted type TIME_MICROS
+    /// is adjusted to UTC.
+    pub fn get_time_type(&self) -> Option<TimeType> {
+        self.time_type
+    }
+
+    pub fn get_max_definition_level(&self) -> i16 {
+        self.max_definition_level
+    }
+
+    pub fn get_max_repetition_level(&self) -> i16 {
+        self.max_repetition_level
+    }
+}
+
+/// The schema tree rebuilt from the flat list of SchemaElements in the footer. The elements are
+/// stored in depth-first order, and the children of a group are the next num_children subtrees.
+pub struct SchemaTree {
+    root: SchemaNode,
+    column_descriptors: Vec<ColumnDescriptor>,
+}
+
+impl SchemaTree {
+    pub fn new(schema: &[SchemaElement]) -> Result<SchemaTree, BoltReaderError> {
+        if unlikely(schema.is_empty()) {
+            return Err(BoltReaderError::MetadataError(String::from(
+                "Schema should contain at least the root element",
+            )));
+        }
+
+        let mut column_descriptors = Vec::new();
+        let mut index = 0;
+        let mut path = Vec::new();
+        let root = Self::build_node(schema, &mut index, 0, 0, &mut path, &mut column_descriptors)?;
+
+        if unlikely(index != schema.len()) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Schema has {} elements, but only {} elements are reachable from the root",
+                schema.len(),
+                index
+            )));
+        }
+
+        Ok(SchemaTree {
+            root,
+            column_descriptors,
+        })
+    }
+
+    fn build_node(
+        schema: &[SchemaElement],
+        index: &mut usize,
+        definition_level: i16,
+        repetition_level: i16,
+        path: &mut Vec<String>,
+        column_descriptors: &mut Vec<ColumnDescriptor>,
+    ) -> Result<SchemaNode, BoltReaderError> {
+        if unlikely(*index >= schema.len()) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Schema element: {} is out of bound, number of schema elements: {}",
+                index,
+                schema.len()
+            )));
+        }
+
+        let element = &schema[*index];
+        let schema_element_index = *index;
+        let is_root = schema_element_index == 0;
+        *index += 1;
+
+        // The repetition type of the root is ignored.
+        let (definition_level, repetition_level) = match element.repetition_type {
+            _ if is_root => (definition_level, repetition_level),
+            Some(FieldRepetitionType::OPTIONAL) => (definition_level + 1, repetition_level),
+            Some(FieldRepetitionType::REPEATED) => (definition_level + 1, repetition_level + 1),
+            _ => (definition_level, repetition_level),
+        };
+
+        if !is_root {
+            path.push(element.name.clone());
+        }
+
+        let num_children = element.num_children.unwrap_or(0);
+        if unlikely(num_children < 0) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Invalid number of children: {} of schema element: {}",
+                num_children, element.name
+            )));
+        }
+
+        let mut children = Vec::with_capacity(num_children as usize);
+        if num_children == 0 && !is_root {
+            let physical_type = match element.type_ {
+                Some(physical_type) => physical_type,
+                None => {
+                    return Err(BoltReaderError::MetadataError(format!(
+                        "Leaf schema element: {} has no physical type",
+                        element.name
+                    )))
+                }
+            };
+
+            let (decimal_precision, decimal_scale) = match &element.logical_type {
+                Some(LogicalType::DECIMAL(decimal)) => {
+                    (Some(decimal.precision), Some(decimal.scale))
+                }
+                _ if element.converted_type == Some(ConvertedType::DECIMAL) => {
+                    (element.precision, Some(element.scale.unwrap_or(0)))
+                }
+                _ => (None, None),
+            };
+
+            let is_date = physical_type == Type::INT32
+                && (matches!(element.logical_type, Some(LogicalType::DATE(_)))
+                    || element.converted_type == Some(ConvertedType::DATE));
+
+            let timestamp_type = match (&element.logical_type, element.converted_type) {
+                _ if physical_type != Type::INT64 => None,
+                (Some(LogicalType::TIMESTAMP(timestamp)), _) => Some(TimestampType::new(
+                    TimestampUnit::from_thrift(&timestamp.unit),
+                    timestamp.is_adjusted_to_u_t_c,
+                )),
+                (_, Some(ConvertedType::TIMESTAMP_MILLIS)) => {
+                    Some(TimestampType::new(TimestampUnit::Millis, true))
+                }
+                (_, Some(ConvertedType::TIMESTAMP_MICROS)) => {
+                    Some(TimestampType::new(TimestampUnit::Micros, true))
+                }
+                _ => None,
+            };
+
+            let time_type = match (&element.logical_type, element.converted_type) {
+                _ if physical_type != Type::INT64 => None,
+                // The TIME(MILLIS) values are stored as INT32.
+                (Some(LogicalType::TIME(time)), _) if matches!(time.unit, TimeUnit::MILLIS(_)) => {
+                    None
+                }
+                (Some(LogicalType::TIME(time)), _) => Some(TimeType::new(
+                    TimestampUnit::from_thrift(&time.unit),
+                    time.is_adjusted_to_u_t_c,
+                )),
+                (_, Some(ConvertedType::TIME_MICROS)) => {
+                    Some(TimeType::new(TimestampUnit::Micros, true))
+                }
+                _ => None,
+            };
+
+            column_descriptors.push(ColumnDescriptor {
+                column_index: column_descriptors.len(),
+                schema_element_index,
+                path: path.clone(),
+                physical_type,
+                type_length: element.type_length,
+                repetition_type: element.repetition_type,
+                decimal_precision,
+                decimal_scale,
+                is_date,
+                timestamp_type,
+                time_type,
+                max_definition_level: definition_level,
+                max_repetition_level: repetition_level,
+            });
+        } else {
+            for _ in 0..num_children {
+                children.push(Self::build_node(
+                    schema,
+                    index,
+                    definition_level,
+                    repetition_level,
+                    path,
+                    column_descriptors,
+                )?);
+            }
+        }
+
+        if !is_root {
+            path.pop();
+        }
+
+        Ok(SchemaNode {
+            name: element.name.clone(),
+            schema_element_index,
+            repetition_type: element.repetition_type,
+            physical_type: element.type_,
+            children,
+        })
+    }
+
+    pub fn get_root(&self) -> &SchemaNode {
+        &self.root
+    }
+
+    pub fn get_column_descriptors(&self) -> &Vec<ColumnDescriptor> {
+        &self.column_descriptors
+    }
+
+    pub fn get_num_columns(&self) -> usize {
+        self.column_descriptors.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift::{FieldRepetitionType, SchemaElement, Type};
+    use crate::metadata::schema::SchemaTree;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    fn create_group(
+        name: &str,
+        repetition_type: Option<FieldRepetitionType>,
+        num_children: i32,
+    ) -> SchemaElement {
+        SchemaElement::new(
+            None,
+            None,
+            repetition_type,
+            String::from(name),
+            num_children,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn create_leaf(name: &str, repetition_type: FieldRepetitionType, type_: Type) -> SchemaElement {
+        SchemaElement::new(
+            type_,
+            None,
+            repetition_type,
+            String::from(name),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_nested_schema() {
+        // message schema {
+        //   required int64 id;
+        //   optional group info {
+        //     optional int32 age;
+        //     repeated binary tags;
+        //   }
+        // }
+        let schema = vec![
+            create_group("schema", None, 2),
+            create_leaf("id", FieldRepetitionType::REQUIRED, Type::INT64),
+            create_group("info", Some(FieldRepetitionType::OPTIONAL), 2),
+            create_leaf("age", FieldRepetitionType::OPTIONAL, Type::INT32),
+            create_leaf("tags", FieldRepetitionType::REPEATED, Type::BYTE_ARRAY),
+        ];
+
+        let res = SchemaTree::new(&schema);
+        assert!(res.is_ok());
+        let schema_tree = res.unwrap();
+
+        let root = schema_tree.get_root();
+        assert_eq!(root.get_name(), "schema");
+        assert_eq!(root.get_children().len(), 2);
+        assert!(root.get_children()[0].is_leaf());
+        assert!(!root.get_children()[1].is_leaf());
+        assert_eq!(root.get_children()[1].get_children()[1].get_name(), "tags");
+
+        let columns = schema_tree.get_column_descriptors();
+        assert_eq!(schema_tree.get_num_columns(), 3);
+
+        let expected = [
+            ("id", Type::INT64, 0, 0, 1),
+            ("info.age", Type::INT32, 2, 0, 3),
+            ("info.tags", Type::BYTE_ARRAY, 2, 1, 4),
+        ];
+        for (i, (path, physical_type, max_def, max_rep, element_index)) in
+            expected.iter().enumerate()
+        {
+            assert_eq!(columns[i].get_column_index(), i);
+            assert_eq!(columns[i].get_path_string(), *path);
+            assert_eq!(columns[i].get_physical_type(), *physical_type);
+            assert_eq!(columns[i].get_max_definition_level(), *max_def);
+            assert_eq!(columns[i].get_max_repetition_level(), *max_rep);
+            assert_eq!(columns[i].get_schema_element_index(), *element_index);
+        }
+        assert_eq!(
+            columns[2].get_path(),
+            &vec![String::from("info"), String::from("tags")]
+        );
+        assert_eq!(columns[2].to_string(), "Column Descriptor: column_index 2, path info.tags, physical_type 6, max_definition_level 2, max_repetition_level 1\n");
+    }
+
+    #[test]
+    fn test_sample_file_schema() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+
+        let res = SchemaTree::new(file_metadata.schema());
+        assert!(res.is_ok());
+        let schema_tree = res.unwrap();
+
+        assert_eq!(schema_tree.get_num_columns(), 22);
+        let columns = schema_tree.get_column_descriptors();
+        assert_eq!(columns[0].get_path_string(), "orderkey");
+        assert_eq!(columns[0].get_physical_type(), Type::INT64);
+        assert_eq!(columns[15].get_path_string(), "comment");
+        assert_eq!(columns[15].get_physical_type(), Type::BYTE_ARRAY);
+        for column in columns {
+            assert_eq!(column.get_max_definition_level(), 1);
+            assert_eq!(column.get_max_repetition_level(), 0);
+        }
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let res = SchemaTree::new(&[]);
+        assert!(res.is_err());
+
+        // The root claims 2 children, but there is only 1.
+        let schema = vec![
+            create_group("schema", None, 2),
+            create_leaf("id", FieldRepetitionType::REQUIRED, Type::INT64),
+        ];
+        let res = SchemaTree::new(&schema);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Schema element: 2 is out of bound, number of schema elements: 2\n"
+        );
+
+        // The leaf has no physical type.
+        let schema = vec![
+            create_group("schema", None, 1),
+            create_group("id", Some(FieldRepetitionType::REQUIRED), 0),
+        ];
+        let res = SchemaTree::new(&schema);
+        assert!(res.is_err());
+
+        // The last element is not reachable from the root.
+        let schema = vec![
+            create_group("schema", None, 1),
+            create_leaf("id", FieldRepetitionType::REQUIRED, Type::INT64),
+            create_leaf("extra", FieldRepetitionType::REQUIRED, Type::INT64),
+        ];
+        let res = SchemaTree::new(&schema);
+        assert!(res.is_err());
+    }
+}
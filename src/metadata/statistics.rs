@@ -0,0 +1,249 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::Type;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The column chunk statistics. The min and max values are the raw PLAIN encoded bytes, which
+/// can be decoded with the typed accessors according to the physical type of the column.
+#[derive(Clone)]
+pub struct Statistics {
+    physical_type: Type,
+    min_value: Option<Vec<u8>>,
+    max_value: Option<Vec<u8>>,
+    null_count: Option<i64>,
+    distinct_count: Option<i64>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Statistics: physical_type {}, min_value {:?}, max_value {:?}, null_count {:?}, distinct_count {:?}",
+            self.physical_type.0,
+            self.min_value,
+            self.max_value,
+            self.null_count,
+            self.distinct_count
+        )
+    }
+}
+
+impl Statistics {
+    /// The deprecated min and max fields are used if min_value and max_value are not set. They
+    /// were written with the signed comparison, which is the sort order of the numeric types.
+    pub fn from_thrift(
+        statistics: &parquet_metadata_thrift::Statistics,
+        physical_type: Type,
+    ) -> Statistics {
+        let (min_value, max_value) = match (&statistics.min_value, &statistics.max_value) {
+            (None, None) => (statistics.min.clone(), statistics.max.clone()),
+            (min_value, max_value) => (min_value.clone(), max_value.clone()),
+        };
+
+        Statistics {
+            physical_type,
+            min_value,
+            max_value,
+            null_count: statistics.null_count,
+            distinct_count: statistics.distinct_count,
+        }
+    }
+
+    pub fn physical_type(&self) -> Type {
+        self.physical_type
+    }
+
+    pub fn min_value(&self) -> Option<&[u8]> {
+        self.min_value.as_deref()
+    }
+
+    pub fn max_value(&self) -> Option<&[u8]> {
+        self.max_value.as_deref()
+    }
+
+    pub fn null_count(&self) -> Option<i64> {
+        self.null_count
+    }
+
+    pub fn distinct_count(&self) -> Option<i64> {
+        self.distinct_count
+    }
+
+    pub fn min_i32(&self) -> Result<Option<i32>, BoltReaderError> {
+        self.decode(Type::INT32, &self.min_value, i32::from_le_bytes)
+    }
+
+    pub fn max_i32(&self) -> Result<Option<i32>, BoltReaderError> {
+        self.decode(Type::INT32, &self.max_value, i32::from_le_bytes)
+    }
+
+    pub fn min_i64(&self) -> Result<Option<i64>, BoltReaderError> {
+        self.decode(Type::INT64, &self.min_value, i64::from_le_bytes)
+    }
+
+    pub fn max_i64(&self) -> Result<Option<i64>, BoltReaderError> {
+        self.decode(Type::INT64, &self.max_value, i64::from_le_bytes)
+    }
+
+    pub fn min_f32(&self) -> Result<Option<f32>, BoltReaderError> {
+        self.decode(Type::FLOAT, &self.min_value, f32::from_le_bytes)
+    }
+
+    pub fn max_f32(&self) -> Result<Option<f32>, BoltReaderError> {
+        self.decode(Type::FLOAT, &self.max_value, f32::from_le_bytes)
+    }
+
+    pub fn min_f64(&self) -> Result<Option<f64>, BoltReaderError> {
+        self.decode(Type::DOUBLE, &self.min_value, f64::from_le_bytes)
+    }
+
+    pub fn max_f64(&self) -> Result<Option<f64>, BoltReaderError> {
+        self.decode(Type::DOUBLE, &self.max_value, f64::from_le_bytes)
+    }
+
+    fn decode<T, const N: usize>(
+        &self,
+        expected_type: Type,
+        value: &Option<Vec<u8>>,
+        from_le_bytes: fn([u8; N]) -> T,
+    ) -> Result<Option<T>, BoltReaderError> {
+        if unlikely(self.physical_type != expected_type) {
+            return Err(BoltReaderError::MetadataError(format!(
+                "Can not decode the statistics of physical type: {} as physical type: {}",
+                self.physical_type.0, expected_type.0
+            )));
+        }
+
+        match value {
+            None => Ok(None),
+            Some(bytes) => match <[u8; N]>::try_from(&bytes[..]) {
+                Ok(bytes) => Ok(Some(from_le_bytes(bytes))),
+                Err(_) => Err(BoltReaderError::MetadataError(format!(
+                    "Invalid statistics value size: {} bytes, expected: {} bytes",
+                    bytes.len(),
+                    N
+                ))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift;
+    use crate::metadata::parquet_metadata_thrift::Type;
+    use crate::metadata::statistics::Statistics;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_column_statistics() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        // orderkey
+        let statistics = row_group.column(0).unwrap().statistics().unwrap();
+        assert_eq!(statistics.physical_type(), Type::INT64);
+        assert_eq!(statistics.null_count(), Some(0));
+        assert_eq!(statistics.distinct_count(), None);
+        let min = statistics.min_i64().unwrap().unwrap();
+        let max = statistics.max_i64().unwrap().unwrap();
+        assert_eq!(min, -439);
+        assert_eq!(max, 56160);
+
+        // linenumber
+        let statistics = row_group.column(3).unwrap().statistics().unwrap();
+        assert_eq!(statistics.min_i32().unwrap(), Some(1));
+        assert_eq!(statistics.max_i32().unwrap(), Some(197));
+
+        // quantity
+        let statistics = row_group.column(4).unwrap().statistics().unwrap();
+        assert_eq!(statistics.min_f64().unwrap(), Some(1.0));
+        assert_eq!(statistics.max_f64().unwrap(), Some(50.0));
+
+        // discount, the min value is -0.0
+        let statistics = row_group.column(6).unwrap().statistics().unwrap();
+        let min = statistics.min_f64().unwrap().unwrap();
+        let max = statistics.max_f64().unwrap().unwrap();
+        assert_eq!(min.to_bits(), (-0.0f64).to_bits());
+        assert!(max > 0.0 && max <= 0.1);
+
+        let res = statistics.min_i64();
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet Metadata Error: Can not decode the statistics of physical type: 5 as physical type: 2\n"
+        );
+
+        // returnflag, the binary values are kept as raw bytes.
+        let statistics = row_group.column(8).unwrap().statistics().unwrap();
+        assert_eq!(statistics.min_value(), Some("A".as_bytes()));
+        assert_eq!(statistics.max_value(), Some("R".as_bytes()));
+    }
+
+    #[test]
+    fn test_deprecated_min_max() {
+        let thrift_statistics = parquet_metadata_thrift::Statistics::new(
+            Some(100i32.to_le_bytes().to_vec()),
+            Some((-5i32).to_le_bytes().to_vec()),
+            Some(3),
+            Some(10),
+            None,
+            None,
+        );
+        let statistics = Statistics::from_thrift(&thrift_statistics, Type::INT32);
+        assert_eq!(statistics.min_i32().unwrap(), Some(-5));
+        assert_eq!(statistics.max_i32().unwrap(), Some(100));
+        assert_eq!(statistics.null_count(), Some(3));
+        assert_eq!(statistics.distinct_count(), Some(10));
+
+        // min_value and max_value take precedence over the deprecated fields.
+        let thrift_statistics = parquet_metadata_thrift::Statistics::new(
+            Some(100i32.to_le_bytes().to_vec()),
+            Some((-5i32).to_le_bytes().to_vec()),
+            None,
+            None,
+            Some(7i32.to_le_bytes().to_vec()),
+            None,
+        );
+        let statistics = Statistics::from_thrift(&thrift_statistics, Type::INT32);
+        assert_eq!(statistics.min_i32().unwrap(), None);
+        assert_eq!(statistics.max_i32().unwrap(), Some(7));
+
+        let thrift_statistics = parquet_metadata_thrift::Statistics::new(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![1, 2]),
+            Some(vec![1, 2]),
+        );
+        let statistics = Statistics::from_thrift(&thrift_statistics, Type::INT32);
+        let res = statistics.min_i32();
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet Metadata Error: Invalid statistics value size: 2 bytes, expected: 4 bytes\n"
+        );
+    }
+}
@@ -0,0 +1,2284 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::{type_name, TypeId};
+use std::fmt::Formatter;
+use std::mem;
+use std::sync::Arc;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::bridge::raw_bridge::RawBridge;
+use crate::metadata::encoding;
+use crate::metadata::offset_index::{OffsetIndex, PageLocation};
+use crate::metadata::page_header::{
+    check_page_size, get_page_encoding, read_page_header, verify_page_crc, DEFAULT_MAX_PAGE_SIZE,
+};
+use crate::metadata::parquet_metadata_thrift::{
+    CompressionCodec, Encoding, PageHeader, PageType, Type,
+};
+use crate::metadata::row_group::ColumnChunkMetaData;
+use crate::page_reader::data_page_v1::byte_stream_split_data_page_v1::ByteStreamSplitDataPageReaderV1;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::page_reader::data_page_v1::delta_binary_packed_data_page_v1::DeltaBinaryPackedDataPageReaderV1;
+use crate::page_reader::data_page_v1::dictionary_data_page_v1::DictionaryDataPageReaderV1;
+use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
+    destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
+};
+use crate::page_reader::data_page_v2::fixed_length_plain_data_page_v2::{
+    destroy_fixed_length_plain_data_page_v2, get_data_page_v2_values_size,
+    FixedLengthPlainDataPageReaderV2,
+};
+use crate::page_reader::decompression::Decompressors;
+use crate::page_reader::dictionary_page::dictionary_page_reader::DictionaryPageReader;
+use crate::utils::buffer_pool::{BufferPool, PooledBuffer, DEFAULT_MAX_FREE_BUFFERS};
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::cancellation_token::CancellationToken;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::read_metrics::ReadMetrics;
+use crate::utils::rep_def_parser::RepDefParser;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+/// The data page created by the ColumnChunkReader, according to the page type and encoding.
+///
+/// The plain data pages may share the memory of the column chunk or the decompressed page
+/// buffer, so the page should be released by destroy_column_chunk_data_page.
+pub enum ColumnChunkDataPage<'a, T> {
+    PlainV1(FixedLengthPlainDataPageReaderV1<'a, T>),
+    DictionaryV1(DictionaryDataPageReaderV1<'a, T>),
+    DeltaBinaryPackedV1(DeltaBinaryPackedDataPageReaderV1<'a, T>),
+    ByteStreamSplitV1(ByteStreamSplitDataPageReaderV1<T>),
+    PlainV2(FixedLengthPlainDataPageReaderV2<'a, T>),
+}
+
+impl<'a, T> ColumnChunkDataPage<'a, T> {
+    fn as_data_page(&self) -> &dyn DataPage<T> {
+        match self {
+            ColumnChunkDataPage::PlainV1(page) => page,
+            ColumnChunkDataPage::DictionaryV1(page) => page,
+            ColumnChunkDataPage::DeltaBinaryPackedV1(page) => page,
+            ColumnChunkDataPage::ByteStreamSplitV1(page) => page,
+            ColumnChunkDataPage::PlainV2(page) => page,
+        }
+    }
+}
+
+impl<'a, T> DataPage<T> for ColumnChunkDataPage<'a, T> {
+    fn data_page_has_null(&self) -> bool {
+        self.as_data_page().data_page_has_null()
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.as_data_page().get_data_page_num_values()
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.as_data_page().get_data_page_offset()
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        self.as_data_page().get_data_page_type_size()
+    }
+}
+
+impl<'a, T: 'static + std::marker::Copy + Default> ColumnChunkDataPage<'a, T> {
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        match self {
+            ColumnChunkDataPage::PlainV1(page) => {
+                page.read(to_read, offset, result_row_range_set, result_bridge)
+            }
+            ColumnChunkDataPage::DictionaryV1(page) => {
+                page.read(to_read, offset, result_row_range_set, result_bridge)
+            }
+            ColumnChunkDataPage::DeltaBinaryPackedV1(page) => {
+                page.read(to_read, offset, result_row_range_set, result_bridge)
+            }
+            ColumnChunkDataPage::ByteStreamSplitV1(page) => {
+                page.read(to_read, offset, result_row_range_set, result_bridge)
+            }
+            ColumnChunkDataPage::PlainV2(page) => {
+                page.read(to_read, offset, result_row_range_set, result_bridge)
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[inline(always)]
+pub fn destroy_column_chunk_data_page<T>(data_page: ColumnChunkDataPage<T>) {
+    match data_page {
+        ColumnChunkDataPage::PlainV1(page) => destroy_fixed_length_plain_data_page_v1(page),
+        ColumnChunkDataPage::PlainV2(page) => destroy_fixed_length_plain_data_page_v2(page),
+        _ => {}
+    }
+}
+
+/// A batch of the consecutive values of a column chunk, returned by next_batch. The offset is
+/// the index of the first value in the column chunk, and the validity has the same length as
+/// the values. The value at the null position is the default value.
+pub struct ValueBatch<T> {
+    offset: usize,
+    values: Vec<T>,
+    validity: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl<T> std::fmt::Display for ValueBatch<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Value Batch: offset {}, num_values {}, num_nulls {}",
+            self.offset,
+            self.values.len(),
+            self.validity.iter().filter(|valid| !**valid).count()
+        )
+    }
+}
+
+impl<T: Copy + Default> ValueBatch<T> {
+    fn new(offset: usize) -> ValueBatch<T> {
+        ValueBatch {
+            offset,
+            values: Vec::new(),
+            validity: Vec::new(),
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_num_values(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn get_values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn get_validity(&self) -> &[bool] {
+        &self.validity
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        !self.validity[index]
+    }
+
+    fn append_bridge(&mut self, bridge: &RawBridge<T>) {
+        let values = bridge.get_raw_result();
+        let validity = bridge.get_raw_validity();
+        self.values.extend_from_slice(values);
+        self.validity.extend_from_slice(validity);
+        // The trailing values without validity are all valid.
+        self.validity.resize(self.values.len(), true);
+    }
+
+    /// Split off the first num_values values as a new batch.
+    fn take_front(&mut self, num_values: usize) -> ValueBatch<T> {
+        let values = self.values.split_off(num_values);
+        let validity = self.validity.split_off(num_values);
+        let batch = ValueBatch {
+            offset: self.offset,
+            values: mem::replace(&mut self.values, values),
+            validity: mem::replace(&mut self.validity, validity),
+        };
+        self.offset += num_values;
+        batch
+    }
+}
+
+/// The metadata of a page from its header, returned by describe_pages.
+/// offset: the offset of the page header in the file
+/// header_size: the size of the page header
+/// compressed_page_size, uncompressed_page_size: the sizes of the page after the header
+/// encoding, num_values: None for the pages without them, e.g. the index pages
+pub struct PageDescription {
+    pub offset: usize,
+    pub page_type: PageType,
+    pub encoding: Option<encoding::Encoding>,
+    pub num_values: Option<usize>,
+    pub header_size: usize,
+    pub compressed_page_size: usize,
+    pub uncompressed_page_size: usize,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for PageDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let encoding = match self.encoding {
+            Some(encoding) => encoding.to_string(),
+            None => String::from("None"),
+        };
+        let num_values = match self.num_values {
+            Some(num_values) => num_values.to_string(),
+            None => String::from("None"),
+        };
+        write!(
+            f,
+            "Page: offset {}, type {}, encoding {}, num_values {}, header_size {}, compressed_page_size {}, uncompressed_page_size {}",
+            self.offset,
+            self.page_type.0,
+            encoding,
+            num_values,
+            self.header_size,
+            self.compressed_page_size,
+            self.uncompressed_page_size
+        )
+    }
+}
+
+/// The Column Chunk Reader loads a column chunk of a top level column and creates its data
+/// pages in sequence. The leading dictionary page is loaded transparently, and the compressed
+/// pages are decompressed one by one.
+///
+/// Each data page borrows the reader, so it must be destroyed before the next page is read.
+/// The data page offset is the index of its first value in the column chunk.
+pub struct ColumnChunkReader<T> {
+    path: String,
+    column_chunk_offset: usize,
+    data_page_offset: usize,
+    codec: CompressionCodec,
+    num_values: usize,
+    max_definition_level: u32,
+    values_read: usize,
+    // The number of the page headers read, including the dictionary and index pages.
+    pages_read: usize,
+    verify_crc: bool,
+    max_page_size: usize,
+    cancellation_token: Option<CancellationToken>,
+    read_metrics: Option<Arc<ReadMetrics>>,
+    buffer: DirectByteBuffer,
+    buffer_pool: Arc<BufferPool>,
+    decompressors: Decompressors,
+    // The decompressed page, which is returned to the buffer pool before the next page.
+    page_buffer: Option<PooledBuffer>,
+    dictionary: Option<Arc<DictionaryPageReader<T>>>,
+    // The values decoded by next_batch but not returned yet.
+    pending: ValueBatch<T>,
+}
+
+#[allow(dead_code)]
+impl<T> std::fmt::Display for ColumnChunkReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Column Chunk Reader: path {}, compression {}, num_values {}, values_read {}, has_dictionary {}",
+            self.path,
+            self.codec.0,
+            self.num_values,
+            self.values_read,
+            self.dictionary.is_some()
+        )
+    }
+}
+
+// The pages are decoded with the size of T, so T has to be the type of the physical type of the
+// column chunk, e.g. i64 for INT64. The other physical types, e.g. BYTE_ARRAY, are read by the
+// list and struct column readers.
+fn check_value_type<T: 'static>(column_chunk: &ColumnChunkMetaData) -> Result<(), BoltReaderError> {
+    let physical_type = column_chunk.physical_type();
+    let value_type = match physical_type {
+        Type::INT32 => Some(TypeId::of::<i32>()),
+        Type::INT64 => Some(TypeId::of::<i64>()),
+        Type::FLOAT => Some(TypeId::of::<f32>()),
+        Type::DOUBLE => Some(TypeId::of::<f64>()),
+        _ => None,
+    };
+    if unlikely(value_type != Some(TypeId::of::<T>())) {
+        return Err(BoltReaderError::ColumnChunkError(format!(
+            "Column chunk: {} of physical type: {} cannot be read as {}",
+            column_chunk.path_in_schema().join("."),
+            physical_type.0,
+            type_name::<T>()
+        )));
+    }
+
+    Ok(())
+}
+
+impl<T: 'static + std::marker::Copy + Default> ColumnChunkReader<T> {
+    pub fn new(
+        column_chunk: &ColumnChunkMetaData,
+        file: &dyn LoadFile,
+        max_definition_level: u32,
+    ) -> Result<ColumnChunkReader<T>, BoltReaderError> {
+        check_value_type::<T>(column_chunk)?;
+
+        // The column chunk of a truncated file is loaded up to the end of the file, so the
+        // complete pages are still read and the truncated page is reported by TruncatedPage.
+        let available_size = file
+            .get_file_size()
+            .saturating_sub(column_chunk.column_chunk_offset());
+        let buffer = DirectByteBuffer::from_file(
+            file,
+            column_chunk.column_chunk_offset(),
+            column_chunk.total_compressed_size().min(available_size),
+        )?;
+
+        Self::from_buffer(column_chunk, buffer, max_definition_level)
+    }
+
+    /// Create the reader from the already loaded column chunk, which starts at the
+    /// column_chunk_offset and spans total_compressed_size bytes.
+    pub fn from_buffer(
+        column_chunk: &ColumnChunkMetaData,
+        buffer: DirectByteBuffer,
+        max_definition_level: u32,
+    ) -> Result<ColumnChunkReader<T>, BoltReaderError> {
+        check_value_type::<T>(column_chunk)?;
+
+        Ok(ColumnChunkReader {
+            path: column_chunk.path_in_schema().join("."),
+            column_chunk_offset: column_chunk.column_chunk_offset(),
+            data_page_offset: column_chunk.data_page_offset(),
+            codec: column_chunk.compression(),
+            num_values: column_chunk.num_values(),
+            max_definition_level,
+            values_read: 0,
+            pages_read: 0,
+            verify_crc: false,
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+            cancellation_token: None,
+            read_metrics: None,
+            buffer,
+            buffer_pool: BufferPool::new(DEFAULT_MAX_FREE_BUFFERS),
+            decompressors: Decompressors::default(),
+            page_buffer: None,
+            dictionary: None,
+            pending: ValueBatch::new(0),
+        })
+    }
+
+    pub fn get_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    pub fn get_values_read(&self) -> usize {
+        self.values_read
+    }
+
+    pub fn has_dictionary(&self) -> bool {
+        self.dictionary.is_some()
+    }
+
+    /// The decoded dictionary of the column chunk, which can be shared by the data pages decoded
+    /// on other threads. None before the dictionary page is read, or without the dictionary.
+    pub fn get_dictionary(&self) -> Option<Arc<DictionaryPageReader<T>>> {
+        self.dictionary.clone()
+    }
+
+    /// Verify the CRC32 of the pages with crc in the header before decoding them.
+    pub fn set_verify_crc(&mut self, verify_crc: bool) {
+        self.verify_crc = verify_crc;
+    }
+
+    /// The pages larger than the max page size are rejected with PageTooLarge before they are
+    /// decompressed. The default is DEFAULT_MAX_PAGE_SIZE.
+    pub fn set_max_page_size(&mut self, max_page_size: usize) {
+        self.max_page_size = max_page_size;
+    }
+
+    /// The token is checked before each page is read, so next_page returns Cancelled once the
+    /// token is cancelled.
+    pub fn set_cancellation_token(&mut self, cancellation_token: CancellationToken) {
+        self.cancellation_token = Some(cancellation_token);
+    }
+
+    /// The data pages created by next_page are counted into the pages_decoded of the metrics.
+    pub fn set_read_metrics(&mut self, read_metrics: Arc<ReadMetrics>) {
+        self.read_metrics = Some(read_metrics);
+    }
+
+    pub fn get_read_metrics(&self) -> Option<&Arc<ReadMetrics>> {
+        self.read_metrics.as_ref()
+    }
+
+    /// The compressed pages are decompressed into the buffers of the pool, e.g. a pool shared by
+    /// the column chunk readers of a scan. By default, each reader has its own pool.
+    pub fn set_buffer_pool(&mut self, buffer_pool: Arc<BufferPool>) {
+        self.buffer_pool = buffer_pool;
+    }
+
+    pub fn get_buffer_pool(&self) -> &Arc<BufferPool> {
+        &self.buffer_pool
+    }
+
+    /// Release the reader and return the column chunk, rewound to its start, e.g. to read it
+    /// again without loading it from the file.
+    pub fn into_buffer(self) -> DirectByteBuffer {
+        let mut buffer = self.buffer;
+        buffer.set_rpos(0);
+        buffer
+    }
+
+    /// The compressed pages are decompressed by the decompressors, e.g. with the custom
+    /// decompressors of the reader. By default, the default decompressor of each codec is used.
+    pub fn set_decompressors(&mut self, decompressors: Decompressors) {
+        self.decompressors = decompressors;
+    }
+
+    /// Describe the pages of the column chunk from their headers without decoding the values,
+    /// e.g. to debug the page layout. The pages are listed from the start of the column chunk,
+    /// and the position of the reader is not changed.
+    pub fn describe_pages(&self) -> Result<Vec<PageDescription>, BoltReaderError> {
+        let bytes = self.buffer.as_bytes();
+        let mut remaining = bytes;
+        let mut pages = Vec::new();
+        while !remaining.is_empty() {
+            let header_begin = bytes.len() - remaining.len();
+            let page_header = read_page_header(&mut remaining)?;
+            let body_begin = bytes.len() - remaining.len();
+            if unlikely(
+                page_header.compressed_page_size < 0 || page_header.uncompressed_page_size < 0,
+            ) {
+                return Err(BoltReaderError::ColumnChunkError(format!(
+                    "Column chunk: {} has invalid page size: {} bytes at offset: {}, column chunk size: {} bytes",
+                    self.path,
+                    page_header.compressed_page_size,
+                    body_begin,
+                    bytes.len()
+                )));
+            }
+            if unlikely(page_header.compressed_page_size as usize > remaining.len()) {
+                return Err(BoltReaderError::TruncatedPage {
+                    page_index: pages.len(),
+                    page_size: page_header.compressed_page_size as usize,
+                    available: remaining.len(),
+                });
+            }
+
+            let num_values = if let Some(header) = &page_header.data_page_header {
+                Some(header.num_values as usize)
+            } else if let Some(header) = &page_header.data_page_header_v2 {
+                Some(header.num_values as usize)
+            } else {
+                page_header
+                    .dictionary_page_header
+                    .as_ref()
+                    .map(|header| header.num_values as usize)
+            };
+            let compressed_page_size = page_header.compressed_page_size as usize;
+            pages.push(PageDescription {
+                offset: self.column_chunk_offset + header_begin,
+                page_type: page_header.type_,
+                encoding: get_page_encoding(&page_header).ok(),
+                num_values,
+                header_size: body_begin - header_begin,
+                compressed_page_size,
+                uncompressed_page_size: page_header.uncompressed_page_size as usize,
+            });
+            let body_end = body_begin + compressed_page_size;
+            remaining = if header_begin == 0 && page_header.type_ == PageType::DICTIONARY_PAGE {
+                &bytes[self.get_first_data_page_position(body_end)?..]
+            } else {
+                &bytes[body_end..]
+            };
+        }
+
+        Ok(pages)
+    }
+
+    /// Estimate the size of the decoded values of the column chunk from the page headers, e.g. to
+    /// allocate the output buffers up front. The values take the type size per value, including
+    /// the nulls.
+    pub fn estimated_decoded_bytes(&self) -> Result<usize, BoltReaderError> {
+        let num_values: usize = self
+            .describe_pages()?
+            .iter()
+            .filter(|page| {
+                page.page_type == PageType::DATA_PAGE || page.page_type == PageType::DATA_PAGE_V2
+            })
+            .filter_map(|page| page.num_values)
+            .sum();
+
+        Ok(num_values * mem::size_of::<T>())
+    }
+
+    // Release the previous page to the pool first, so its allocation can be reused.
+    fn acquire_page_buffer(
+        buffer_pool: &Arc<BufferPool>,
+        page_buffer: &mut Option<PooledBuffer>,
+        size: usize,
+    ) -> Result<(), BoltReaderError> {
+        *page_buffer = None;
+        *page_buffer = Some(buffer_pool.acquire(size)?);
+        Ok(())
+    }
+
+    /// Create the next data page, or return None when all the values of the column chunk are
+    /// read. The dictionary and index pages are not returned.
+    pub fn next_page(&mut self) -> Result<Option<ColumnChunkDataPage<'_, T>>, BoltReaderError> {
+        loop {
+            if self.values_read >= self.num_values || self.buffer.get_rpos() >= self.buffer.len() {
+                return Ok(None);
+            }
+
+            if let Some(cancellation_token) = &self.cancellation_token {
+                cancellation_token.check()?;
+            }
+            let page_header = read_page_header(&mut self.buffer)?;
+            self.pages_read += 1;
+            let body_begin = self.buffer.get_rpos();
+            let body_end = body_begin + self.get_compressed_page_size(&page_header)?;
+            if self.verify_crc {
+                verify_page_crc(&page_header, &self.buffer.as_bytes()[body_begin..body_end])?;
+            }
+
+            match page_header.type_ {
+                PageType::DICTIONARY_PAGE => {
+                    if unlikely(self.dictionary.is_some() || self.values_read > 0) {
+                        return Err(BoltReaderError::ColumnChunkError(format!(
+                            "Column chunk: {} dictionary page should be the first page",
+                            self.path
+                        )));
+                    }
+                    self.load_dictionary_page(&page_header, body_begin, body_end)?;
+                }
+                PageType::DATA_PAGE => {
+                    if let Some(read_metrics) = &self.read_metrics {
+                        read_metrics.add_pages_decoded(1);
+                    }
+                    return self
+                        .create_data_page_v1(&page_header, body_begin, body_end)
+                        .map(Some);
+                }
+                PageType::DATA_PAGE_V2 => {
+                    if let Some(read_metrics) = &self.read_metrics {
+                        read_metrics.add_pages_decoded(1);
+                    }
+                    return self
+                        .create_data_page_v2(&page_header, body_begin, body_end)
+                        .map(Some);
+                }
+                _ => self.buffer.set_rpos(body_end),
+            }
+        }
+    }
+
+    /// Return the next size values of the column chunk, which may span several data pages, or
+    /// None when all the values are returned. The last batch may have fewer values.
+    ///
+    /// The data pages are decoded in full, and the values after the batch are kept for the next
+    /// call, so next_batch should not be mixed with next_page.
+    pub fn next_batch(&mut self, size: usize) -> Result<Option<ValueBatch<T>>, BoltReaderError> {
+        if unlikely(size == 0) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} batch size should be positive",
+                self.path
+            )));
+        }
+
+        while self.pending.get_num_values() < size {
+            if !self.decode_next_page()? {
+                break;
+            }
+        }
+
+        if self.pending.get_num_values() == 0 {
+            return Ok(None);
+        }
+
+        let num_values = size.min(self.pending.get_num_values());
+        Ok(Some(self.pending.take_front(num_values)))
+    }
+
+    /// Decode the next data page in full and append its values to the pending values. Return
+    /// false when all the values of the column chunk are read.
+    fn decode_next_page(&mut self) -> Result<bool, BoltReaderError> {
+        let data_page = match self.next_page()? {
+            Some(data_page) => data_page,
+            None => return Ok(false),
+        };
+
+        let page_begin = data_page.get_data_page_offset();
+        let page_end = page_begin + data_page.get_data_page_num_values();
+        let mut result_row_range_set = RowRangeSet::new(page_begin);
+        let mut result_bridge = RawBridge::new(
+            data_page.data_page_has_null(),
+            data_page.get_data_page_num_values(),
+        );
+        let to_read = RowRange::new(page_begin, page_end);
+        let res = match data_page.get_data_page_covered_range(page_begin, page_end, 0, &to_read) {
+            Ok(Some(covered)) if !covered.is_empty() => {
+                data_page.read(covered, 0, &mut result_row_range_set, &mut result_bridge)
+            }
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        };
+        destroy_column_chunk_data_page(data_page);
+        res?;
+
+        if self.pending.get_num_values() == 0 {
+            self.pending.offset = page_begin;
+        }
+        self.pending.append_bridge(&result_bridge);
+        Ok(true)
+    }
+
+    /// Collect the values of the selected rows in row order. The ranges are value indexes in the
+    /// column chunk after adding the offset of the row range set, and the values beyond the
+    /// column chunk are ignored. The value at the null position is the default value.
+    ///
+    /// The column chunk is read from the next page, so collect_selected should not be mixed with
+    /// next_page or next_batch.
+    pub fn collect_selected(&mut self, ranges: &RowRangeSet) -> Result<Vec<T>, BoltReaderError> {
+        Ok(self.collect_selected_with_validity(ranges)?.0)
+    }
+
+    /// Collect the values of the selected rows in row order, see collect_selected, with the
+    /// validity of the same length as the values.
+    pub fn collect_selected_with_validity(
+        &mut self,
+        ranges: &RowRangeSet,
+    ) -> Result<(Vec<T>, Vec<bool>), BoltReaderError> {
+        let mut column_chunk_range_set = RowRangeSet::new(0);
+        column_chunk_range_set.add_row_ranges(0, self.num_values);
+        let selection = column_chunk_range_set.intersect(ranges);
+        let num_rows = selection.count_rows();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut result_bridge = RawBridge::new(self.max_definition_level > 0, num_rows);
+        while result_bridge.get_size() < num_rows {
+            let data_page = match self.next_page()? {
+                Some(data_page) => data_page,
+                None => break,
+            };
+
+            let page_begin = data_page.get_data_page_offset();
+            let page_end = page_begin + data_page.get_data_page_num_values();
+            let mut res = Ok(());
+            for range in selection.get_row_ranges() {
+                if range.end <= page_begin {
+                    continue;
+                }
+                if range.begin >= page_end {
+                    break;
+                }
+
+                let to_read = RowRange::new(range.begin.max(page_begin), range.end);
+                res = match data_page.get_data_page_covered_range(page_begin, page_end, 0, &to_read)
+                {
+                    Ok(Some(covered)) if !covered.is_empty() => {
+                        data_page.read(covered, 0, &mut result_row_range_set, &mut result_bridge)
+                    }
+                    Ok(_) => Ok(()),
+                    Err(e) => Err(e),
+                };
+                if res.is_err() {
+                    break;
+                }
+            }
+            destroy_column_chunk_data_page(data_page);
+            res?;
+        }
+
+        if unlikely(result_bridge.get_size() != num_rows) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} has read {} values, expected {} selected values",
+                self.path,
+                result_bridge.get_size(),
+                num_rows
+            )));
+        }
+
+        let mut batch = ValueBatch::new(0);
+        batch.append_bridge(&result_bridge);
+        Ok((batch.values, batch.validity))
+    }
+
+    /// Move to the row of the column chunk, so the next_batch starts from it. The page
+    /// containing the row is located by the OffsetIndex and decoded from the page start, as the
+    /// delta encoded values depend on the previous values in the page.
+    pub fn seek_to_row(
+        &mut self,
+        offset_index: &OffsetIndex,
+        row: usize,
+    ) -> Result<(), BoltReaderError> {
+        let page_locations = offset_index.page_locations();
+        let page_index = page_locations.partition_point(|location| location.first_row_index <= row);
+        if unlikely(row >= self.num_values || page_index == 0) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} cannot seek to row: {}, num_values: {}",
+                self.path, row, self.num_values
+            )));
+        }
+
+        let location = &page_locations[page_index - 1];
+        self.skip_to_page(location)?;
+        self.decode_next_page()?;
+        if unlikely(self.pending.get_offset() + self.pending.get_num_values() <= row) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} page at offset: {} does not contain row: {}",
+                self.path, location.offset, row
+            )));
+        }
+        self.pending.take_front(row - self.pending.get_offset());
+
+        Ok(())
+    }
+
+    /// Move to the data page at the location from the OffsetIndex, so the next_page returns it.
+    /// The first page of the column chunk is checked for the dictionary, which is needed by the
+    /// dictionary encoded pages after the skipped ones.
+    pub fn skip_to_page(&mut self, location: &PageLocation) -> Result<(), BoltReaderError> {
+        if unlikely(
+            location.offset < self.column_chunk_offset
+                || location.offset + location.compressed_page_size
+                    > self.column_chunk_offset + self.buffer.len(),
+        ) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} page location: [{}, {}) is out of the column chunk: [{}, {})",
+                self.path,
+                location.offset,
+                location.offset + location.compressed_page_size,
+                self.column_chunk_offset,
+                self.column_chunk_offset + self.buffer.len()
+            )));
+        }
+
+        if self.values_read == 0 && self.dictionary.is_none() && self.buffer.get_rpos() == 0 {
+            let page_header = read_page_header(&mut self.buffer)?;
+            self.pages_read += 1;
+            if page_header.type_ == PageType::DICTIONARY_PAGE {
+                let body_begin = self.buffer.get_rpos();
+                let body_end = body_begin + self.get_compressed_page_size(&page_header)?;
+                self.load_dictionary_page(&page_header, body_begin, body_end)?;
+            }
+        }
+
+        self.buffer
+            .set_rpos(location.offset - self.column_chunk_offset);
+        self.values_read = location.first_row_index;
+        self.pending = ValueBatch::new(location.first_row_index);
+        Ok(())
+    }
+
+    fn load_dictionary_page(
+        &mut self,
+        page_header: &PageHeader,
+        body_begin: usize,
+        body_end: usize,
+    ) -> Result<(), BoltReaderError> {
+        let dictionary = match self.codec {
+            CompressionCodec::UNCOMPRESSED => {
+                DictionaryPageReader::new_with_header(page_header, &mut self.buffer)?
+            }
+            _ => {
+                let uncompressed_size = page_header.uncompressed_page_size as usize;
+                let mut page_buffer = self.buffer_pool.acquire(uncompressed_size)?;
+                page_buffer.fill(|bytes| {
+                    self.decompressors.decompress_page_into(
+                        &self.buffer.as_bytes()[body_begin..body_end],
+                        self.codec,
+                        uncompressed_size,
+                        bytes,
+                    )
+                })?;
+                DictionaryPageReader::new_with_header(page_header, &mut page_buffer)?
+            }
+        };
+        self.dictionary = Some(Arc::new(dictionary));
+        let first_data_page = self.get_first_data_page_position(body_end)?;
+        self.buffer.set_rpos(first_data_page);
+        Ok(())
+    }
+
+    /// The first data page starts at the data_page_offset, which may leave a gap after the
+    /// dictionary page. The data_page_offset before the end of the dictionary page is not
+    /// reliable, e.g. without the dictionary_page_offset, so the data page is assumed to follow
+    /// the dictionary page.
+    fn get_first_data_page_position(&self, body_end: usize) -> Result<usize, BoltReaderError> {
+        let position = match self.data_page_offset.checked_sub(self.column_chunk_offset) {
+            Some(position) if position > body_end => position,
+            _ => return Ok(body_end),
+        };
+
+        if unlikely(position >= self.buffer.len()) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} data page offset: {} is out of the column chunk: [{}, {})",
+                self.path,
+                self.data_page_offset,
+                self.column_chunk_offset,
+                self.column_chunk_offset + self.buffer.len()
+            )));
+        }
+
+        Ok(position)
+    }
+
+    fn get_compressed_page_size(&self, page_header: &PageHeader) -> Result<usize, BoltReaderError> {
+        check_page_size(page_header, self.max_page_size)?;
+        let body_begin = self.buffer.get_rpos();
+        if unlikely(page_header.compressed_page_size < 0 || page_header.uncompressed_page_size < 0)
+        {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Column chunk: {} has invalid page size: {} bytes at offset: {}, column chunk size: {} bytes",
+                self.path,
+                page_header.compressed_page_size,
+                body_begin,
+                self.buffer.len()
+            )));
+        }
+
+        // The page extends past the loaded bytes, e.g. the file is truncated in the middle of it.
+        let compressed_page_size = page_header.compressed_page_size as usize;
+        if unlikely(body_begin + compressed_page_size > self.buffer.len()) {
+            return Err(BoltReaderError::TruncatedPage {
+                page_index: self.pages_read - 1,
+                page_size: compressed_page_size,
+                available: self.buffer.len() - body_begin,
+            });
+        }
+
+        Ok(compressed_page_size)
+    }
+
+    /// A data page should not have more values than the rest of the column chunk, otherwise the
+    /// page ranges exceed the column chunk.
+    fn check_page_num_values(&self, num_values: i64) -> Result<(), BoltReaderError> {
+        if unlikely(num_values < 0 || self.values_read + num_values as usize > self.num_values) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Column chunk: {} data page num_values: {} exceeds the remaining values: {}",
+                self.path,
+                num_values,
+                self.num_values - self.values_read
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn create_data_page_v1(
+        &mut self,
+        page_header: &PageHeader,
+        body_begin: usize,
+        body_end: usize,
+    ) -> Result<ColumnChunkDataPage<'_, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::ColumnChunkError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+        let num_values = header.num_values as usize;
+        self.check_page_num_values(header.num_values as i64)?;
+        let current_offset = self.values_read;
+        let max_definition_level = self.max_definition_level;
+
+        let ColumnChunkReader {
+            path,
+            codec,
+            buffer,
+            buffer_pool,
+            decompressors,
+            page_buffer,
+            dictionary,
+            ..
+        } = self;
+
+        let page_buffer: &mut DirectByteBuffer = match *codec {
+            CompressionCodec::UNCOMPRESSED => buffer,
+            _ => {
+                let uncompressed_size = page_header.uncompressed_page_size as usize;
+                Self::acquire_page_buffer(buffer_pool, page_buffer, uncompressed_size)?;
+                let pooled_buffer = page_buffer.as_mut().unwrap();
+                pooled_buffer.fill(|bytes| {
+                    decompressors.decompress_page_into(
+                        &buffer.as_bytes()[body_begin..body_end],
+                        *codec,
+                        uncompressed_size,
+                        bytes,
+                    )
+                })?;
+                buffer.set_rpos(body_end);
+                pooled_buffer
+            }
+        };
+
+        // The encoding is chosen per page, as the writer falls back from the dictionary to PLAIN
+        // when the dictionary is full, and the dictionary is kept for the whole column chunk.
+        let data_page = match encoding::Encoding::from(header.encoding) {
+            encoding::Encoding::Plain => ColumnChunkDataPage::PlainV1(
+                FixedLengthPlainDataPageReaderV1::new_with_definition_levels(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    mem::size_of::<T>(),
+                    max_definition_level,
+                    None,
+                )?,
+            ),
+            encoding::Encoding::RleDictionary | encoding::Encoding::PlainDictionary => {
+                let dictionary = match dictionary.as_deref() {
+                    Some(dictionary) => dictionary,
+                    None => {
+                        return Err(BoltReaderError::ColumnChunkError(format!(
+                        "Column chunk: {} has dictionary encoded data page without dictionary page",
+                        path
+                    )))
+                    }
+                };
+                let (has_null, validity) =
+                    Self::load_validity(page_buffer, num_values, max_definition_level)?;
+                ColumnChunkDataPage::DictionaryV1(DictionaryDataPageReaderV1::new(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    has_null,
+                    dictionary,
+                    None,
+                    validity,
+                )?)
+            }
+            encoding::Encoding::DeltaBinaryPacked => {
+                let (has_null, validity) =
+                    Self::load_validity(page_buffer, num_values, max_definition_level)?;
+                ColumnChunkDataPage::DeltaBinaryPackedV1(DeltaBinaryPackedDataPageReaderV1::new(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    mem::size_of::<T>(),
+                    has_null,
+                    None,
+                    validity,
+                )?)
+            }
+            encoding::Encoding::ByteStreamSplit => {
+                let (has_null, validity) =
+                    Self::load_validity(page_buffer, num_values, max_definition_level)?;
+                ColumnChunkDataPage::ByteStreamSplitV1(ByteStreamSplitDataPageReaderV1::new(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    has_null,
+                    validity,
+                )?)
+            }
+            _ => return Err(BoltReaderError::UnsupportedEncoding(header.encoding)),
+        };
+
+        if *codec == CompressionCodec::UNCOMPRESSED {
+            buffer.set_rpos(body_end);
+        }
+        self.values_read += num_values;
+
+        Ok(data_page)
+    }
+
+    fn create_data_page_v2(
+        &mut self,
+        page_header: &PageHeader,
+        body_begin: usize,
+        body_end: usize,
+    ) -> Result<ColumnChunkDataPage<'_, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header_v2 {
+            Some(data_page_v2) => data_page_v2,
+            None => {
+                return Err(BoltReaderError::ColumnChunkError(String::from(
+                    "Error when reading Data Page V2 Header",
+                )))
+            }
+        };
+        if unlikely(header.encoding != Encoding::PLAIN) {
+            return Err(BoltReaderError::UnsupportedEncoding(header.encoding));
+        }
+        self.check_page_num_values(header.num_rows as i64)?;
+        let num_rows = header.num_rows as usize;
+        let current_offset = self.values_read;
+
+        // The levels of Data Page V2 are never compressed, only the values are decompressed.
+        let is_compressed = header.is_compressed.unwrap_or(true);
+        let page_buffer: &mut DirectByteBuffer = if self.codec == CompressionCodec::UNCOMPRESSED
+            || !is_compressed
+        {
+            &mut self.buffer
+        } else {
+            let (compressed_size, values_size) = get_data_page_v2_values_size(page_header, header)?;
+            let uncompressed_size = page_header.uncompressed_page_size as usize;
+            let levels_length = page_header.compressed_page_size as usize - compressed_size;
+
+            Self::acquire_page_buffer(&self.buffer_pool, &mut self.page_buffer, uncompressed_size)?;
+            let bytes = self.buffer.as_bytes();
+            let pooled_buffer = self.page_buffer.as_mut().unwrap();
+            pooled_buffer.fill(|page| {
+                page.extend_from_slice(&bytes[body_begin..body_begin + levels_length]);
+                self.decompressors.decompress_page_into(
+                    &bytes[body_begin + levels_length..body_end],
+                    self.codec,
+                    values_size,
+                    page,
+                )
+            })?;
+
+            self.buffer.set_rpos(body_end);
+            pooled_buffer
+        };
+
+        // The page buffer is already decompressed.
+        let data_page = FixedLengthPlainDataPageReaderV2::new(
+            page_header,
+            page_buffer,
+            current_offset,
+            mem::size_of::<T>(),
+            CompressionCodec::UNCOMPRESSED,
+            None,
+        )?;
+
+        self.buffer.set_rpos(body_end);
+        self.values_read += num_rows;
+
+        Ok(ColumnChunkDataPage::PlainV2(data_page))
+    }
+
+    /// Decode the length-prefixed RLE/BP definition levels of a Data Page V1. The validity is
+    /// None if the page has no null.
+    fn load_validity(
+        buffer: &mut dyn ByteBufferBase,
+        num_values: usize,
+        max_definition_level: u32,
+    ) -> Result<(bool, Option<Vec<bool>>), BoltReaderError> {
+        if max_definition_level == 0 {
+            return Ok((false, None));
+        }
+
+        let levels = RepDefParser::load_level(buffer, max_definition_level)?;
+        if unlikely(levels.len() < num_values) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Data Page V1 definition levels: {} are fewer than num_values: {}",
+                levels.len(),
+                num_values
+            )));
+        }
+
+        let validity: Vec<bool> = levels
+            .iter()
+            .take(num_values)
+            .map(|&level| level == max_definition_level)
+            .collect();
+        if validity.iter().all(|&valid| valid) {
+            return Ok((false, None));
+        }
+
+        Ok((true, Some(validity)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use thrift::protocol::{TCompactOutputProtocol, TSerializable};
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::compression::Compression;
+    use crate::metadata::encoding;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::offset_index::read_offset_index;
+    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::{
+        ColumnChunk, DataPageHeader, DataPageHeaderV2, DictionaryPageHeader, Encoding, PageHeader,
+        PageType, Type,
+    };
+    use crate::metadata::row_group::ColumnChunkMetaData;
+    use crate::page_reader::column_chunk_reader::{
+        destroy_column_chunk_data_page, ColumnChunkDataPage, ColumnChunkReader,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::decompression::{Decompressor, Decompressors, SnappyDecompressor};
+    use crate::utils::buffer_pool::{BufferPool, DEFAULT_MAX_FREE_BUFFERS};
+    use crate::utils::cancellation_token::CancellationToken;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+    use crate::utils::test_utils::InMemoryFile;
+
+    // Read all the pages of the first column into the bridge, and return the number of pages.
+    fn read_column_chunk(
+        path: &str,
+        max_definition_level: u32,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut RawBridge<i64>,
+    ) -> (usize, bool) {
+        let path = String::from(path);
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        let res = ColumnChunkReader::<i64>::new(column, &file, max_definition_level);
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+        assert_eq!(reader.get_num_values(), column.num_values());
+
+        let mut num_pages = 0;
+        let mut offset = 0;
+        while let Some(data_page) = reader.next_page().unwrap() {
+            assert_eq!(data_page.get_data_page_offset(), offset);
+            assert_eq!(data_page.get_data_page_type_size(), 8);
+            let num_values = data_page.get_data_page_num_values();
+            data_page
+                .read(
+                    RowRange::new(offset, offset + num_values),
+                    0,
+                    result_row_range_set,
+                    result_bridge,
+                )
+                .unwrap();
+            destroy_column_chunk_data_page(data_page);
+
+            offset += num_values;
+            num_pages += 1;
+        }
+
+        assert_eq!(offset, column.num_values());
+        assert_eq!(reader.get_values_read(), column.num_values());
+        (num_pages, reader.has_dictionary())
+    }
+
+    #[test]
+    fn test_read_multi_page_column_chunk() {
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 1000);
+        let (num_pages, has_dictionary) = read_column_chunk(
+            "src/sample_files/page_index.parquet",
+            1,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert_eq!(num_pages, 4);
+        assert!(!has_dictionary);
+        assert_eq!(raw_bridge.get_size(), 1000);
+
+        // The pages are written as [0, 2, 4, ...], [null, 1001, 1002, ...], all nulls and
+        // [0, -1, -2, ...], 250 values each.
+        let mut num_nulls = 0;
+        for i in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            let row = (i % 250) as i64;
+            let expected = match i / 250 {
+                0 => Some(row * 2),
+                1 if row % 5 == 0 => None,
+                1 => Some(1000 + row),
+                2 => None,
+                _ => Some(-row),
+            };
+            assert_eq!(valid, expected.is_some());
+            if let Some(expected) = expected {
+                assert_eq!(value, expected);
+            } else {
+                num_nulls += 1;
+            }
+        }
+        assert_eq!(num_nulls, 300);
+    }
+
+    #[test]
+    fn test_read_dictionary_column_chunk() {
+        let mut plain_row_range_set = RowRangeSet::new(0);
+        let mut plain_bridge = RawBridge::new(false, 11212);
+        let (num_pages, has_dictionary) = read_column_chunk(
+            "src/sample_files/lineitem.parquet",
+            1,
+            &mut plain_row_range_set,
+            &mut plain_bridge,
+        );
+        assert_eq!(num_pages, 1);
+        assert!(!has_dictionary);
+
+        let mut dictionary_row_range_set = RowRangeSet::new(0);
+        let mut dictionary_bridge = RawBridge::new(false, 11212);
+        let (_, has_dictionary) = read_column_chunk(
+            "src/sample_files/lineitem_dictionary.parquet",
+            1,
+            &mut dictionary_row_range_set,
+            &mut dictionary_bridge,
+        );
+        assert!(has_dictionary);
+        assert_eq!(dictionary_bridge.get_size(), 11212);
+
+        for i in 0..11212 {
+            assert_eq!(
+                dictionary_bridge
+                    .get_validity_and_value(0, i, &dictionary_row_range_set)
+                    .unwrap(),
+                plain_bridge
+                    .get_validity_and_value(0, i, &plain_row_range_set)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_compressed_column_chunk() {
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1000);
+        let (num_pages, _) = read_column_chunk(
+            "src/sample_files/snappy_data_page_v1.parquet",
+            // The sample page is written without definition levels.
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert_eq!(num_pages, 1);
+
+        for i in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(value, (i as i64) % 100 * 3);
+        }
+    }
+
+    struct CountingDecompressor {
+        num_calls: AtomicUsize,
+    }
+
+    impl Decompressor for CountingDecompressor {
+        fn decompress(
+            &self,
+            compressed: &[u8],
+            uncompressed_size: usize,
+            output: &mut Vec<u8>,
+        ) -> Result<(), BoltReaderError> {
+            self.num_calls.fetch_add(1, Ordering::Relaxed);
+            SnappyDecompressor.decompress(compressed, uncompressed_size, output)
+        }
+    }
+
+    #[test]
+    fn test_read_with_custom_decompressor() {
+        let path = String::from("src/sample_files/snappy_data_page_v1.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        let decompressor = Arc::new(CountingDecompressor {
+            num_calls: AtomicUsize::new(0),
+        });
+        let mut decompressors = Decompressors::default();
+        decompressors
+            .register(Compression::Snappy, decompressor.clone())
+            .unwrap();
+
+        // The sample page is written without definition levels.
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 0).unwrap();
+        reader.set_decompressors(decompressors);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1000);
+        let data_page = reader.next_page().unwrap().unwrap();
+        assert_eq!(decompressor.num_calls.load(Ordering::Relaxed), 1);
+        data_page
+            .read(
+                RowRange::new(0, 1000),
+                0,
+                &mut result_row_range_set,
+                &mut raw_bridge,
+            )
+            .unwrap();
+        destroy_column_chunk_data_page(data_page);
+        assert!(reader.next_page().unwrap().is_none());
+
+        for i in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(value, (i as i64) % 100 * 3);
+        }
+    }
+
+    #[test]
+    fn test_read_data_page_v2_column_chunk() {
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 1000);
+        let (num_pages, _) = read_column_chunk(
+            "src/sample_files/data_page_v2.parquet",
+            1,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert_eq!(num_pages, 1);
+
+        for i in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, i % 7 != 3);
+            if valid {
+                assert_eq!(value, (i as i64) * 10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_column_chunk_to_end() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let data_page = reader.next_page().unwrap().unwrap();
+        assert!(matches!(data_page, ColumnChunkDataPage::PlainV1(_)));
+        destroy_column_chunk_data_page(data_page);
+        assert!(reader.next_page().unwrap().is_none());
+        assert_eq!(
+            reader.to_string(),
+            "Column Chunk Reader: path orderkey, compression 0, num_values 11212, values_read 11212, has_dictionary false\n"
+        );
+    }
+
+    #[test]
+    fn test_next_batch() {
+        let mut plain_row_range_set = RowRangeSet::new(0);
+        let mut plain_bridge = RawBridge::new(false, 11212);
+        read_column_chunk(
+            "src/sample_files/lineitem.parquet",
+            1,
+            &mut plain_row_range_set,
+            &mut plain_bridge,
+        );
+
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+
+        // 11212 values in batches of 1000, the last batch has 212 values.
+        let mut batch_sizes = Vec::new();
+        let mut offset = 0;
+        while let Some(batch) = reader.next_batch(1000).unwrap() {
+            assert_eq!(batch.get_offset(), offset);
+            assert_eq!(batch.get_validity().len(), batch.get_num_values());
+            for i in 0..batch.get_num_values() {
+                let (valid, value) = plain_bridge
+                    .get_validity_and_value(0, offset + i, &plain_row_range_set)
+                    .unwrap();
+                assert_eq!(!batch.is_null(i), valid);
+                assert_eq!(batch.get_values()[i], value);
+            }
+            offset += batch.get_num_values();
+            batch_sizes.push(batch.get_num_values());
+        }
+        assert_eq!(batch_sizes.len(), 12);
+        assert!(batch_sizes[..11].iter().all(|size| *size == 1000));
+        assert_eq!(batch_sizes[11], 212);
+        assert!(reader.next_batch(1000).unwrap().is_none());
+
+        let res = reader.next_batch(0);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Column Chunk Error: Column chunk: orderkey batch size should be positive\n"
+        );
+    }
+
+    #[test]
+    fn test_next_batch_across_pages() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+
+        // The 4 pages have 250 values each, so the batches span the page boundaries.
+        let mut batch_sizes = Vec::new();
+        let mut num_nulls = 0;
+        while let Some(batch) = reader.next_batch(300).unwrap() {
+            for i in 0..batch.get_num_values() {
+                let index = batch.get_offset() + i;
+                let row = (index % 250) as i64;
+                let expected = match index / 250 {
+                    0 => Some(row * 2),
+                    1 if row % 5 == 0 => None,
+                    1 => Some(1000 + row),
+                    2 => None,
+                    _ => Some(-row),
+                };
+                assert_eq!(batch.is_null(i), expected.is_none());
+                match expected {
+                    Some(expected) => assert_eq!(batch.get_values()[i], expected),
+                    None => num_nulls += 1,
+                }
+            }
+            batch_sizes.push(batch.get_num_values());
+        }
+        assert_eq!(batch_sizes, vec![300, 300, 300, 100]);
+        assert_eq!(num_nulls, 300);
+    }
+
+    #[test]
+    fn test_seek_to_row() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let offset_index = read_offset_index(
+            &file,
+            column.offset_index_offset().unwrap(),
+            column.offset_index_length().unwrap(),
+        )
+        .unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+
+        // The first row of the second page is null, followed by 1001, 1002, ...
+        assert!(reader.seek_to_row(&offset_index, 250).is_ok());
+        let batch = reader.next_batch(5).unwrap().unwrap();
+        assert_eq!(batch.get_offset(), 250);
+        assert!(batch.is_null(0));
+        assert_eq!(batch.get_values()[1..], [1001, 1002, 1003, 1004]);
+
+        // The third page is all nulls.
+        assert!(reader.seek_to_row(&offset_index, 500).is_ok());
+        let batch = reader.next_batch(250).unwrap().unwrap();
+        assert_eq!(batch.get_offset(), 500);
+        assert_eq!(batch.get_num_values(), 250);
+        assert!(batch.get_validity().iter().all(|valid| !*valid));
+
+        // Seek backward into the middle of the first page, the batch spans the next page.
+        assert!(reader.seek_to_row(&offset_index, 248).is_ok());
+        let batch = reader.next_batch(4).unwrap().unwrap();
+        assert_eq!(batch.get_offset(), 248);
+        assert_eq!(batch.get_validity(), [true, true, false, true]);
+        assert_eq!(batch.get_values()[..2], [496, 498]);
+        assert_eq!(batch.get_values()[3], 1001);
+
+        let res = reader.seek_to_row(&offset_index, 1000);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Column Chunk Error: Column chunk: col cannot seek to row: 1000, num_values: 1000\n"
+        );
+    }
+
+    #[test]
+    fn test_describe_pages() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let offset_index = read_offset_index(
+            &file,
+            column.offset_index_offset().unwrap(),
+            column.offset_index_length().unwrap(),
+        )
+        .unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+
+        let res = reader.describe_pages();
+        assert!(res.is_ok());
+        let pages = res.unwrap();
+        assert_eq!(pages.len(), 4);
+        for (page, location) in pages.iter().zip(offset_index.page_locations()) {
+            assert_eq!(page.page_type, PageType::DATA_PAGE);
+            assert_eq!(page.encoding, Some(encoding::Encoding::Plain));
+            assert_eq!(page.num_values, Some(250));
+            assert_eq!(page.offset, location.offset);
+            assert_eq!(
+                page.header_size + page.compressed_page_size,
+                location.compressed_page_size
+            );
+            assert_eq!(page.compressed_page_size, page.uncompressed_page_size);
+        }
+        let last = &pages[3];
+        assert_eq!(
+            last.offset + last.header_size + last.compressed_page_size,
+            column.column_chunk_offset() + column.total_compressed_size()
+        );
+
+        // The reader still starts from the first page.
+        let data_page = reader.next_page().unwrap().unwrap();
+        assert_eq!(data_page.get_data_page_offset(), 0);
+        assert_eq!(data_page.get_data_page_num_values(), 250);
+        destroy_column_chunk_data_page(data_page);
+
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let pages = reader.describe_pages().unwrap();
+        assert_eq!(pages[0].page_type, PageType::DICTIONARY_PAGE);
+        assert_eq!(pages[0].offset, column.column_chunk_offset());
+        let num_values: usize = pages[1..].iter().map(|page| page.num_values.unwrap()).sum();
+        assert_eq!(num_values, column.num_values());
+        assert!(pages[1..]
+            .iter()
+            .all(|page| page.page_type == PageType::DATA_PAGE
+                && page.encoding == Some(encoding::Encoding::RleDictionary)));
+    }
+
+    #[test]
+    fn test_estimated_decoded_bytes() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+
+        let res = reader.estimated_decoded_bytes();
+        assert!(res.is_ok());
+        let estimated_bytes = res.unwrap();
+
+        // The nulls are decoded as the default value, so they are counted too.
+        let mut decoded_bytes = 0;
+        while let Some(batch) = reader.next_batch(300).unwrap() {
+            decoded_bytes += batch.get_num_values() * std::mem::size_of::<i64>();
+        }
+        assert_eq!(estimated_bytes, 8000);
+        assert_eq!(estimated_bytes, decoded_bytes);
+
+        // The dictionary page is not counted.
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        assert_eq!(
+            reader.estimated_decoded_bytes().unwrap(),
+            column.num_values() * 8
+        );
+    }
+
+    #[test]
+    fn test_check_value_type() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        // The BYTE_ARRAY column is not read by the ColumnChunkReader.
+        let column = row_group.column(15).unwrap();
+        assert_eq!(column.physical_type(), Type::BYTE_ARRAY);
+        let res = ColumnChunkReader::<i64>::new(column, &file, 1);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Column Chunk Error: Column chunk: comment of physical type: 6 cannot be read as i64\n"
+        );
+
+        let column = row_group.column(0).unwrap();
+        assert_eq!(column.physical_type(), Type::INT64);
+        let res = ColumnChunkReader::<i32>::new(column, &file, 1);
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().to_string(), "Column Chunk Error: Column chunk: orderkey of physical type: 2 cannot be read as i32\n");
+
+        let buffer = DirectByteBuffer::from_loaded_bytes(Vec::new());
+        assert!(ColumnChunkReader::<f64>::from_buffer(column, buffer, 1).is_err());
+        assert!(ColumnChunkReader::<i64>::new(column, &file, 1).is_ok());
+    }
+
+    #[test]
+    fn test_read_truncated_column_chunk() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let last_page_size = reader.describe_pages().unwrap()[3].compressed_page_size;
+
+        // Truncate the file in the middle of the last page.
+        let column_chunk_end = column.column_chunk_offset() + column.total_compressed_size();
+        let truncated_file = InMemoryFile {
+            path: path.clone(),
+            data: file
+                .load_file_to_buffer(0, column_chunk_end - 100)
+                .unwrap()
+                .into_vec(),
+        };
+
+        let res = ColumnChunkReader::<i64>::new(column, &truncated_file, 1);
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+        for _ in 0..3 {
+            let batch = reader.next_batch(250).unwrap().unwrap();
+            assert_eq!(batch.get_num_values(), 250);
+        }
+
+        let res = reader.next_batch(250);
+        assert!(res.is_err());
+        let error = res.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Truncated Page: page 3 of {} bytes has only {} bytes available\n",
+                last_page_size,
+                last_page_size - 100
+            )
+        );
+        assert!(matches!(
+            error,
+            BoltReaderError::TruncatedPage { page_index: 3, .. }
+        ));
+
+        let res = reader.describe_pages();
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::TruncatedPage { page_index: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_cancel_column_chunk_reader() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let cancellation_token = CancellationToken::new();
+        reader.set_cancellation_token(cancellation_token.clone());
+
+        let data_page = reader.next_page().unwrap().unwrap();
+        assert_eq!(data_page.get_data_page_num_values(), 250);
+        destroy_column_chunk_data_page(data_page);
+
+        // The pages after the cancellation are not read.
+        cancellation_token.cancel();
+        let res = reader.next_page();
+        assert!(matches!(res, Err(BoltReaderError::Cancelled)));
+        assert_eq!(reader.get_values_read(), 250);
+        assert!(matches!(
+            reader.next_batch(100),
+            Err(BoltReaderError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_collect_selected() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        // The alternating single rows across the 4 pages of 250 values, and the rows beyond the
+        // column chunk are ignored.
+        let mut ranges = RowRangeSet::new(0);
+        for row in (1..1000).step_by(2) {
+            ranges.add_row_ranges(row, row + 1);
+        }
+        ranges.add_row_ranges(1000, 1010);
+
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let (values, validity) = reader.collect_selected_with_validity(&ranges).unwrap();
+        assert_eq!(values.len(), 500);
+        assert_eq!(validity.len(), 500);
+
+        // The same values as the pages in test_read_multi_page_column_chunk.
+        for (i, row) in (1..1000).step_by(2).enumerate() {
+            let index = (row % 250) as i64;
+            let expected = match row / 250 {
+                0 => Some(index * 2),
+                1 if index % 5 == 0 => None,
+                1 => Some(1000 + index),
+                2 => None,
+                _ => Some(-index),
+            };
+            assert_eq!(validity[i], expected.is_some());
+            assert_eq!(values[i], expected.unwrap_or_default());
+        }
+
+        // The ranges are relative to the offset of the row range set.
+        let mut ranges = RowRangeSet::new(100);
+        ranges.add_row_ranges(0, 3);
+        ranges.add_row_ranges(800, 802);
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        assert_eq!(
+            reader.collect_selected(&ranges).unwrap(),
+            vec![200, 202, 204, -150, -151]
+        );
+
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        let (values, validity) = reader
+            .collect_selected_with_validity(&RowRangeSet::new(0))
+            .unwrap();
+        assert!(values.is_empty());
+        assert!(validity.is_empty());
+        assert_eq!(reader.get_values_read(), 0);
+    }
+
+    #[test]
+    fn test_read_unsupported_encoding() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        // A single data page of 8 INT64 values.
+        let create_column_chunk = |page_header: PageHeader| {
+            let mut bytes: Vec<u8> = vec![];
+            let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+            page_header.write_to_out_protocol(&mut protocol).unwrap();
+            bytes.extend_from_slice(&[0u8; 64]);
+            DirectByteBuffer::from_loaded_bytes(bytes)
+        };
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            64,
+            64,
+            None,
+            DataPageHeader::new(
+                8,
+                Encoding::DELTA_LENGTH_BYTE_ARRAY,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(page_header), 0)
+                .unwrap();
+        let res = reader.next_page();
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::UnsupportedEncoding(
+                Encoding::DELTA_LENGTH_BYTE_ARRAY
+            ))
+        ));
+        assert_eq!(res.err().unwrap().to_string(), "Unsupported Encoding: 6\n");
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE_V2,
+            64,
+            64,
+            None,
+            None,
+            None,
+            None,
+            DataPageHeaderV2::new(8, 0, 8, Encoding::RLE_DICTIONARY, 0, 0, false, None),
+        );
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(page_header), 0)
+                .unwrap();
+        assert!(matches!(
+            reader.next_page(),
+            Err(BoltReaderError::UnsupportedEncoding(
+                Encoding::RLE_DICTIONARY
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_read_inflated_num_values() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        assert_eq!(column.num_values(), 11212);
+
+        let create_column_chunk = |page_header: PageHeader| {
+            let mut bytes: Vec<u8> = vec![];
+            let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+            page_header.write_to_out_protocol(&mut protocol).unwrap();
+            bytes.extend_from_slice(&[0u8; 64]);
+            DirectByteBuffer::from_loaded_bytes(bytes)
+        };
+
+        // The page claims one more value than the column chunk.
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            64,
+            64,
+            None,
+            DataPageHeader::new(11213, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(page_header), 0)
+                .unwrap();
+        let res = reader.next_page();
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::FixedLengthDataPageError(_))
+        ));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Fixed Length Data Page Error: Column chunk: orderkey data page num_values: 11213 exceeds the remaining values: 11212\n"
+        );
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE_V2,
+            64,
+            64,
+            None,
+            None,
+            None,
+            None,
+            DataPageHeaderV2::new(11213, 0, 11213, Encoding::PLAIN, 0, 0, false, None),
+        );
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(page_header), 0)
+                .unwrap();
+        assert!(matches!(
+            reader.next_batch(1000),
+            Err(BoltReaderError::FixedLengthDataPageError(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_page_crc() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        // Rewrite the first page header with the crc of the page.
+        let mut buffer = DirectByteBuffer::from_file(
+            &file,
+            column.column_chunk_offset(),
+            column.total_compressed_size(),
+        )
+        .unwrap();
+        let mut page_header = read_page_header(&mut buffer).unwrap();
+        let body_begin = buffer.get_rpos();
+        let body_end = body_begin + page_header.compressed_page_size as usize;
+        let page = buffer.as_bytes()[body_begin..body_end].to_vec();
+        page_header.crc = Some(crc32fast::hash(&page) as i32);
+
+        let create_column_chunk = |page: &[u8]| {
+            let mut bytes: Vec<u8> = vec![];
+            let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+            page_header.write_to_out_protocol(&mut protocol).unwrap();
+            bytes.extend_from_slice(page);
+            DirectByteBuffer::from_loaded_bytes(bytes)
+        };
+
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(&page), 1).unwrap();
+        reader.set_verify_crc(true);
+        let data_page = reader.next_page().unwrap().unwrap();
+        assert_eq!(data_page.get_data_page_num_values(), 250);
+        destroy_column_chunk_data_page(data_page);
+
+        // Flip a byte of the values.
+        let mut corrupted = page.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 0x01;
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(&corrupted), 1)
+                .unwrap();
+        reader.set_verify_crc(true);
+        let res = reader.next_page();
+        match res {
+            Err(BoltReaderError::CrcMismatch { expected, computed }) => {
+                assert_eq!(expected, crc32fast::hash(&page));
+                assert_eq!(computed, crc32fast::hash(&corrupted));
+            }
+            _ => panic!("The corrupted page should fail the crc verification"),
+        }
+
+        // The crc is not verified by default.
+        let mut reader =
+            ColumnChunkReader::<i64>::from_buffer(column, create_column_chunk(&corrupted), 1)
+                .unwrap();
+        let data_page = reader.next_page().unwrap().unwrap();
+        destroy_column_chunk_data_page(data_page);
+    }
+
+    #[test]
+    fn test_max_page_size() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        let mut reader = ColumnChunkReader::<i64>::new(column, &file, 1).unwrap();
+        reader.set_max_page_size(100);
+        let res = reader.next_page();
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::PageTooLarge {
+                max_page_size: 100,
+                ..
+            })
+        ));
+
+        // A header claiming a 2GB page, the body is never loaded or decompressed.
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            i32::MAX,
+            i32::MAX,
+            None,
+            DataPageHeader::new(10, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut bytes: Vec<u8> = vec![];
+        let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+        page_header.write_to_out_protocol(&mut protocol).unwrap();
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            column,
+            DirectByteBuffer::from_loaded_bytes(bytes),
+            1,
+        )
+        .unwrap();
+        let res = reader.next_page();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Page Too Large: page size 2147483647 bytes exceeds the max page size 268435456 bytes\n"
+        );
+    }
+
+    #[test]
+    fn test_reuse_page_buffer() {
+        let path = String::from("src/sample_files/snappy_data_page_v1.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        // Repeat the single snappy page of 1000 values 3 times.
+        let page = DirectByteBuffer::from_file(
+            &file,
+            column.column_chunk_offset(),
+            column.total_compressed_size(),
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+        for _ in 0..3 {
+            bytes.extend_from_slice(page.as_bytes());
+        }
+        let mut metadata = column.get_thrift_metadata().clone();
+        metadata.num_values *= 3;
+        metadata.total_compressed_size *= 3;
+        let column_chunk = ColumnChunk::new(
+            None::<String>,
+            column.file_offset() as i64,
+            metadata,
+            None::<i64>,
+            None::<i32>,
+            None::<i64>,
+            None::<i32>,
+            None,
+            None::<Vec<u8>>,
+        );
+        let column = ColumnChunkMetaData::from_thrift(column_chunk).unwrap();
+
+        let pool = BufferPool::new(DEFAULT_MAX_FREE_BUFFERS);
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            &column,
+            DirectByteBuffer::from_loaded_bytes(bytes),
+            0,
+        )
+        .unwrap();
+        reader.set_buffer_pool(Arc::clone(&pool));
+
+        let mut page_buffers = Vec::new();
+        while let Some(data_page) = reader.next_page().unwrap() {
+            assert_eq!(data_page.get_data_page_num_values(), 1000);
+            destroy_column_chunk_data_page(data_page);
+            page_buffers.push(reader.page_buffer.as_ref().unwrap().as_bytes().as_ptr());
+        }
+
+        // The pages of the same size are decompressed into the same allocation.
+        assert_eq!(page_buffers.len(), 3);
+        assert!(page_buffers.iter().all(|ptr| *ptr == page_buffers[0]));
+        assert_eq!(pool.get_num_allocations(), 1);
+        assert_eq!(pool.get_num_free_buffers(), 0);
+        drop(reader);
+        assert_eq!(pool.get_num_free_buffers(), 1);
+    }
+
+    #[test]
+    fn test_read_dictionary_fallback_column_chunk() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        // The writer falls back to PLAIN when the dictionary is full, so the first pages are
+        // RLE_DICTIONARY and the rest are PLAIN in the same column chunk.
+        let dictionary: Vec<i64> = vec![10, 20, 30, 40];
+        let mut expected: Vec<i64> = Vec::new();
+        let mut bytes: Vec<u8> = vec![];
+        let mut append_page = |page_header: PageHeader, page: &[u8]| {
+            let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+            page_header.write_to_out_protocol(&mut protocol).unwrap();
+            bytes.extend_from_slice(page);
+        };
+        let create_page_header = |num_values: usize, encoding: Encoding, page_size: usize| {
+            PageHeader::new(
+                PageType::DATA_PAGE,
+                page_size as i32,
+                page_size as i32,
+                None,
+                DataPageHeader::new(
+                    num_values as i32,
+                    encoding,
+                    Encoding::RLE,
+                    Encoding::RLE,
+                    None,
+                ),
+                None,
+                None,
+                None,
+            )
+        };
+
+        let page: Vec<u8> = dictionary.iter().flat_map(|v| v.to_le_bytes()).collect();
+        append_page(
+            PageHeader::new(
+                PageType::DICTIONARY_PAGE,
+                page.len() as i32,
+                page.len() as i32,
+                None,
+                None,
+                None,
+                DictionaryPageHeader::new(dictionary.len() as i32, Encoding::PLAIN, None),
+                None,
+            ),
+            &page,
+        );
+
+        for step in [1, 3] {
+            let indices: Vec<u32> = (0..100).map(|i| (i * step) % 4).collect();
+            let mut page = vec![2u8];
+            parquet2::encoding::hybrid_rle::encode_u32(&mut page, indices.iter().cloned(), 2)
+                .unwrap();
+            append_page(
+                create_page_header(100, Encoding::RLE_DICTIONARY, page.len()),
+                &page,
+            );
+            expected.extend(indices.iter().map(|index| dictionary[*index as usize]));
+        }
+
+        for (begin, num_values) in [(1000, 100), (-50, 50)] {
+            let values: Vec<i64> = (begin..begin + num_values).collect();
+            let page: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+            append_page(
+                create_page_header(values.len(), Encoding::PLAIN, page.len()),
+                &page,
+            );
+            expected.extend(values);
+        }
+
+        let mut metadata = column.get_thrift_metadata().clone();
+        metadata.num_values = expected.len() as i64;
+        metadata.total_compressed_size = bytes.len() as i64;
+        let column_chunk = ColumnChunk::new(
+            None::<String>,
+            column.file_offset() as i64,
+            metadata,
+            None::<i64>,
+            None::<i32>,
+            None::<i64>,
+            None::<i32>,
+            None,
+            None::<Vec<u8>>,
+        );
+        let column = ColumnChunkMetaData::from_thrift(column_chunk).unwrap();
+
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            &column,
+            DirectByteBuffer::from_loaded_bytes(bytes.clone()),
+            0,
+        )
+        .unwrap();
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, expected.len());
+        let mut num_dictionary_pages = 0;
+        let mut num_plain_pages = 0;
+        let mut offset = 0;
+        while let Some(data_page) = reader.next_page().unwrap() {
+            match data_page {
+                ColumnChunkDataPage::DictionaryV1(_) => num_dictionary_pages += 1,
+                ColumnChunkDataPage::PlainV1(_) => num_plain_pages += 1,
+                _ => panic!("The data pages should be either RLE_DICTIONARY or PLAIN"),
+            }
+            assert_eq!(data_page.get_data_page_offset(), offset);
+            let num_values = data_page.get_data_page_num_values();
+            data_page
+                .read(
+                    RowRange::new(offset, offset + num_values),
+                    0,
+                    &mut result_row_range_set,
+                    &mut raw_bridge,
+                )
+                .unwrap();
+            destroy_column_chunk_data_page(data_page);
+            offset += num_values;
+        }
+        assert_eq!(num_dictionary_pages, 2);
+        assert_eq!(num_plain_pages, 2);
+        assert!(reader.has_dictionary());
+        assert_eq!(reader.get_values_read(), 350);
+
+        for (i, value) in expected.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, *value)
+            );
+        }
+
+        // The batches span the last dictionary encoded page and the first PLAIN page.
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            &column,
+            DirectByteBuffer::from_loaded_bytes(bytes),
+            0,
+        )
+        .unwrap();
+        let mut offset = 0;
+        while let Some(batch) = reader.next_batch(120).unwrap() {
+            assert_eq!(batch.get_offset(), offset);
+            assert_eq!(
+                batch.get_values(),
+                &expected[offset..offset + batch.get_num_values()]
+            );
+            offset += batch.get_num_values();
+        }
+        assert_eq!(offset, 350);
+    }
+
+    #[test]
+    fn test_read_column_chunk_with_gap_after_dictionary() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+
+        let dictionary: Vec<i64> = vec![7, 8, 9];
+        let mut bytes: Vec<u8> = vec![];
+        let page: Vec<u8> = dictionary.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+        PageHeader::new(
+            PageType::DICTIONARY_PAGE,
+            page.len() as i32,
+            page.len() as i32,
+            None,
+            None,
+            None,
+            DictionaryPageHeader::new(dictionary.len() as i32, Encoding::PLAIN, None),
+            None,
+        )
+        .write_to_out_protocol(&mut protocol)
+        .unwrap();
+        bytes.extend_from_slice(&page);
+
+        // The padding bytes are not a valid page header.
+        bytes.extend_from_slice(&[0xff, 0xff, 0xff]);
+        let data_page_position = bytes.len();
+
+        let indices: Vec<u32> = (0..24).map(|i| i % 3).collect();
+        let mut page = vec![2u8];
+        parquet2::encoding::hybrid_rle::encode_u32(&mut page, indices.iter().cloned(), 2).unwrap();
+        let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            page.len() as i32,
+            page.len() as i32,
+            None,
+            DataPageHeader::new(
+                indices.len() as i32,
+                Encoding::RLE_DICTIONARY,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+        .write_to_out_protocol(&mut protocol)
+        .unwrap();
+        bytes.extend_from_slice(&page);
+
+        let column_chunk_offset = 4;
+        let create_column = |data_page_offset: usize, total_compressed_size: usize| {
+            let mut metadata = column.get_thrift_metadata().clone();
+            metadata.num_values = indices.len() as i64;
+            metadata.total_compressed_size = total_compressed_size as i64;
+            metadata.dictionary_page_offset = Some(column_chunk_offset as i64);
+            metadata.data_page_offset = data_page_offset as i64;
+            let column_chunk = ColumnChunk::new(
+                None::<String>,
+                column.file_offset() as i64,
+                metadata,
+                None::<i64>,
+                None::<i32>,
+                None::<i64>,
+                None::<i32>,
+                None,
+                None::<Vec<u8>>,
+            );
+            ColumnChunkMetaData::from_thrift(column_chunk).unwrap()
+        };
+
+        let column = create_column(column_chunk_offset + data_page_position, bytes.len());
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            &column,
+            DirectByteBuffer::from_loaded_bytes(bytes.clone()),
+            0,
+        )
+        .unwrap();
+        let batch = reader.next_batch(100).unwrap().unwrap();
+        assert!(reader.has_dictionary());
+        assert_eq!(batch.get_offset(), 0);
+        let expected: Vec<i64> = indices
+            .iter()
+            .map(|index| dictionary[*index as usize])
+            .collect();
+        assert_eq!(batch.get_values(), expected);
+        assert!(reader.next_batch(100).unwrap().is_none());
+
+        let pages = reader.describe_pages().unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].page_type, PageType::DICTIONARY_PAGE);
+        assert_eq!(pages[1].offset, column.data_page_offset());
+
+        // The data page offset beyond the column chunk is rejected.
+        let column_chunk_end = column_chunk_offset + bytes.len();
+        let column = create_column(column_chunk_end, bytes.len());
+        let mut reader = ColumnChunkReader::<i64>::from_buffer(
+            &column,
+            DirectByteBuffer::from_loaded_bytes(bytes),
+            0,
+        )
+        .unwrap();
+        let res = reader.next_page();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            format!(
+                "Column Chunk Error: Column chunk: orderkey data page offset: {} is out of the column chunk: [4, {})\n",
+                column_chunk_end, column_chunk_end
+            )
+        );
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet Boolean Plain Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// PLAIN encoded BOOLEAN values are bit-packed, 1 bit per value starting from the least
+/// significant bit. Only the non-null values are stored, the values are expanded to a Vec<bool>
+/// with false at the null positions.
+pub struct BooleanPlainDataPageReaderV1 {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    validity: Option<Vec<bool>>,
+    data: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for BooleanPlainDataPageReaderV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(bool::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Boolean Plain Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl DataPage<bool> for BooleanPlainDataPageReaderV1 {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        mem::size_of::<bool>()
+    }
+}
+
+impl BooleanPlainDataPageReaderV1 {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+    ) -> Result<BooleanPlainDataPageReaderV1, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::PLAIN {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Plain Data Page Encoding should be PLAIN",
+            )));
+        }
+
+        let validity_slice = match &validity {
+            Some(validity) if has_null => Some(&validity[..]),
+            _ => None,
+        };
+        let num_non_null_values = match validity_slice {
+            Some(validity) => validity[..num_values].iter().filter(|v| **v).count(),
+            None => num_values,
+        };
+
+        // The trailing partial byte is padded with zero bits.
+        let data_size = num_non_null_values.div_ceil(8);
+        let bytes = if data_size == 0 {
+            Vec::new()
+        } else if buffer.can_create_buffer_slice(buffer.get_rpos(), data_size) {
+            let res = Vec::from(buffer.create_buffer_slice(buffer.get_rpos(), data_size)?);
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+
+            res
+        } else {
+            buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), data_size)?
+        };
+
+        let mut data = Vec::with_capacity(num_values);
+        let mut bit = 0;
+        for i in 0..num_values {
+            if let Some(validity) = validity_slice {
+                if !validity[i] {
+                    data.push(false);
+                    continue;
+                }
+            }
+
+            data.push(bytes[bit / 8] & (1 << (bit % 8)) != 0);
+            bit += 1;
+        }
+
+        Ok(BooleanPlainDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            validity,
+            data,
+        })
+    }
+
+    /// The value at the null position is false.
+    pub fn get_value(&self, index: usize) -> bool {
+        self.data[index]
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        match &self.validity {
+            Some(validity) if self.has_null => !validity[index],
+            _ => false,
+        }
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<bool>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytebuffer::Endian::LittleEndian;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::boolean_plain_data_page_v1::BooleanPlainDataPageReaderV1;
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::utils::byte_buffer_base::ByteBufferBase;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn encode_booleans(values: &[bool]) -> Vec<u8> {
+        let mut bytes = vec![0u8; values.len().div_ceil(8)];
+        for (i, value) in values.iter().enumerate() {
+            if *value {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes
+    }
+
+    fn create_page_header(num_values: usize, page_size: usize) -> PageHeader {
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_read_boolean_data_page() {
+        // 13 values, the last byte holds only 5 values.
+        let values: Vec<bool> = (0..13).map(|i| i % 3 == 0 || i == 12).collect();
+        let mut page = encode_booleans(&values);
+        assert_eq!(page.len(), 2);
+        // The bytes after the page should not be read.
+        page.push(0xff);
+
+        let page_header = create_page_header(values.len(), 2);
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        let res = BooleanPlainDataPageReaderV1::new(&page_header, &mut buf, 100, false, None);
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(ByteBufferBase::get_rpos(&buf), 2);
+        assert_eq!(data_page.get_data_page_num_values(), 13);
+        assert_eq!(data_page.get_data_page_offset(), 100);
+        assert_eq!(data_page.get_data_page_type_size(), 1);
+        assert!(!data_page.data_page_has_null());
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(data_page.get_value(i), *value);
+            assert!(!data_page.is_null(i));
+        }
+
+        // Read the trailing partial byte only.
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 5);
+        let res = data_page.read(
+            RowRange::new(108, 113),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        for (i, value) in values[8..].iter().enumerate() {
+            let (valid, result) = raw_bridge
+                .get_validity_and_value(0, 108 + i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(result, *value);
+        }
+    }
+
+    #[test]
+    fn test_read_nullable_boolean_data_page() {
+        let values: Vec<Option<bool>> = (0..11)
+            .map(|i| match i % 4 {
+                0 => None,
+                1 => Some(true),
+                _ => Some(i % 2 == 0),
+            })
+            .collect();
+        let validity: Vec<bool> = values.iter().map(Option::is_some).collect();
+        let non_null: Vec<bool> = values.iter().flatten().cloned().collect();
+        assert_eq!(non_null.len(), 8);
+        let page = encode_booleans(&non_null);
+
+        let page_header = create_page_header(values.len(), page.len());
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        let res =
+            BooleanPlainDataPageReaderV1::new(&page_header, &mut buf, 0, true, Some(validity));
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(ByteBufferBase::get_rpos(&buf), 1);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, values.len());
+        let res = data_page.read(
+            RowRange::new(0, values.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(data_page.is_null(i), value.is_none());
+            let (valid, result) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, value.is_some());
+            if let Some(value) = value {
+                assert_eq!(result, *value);
+                assert_eq!(data_page.get_value(i), *value);
+            }
+        }
+    }
+}
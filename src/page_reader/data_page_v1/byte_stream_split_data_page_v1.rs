@@ -0,0 +1,338 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::{mem, ptr};
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet BYTE_STREAM_SPLIT Data Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// For N values of a type with K bytes, the page holds K byte streams of N bytes each. The i-th
+/// value is reassembled from the i-th byte of every stream.
+pub struct ByteStreamSplitDataPageReaderV1<T> {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    validity: Option<Vec<bool>>,
+    data: Vec<T>,
+}
+
+#[allow(dead_code)]
+impl<T: ToString> std::fmt::Display for ByteStreamSplitDataPageReaderV1<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(T::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Byte Stream Split Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl<T> DataPage<T> for ByteStreamSplitDataPageReaderV1<T> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        mem::size_of::<T>()
+    }
+}
+
+impl<T: 'static + std::marker::Copy + Default> ByteStreamSplitDataPageReaderV1<T> {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+    ) -> Result<ByteStreamSplitDataPageReaderV1<T>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::BYTE_STREAM_SPLIT {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Byte Stream Split Data Page Encoding should be BYTE_STREAM_SPLIT",
+            )));
+        }
+
+        let type_size = mem::size_of::<T>();
+        if unlikely(type_size == 0 || type_size > 16) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Byte Stream Split Data Page does not support the type size: {}",
+                type_size
+            )));
+        }
+
+        let num_non_null_values = match &validity {
+            Some(validity) if has_null => validity[..num_values].iter().filter(|&&x| x).count(),
+            _ => num_values,
+        };
+
+        let data_size = num_non_null_values * type_size;
+        let streams = if data_size == 0 {
+            Vec::new()
+        } else if buffer.can_create_buffer_slice(buffer.get_rpos(), data_size) {
+            let res = Vec::from(buffer.create_buffer_slice(buffer.get_rpos(), data_size)?);
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+
+            res
+        } else {
+            buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), data_size)?
+        };
+
+        let mut values: Vec<T> = Vec::with_capacity(num_non_null_values);
+        let mut bytes = [0u8; 16];
+        for i in 0..num_non_null_values {
+            for (k, byte) in bytes.iter_mut().take(type_size).enumerate() {
+                *byte = streams[k * num_non_null_values + i];
+            }
+            values.push(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) });
+        }
+
+        let data = match &validity {
+            Some(validity) if has_null => {
+                let mut data = Vec::with_capacity(num_values);
+                let mut idx = 0;
+                for &valid in validity.iter().take(num_values) {
+                    if valid {
+                        data.push(values[idx]);
+                        idx += 1;
+                    } else {
+                        data.push(T::default());
+                    }
+                }
+                data
+            }
+            _ => values,
+        };
+
+        Ok(ByteStreamSplitDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            validity,
+            data,
+        })
+    }
+
+    pub fn get_value(&self, index: usize) -> Option<T> {
+        match &self.validity {
+            Some(validity) if self.has_null && !validity[index] => None,
+            _ => Some(self.data[index]),
+        }
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::byte_stream_split_data_page_v1::ByteStreamSplitDataPageReaderV1;
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::utils::byte_buffer_base::ByteBufferBase;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn create_page_header(num_values: usize) -> PageHeader {
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::BYTE_STREAM_SPLIT,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Scatter the little-endian bytes of the values into the byte streams.
+    fn encode_byte_stream_split(values: &[Vec<u8>]) -> Vec<u8> {
+        let type_size = values.first().map_or(0, Vec::len);
+        let mut streams = vec![0u8; values.len() * type_size];
+        for (i, value) in values.iter().enumerate() {
+            for (k, byte) in value.iter().enumerate() {
+                streams[k * values.len() + i] = *byte;
+            }
+        }
+        streams
+    }
+
+    #[test]
+    fn test_read_byte_stream_split_f64() {
+        let mut values: Vec<f64> = (0..1000).map(|i| (i as f64 - 500.0) * 0.37).collect();
+        values.extend([f64::MAX, f64::MIN_POSITIVE, -0.0, f64::NEG_INFINITY]);
+        values.push(f64::from_bits(0x7ff0000000000001));
+        let streams = encode_byte_stream_split(
+            &values
+                .iter()
+                .map(|v| v.to_le_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(streams.len(), values.len() * 8);
+        let mut buf = DirectByteBuffer::from_vec(streams);
+
+        let res: Result<ByteStreamSplitDataPageReaderV1<f64>, _> =
+            ByteStreamSplitDataPageReaderV1::new(
+                &create_page_header(values.len()),
+                &mut buf,
+                0,
+                false,
+                None,
+            );
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(ByteBufferBase::get_rpos(&buf), values.len() * 8);
+        assert_eq!(data_page.get_data_page_type_size(), 8);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, values.len());
+        let res = data_page.read(
+            RowRange::new(0, values.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        for (i, value) in values.iter().enumerate() {
+            let (valid, result) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(result.to_bits(), value.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_read_byte_stream_split_f32() {
+        let values: Vec<Option<f32>> = (0..333)
+            .map(|i| {
+                if i % 7 == 3 {
+                    None
+                } else {
+                    Some(i as f32 * -1.25 + 0.001)
+                }
+            })
+            .collect();
+        let validity: Vec<bool> = values.iter().map(Option::is_some).collect();
+        let streams = encode_byte_stream_split(
+            &values
+                .iter()
+                .flatten()
+                .map(|v| v.to_le_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        );
+        let mut buf = DirectByteBuffer::from_vec(streams);
+
+        let res: Result<ByteStreamSplitDataPageReaderV1<f32>, _> =
+            ByteStreamSplitDataPageReaderV1::new(
+                &create_page_header(values.len()),
+                &mut buf,
+                0,
+                true,
+                Some(validity),
+            );
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(data_page.get_data_page_type_size(), 4);
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(
+                data_page.get_value(i).map(f32::to_bits),
+                value.map(f32::to_bits)
+            );
+        }
+    }
+}
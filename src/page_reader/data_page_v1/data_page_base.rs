@@ -14,9 +14,9 @@
 // limitations under the License.
 
 use std::cmp::{max, min};
-use std::intrinsics::unlikely;
 
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
 use crate::utils::row_range_set::RowRange;
 
 pub trait DataPage<T> {
@@ -35,7 +35,12 @@ pub trait DataPage<T> {
         offset: usize,
         row_range: &RowRange,
     ) -> Result<Option<RowRange>, BoltReaderError> {
-        let begin = row_range.begin + offset;
+        // An empty page covers no row.
+        if page_begin == page_end {
+            return Ok(None);
+        }
+
+        let begin = add_offset(row_range.begin, offset)?;
 
         if unlikely(begin < page_begin) {
             return Err(BoltReaderError::FixedLengthDataPageError(format!("Range processing error. Input range begin: {} cannot be smaller than the data page begin: {} with offset", begin, page_begin)));
@@ -44,7 +49,7 @@ pub trait DataPage<T> {
         if begin >= page_begin && begin <= page_end {
             return Ok(Some(RowRange::new(
                 row_range.begin,
-                min(row_range.end, page_end - offset),
+                min(row_range.end, sub_offset(page_end, offset)?),
             )));
         }
 
@@ -58,8 +63,13 @@ pub trait DataPage<T> {
         offset: usize,
         row_range: &RowRange,
     ) -> Result<Option<RowRange>, BoltReaderError> {
-        let begin = row_range.begin + offset;
-        let end = row_range.end + offset;
+        // The whole range remains after an empty page.
+        if page_begin == page_end {
+            return Ok(Some(RowRange::new(row_range.begin, row_range.end)));
+        }
+
+        let begin = add_offset(row_range.begin, offset)?;
+        let end = add_offset(row_range.end, offset)?;
 
         if unlikely(begin < page_begin) {
             return Err(BoltReaderError::FixedLengthDataPageError(format!("Range processing error. Input range begin: {} cannot be smaller than the data page begin: {} with offset", begin, page_begin)));
@@ -70,11 +80,35 @@ pub trait DataPage<T> {
         }
 
         Ok(Option::from(RowRange::new(
-            max(row_range.begin, page_end - offset),
+            max(row_range.begin, sub_offset(page_end, offset)?),
             row_range.end,
         )))
     }
 }
+
+/// The offsets can be large absolute row positions, so the arithmetic is checked instead of
+/// wrapping around in the release builds.
+#[inline(always)]
+fn add_offset(row: usize, offset: usize) -> Result<usize, BoltReaderError> {
+    match row.checked_add(offset) {
+        Some(res) => Ok(res),
+        None => Err(BoltReaderError::FixedLengthDataPageError(format!(
+            "Range processing error. Row: {} with offset: {} overflows",
+            row, offset
+        ))),
+    }
+}
+
+#[inline(always)]
+fn sub_offset(page_end: usize, offset: usize) -> Result<usize, BoltReaderError> {
+    match page_end.checked_sub(offset) {
+        Some(res) => Ok(res),
+        None => Err(BoltReaderError::FixedLengthDataPageError(format!(
+            "Range processing error. Data page end: {} cannot be smaller than the offset: {}",
+            page_end, offset
+        ))),
+    }
+}
 #[cfg(test)]
 mod tests {
     use std::mem;
@@ -298,6 +332,66 @@ mod tests {
         destroy_fixed_length_plain_data_page_v1(data_page);
     }
 
+    #[test]
+    fn test_empty_covered_range() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let data_page_offset = 100;
+
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV1<i64>, _>, _) =
+            load_plain_data_page(data_page_offset, path);
+        assert!(data_page.is_ok());
+
+        let data_page = data_page.unwrap();
+        let page_end = data_page.get_data_page_num_values() + data_page.get_data_page_offset();
+
+        // The range begins at the page end, so the clipped range is empty.
+        let row_range = RowRange::new(page_end, page_end + 10);
+        let res = data_page.get_data_page_covered_range(
+            data_page.get_data_page_offset(),
+            page_end,
+            0,
+            &row_range,
+        );
+
+        assert!(res.is_ok());
+        let covered_range = res.unwrap().unwrap();
+        assert_eq!(covered_range.begin, page_end);
+        assert_eq!(covered_range.end, page_end);
+        assert!(covered_range.is_empty());
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_remaining_range_offset_after_page_end() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let data_page_offset = 100;
+
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV1<i64>, _>, _) =
+            load_plain_data_page(data_page_offset, path);
+        assert!(data_page.is_ok());
+
+        let data_page = data_page.unwrap();
+        let page_end = data_page.get_data_page_num_values() + data_page.get_data_page_offset();
+
+        let row_range = RowRange::new(1, 5);
+        let offset = page_end + 10;
+        let res = data_page.get_data_page_remaining_range(
+            data_page.get_data_page_offset(),
+            page_end,
+            offset,
+            &row_range,
+        );
+
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            format!("Fixed Length Data Page Error: Range processing error. Data page end: {} cannot be smaller than the offset: {}\n", page_end, offset)
+        );
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
     #[test]
     fn test_invalid_remaining_range() {
         let path = String::from("src/sample_files/linitem_plain_data_page");
@@ -322,4 +416,136 @@ mod tests {
 
         destroy_fixed_length_plain_data_page_v1(data_page);
     }
+
+    struct StubDataPage {
+        offset: usize,
+        num_values: usize,
+    }
+
+    impl DataPage<i64> for StubDataPage {
+        fn data_page_has_null(&self) -> bool {
+            false
+        }
+
+        fn get_data_page_num_values(&self) -> usize {
+            self.num_values
+        }
+
+        fn get_data_page_offset(&self) -> usize {
+            self.offset
+        }
+
+        fn get_data_page_type_size(&self) -> usize {
+            mem::size_of::<i64>()
+        }
+    }
+
+    #[test]
+    fn test_empty_page_covered_range() {
+        let data_page = StubDataPage {
+            offset: 100,
+            num_values: 0,
+        };
+        let page_begin = data_page.get_data_page_offset();
+        let page_end = page_begin + data_page.get_data_page_num_values();
+
+        // Ranges before, at and after the empty page.
+        for (row_range, offset) in [
+            (RowRange::new(0, 5), 0),
+            (RowRange::new(0, 5), 100),
+            (RowRange::new(100, 200), 0),
+            (RowRange::new(50, 60), 1000),
+        ] {
+            let res =
+                data_page.get_data_page_covered_range(page_begin, page_end, offset, &row_range);
+            assert!(res.is_ok());
+            assert!(res.unwrap().is_none());
+        }
+
+        // An empty page at the beginning of the column chunk.
+        let data_page = StubDataPage {
+            offset: 0,
+            num_values: 0,
+        };
+        let res = data_page.get_data_page_covered_range(0, 0, 0, &RowRange::new(0, 10));
+        assert!(res.is_ok());
+        assert!(res.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_empty_page_remaining_range() {
+        let data_page = StubDataPage {
+            offset: 100,
+            num_values: 0,
+        };
+        let page_begin = data_page.get_data_page_offset();
+        let page_end = page_begin + data_page.get_data_page_num_values();
+
+        for (row_range, offset) in [
+            (RowRange::new(0, 5), 0),
+            (RowRange::new(0, 5), 100),
+            (RowRange::new(100, 200), 0),
+            (RowRange::new(50, 60), 1000),
+        ] {
+            let res =
+                data_page.get_data_page_remaining_range(page_begin, page_end, offset, &row_range);
+            assert!(res.is_ok());
+            let remaining_range = res.unwrap();
+            assert!(remaining_range.is_some());
+            let remaining_range = remaining_range.unwrap();
+            assert_eq!(remaining_range.begin, row_range.begin);
+            assert_eq!(remaining_range.end, row_range.end);
+        }
+    }
+
+    #[test]
+    fn test_overflow_offset() {
+        let data_page = StubDataPage {
+            offset: usize::MAX - 100,
+            num_values: 50,
+        };
+        let page_begin = data_page.get_data_page_offset();
+        let page_end = page_begin + data_page.get_data_page_num_values();
+
+        // Close to usize::MAX without overflow.
+        let row_range = RowRange::new(10, 20);
+        let res = data_page.get_data_page_covered_range(
+            page_begin,
+            page_end,
+            usize::MAX - 95,
+            &row_range,
+        );
+        assert!(res.is_ok());
+        let covered_range = res.unwrap().unwrap();
+        assert_eq!(covered_range.begin, 10);
+        assert_eq!(covered_range.end, 20);
+
+        let offset = usize::MAX - 5;
+        let res = data_page.get_data_page_covered_range(page_begin, page_end, offset, &row_range);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            format!(
+                "Fixed Length Data Page Error: Range processing error. Row: 10 with offset: {} overflows\n",
+                offset
+            )
+        );
+
+        let res =
+            data_page.get_data_page_remaining_range(page_begin, page_end, usize::MAX, &row_range);
+        assert!(res.is_err());
+
+        // The range end overflows only.
+        let offset = usize::MAX - 70;
+        let row_range = RowRange::new(10, 80);
+        let res = data_page.get_data_page_remaining_range(page_begin, page_end, offset, &row_range);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            format!(
+                "Fixed Length Data Page Error: Range processing error. Row: 80 with offset: {} overflows\n",
+                offset
+            )
+        );
+    }
 }
@@ -0,0 +1,495 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::TypeId;
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::convert_generic_vec;
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::encoding::delta_binary_packed::DeltaBinaryPackedDecoder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet, RowRangeSetGenerator};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet DELTA_BINARY_PACKED Data Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// The values are decoded when the page is created. Only INT32 and INT64 columns can be
+/// DELTA_BINARY_PACKED encoded.
+pub struct DeltaBinaryPackedDataPageReaderV1<'a, T> {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    type_size: usize,
+    filter: Option<&'a dyn FixedLengthRangeFilter>,
+    validity: Option<Vec<bool>>,
+    data: Vec<T>,
+}
+
+#[allow(dead_code)]
+impl<'a, T: ToString> std::fmt::Display for DeltaBinaryPackedDataPageReaderV1<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(T::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Delta Binary Packed Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl<'a, T> DataPage<T> for DeltaBinaryPackedDataPageReaderV1<'a, T> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        self.type_size
+    }
+}
+
+impl<'a, T: 'static + std::marker::Copy + Default> DeltaBinaryPackedDataPageReaderV1<'a, T> {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_size: usize,
+        has_null: bool,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+        validity: Option<Vec<bool>>,
+    ) -> Result<DeltaBinaryPackedDataPageReaderV1<'a, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::DELTA_BINARY_PACKED {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Delta Binary Packed Data Page Encoding should be DELTA_BINARY_PACKED",
+            )));
+        }
+
+        let is_i32 = TypeId::of::<T>() == TypeId::of::<i32>();
+        let is_i64 = TypeId::of::<T>() == TypeId::of::<i64>();
+        if unlikely(!is_i32 && !is_i64) || unlikely(type_size != mem::size_of::<T>()) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Delta Binary Packed Data Page only supports INT32 and INT64, type size: {}",
+                type_size
+            )));
+        }
+
+        let num_non_null_values = match &validity {
+            Some(validity) if has_null => validity.iter().filter(|&&x| x).count(),
+            _ => num_values,
+        };
+        let decoded = DeltaBinaryPackedDecoder::decode(buffer, num_non_null_values)?;
+        if unlikely(decoded.len() != num_non_null_values) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Delta Binary Packed Data Page decoded {} values, expected {}",
+                decoded.len(),
+                num_non_null_values
+            )));
+        }
+
+        let values: Vec<T> = if is_i64 {
+            let vec = unsafe { convert_generic_vec!(&decoded[..], mem::size_of::<i64>(), T) };
+            let res = vec.clone();
+            mem::forget(vec);
+            res
+        } else {
+            let truncated: Vec<i32> = decoded.iter().map(|&v| v as i32).collect();
+            let vec = unsafe { convert_generic_vec!(&truncated[..], mem::size_of::<i32>(), T) };
+            let res = vec.clone();
+            mem::forget(vec);
+            res
+        };
+
+        let data = match &validity {
+            Some(validity) if has_null => {
+                let mut data = Vec::with_capacity(num_values);
+                let mut idx = 0;
+                for &valid in validity.iter().take(num_values) {
+                    if valid {
+                        data.push(values[idx]);
+                        idx += 1;
+                    } else {
+                        data.push(T::default());
+                    }
+                }
+                data
+            }
+            _ => values,
+        };
+
+        Ok(DeltaBinaryPackedDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            type_size,
+            filter,
+            validity,
+            data,
+        })
+    }
+
+    pub fn read_with_filter(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let filter = self.filter.unwrap();
+
+        if TypeId::of::<T>() == TypeId::of::<i64>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<T>(), i64) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<i32>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<T>(), i32) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the value check to every row in the range. Null rows pass the filter only if the
+    /// filter allows nulls.
+    #[inline(always)]
+    fn filter_values<F: Fn(usize) -> bool>(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        check: F,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        let null_allowed = self.filter.unwrap().get_null_allowed();
+
+        let mut generator = RowRangeSetGenerator::new(result_row_range_set);
+
+        for i in start..end {
+            let valid = match &self.validity {
+                Some(validity) if self.has_null => validity[i],
+                _ => true,
+            };
+
+            let filter_res = if valid { check(i) } else { null_allowed };
+            generator.update(i + self.current_offset - offset, filter_res);
+            if filter_res {
+                result_bridge.append_result(valid, self.data[i]);
+            }
+        }
+        generator.finish(end + self.current_offset - offset);
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use parquet2::encoding::delta_bitpacked::encode;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::delta_binary_packed_data_page_v1::DeltaBinaryPackedDataPageReaderV1;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn create_data_page(data: &[i64]) -> (PageHeader, DirectByteBuffer) {
+        let header = PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                data.len() as i32,
+                Encoding::DELTA_BINARY_PACKED,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+
+        let mut vec = vec![];
+        encode(data.iter().cloned(), &mut vec);
+        (header, DirectByteBuffer::from_vec(vec))
+    }
+
+    fn read_all<T: 'static + std::marker::Copy + Default>(
+        data_page: &DeltaBinaryPackedDataPageReaderV1<T>,
+    ) -> (RawBridge<T>, RowRangeSet) {
+        let offset = data_page.get_data_page_offset();
+        let mut result_row_range_set = RowRangeSet::new(offset);
+        let mut raw_bridge = RawBridge::new(false, data_page.get_data_page_num_values());
+        let res = data_page.read(
+            RowRange::new(0, data_page.get_data_page_num_values()),
+            offset,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        (raw_bridge, result_row_range_set)
+    }
+
+    #[test]
+    fn test_read_increasing_sequence_i64() {
+        let data: Vec<i64> = (0..1000).map(|i| 1000000 + i * 7).collect();
+        let (header, mut buf) = create_data_page(&data);
+
+        let data_page: DeltaBinaryPackedDataPageReaderV1<i64> =
+            DeltaBinaryPackedDataPageReaderV1::new(
+                &header,
+                &mut buf,
+                100,
+                mem::size_of::<i64>(),
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(data_page.get_data_page_type_size(), 8);
+        assert_eq!(data_page.to_string(), "Delta Binary Packed Data Page: has_null false, num_values 1000, current_offset 100\nData: 1000000, 1000007, 1000014, 1000021, 1000028, 1000035, 1000042, 1000049, 1000056, 1000063 ...\nValidity: true, true, true, true, true, true, true, true, true, true ...\n");
+
+        let (raw_bridge, result_row_range_set) = read_all(&data_page);
+        for (i, &value) in data.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(100, i, &result_row_range_set)
+                    .unwrap(),
+                (true, value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_negative_deltas_i32() {
+        let data: Vec<i64> = (0..1000)
+            .map(|i| if i % 3 == 0 { -i * 1000 } else { i - 500 })
+            .collect();
+        let (header, mut buf) = create_data_page(&data);
+
+        let data_page: DeltaBinaryPackedDataPageReaderV1<i32> =
+            DeltaBinaryPackedDataPageReaderV1::new(
+                &header,
+                &mut buf,
+                0,
+                mem::size_of::<i32>(),
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(data_page.get_data_page_type_size(), 4);
+
+        let (raw_bridge, result_row_range_set) = read_all(&data_page);
+        for (i, &value) in data.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, value as i32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_with_filter() {
+        let data: Vec<i64> = (0..500).map(|i| 250 - i).collect();
+        let (header, mut buf) = create_data_page(&data);
+        let filter = IntegerRangeFilter::new(-10, 10, false);
+
+        let data_page: DeltaBinaryPackedDataPageReaderV1<i64> =
+            DeltaBinaryPackedDataPageReaderV1::new(
+                &header,
+                &mut buf,
+                0,
+                mem::size_of::<i64>(),
+                false,
+                Option::Some(&filter),
+                None,
+            )
+            .unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read_with_filter(
+            RowRange::new(0, 500),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        assert_eq!(raw_bridge.get_size(), 21);
+        assert_eq!(result_row_range_set.get_row_ranges().len(), 1);
+        assert_eq!(result_row_range_set.get_row_ranges()[0].begin, 240);
+        assert_eq!(result_row_range_set.get_row_ranges()[0].end, 261);
+        assert_eq!(
+            raw_bridge
+                .get_validity_and_value(0, 240, &result_row_range_set)
+                .unwrap(),
+            (true, 10)
+        );
+    }
+
+    #[test]
+    fn test_read_with_nulls() {
+        let validity: Vec<bool> = (0..300).map(|i| i % 4 != 1).collect();
+        let data: Vec<i64> = (0..validity.iter().filter(|&&x| x).count() as i64).collect();
+        let (_, mut buf) = create_data_page(&data);
+        let (header, _) = create_data_page(&vec![0; validity.len()]);
+
+        let data_page: DeltaBinaryPackedDataPageReaderV1<i64> =
+            DeltaBinaryPackedDataPageReaderV1::new(
+                &header,
+                &mut buf,
+                0,
+                mem::size_of::<i64>(),
+                true,
+                None,
+                Option::Some(validity.clone()),
+            )
+            .unwrap();
+        assert!(data_page.data_page_has_null());
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 1024);
+        let res = data_page.read(
+            RowRange::new(0, validity.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let mut expected = 0;
+        for (i, &valid) in validity.iter().enumerate() {
+            let (res_valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(res_valid, valid);
+            if valid {
+                assert_eq!(value, expected);
+                expected += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let data: Vec<i64> = (0..10).collect();
+        let (header, mut buf) = create_data_page(&data);
+
+        let data_page: Result<DeltaBinaryPackedDataPageReaderV1<f64>, _> =
+            DeltaBinaryPackedDataPageReaderV1::new(
+                &header,
+                &mut buf,
+                0,
+                mem::size_of::<f64>(),
+                false,
+                None,
+                None,
+            );
+        assert!(data_page.is_err());
+    }
+}
@@ -0,0 +1,402 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::encoding::delta_binary_packed::DeltaBinaryPackedDecoder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+// BYTE_ARRAY values are not fixed width, the type size is reported as 0.
+const VARIABLE_LENGTH_TYPE_SIZE: usize = 0;
+
+/// The Parquet DELTA_BYTE_ARRAY Data Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// Each value is stored as the length of the prefix shared with the previous value, plus the
+/// suffix. The prefix lengths are a DELTA_BINARY_PACKED stream, and the suffixes are
+/// DELTA_LENGTH_BYTE_ARRAY encoded. As the values depend on the previous ones, they are
+/// materialized when the page is created.
+pub struct DeltaByteArrayDataPageReaderV1 {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    validity: Option<Vec<bool>>,
+    data: Vec<Vec<u8>>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for DeltaByteArrayDataPageReaderV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(|value| String::from_utf8_lossy(value).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Delta Byte Array Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl DataPage<Vec<u8>> for DeltaByteArrayDataPageReaderV1 {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        VARIABLE_LENGTH_TYPE_SIZE
+    }
+}
+
+impl DeltaByteArrayDataPageReaderV1 {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+    ) -> Result<DeltaByteArrayDataPageReaderV1, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::DELTA_BYTE_ARRAY {
+            return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                "Delta Byte Array Data Page Encoding should be DELTA_BYTE_ARRAY",
+            )));
+        }
+
+        let validity_slice = match &validity {
+            Some(validity) if has_null => Some(&validity[..num_values]),
+            _ => None,
+        };
+        let num_non_null_values = match validity_slice {
+            Some(validity) => validity.iter().filter(|&&x| x).count(),
+            None => num_values,
+        };
+
+        let prefix_lengths = DeltaBinaryPackedDecoder::decode(buffer, num_non_null_values)?;
+        let suffix_lengths = DeltaBinaryPackedDecoder::decode(buffer, num_non_null_values)?;
+        if unlikely(
+            prefix_lengths.len() != num_non_null_values
+                || suffix_lengths.len() != num_non_null_values,
+        ) {
+            return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                "Delta Byte Array Data Page decoded {} prefix lengths and {} suffix lengths, expected {}",
+                prefix_lengths.len(),
+                suffix_lengths.len(),
+                num_non_null_values
+            )));
+        }
+
+        for &length in prefix_lengths.iter().chain(suffix_lengths.iter()) {
+            if unlikely(length < 0) {
+                return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                    "Invalid negative prefix or suffix length: {}",
+                    length
+                )));
+            }
+        }
+        let mut suffix_size: usize = 0;
+        for &length in suffix_lengths.iter() {
+            suffix_size = match suffix_size.checked_add(length as usize) {
+                Some(suffix_size) => suffix_size,
+                None => {
+                    return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                        "The total size of the suffixes overflows",
+                    )))
+                }
+            };
+        }
+
+        let suffixes = if suffix_size == 0 {
+            Vec::new()
+        } else if buffer.can_create_buffer_slice(buffer.get_rpos(), suffix_size) {
+            let res = Vec::from(buffer.create_buffer_slice(buffer.get_rpos(), suffix_size)?);
+            buffer.set_rpos(buffer.get_rpos() + suffix_size);
+
+            res
+        } else {
+            buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), suffix_size)?
+        };
+
+        let mut data: Vec<Vec<u8>> = Vec::with_capacity(num_values);
+        let mut previous: Vec<u8> = Vec::new();
+        let mut pos = 0;
+        let mut idx = 0;
+        for i in 0..num_values {
+            if let Some(validity) = validity_slice {
+                if !validity[i] {
+                    data.push(Vec::new());
+                    continue;
+                }
+            }
+
+            let prefix_length = prefix_lengths[idx] as usize;
+            let suffix_length = suffix_lengths[idx] as usize;
+            idx += 1;
+
+            if unlikely(prefix_length > previous.len()) {
+                return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                    "Value: {} has prefix length: {}, longer than the previous value length: {}",
+                    i,
+                    prefix_length,
+                    previous.len()
+                )));
+            }
+
+            let mut value = Vec::with_capacity(prefix_length + suffix_length);
+            value.extend_from_slice(&previous[..prefix_length]);
+            value.extend_from_slice(&suffixes[pos..pos + suffix_length]);
+            pos += suffix_length;
+
+            previous.clone_from(&value);
+            data.push(value);
+        }
+
+        Ok(DeltaByteArrayDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            validity,
+            data,
+        })
+    }
+
+    pub fn get_value(&self, index: usize) -> Option<&[u8]> {
+        match &self.validity {
+            Some(validity) if self.has_null && !validity[index] => None,
+            _ => Some(&self.data[index]),
+        }
+    }
+
+    /// The values are appended to the bridge as slices borrowing from the page.
+    pub fn read<'b>(
+        &'b self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<&'b [u8]>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        let values: Vec<&'b [u8]> = self.data[start..end].iter().map(Vec::as_slice).collect();
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &values)?
+            }
+            _ => result_bridge.append_non_null_results(&values)?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::encoding::delta_byte_array::encode;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::delta_byte_array_data_page_v1::DeltaByteArrayDataPageReaderV1;
+    use crate::utils::byte_buffer_base::ByteBufferBase;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn create_data_page(num_values: usize, values: &[&[u8]]) -> (PageHeader, DirectByteBuffer) {
+        let header = PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::DELTA_BYTE_ARRAY,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+
+        let mut vec = vec![];
+        encode(values.iter().cloned(), &mut vec);
+        (header, DirectByteBuffer::from_vec(vec))
+    }
+
+    #[test]
+    fn test_read_delta_byte_array_data_page() {
+        // Sorted urls sharing long prefixes
+        let mut strings: Vec<String> = (0..200)
+            .map(|i| {
+                format!(
+                    "https://www.example.com/catalog/category_{}/product_{}",
+                    i / 50,
+                    i * 13 % 97
+                )
+            })
+            .collect();
+        strings.push(String::from(
+            "https://www.example.com/catalog/category_3/product_99/x",
+        ));
+        strings.push(String::from("https://www.example.org"));
+        strings.push(String::new());
+        strings.push(String::from("z"));
+        strings.sort();
+        let values: Vec<&[u8]> = strings.iter().map(|s| s.as_bytes()).collect();
+        let (header, mut buf) = create_data_page(values.len(), &values);
+        let page_size = buf.len();
+
+        let res = DeltaByteArrayDataPageReaderV1::new(&header, &mut buf, 0, false, None);
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(ByteBufferBase::get_rpos(&buf), page_size);
+        assert_eq!(data_page.get_data_page_num_values(), values.len());
+        assert_eq!(data_page.get_data_page_type_size(), 0);
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(data_page.get_value(i), Some(*value));
+        }
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, values.len());
+        let res = data_page.read(
+            RowRange::new(100, values.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        for (i, value) in values.iter().enumerate().skip(100) {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, *value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_nullable_delta_byte_array_data_page() {
+        let validity = vec![true, false, true, true, false, false, true];
+        let values: Vec<&[u8]> = vec![b"apple", b"applesauce", b"apply", b"banana"];
+        let (header, mut buf) = create_data_page(validity.len(), &values);
+
+        let res =
+            DeltaByteArrayDataPageReaderV1::new(&header, &mut buf, 0, true, Some(validity.clone()));
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+
+        let expected: Vec<Option<&[u8]>> = vec![
+            Some(b"apple"),
+            None,
+            Some(b"applesauce"),
+            Some(b"apply"),
+            None,
+            None,
+            Some(b"banana"),
+        ];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(data_page.get_value(i), *expected);
+        }
+    }
+
+    #[test]
+    fn test_read_invalid_prefix_length() {
+        // Prefix lengths [0, 3] with suffixes ["a", "b"], the second prefix is too long.
+        let mut vec = vec![];
+        parquet2::encoding::delta_bitpacked::encode([0i64, 3].into_iter(), &mut vec);
+        parquet2::encoding::delta_length_byte_array::encode([b"a", b"b"].iter(), &mut vec);
+        let (header, _) = create_data_page(2, &[]);
+        let mut buf = DirectByteBuffer::from_vec(vec);
+
+        let res = DeltaByteArrayDataPageReaderV1::new(&header, &mut buf, 0, false, None);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Variable Length Data Page Error: Value: 1 has prefix length: 3, longer than the previous value length: 1\n"
+        );
+    }
+
+    #[test]
+    fn test_read_overflowed_suffix_lengths() {
+        // Prefix lengths [0, 0, 0] with suffix lengths [i64::MAX; 3], the sum overflows.
+        let mut vec = vec![];
+        parquet2::encoding::delta_bitpacked::encode([0i64; 3].into_iter(), &mut vec);
+        parquet2::encoding::delta_bitpacked::encode([i64::MAX; 3].into_iter(), &mut vec);
+        let (header, _) = create_data_page(3, &[]);
+        let mut buf = DirectByteBuffer::from_vec(vec);
+
+        let res = DeltaByteArrayDataPageReaderV1::new(&header, &mut buf, 0, false, None);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Variable Length Data Page Error: The total size of the suffixes overflows\n"
+        );
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::encoding::delta_binary_packed::DeltaBinaryPackedDecoder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+// BYTE_ARRAY values are not fixed width, the type size is reported as 0.
+const VARIABLE_LENGTH_TYPE_SIZE: usize = 0;
+
+/// The Parquet DELTA_LENGTH_BYTE_ARRAY Data Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// The lengths of all the values are stored as a DELTA_BINARY_PACKED stream, followed by the
+/// concatenated bytes of the values. The values are returned as slices borrowing from the
+/// DirectByteBuffer.
+pub struct DeltaLengthByteArrayDataPageReaderV1<'a> {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    validity: Option<Vec<bool>>,
+    data: Vec<&'a [u8]>,
+}
+
+#[allow(dead_code)]
+impl<'a> std::fmt::Display for DeltaLengthByteArrayDataPageReaderV1<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(|value| String::from_utf8_lossy(value).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Delta Length Byte Array Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl<'a> DataPage<&'a [u8]> for DeltaLengthByteArrayDataPageReaderV1<'a> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        VARIABLE_LENGTH_TYPE_SIZE
+    }
+}
+
+impl<'a> DeltaLengthByteArrayDataPageReaderV1<'a> {
+    /// The page is read starting from the current rpos of the buffer, and the rpos is moved to
+    /// the end of the page. The buffer is borrowed by the returned values.
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &'a mut DirectByteBuffer,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+    ) -> Result<DeltaLengthByteArrayDataPageReaderV1<'a>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::DELTA_LENGTH_BYTE_ARRAY {
+            return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                "Delta Length Byte Array Data Page Encoding should be DELTA_LENGTH_BYTE_ARRAY",
+            )));
+        }
+
+        let validity_slice = match &validity {
+            Some(validity) if has_null => Some(&validity[..num_values]),
+            _ => None,
+        };
+        let num_non_null_values = match validity_slice {
+            Some(validity) => validity.iter().filter(|&&x| x).count(),
+            None => num_values,
+        };
+
+        let lengths = DeltaBinaryPackedDecoder::decode(buffer, num_non_null_values)?;
+        if unlikely(lengths.len() != num_non_null_values) {
+            return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                "Delta Length Byte Array Data Page decoded {} lengths, expected {}",
+                lengths.len(),
+                num_non_null_values
+            )));
+        }
+
+        let data_begin = buffer.get_rpos();
+        let mut data_size: usize = 0;
+        for &length in lengths.iter() {
+            if unlikely(length < 0) {
+                return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                    "Invalid negative value length: {}",
+                    length
+                )));
+            }
+            data_size = match data_size.checked_add(length as usize) {
+                Some(data_size) => data_size,
+                None => {
+                    return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                        "The total size of the values overflows",
+                    )))
+                }
+            };
+        }
+        match data_begin.checked_add(data_size) {
+            Some(data_end) if data_end <= buffer.len() => buffer.set_rpos(data_end),
+            _ => return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                "The values with total size: {} bytes are out of the buffer, remaining bytes: {}",
+                data_size,
+                buffer.len() - data_begin
+            ))),
+        }
+
+        let bytes = &buffer.as_bytes()[data_begin..data_begin + data_size];
+        let mut data: Vec<&'a [u8]> = Vec::with_capacity(num_values);
+        let mut pos = 0;
+        let mut lengths = lengths.iter();
+        for i in 0..num_values {
+            if let Some(validity) = validity_slice {
+                if !validity[i] {
+                    data.push(&[]);
+                    continue;
+                }
+            }
+
+            let length = *lengths.next().unwrap() as usize;
+            data.push(&bytes[pos..pos + length]);
+            pos += length;
+        }
+
+        Ok(DeltaLengthByteArrayDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            validity,
+            data,
+        })
+    }
+
+    pub fn get_value(&self, index: usize) -> Option<&'a [u8]> {
+        match &self.validity {
+            Some(validity) if self.has_null && !validity[index] => None,
+            _ => Some(self.data[index]),
+        }
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<&'a [u8]>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::encoding::delta_bitpacked;
+    use parquet2::encoding::delta_length_byte_array::encode;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::delta_length_byte_array_data_page_v1::DeltaLengthByteArrayDataPageReaderV1;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn create_data_page(num_values: usize, values: &[&[u8]]) -> (PageHeader, DirectByteBuffer) {
+        let header = PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::DELTA_LENGTH_BYTE_ARRAY,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+
+        let mut vec = vec![];
+        encode(values.iter(), &mut vec);
+        // The bytes after the page should not be read.
+        vec.extend_from_slice(b"next page");
+        (header, DirectByteBuffer::from_vec(vec))
+    }
+
+    #[test]
+    fn test_read_delta_length_byte_array_data_page() {
+        let strings: Vec<String> = (0..300)
+            .map(|i| match i % 5 {
+                0 => String::new(),
+                1 => format!("value_{}", i),
+                2 => "x".repeat(i % 37),
+                3 => format!("a longer string value number {}", i * 7919),
+                _ => String::from("delta"),
+            })
+            .collect();
+        let values: Vec<&[u8]> = strings.iter().map(|s| s.as_bytes()).collect();
+        let (header, mut buf) = create_data_page(values.len(), &values);
+        let page_size = buf.len() - "next page".len();
+
+        let res = DeltaLengthByteArrayDataPageReaderV1::new(&header, &mut buf, 10, false, None);
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(data_page.get_data_page_num_values(), 300);
+        assert_eq!(data_page.get_data_page_offset(), 10);
+        assert_eq!(data_page.get_data_page_type_size(), 0);
+        assert!(!data_page.data_page_has_null());
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(data_page.get_value(i), Some(*value));
+        }
+
+        let mut result_row_range_set = RowRangeSet::new(10);
+        let mut raw_bridge = RawBridge::new(false, 300);
+        let res = data_page.read(
+            RowRange::new(0, 300),
+            10,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(10, i, &result_row_range_set)
+                    .unwrap(),
+                (true, *value)
+            );
+        }
+
+        drop(data_page);
+        assert_eq!(buf.get_rpos(), page_size);
+    }
+
+    #[test]
+    fn test_read_nullable_delta_length_byte_array_data_page() {
+        let validity: Vec<bool> = (0..20).map(|i| i % 3 != 0).collect();
+        let strings: Vec<String> = (0..20)
+            .filter(|i| i % 3 != 0)
+            .map(|i| format!("string {}", i))
+            .collect();
+        let values: Vec<&[u8]> = strings.iter().map(|s| s.as_bytes()).collect();
+        let (header, mut buf) = create_data_page(validity.len(), &values);
+
+        let res = DeltaLengthByteArrayDataPageReaderV1::new(
+            &header,
+            &mut buf,
+            0,
+            true,
+            Some(validity.clone()),
+        );
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+
+        let mut expected = values.iter();
+        for (i, valid) in validity.iter().enumerate() {
+            if *valid {
+                assert_eq!(data_page.get_value(i), Some(*expected.next().unwrap()));
+            } else {
+                assert_eq!(data_page.get_value(i), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_mismatched_delta_length_byte_array_data_page() {
+        let values: Vec<&[u8]> = vec![b"a", b"b"];
+        let (header, mut buf) = create_data_page(3, &values);
+
+        let res = DeltaLengthByteArrayDataPageReaderV1::new(&header, &mut buf, 0, false, None);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Variable Length Data Page Error: Delta Length Byte Array Data Page decoded 2 lengths, expected 3\n"
+        );
+    }
+
+    #[test]
+    fn test_read_overflowed_delta_length_byte_array_data_page() {
+        let (header, _) = create_data_page(3, &[]);
+        let mut vec = vec![];
+        delta_bitpacked::encode([i64::MAX; 3].into_iter(), &mut vec);
+        let mut buf = DirectByteBuffer::from_vec(vec);
+
+        let res = DeltaLengthByteArrayDataPageReaderV1::new(&header, &mut buf, 0, false, None);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Variable Length Data Page Error: The total size of the values overflows\n"
+        );
+    }
+}
@@ -0,0 +1,802 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::TypeId;
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::convert_generic_vec;
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::page_reader::dictionary_page::dictionary_page_base::DictionaryPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::encoding::rle_bp::RleBpDecoder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet, RowRangeSetGenerator};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet Dictionary Data Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// The data page stores a 1-byte bit width followed by the RLE/BP hybrid encoded dictionary
+/// indices. The indices are decoded when the page is created, and the values are looked up
+/// from the dictionary on demand.
+pub struct DictionaryDataPageReaderV1<'a, T> {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    type_size: usize,
+    bit_width: usize,
+    dictionary: &'a dyn DictionaryPage<T>,
+    filter: Option<&'a dyn FixedLengthRangeFilter>,
+    validity: Option<Vec<bool>>,
+    indices: Vec<u32>,
+}
+
+#[allow(dead_code)]
+impl<'a, T: ToString> std::fmt::Display for DictionaryDataPageReaderV1<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .indices
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(|&index| self.dictionary.find(index as usize).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Dictionary Data Page: has_null {}, num_values {}, current_offset {}, bit_width {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, self.bit_width, data_str, validity_str
+        )
+    }
+}
+
+impl<'a, T> DataPage<T> for DictionaryDataPageReaderV1<'a, T> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        self.type_size
+    }
+}
+
+impl<'a, T: 'static + std::marker::Copy> DictionaryDataPageReaderV1<'a, T> {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        has_null: bool,
+        dictionary: &'a dyn DictionaryPage<T>,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+        validity: Option<Vec<bool>>,
+    ) -> Result<DictionaryDataPageReaderV1<'a, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::RLE_DICTIONARY
+            && encoding != parquet_metadata_thrift::Encoding::PLAIN_DICTIONARY
+        {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Dictionary Data Page Encoding should be either RLE_DICTIONARY or PLAIN_DICTIONARY",
+            )));
+        }
+
+        let num_non_null_values = match &validity {
+            Some(validity) if has_null => validity.iter().filter(|&&x| x).count(),
+            _ => num_values,
+        };
+
+        let bit_width = buffer.read_u8()? as usize;
+        if unlikely(bit_width > 32) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Invalid dictionary index bit width: {}",
+                bit_width
+            )));
+        }
+
+        let non_null_indices = Self::decode_indices(buffer, bit_width, num_non_null_values)?;
+
+        let num_dictionary_values = dictionary.get_num_values();
+        if let Some(&index) = non_null_indices
+            .iter()
+            .find(|&&index| index as usize >= num_dictionary_values)
+        {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Dictionary index: {} is out of the dictionary size: {}",
+                index, num_dictionary_values
+            )));
+        }
+
+        let indices = match &validity {
+            Some(validity) if has_null => {
+                let mut indices = Vec::with_capacity(num_values);
+                let mut idx = 0;
+                for &valid in validity.iter().take(num_values) {
+                    if valid {
+                        indices.push(non_null_indices[idx]);
+                        idx += 1;
+                    } else {
+                        indices.push(0);
+                    }
+                }
+                indices
+            }
+            _ => non_null_indices,
+        };
+
+        Ok(DictionaryDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            type_size: dictionary.get_type_size(),
+            bit_width,
+            dictionary,
+            filter,
+            validity,
+            indices,
+        })
+    }
+
+    /// Decode the RLE/BP hybrid runs until the required number of indices are loaded.
+    ///
+    /// Bit-packed runs are always padded to a multiple of 8 values, so the padding values at the
+    /// end of the last run are dropped.
+    fn decode_indices(
+        buffer: &mut dyn ByteBufferBase,
+        bit_width: usize,
+        num_values: usize,
+    ) -> Result<Vec<u32>, BoltReaderError> {
+        if bit_width == 0 {
+            return Ok(vec![0; num_values]);
+        }
+
+        let mut indices: Vec<u32> = Vec::with_capacity(num_values);
+        while indices.len() < num_values {
+            let mut run = RleBpDecoder::decode(buffer, bit_width)?;
+            if unlikely(run.is_empty()) {
+                return Err(BoltReaderError::RleBpDecodingError(String::from(
+                    "Empty RLE/BP run in dictionary data page",
+                )));
+            }
+            indices.append(&mut run);
+        }
+        indices.truncate(num_values);
+
+        Ok(indices)
+    }
+
+    pub fn get_bit_width(&self) -> usize {
+        self.bit_width
+    }
+
+    #[inline(always)]
+    fn materialize_values(&self, start: usize, end: usize) -> Vec<T> {
+        self.indices[start..end]
+            .iter()
+            .map(|&index| self.dictionary.find(index as usize))
+            .collect()
+    }
+
+    pub fn read_with_filter(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        let filter = self.filter.unwrap();
+        let values = self.materialize_values(start, end);
+
+        if TypeId::of::<T>() == TypeId::of::<i64>() {
+            let vec = unsafe { convert_generic_vec!(&values[..], mem::size_of::<T>(), i64) };
+            self.filter_values(
+                start,
+                offset,
+                &values,
+                |i| filter.check_i64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<i32>() {
+            let vec = unsafe { convert_generic_vec!(&values[..], mem::size_of::<T>(), i32) };
+            self.filter_values(
+                start,
+                offset,
+                &values,
+                |i| filter.check_i32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+            let vec = unsafe { convert_generic_vec!(&values[..], mem::size_of::<T>(), f64) };
+            self.filter_values(
+                start,
+                offset,
+                &values,
+                |i| filter.check_f64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<f32>() {
+            let vec = unsafe { convert_generic_vec!(&values[..], mem::size_of::<T>(), f32) };
+            self.filter_values(
+                start,
+                offset,
+                &values,
+                |i| filter.check_f32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the value check to the materialized values, starting from the page index `start`.
+    /// Null rows pass the filter only if the filter allows nulls.
+    #[inline(always)]
+    fn filter_values<F: Fn(usize) -> bool>(
+        &self,
+        start: usize,
+        offset: usize,
+        values: &[T],
+        check: F,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) {
+        let null_allowed = self.filter.unwrap().get_null_allowed();
+        let mut generator = RowRangeSetGenerator::new(result_row_range_set);
+
+        for (i, &value) in values.iter().enumerate() {
+            let valid = match &self.validity {
+                Some(validity) if self.has_null => validity[start + i],
+                _ => true,
+            };
+
+            let filter_res = if valid { check(i) } else { null_allowed };
+            generator.update(start + i + self.current_offset - offset, filter_res);
+            if filter_res {
+                result_bridge.append_result(valid, value);
+            }
+        }
+        generator.finish(start + values.len() + self.current_offset - offset);
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        let values = self.materialize_values(start, end);
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &values)?
+            }
+            _ => result_bridge.append_non_null_results(&values)?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+    use std::sync::Arc;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, DictionaryPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::dictionary_data_page_v1::DictionaryDataPageReaderV1;
+    use crate::page_reader::dictionary_page::dictionary_page_reader::DictionaryPageReader;
+    use crate::page_reader::dictionary_page::fixed_length_dictionary_page::{
+        destroy_dictionary, FixedLengthDictionary,
+    };
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::encoding::varint::encode_varint;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    const DICTIONARY_DATA_PAGE_OFFSET: usize = 23391;
+
+    fn create_dictionary(values: &[i64]) -> (FixedLengthDictionary<i64>, DirectByteBuffer) {
+        let header = PageHeader::new(
+            PageType::DICTIONARY_PAGE,
+            (values.len() * 8) as i32,
+            (values.len() * 8) as i32,
+            None,
+            None,
+            None,
+            DictionaryPageHeader::new(values.len() as i32, Encoding::PLAIN, false),
+            None,
+        );
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut buf = DirectByteBuffer::from_vec(bytes);
+        let dictionary = FixedLengthDictionary::new(&header, &mut buf, mem::size_of::<i64>());
+        assert!(dictionary.is_ok());
+        (dictionary.unwrap(), buf)
+    }
+
+    fn create_data_page_header(num_values: usize) -> PageHeader {
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::RLE_DICTIONARY,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn encode_rle_run(value: u8, num_values: usize) -> Vec<u8> {
+        let mut run = encode_varint((num_values << 1) as u64).into_vec();
+        run.push(value);
+        run
+    }
+
+    #[test]
+    fn test_read_dictionary_data_page_from_file() {
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+
+        let page_header = read_page_header(&mut buf).unwrap();
+        let dictionary =
+            FixedLengthDictionary::<i64>::new(&page_header, &mut buf, mem::size_of::<i64>());
+        assert!(dictionary.is_ok());
+        let dictionary = dictionary.unwrap();
+
+        buf.set_rpos(DICTIONARY_DATA_PAGE_OFFSET - 4);
+        let page_header = read_page_header(&mut buf).unwrap();
+        buf.set_rpos(buf.get_rpos() + 8);
+
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            100,
+            false,
+            &dictionary,
+            None,
+            None,
+        );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        assert_eq!(data_page.get_data_page_num_values(), 11212);
+        assert_eq!(data_page.get_data_page_type_size(), 8);
+        assert!(!data_page.data_page_has_null());
+        assert_eq!(data_page.to_string(), format!("Dictionary Data Page: has_null false, num_values 11212, current_offset 100, bit_width {}\nData: 429, 54914, 54914, 54914, 54914, 54914, 54914, 54914, 54915, 54916 ...\nValidity: true, true, true, true, true, true, true, true, true, true ...\n", data_page.get_bit_width()));
+
+        let offset = 50;
+        let to_read = data_page
+            .get_data_page_covered_range(
+                data_page.get_data_page_offset(),
+                data_page.get_data_page_offset() + data_page.get_data_page_num_values(),
+                offset,
+                &RowRange::new(50, 60),
+            )
+            .unwrap()
+            .unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(offset);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read(to_read, offset, &mut result_row_range_set, &mut raw_bridge);
+        assert!(res.is_ok());
+
+        let expected = [
+            429, 54914, 54914, 54914, 54914, 54914, 54914, 54914, 54915, 54916,
+        ];
+        for (i, &value) in expected.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(offset, 50 + i, &result_row_range_set)
+                    .unwrap(),
+                (true, value)
+            );
+        }
+
+        drop(data_page);
+        destroy_dictionary(dictionary);
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_read_with_shared_dictionary() {
+        assert_send_sync::<DictionaryPageReader<i64>>();
+        assert_send_sync::<Arc<DictionaryPageReader<f64>>>();
+
+        let dictionary_values: Vec<i64> = (0..16).map(|i| i * 100 - 700).collect();
+        let page_header = PageHeader::new(
+            PageType::DICTIONARY_PAGE,
+            (dictionary_values.len() * 8) as i32,
+            (dictionary_values.len() * 8) as i32,
+            None,
+            None,
+            None,
+            DictionaryPageHeader::new(dictionary_values.len() as i32, Encoding::PLAIN, false),
+            None,
+        );
+        let bytes: Vec<u8> = dictionary_values
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let mut buf = DirectByteBuffer::from_vec(bytes);
+        let dictionary =
+            Arc::new(DictionaryPageReader::<i64>::new_with_header(&page_header, &mut buf).unwrap());
+
+        // Two data pages of different indices on the same dictionary.
+        let pages: Vec<Vec<u32>> = vec![
+            (0..1000).map(|i| (i * 7) % 16).collect(),
+            (0..800).map(|i| 15 - (i % 16)).collect(),
+        ];
+        let decode = |indices: &[u32], dictionary: &DictionaryPageReader<i64>| {
+            let mut data = vec![4u8];
+            parquet2::encoding::hybrid_rle::encode_u32(&mut data, indices.iter().cloned(), 4)
+                .unwrap();
+            let mut buf = DirectByteBuffer::from_vec(data);
+            let page_header = create_data_page_header(indices.len());
+            let data_page = DictionaryDataPageReaderV1::new(
+                &page_header,
+                &mut buf,
+                0,
+                false,
+                dictionary,
+                None,
+                None,
+            )
+            .unwrap();
+
+            let mut result_row_range_set = RowRangeSet::new(0);
+            let mut raw_bridge = RawBridge::new(false, indices.len());
+            data_page
+                .read(
+                    RowRange::new(0, indices.len()),
+                    0,
+                    &mut result_row_range_set,
+                    &mut raw_bridge,
+                )
+                .unwrap();
+            raw_bridge.get_raw_result().to_vec()
+        };
+
+        let sequential: Vec<Vec<i64>> = pages
+            .iter()
+            .map(|indices| decode(indices, &dictionary))
+            .collect();
+        let concurrent: Vec<Vec<i64>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = pages
+                .iter()
+                .map(|indices| {
+                    let dictionary = Arc::clone(&dictionary);
+                    scope.spawn(move || decode(indices, &dictionary))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        assert_eq!(concurrent, sequential);
+        for (indices, values) in pages.iter().zip(concurrent.iter()) {
+            assert_eq!(values.len(), indices.len());
+            for (index, value) in indices.iter().zip(values.iter()) {
+                assert_eq!(*value, dictionary_values[*index as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_single_rle_run() {
+        let (dictionary, _dictionary_buffer) = create_dictionary(&[7, 11, 13]);
+
+        let num_values = 1000;
+        let mut data = vec![2u8];
+        data.extend(encode_rle_run(2, num_values));
+        let mut buf = DirectByteBuffer::from_vec(data);
+
+        let page_header = create_data_page_header(num_values);
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            0,
+            false,
+            &dictionary,
+            None,
+            None,
+        );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        assert_eq!(data_page.get_bit_width(), 2);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read(
+            RowRange::new(0, num_values),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        assert_eq!(raw_bridge.get_size(), num_values);
+
+        for i in 0..num_values {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, 13)
+            );
+        }
+
+        drop(data_page);
+        destroy_dictionary(dictionary);
+    }
+
+    #[test]
+    fn test_read_mixed_runs() {
+        let dictionary_values: Vec<i64> = (0..16).map(|i| i * 100).collect();
+        let (dictionary, _dictionary_buffer) = create_dictionary(&dictionary_values);
+        let bit_width = 4;
+
+        // RLE run: 20 x index 3
+        let mut data = vec![bit_width as u8];
+        data.extend(encode_rle_run(3, 20));
+        let mut expected: Vec<u32> = vec![3; 20];
+
+        // Bit-packed run: 16 values, indices 0..16
+        let bit_packed: Vec<u32> = (0..16).collect();
+        parquet2::encoding::hybrid_rle::encode_u32(
+            &mut data,
+            bit_packed.iter().cloned(),
+            bit_width,
+        )
+        .unwrap();
+        expected.extend(&bit_packed);
+
+        // RLE run: 5 x index 15
+        data.extend(encode_rle_run(15, 5));
+        expected.extend(vec![15; 5]);
+
+        // Bit-packed run: 3 values padded to 8 values
+        let bit_packed: Vec<u32> = vec![9, 1, 4, 0, 0, 0, 0, 0];
+        parquet2::encoding::hybrid_rle::encode_u32(
+            &mut data,
+            bit_packed.iter().cloned(),
+            bit_width,
+        )
+        .unwrap();
+        expected.extend(&bit_packed[..3]);
+
+        let mut buf = DirectByteBuffer::from_vec(data);
+        let page_header = create_data_page_header(expected.len());
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            0,
+            false,
+            &dictionary,
+            None,
+            None,
+        );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read(
+            RowRange::new(0, expected.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        assert_eq!(raw_bridge.get_size(), expected.len());
+
+        for (i, &index) in expected.iter().enumerate() {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, index as i64 * 100)
+            );
+        }
+
+        let filter = IntegerRangeFilter::new(300, 900, false);
+        let mut buf = DirectByteBuffer::from_vec(buf.into_vec());
+        buf.set_rpos(0);
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            0,
+            false,
+            &dictionary,
+            Option::Some(&filter),
+            None,
+        )
+        .unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read_with_filter(
+            RowRange::new(0, expected.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let expected_rows: Vec<usize> = (0..expected.len())
+            .filter(|&i| filter.check_i64(expected[i] as i64 * 100))
+            .collect();
+        assert_eq!(raw_bridge.get_size(), expected_rows.len());
+        for i in expected_rows {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap(),
+                (true, expected[i] as i64 * 100)
+            );
+        }
+
+        drop(data_page);
+        destroy_dictionary(dictionary);
+    }
+
+    #[test]
+    fn test_read_dictionary_data_page_with_nulls() {
+        let (dictionary, _dictionary_buffer) = create_dictionary(&[5, 6]);
+
+        let validity: Vec<bool> = (0..10).map(|i| i % 3 != 0).collect();
+        let num_non_null = validity.iter().filter(|&&x| x).count();
+
+        let mut data = vec![1u8];
+        data.extend(encode_rle_run(1, num_non_null));
+        let mut buf = DirectByteBuffer::from_vec(data);
+
+        let page_header = create_data_page_header(validity.len());
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            0,
+            true,
+            &dictionary,
+            None,
+            Option::Some(validity.clone()),
+        );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 1024);
+        let res = data_page.read(
+            RowRange::new(0, validity.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        for (i, &valid) in validity.iter().enumerate() {
+            let (res_valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(res_valid, valid);
+            if valid {
+                assert_eq!(value, 6);
+            }
+        }
+
+        drop(data_page);
+        destroy_dictionary(dictionary);
+    }
+
+    #[test]
+    fn test_dictionary_index_out_of_bound() {
+        let (dictionary, _dictionary_buffer) = create_dictionary(&[5, 6]);
+
+        let mut data = vec![2u8];
+        data.extend(encode_rle_run(3, 10));
+        let mut buf = DirectByteBuffer::from_vec(data);
+
+        let page_header = create_data_page_header(10);
+        let data_page = DictionaryDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            0,
+            false,
+            &dictionary,
+            None,
+            None,
+        );
+        assert!(data_page.is_err());
+        assert_eq!(
+            data_page.err().unwrap().to_string(),
+            "Fixed Length Data Page Error: Dictionary index: 3 is out of the dictionary size: 2\n"
+        );
+
+        destroy_dictionary(dictionary);
+    }
+}
@@ -0,0 +1,390 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::decimal::Decimal128;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::rep_def_parser::RepDefParser;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet FIXED_LEN_BYTE_ARRAY Decimal Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// Each PLAIN encoded value is a big-endian two's complement integer of type_length bytes, which
+/// is the unscaled value of the decimal. The values are decoded to i128 with 0 at the null
+/// positions, and the scale comes from the DECIMAL logical type of the column.
+pub struct FixedLenByteArrayDataPageReaderV1 {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    type_length: usize,
+    scale: i32,
+    validity: Option<Vec<bool>>,
+    data: Vec<i128>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for FixedLenByteArrayDataPageReaderV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = (0..self.num_values)
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(|i| self.get_decimal128(i).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Fixed Len Byte Array Data Page: has_null {}, num_values {}, current_offset {}, type_length {}, scale {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, self.type_length, self.scale, data_str, validity_str
+        )
+    }
+}
+
+impl DataPage<i128> for FixedLenByteArrayDataPageReaderV1 {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        mem::size_of::<i128>()
+    }
+}
+
+impl FixedLenByteArrayDataPageReaderV1 {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_length: usize,
+        scale: i32,
+        max_definition_level: u32,
+    ) -> Result<FixedLenByteArrayDataPageReaderV1, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        if header.encoding != parquet_metadata_thrift::Encoding::PLAIN {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Plain Data Page Encoding should be PLAIN",
+            )));
+        }
+
+        if unlikely(type_length == 0 || type_length > Decimal128::MAX_TYPE_SIZE) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "FIXED_LEN_BYTE_ARRAY decimal type length: {} should be 1 to {}",
+                type_length,
+                Decimal128::MAX_TYPE_SIZE
+            )));
+        }
+
+        let validity = if max_definition_level > 0 {
+            let levels = RepDefParser::load_level(buffer, max_definition_level)?;
+            if unlikely(levels.len() < num_values) {
+                return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                    "Data Page V1 definition levels: {} are fewer than num_values: {}",
+                    levels.len(),
+                    num_values
+                )));
+            }
+            let validity: Vec<bool> = levels
+                .iter()
+                .take(num_values)
+                .map(|&level| level == max_definition_level)
+                .collect();
+            if validity.iter().all(|&valid| valid) {
+                None
+            } else {
+                Some(validity)
+            }
+        } else {
+            None
+        };
+
+        let num_non_null_values = match &validity {
+            Some(validity) => validity.iter().filter(|v| **v).count(),
+            None => num_values,
+        };
+        let data_size = num_non_null_values * type_length;
+        let bytes = if data_size == 0 {
+            Vec::new()
+        } else if buffer.can_create_buffer_slice(buffer.get_rpos(), data_size) {
+            let res = Vec::from(buffer.create_buffer_slice(buffer.get_rpos(), data_size)?);
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+
+            res
+        } else {
+            buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), data_size)?
+        };
+
+        let mut data = Vec::with_capacity(num_values);
+        let mut values = bytes.chunks_exact(type_length);
+        for i in 0..num_values {
+            if let Some(validity) = &validity {
+                if !validity[i] {
+                    data.push(0);
+                    continue;
+                }
+            }
+
+            // The chunk exists, the data size is counted from the non-null values.
+            let value = values.next().unwrap();
+            data.push(Decimal128::from_be_bytes(value, scale)?.get_unscaled_value());
+        }
+
+        Ok(FixedLenByteArrayDataPageReaderV1 {
+            has_null: validity.is_some(),
+            num_values,
+            current_offset,
+            type_length,
+            scale,
+            validity,
+            data,
+        })
+    }
+
+    pub fn get_scale(&self) -> i32 {
+        self.scale
+    }
+
+    /// The unscaled value, which is 0 at the null position.
+    pub fn get_decimal(&self, index: usize) -> i128 {
+        self.data[index]
+    }
+
+    pub fn get_decimal128(&self, index: usize) -> Decimal128 {
+        Decimal128::new(self.data[index], self.scale)
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        match &self.validity {
+            Some(validity) if self.has_null => !validity[index],
+            _ => false,
+        }
+    }
+
+    /// Read the unscaled values into the bridge.
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<i128>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, DecimalType, Encoding, FieldRepetitionType, LogicalType, PageHeader,
+        PageType, SchemaElement, Type,
+    };
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::fixed_len_byte_array_data_page_v1::FixedLenByteArrayDataPageReaderV1;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    fn create_page_header(num_values: usize, page_size: usize) -> PageHeader {
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+    }
+
+    // The DECIMAL(38, 9) column stored as FIXED_LEN_BYTE_ARRAY(16).
+    fn create_schema_tree(repetition_type: FieldRepetitionType) -> SchemaTree {
+        let schema = vec![
+            SchemaElement::new(
+                None,
+                None,
+                None,
+                String::from("schema"),
+                1,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            SchemaElement::new(
+                Type::FIXED_LEN_BYTE_ARRAY,
+                16,
+                repetition_type,
+                String::from("price"),
+                None,
+                None,
+                None,
+                None,
+                None,
+                LogicalType::DECIMAL(DecimalType::new(9, 38)),
+            ),
+        ];
+        SchemaTree::new(&schema).unwrap()
+    }
+
+    #[test]
+    fn test_read_decimal_data_page() {
+        let schema_tree = create_schema_tree(FieldRepetitionType::REQUIRED);
+        let column = &schema_tree.get_column_descriptors()[0];
+        assert_eq!(column.get_decimal_precision(), Some(38));
+        assert_eq!(column.get_decimal_scale(), Some(9));
+        let type_length = column.get_type_length().unwrap() as usize;
+
+        // 123.456789012, -12345.67 and the min value of 16 bytes.
+        let values: Vec<i128> = vec![123456789012, -12345670000000, i128::MIN];
+        let mut page: Vec<u8> = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+        assert_eq!(page[16] & 0x80, 0x80);
+        // The bytes after the page should not be read.
+        page.push(0xff);
+
+        let page_header = create_page_header(values.len(), 48);
+        let mut buf = DirectByteBuffer::from_loaded_bytes(page);
+        let res = FixedLenByteArrayDataPageReaderV1::new(
+            &page_header,
+            &mut buf,
+            100,
+            type_length,
+            column.get_decimal_scale().unwrap(),
+            column.get_max_definition_level() as u32,
+        );
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(buf.get_rpos(), 48);
+        assert_eq!(data_page.get_data_page_num_values(), 3);
+        assert_eq!(data_page.get_data_page_offset(), 100);
+        assert_eq!(data_page.get_data_page_type_size(), 16);
+        assert_eq!(data_page.get_scale(), 9);
+        assert!(!data_page.data_page_has_null());
+
+        assert_eq!(data_page.get_decimal(0), 123456789012);
+        assert_eq!(data_page.get_decimal128(0).to_string(), "123.456789012");
+        assert_eq!(data_page.get_decimal(1), -12345670000000);
+        assert_eq!(data_page.get_decimal128(1).to_string(), "-12345.670000000");
+        assert_eq!(data_page.get_decimal(2), i128::MIN);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 2);
+        let res = data_page.read(
+            RowRange::new(101, 103),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        for i in 101..103 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(value, values[i - 100]);
+        }
+    }
+
+    #[test]
+    fn test_read_nullable_short_decimal_data_page() {
+        // DECIMAL(4, 2) stored as FIXED_LEN_BYTE_ARRAY(2), the values are 12.34, null and -0.01.
+        // The RLE runs of the definition levels are 1, 0 and 1.
+        let mut page = vec![6, 0, 0, 0, 2, 1, 2, 0, 2, 1];
+        page.extend_from_slice(&1234i16.to_be_bytes());
+        page.extend_from_slice(&(-1i16).to_be_bytes());
+
+        let page_header = create_page_header(3, page.len());
+        let mut buf = DirectByteBuffer::from_loaded_bytes(page);
+        let res = FixedLenByteArrayDataPageReaderV1::new(&page_header, &mut buf, 0, 2, 2, 1);
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert!(data_page.data_page_has_null());
+        assert_eq!(buf.get_rpos(), 14);
+
+        assert_eq!(data_page.get_decimal(0), 1234);
+        assert_eq!(data_page.get_decimal128(0).to_string(), "12.34");
+        assert!(data_page.is_null(1));
+        assert_eq!(data_page.get_decimal(1), 0);
+        assert_eq!(data_page.get_decimal(2), -1);
+        assert_eq!(data_page.get_decimal128(2).to_string(), "-0.01");
+        assert_eq!(data_page.to_string(), "Fixed Len Byte Array Data Page: has_null true, num_values 3, current_offset 0, type_length 2, scale 2\nData: 12.34, 0.00, -0.01 ...\nValidity: true, false, true ...\n");
+
+        // The type length is limited to 16 bytes.
+        let page_header = create_page_header(1, 17);
+        let mut buf = DirectByteBuffer::from_loaded_bytes(vec![0; 17]);
+        let res = FixedLenByteArrayDataPageReaderV1::new(&page_header, &mut buf, 0, 17, 0, 0);
+        assert!(res.is_err());
+    }
+}
@@ -26,6 +26,9 @@ use crate::page_reader::data_page_v1::data_page_base::DataPage;
 use crate::utils::byte_buffer_base::ByteBufferBase;
 use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::plain_values::expand_non_null_values;
+use crate::utils::rep_def_parser::RepDefParser;
 use crate::utils::row_range_set::{RowRange, RowRangeSet, RowRangeSetGenerator};
 
 // Currently, we display 10 pieces of data only
@@ -40,10 +43,12 @@ pub struct FixedLengthPlainDataPageReaderV1<'a, T> {
     num_values: usize,
     current_offset: usize,
     type_size: usize,
-    #[allow(dead_code)]
     zero_copy: bool,
     filter: Option<&'a dyn FixedLengthRangeFilter>,
     validity: Option<Vec<bool>>,
+    max_definition_level: i16,
+    repetition_levels: Vec<i16>,
+    definition_levels: Vec<i16>,
     data: Vec<T>,
 }
 
@@ -141,6 +146,9 @@ impl<'a, T: 'static + std::marker::Copy> FixedLengthPlainDataPageReaderV1<'a, T>
             )?
         };
 
+        // Without the definition levels, the page is a flat column.
+        let max_definition_level = (has_null && validity.is_some()) as i16;
+
         Ok(FixedLengthPlainDataPageReaderV1 {
             has_null,
             num_values,
@@ -149,10 +157,70 @@ impl<'a, T: 'static + std::marker::Copy> FixedLengthPlainDataPageReaderV1<'a, T>
             zero_copy,
             filter,
             validity,
+            max_definition_level,
+            repetition_levels: Vec::new(),
+            definition_levels: Vec::new(),
             data,
         })
     }
 
+    /// The repetition levels are empty if the column is not repeated.
+    pub fn get_repetition_levels(&self) -> &[i16] {
+        &self.repetition_levels
+    }
+
+    /// The definition levels are kept when the page has nulls or repetition levels, to tell the
+    /// null structs, the empty and null lists apart from the null elements.
+    pub fn get_definition_levels(&self) -> &[i16] {
+        &self.definition_levels
+    }
+
+    /// For debugging: the value, None at the null position, with its raw definition level. The
+    /// pages without nulls have the max definition level at every position, and the pages created
+    /// from the validity only are flat columns, i.e. the level is 0 for the nulls and 1 for the
+    /// values.
+    pub fn get_value_with_def_level(&self, index: usize) -> (Option<T>, i16) {
+        let value = if self.is_null(index) {
+            None
+        } else {
+            Some(self.data[index])
+        };
+
+        let definition_level = match self.definition_levels.get(index) {
+            Some(level) => *level,
+            None if value.is_none() => 0,
+            None => self.max_definition_level,
+        };
+
+        (value, definition_level)
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        match &self.validity {
+            Some(validity) if self.has_null => !validity[index],
+            _ => false,
+        }
+    }
+
+    /// The value at the null position is a placeholder, which is T::default() when the page is
+    /// loaded with the definition levels.
+    pub fn get_value(&self, index: usize) -> T {
+        self.data[index]
+    }
+
+    /// Iterate the values of the page in order. The null positions are skipped with skip_nulls,
+    /// otherwise they yield the placeholder of get_value and can be told apart by is_null.
+    ///
+    /// The page is decoded when it is created and read by index, so there is no cursor to reset:
+    /// the values can be iterated or read again, e.g. after a first pass counting the nulls.
+    pub fn iter_values(&self, skip_nulls: bool) -> impl Iterator<Item = T> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| !skip_nulls || !self.is_null(*index))
+            .map(|(_, value)| *value)
+    }
+
     pub fn read_with_filter(
         &self,
         to_read: RowRange,
@@ -160,77 +228,81 @@ impl<'a, T: 'static + std::marker::Copy> FixedLengthPlainDataPageReaderV1<'a, T>
         result_row_range_set: &mut RowRangeSet,
         result_bridge: &mut dyn Bridge<T>,
     ) -> Result<(), BoltReaderError> {
-        let start = to_read.begin + offset - self.current_offset;
-        let end = to_read.end + offset - self.current_offset;
         let filter = self.filter.unwrap();
-        let vec_t = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<T>(), T) };
 
         if TypeId::of::<T>() == TypeId::of::<i64>() {
             let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<i64>(), i64) };
-
-            let mut generator = RowRangeSetGenerator::new(result_row_range_set);
-
-            for i in start..end {
-                let filter_res = filter.check_i64(vec[i]);
-                generator.update(i + self.current_offset - offset, filter_res);
-                if filter_res {
-                    result_bridge.append_non_null_result(vec_t[i]);
-                }
-            }
-            generator.finish(end + self.current_offset - offset);
-
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
             mem::forget(vec);
         } else if TypeId::of::<T>() == TypeId::of::<i32>() {
             let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<i32>(), i32) };
-
-            let mut generator = RowRangeSetGenerator::new(result_row_range_set);
-
-            for i in start..end {
-                let filter_res = filter.check_i32(vec[i]);
-                generator.update(i + self.current_offset - offset, filter_res);
-                if filter_res {
-                    result_bridge.append_non_null_result(vec_t[i]);
-                }
-            }
-            generator.finish(end + self.current_offset - offset);
-
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
             mem::forget(vec);
         } else if TypeId::of::<T>() == TypeId::of::<f64>() {
             let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<f64>(), f64) };
-
-            let mut generator = RowRangeSetGenerator::new(result_row_range_set);
-
-            for i in start..end {
-                let filter_res = filter.check_f64(vec[i]);
-                generator.update(i + self.current_offset - offset, filter_res);
-                if filter_res {
-                    result_bridge.append_non_null_result(vec_t[i]);
-                }
-            }
-            generator.finish(end + self.current_offset - offset);
-
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_f64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
             mem::forget(vec);
         } else if TypeId::of::<T>() == TypeId::of::<f32>() {
             let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<f32>(), f32) };
-
-            let mut generator = RowRangeSetGenerator::new(result_row_range_set);
-
-            for i in start..end {
-                let filter_res = filter.check_f32(vec[i]);
-                generator.update(i + self.current_offset - offset, filter_res);
-                if filter_res {
-                    result_bridge.append_non_null_result(vec_t[i]);
-                }
-            }
-            generator.finish(end + self.current_offset - offset);
-
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_f32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
             mem::forget(vec);
         }
 
-        mem::forget(vec_t);
         Ok(())
     }
 
+    /// Apply the value check to every row in the range. Null rows pass the filter only if the
+    /// filter allows nulls.
+    #[inline(always)]
+    fn filter_values<F: Fn(usize) -> bool>(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        check: F,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        let null_allowed = self.filter.unwrap().get_null_allowed();
+
+        let mut generator = RowRangeSetGenerator::new(result_row_range_set);
+
+        for i in start..end {
+            let valid = !self.is_null(i);
+            let filter_res = if valid { check(i) } else { null_allowed };
+            generator.update(i + self.current_offset - offset, filter_res);
+            if filter_res {
+                result_bridge.append_result(valid, self.data[i]);
+            }
+        }
+        generator.finish(end + self.current_offset - offset);
+    }
+
     pub fn read(
         &self,
         to_read: RowRange,
@@ -238,27 +310,258 @@ impl<'a, T: 'static + std::marker::Copy> FixedLengthPlainDataPageReaderV1<'a, T>
         result_row_range_set: &mut RowRangeSet,
         result_bridge: &mut dyn Bridge<T>,
     ) -> Result<(), BoltReaderError> {
-        if self.has_null {
-            return Err(BoltReaderError::NotYetImplementedError(String::from(
-                "Not Yet Implemented: Read Data Page with nulls",
+        let num_values = match &self.validity {
+            Some(validity) if self.has_null => self.data.len().min(validity.len()),
+            _ => self.data.len(),
+        };
+        // The range is checked without overflow for any input, so the slicing below cannot panic.
+        let range = match (
+            to_read.begin.checked_add(offset),
+            to_read.end.checked_add(offset),
+        ) {
+            (Some(begin), Some(end))
+                if begin >= self.current_offset
+                    && end >= begin
+                    && end - self.current_offset <= num_values =>
+            {
+                Some((begin, end))
+            }
+            _ => None,
+        };
+        let (begin, end) = match range {
+            Some(range) => range,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                    "Reading range: [{}, {}) with offset: {} is out of the data page: [{}, {})",
+                    to_read.begin,
+                    to_read.end,
+                    offset,
+                    self.current_offset,
+                    self.current_offset + num_values
+                )))
+            }
+        };
+
+        let start = begin - self.current_offset;
+        let end_in_page = end - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            begin - result_row_range_set.get_offset(),
+            end - result_row_range_set.get_offset(),
+        );
+
+        // The values of the range are appended to the bridge in one run.
+        let values = &self.data[start..end_in_page];
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end_in_page], values)
+            }
+            _ => result_bridge.append_non_null_results(values),
+        }
+    }
+}
+
+impl<'a, T: 'static + std::marker::Copy + Default> FixedLengthPlainDataPageReaderV1<'a, T> {
+    /// Decode the length-prefixed RLE/BP definition levels before loading the values.
+    ///
+    /// Only the values with the max definition level are stored in the page. When the page has
+    /// nulls, the values are expanded and the null positions are filled with T::default().
+    pub fn new_with_definition_levels(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_size: usize,
+        max_definition_level: u32,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+    ) -> Result<FixedLengthPlainDataPageReaderV1<'a, T>, BoltReaderError> {
+        if max_definition_level == 0 {
+            return Self::new(
+                page_header,
+                buffer,
+                current_offset,
+                type_size,
+                false,
+                filter,
+                None,
+            );
+        }
+
+        let levels = RepDefParser::load_level(buffer, max_definition_level)?;
+        Self::new_with_loaded_definition_levels(
+            page_header,
+            buffer,
+            current_offset,
+            type_size,
+            max_definition_level,
+            &levels,
+            filter,
+        )
+    }
+
+    /// Decode the values with the definition levels already loaded from the buffer, e.g. by a
+    /// reader that needs the levels themselves.
+    pub fn new_with_loaded_definition_levels(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_size: usize,
+        max_definition_level: u32,
+        levels: &[u32],
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+    ) -> Result<FixedLengthPlainDataPageReaderV1<'a, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        if unlikely(levels.len() < num_values) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Data Page V1 definition levels: {} are fewer than num_values: {}",
+                levels.len(),
+                num_values
+            )));
+        }
+
+        let validity: Vec<bool> = levels
+            .iter()
+            .take(num_values)
+            .map(|&level| level == max_definition_level)
+            .collect();
+        let num_non_null = validity.iter().filter(|&&valid| valid).count();
+
+        if num_non_null == num_values {
+            let mut data_page = Self::new(
+                page_header,
+                buffer,
+                current_offset,
+                type_size,
+                false,
+                filter,
+                None,
+            )?;
+            data_page.max_definition_level = max_definition_level as i16;
+            return Ok(data_page);
+        }
+
+        if header.encoding != parquet_metadata_thrift::Encoding::PLAIN {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Plain Data Page Encoding should be PLAIN",
             )));
+        }
+
+        let data_size = num_non_null * type_size;
+        let non_null_data: Vec<T> = if data_size > 0 {
+            let res = DirectByteBuffer::convert_byte_vec(
+                buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), data_size)?,
+                type_size,
+            )?;
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+            res
         } else {
-            let start = to_read.begin + offset - self.current_offset;
-            let end = to_read.end + offset - self.current_offset;
-            result_row_range_set.add_row_ranges(
-                to_read.begin + offset - result_row_range_set.get_offset(),
-                to_read.end + offset - result_row_range_set.get_offset(),
+            Vec::new()
+        };
+
+        let data = expand_non_null_values(&non_null_data, &validity);
+        let definition_levels = levels
+            .iter()
+            .take(num_values)
+            .map(|&level| level as i16)
+            .collect();
+
+        Ok(FixedLengthPlainDataPageReaderV1 {
+            has_null: true,
+            num_values,
+            current_offset,
+            type_size,
+            zero_copy: false,
+            filter,
+            validity: Some(validity),
+            max_definition_level: max_definition_level as i16,
+            repetition_levels: Vec::new(),
+            definition_levels,
+            data,
+        })
+    }
+
+    /// Decode the length-prefixed RLE/BP repetition levels, followed by the definition levels and
+    /// the values. The repetition level 0 marks the beginning of a new record.
+    pub fn new_with_levels(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_size: usize,
+        max_repetition_level: u32,
+        max_definition_level: u32,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+    ) -> Result<FixedLengthPlainDataPageReaderV1<'a, T>, BoltReaderError> {
+        if max_repetition_level == 0 {
+            return Self::new_with_definition_levels(
+                page_header,
+                buffer,
+                current_offset,
+                type_size,
+                max_definition_level,
+                filter,
             );
-            result_bridge.append_non_null_results(&self.data[start..end])?;
         }
-        Ok(())
+
+        let num_values = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1.num_values as usize,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let levels = RepDefParser::load_level(buffer, max_repetition_level)?;
+        if unlikely(levels.len() < num_values) {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Data Page V1 repetition levels: {} are fewer than num_values: {}",
+                levels.len(),
+                num_values
+            )));
+        }
+
+        let definition_levels = if max_definition_level == 0 {
+            vec![0; num_values]
+        } else {
+            RepDefParser::load_level(buffer, max_definition_level)?
+        };
+        let mut data_page = Self::new_with_loaded_definition_levels(
+            page_header,
+            buffer,
+            current_offset,
+            type_size,
+            max_definition_level,
+            &definition_levels,
+            filter,
+        )?;
+        data_page.repetition_levels = levels
+            .iter()
+            .take(num_values)
+            .map(|&level| level as i16)
+            .collect();
+        data_page.definition_levels = definition_levels
+            .iter()
+            .take(num_values)
+            .map(|&level| level as i16)
+            .collect();
+
+        Ok(data_page)
     }
 }
 
 #[allow(dead_code)]
 #[inline(always)]
 pub fn destroy_fixed_length_plain_data_page_v1<T>(data_page: FixedLengthPlainDataPageReaderV1<T>) {
-    mem::forget(data_page.data);
+    if data_page.zero_copy {
+        mem::forget(data_page.data);
+    }
 }
 
 #[cfg(test)]
@@ -266,11 +569,17 @@ mod tests {
 
     use std::mem;
 
+    use bytebuffer::Endian::LittleEndian;
+    use parquet2::encoding::hybrid_rle::encode_u32;
+
     use crate::bridge::bridge_base::Bridge;
     use crate::bridge::raw_bridge::RawBridge;
     use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
     use crate::filters::integer_range_filter::IntegerRangeFilter;
     use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
     use crate::page_reader::data_page_v1::data_page_base::DataPage;
     use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
         destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
@@ -280,6 +589,7 @@ mod tests {
     use crate::utils::exceptions::BoltReaderError;
     use crate::utils::file_loader::LoadFile;
     use crate::utils::file_streaming_byte_buffer::{FileStreamingBuffer, StreamingByteBuffer};
+    use crate::utils::int96::Int96;
     use crate::utils::local_file_loader::LocalFileLoader;
     use crate::utils::row_range_set::{RowRange, RowRangeSet};
 
@@ -435,6 +745,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_iter_values() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV1<i64>, _>, _) =
+            load_non_null_plain_data_page(100, path);
+        let data_page = data_page.unwrap();
+
+        let values: Vec<i64> = data_page.iter_values(false).collect();
+        assert_eq!(values.len(), 11212);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(*value, data_page.get_value(i));
+        }
+        assert_eq!(data_page.iter_values(true).count(), 11212);
+
+        if data_page.zero_copy {
+            destroy_fixed_length_plain_data_page_v1(data_page);
+        }
+
+        let (data_page, _buf) = load_nullable_plain_data_page(0, None);
+        let data_page = data_page.unwrap();
+
+        let values: Vec<i64> = data_page.iter_values(false).collect();
+        assert_eq!(values.len(), 1000);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(*value, data_page.get_value(i));
+            if data_page.is_null(i) {
+                assert_eq!(*value, 0);
+            }
+        }
+
+        let non_null_values: Vec<i64> = data_page.iter_values(true).collect();
+        let expected: Vec<i64> = (0..1000)
+            .filter(|i| !is_null_in_nullable_page(*i))
+            .map(|i| data_page.get_value(i))
+            .collect();
+        assert_eq!(non_null_values, expected);
+        assert_eq!(non_null_values.len(), 713);
+        assert_eq!(non_null_values[..3], [0, 20, 30]);
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_data_page_twice() {
+        let (data_page, _buf) = load_nullable_plain_data_page(0, None);
+        let data_page = data_page.unwrap();
+
+        // The first pass counts the nulls, the second one reads the values.
+        let num_nulls = (0..1000).filter(|i| data_page.is_null(*i)).count();
+        assert_eq!(num_nulls, 287);
+        let first: Vec<i64> = data_page.iter_values(true).collect();
+        let second: Vec<i64> = data_page.iter_values(true).collect();
+        assert_eq!(first.len(), 1000 - num_nulls);
+        assert_eq!(first, second);
+
+        let read_page = || {
+            let mut result_row_range_set = RowRangeSet::new(0);
+            let mut raw_bridge = RawBridge::new(true, 1000);
+            data_page
+                .read(
+                    RowRange::new(0, 1000),
+                    0,
+                    &mut result_row_range_set,
+                    &mut raw_bridge,
+                )
+                .unwrap();
+            (0..1000)
+                .map(|i| {
+                    raw_bridge
+                        .get_validity_and_value(0, i, &result_row_range_set)
+                        .unwrap()
+                })
+                .collect::<Vec<(bool, i64)>>()
+        };
+        let first = read_page();
+        assert_eq!(first.iter().filter(|(valid, _)| !valid).count(), num_nulls);
+        assert_eq!(first, read_page());
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
     #[test]
     fn test_read_data_page() {
         let path = String::from("src/sample_files/linitem_plain_data_page");
@@ -1087,4 +1478,650 @@ mod tests {
             destroy_fixed_length_plain_data_page_v1(data_page);
         }
     }
+
+    fn load_nullable_plain_data_page(
+        data_page_offset: usize,
+        filter: Option<&dyn FixedLengthRangeFilter>,
+    ) -> (
+        Result<FixedLengthPlainDataPageReaderV1<i64>, BoltReaderError>,
+        DirectByteBuffer,
+    ) {
+        let path = String::from("src/sample_files/nullable_int64_data_page_v1.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let page_header = read_page_header(&mut buf);
+        assert!(page_header.is_ok());
+        let page_header = page_header.unwrap();
+
+        (
+            FixedLengthPlainDataPageReaderV1::new_with_definition_levels(
+                &page_header,
+                &mut buf,
+                data_page_offset,
+                mem::size_of::<i64>(),
+                1,
+                filter,
+            ),
+            buf,
+        )
+    }
+
+    /// The nullable INT64 column has 1000 rows, the value is i * 10, and it is null if
+    /// i % 4 == 1 or 300 <= i < 350.
+    fn is_null_in_nullable_page(i: usize) -> bool {
+        i % 4 == 1 || (300..350).contains(&i)
+    }
+
+    #[test]
+    fn test_read_nullable_data_page() {
+        let data_page_offset = 100;
+        let (data_page, buf) = load_nullable_plain_data_page(data_page_offset, None);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        assert!(data_page.data_page_has_null());
+        assert_eq!(data_page.get_data_page_num_values(), 1000);
+        assert_eq!(buf.get_rpos(), 5855);
+        assert_eq!(data_page.to_string(), "Plain Data Page: has_null true, num_values 1000, current_offset 100\nData: 0, 0, 20, 30, 40, 0, 60, 70, 80, 0 ...\nValidity: true, false, true, true, true, false, true, true, true, false ...\n");
+
+        for i in 0..1000 {
+            assert_eq!(data_page.is_null(i), is_null_in_nullable_page(i));
+        }
+
+        let offset = 50;
+        let mut result_row_range_set = RowRangeSet::new(offset);
+        let mut raw_bridge = RawBridge::new(true, 1000);
+        let res = data_page.read(
+            RowRange::new(50, 1050),
+            offset,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        for i in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(offset, i + 50, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, !is_null_in_nullable_page(i));
+            if valid {
+                assert_eq!(value, i as i64 * 10);
+            }
+        }
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_nullable_data_page_with_filter() {
+        let data_page_offset = 0;
+        for null_allowed in [true, false] {
+            let filter = IntegerRangeFilter::new(1000, 5000, null_allowed);
+            let (data_page, _buf) = load_nullable_plain_data_page(data_page_offset, Some(&filter));
+            assert!(data_page.is_ok());
+            let data_page = data_page.unwrap();
+
+            let mut result_row_range_set = RowRangeSet::new(0);
+            let mut raw_bridge = RawBridge::new(true, 1000);
+            let res = data_page.read_with_filter(
+                RowRange::new(0, 1000),
+                0,
+                &mut result_row_range_set,
+                &mut raw_bridge,
+            );
+            assert!(res.is_ok());
+
+            let mut expected_size = 0;
+            for i in 0..1000 {
+                let is_null = is_null_in_nullable_page(i);
+                let passed = if is_null {
+                    null_allowed
+                } else {
+                    (100..=500).contains(&i)
+                };
+                if !passed {
+                    continue;
+                }
+                expected_size += 1;
+
+                let (valid, value) = raw_bridge
+                    .get_validity_and_value(0, i, &result_row_range_set)
+                    .unwrap();
+                assert_eq!(valid, !is_null);
+                if valid {
+                    assert_eq!(value, i as i64 * 10);
+                }
+            }
+            assert_eq!(raw_bridge.get_size(), expected_size);
+
+            destroy_fixed_length_plain_data_page_v1(data_page);
+        }
+    }
+
+    #[test]
+    fn test_read_non_null_data_page_with_definition_levels() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 0, file.get_file_size());
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let page_header = read_page_header(&mut buf).unwrap();
+
+        let data_page: Result<FixedLengthPlainDataPageReaderV1<i64>, BoltReaderError> =
+            FixedLengthPlainDataPageReaderV1::new_with_definition_levels(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i64>(),
+                1,
+                None,
+            );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        assert!(!data_page.data_page_has_null());
+        assert!(data_page.zero_copy);
+        assert!(!data_page.is_null(0));
+        assert_eq!(buf.get_rpos(), buf.len());
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    fn encode_levels(levels: &[u32], bit_width: u32) -> Vec<u8> {
+        let mut encoded = vec![];
+        encode_u32(&mut encoded, levels.iter().cloned(), bit_width).unwrap();
+        let mut res = (encoded.len() as u32).to_le_bytes().to_vec();
+        res.extend(encoded);
+        res
+    }
+
+    #[test]
+    fn test_read_list_data_page() {
+        // optional group col (LIST) { repeated group list { optional int32 element; } }
+        // Records: [1, 2, 3], [], null, [4, null], [5]
+        let repetition_levels: [u32; 8] = [0, 1, 1, 0, 0, 0, 1, 0];
+        let definition_levels: [u32; 8] = [3, 3, 3, 1, 0, 3, 2, 3];
+        let values: [i32; 5] = [1, 2, 3, 4, 5];
+
+        let mut page = encode_levels(&repetition_levels, 1);
+        page.extend(encode_levels(&definition_levels, 2));
+        page.extend(values.iter().flat_map(|v| v.to_le_bytes()));
+        let page_size = page.len();
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(8, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        let data_page: Result<FixedLengthPlainDataPageReaderV1<i32>, BoltReaderError> =
+            FixedLengthPlainDataPageReaderV1::new_with_levels(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i32>(),
+                1,
+                3,
+                None,
+            );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        assert_eq!(buf.get_rpos(), page_size);
+
+        let expected_repetition_levels: Vec<i16> =
+            repetition_levels.iter().map(|&x| x as i16).collect();
+        assert_eq!(
+            data_page.get_repetition_levels(),
+            &expected_repetition_levels[..]
+        );
+        let expected_definition_levels: Vec<i16> =
+            definition_levels.iter().map(|&x| x as i16).collect();
+        assert_eq!(
+            data_page.get_definition_levels(),
+            &expected_definition_levels[..]
+        );
+
+        // Each repetition level 0 starts a new record.
+        let record_begins: Vec<usize> = data_page
+            .get_repetition_levels()
+            .iter()
+            .enumerate()
+            .filter(|(_, &level)| level == 0)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(record_begins, vec![0, 3, 4, 5, 7]);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 8);
+        let res = data_page.read(
+            RowRange::new(0, 8),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let expected = [
+            Some(1),
+            Some(2),
+            Some(3),
+            None,
+            None,
+            Some(4),
+            None,
+            Some(5),
+        ];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(data_page.is_null(i), expected.is_none());
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, expected.is_some());
+            if let Some(expected) = expected {
+                assert_eq!(value, *expected);
+            }
+        }
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_non_repeated_data_page_with_levels() {
+        let (data_page, _buf) = load_nullable_plain_data_page(0, None);
+        let data_page = data_page.unwrap();
+        assert!(data_page.get_repetition_levels().is_empty());
+        assert_eq!(data_page.get_definition_levels().len(), 1000);
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_get_value_with_def_level() {
+        let (data_page, _buf) = load_nullable_plain_data_page(0, None);
+        let data_page = data_page.unwrap();
+        for i in 0..1000 {
+            let (value, definition_level) = data_page.get_value_with_def_level(i);
+            if is_null_in_nullable_page(i) {
+                assert_eq!(value, None);
+                assert_eq!(definition_level, 0);
+            } else {
+                assert_eq!(value, Some(i as i64 * 10));
+                assert_eq!(definition_level, 1);
+            }
+        }
+        destroy_fixed_length_plain_data_page_v1(data_page);
+
+        // optional group s { optional int32 a; }, the level 0 is a null struct and the level 1
+        // is a null field.
+        let definition_levels: [u32; 4] = [2, 0, 1, 2];
+        let page = [7i32, 8].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            8,
+            8,
+            None,
+            DataPageHeader::new(4, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+        let data_page: FixedLengthPlainDataPageReaderV1<i32> =
+            FixedLengthPlainDataPageReaderV1::new_with_loaded_definition_levels(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i32>(),
+                2,
+                &definition_levels,
+                None,
+            )
+            .unwrap();
+        assert_eq!(data_page.get_value_with_def_level(0), (Some(7), 2));
+        assert_eq!(data_page.get_value_with_def_level(1), (None, 0));
+        assert_eq!(data_page.get_value_with_def_level(2), (None, 1));
+        assert_eq!(data_page.get_value_with_def_level(3), (Some(8), 2));
+        destroy_fixed_length_plain_data_page_v1(data_page);
+
+        // The page without nulls has the max definition level everywhere.
+        let mut buf = DirectByteBuffer::from_vec(
+            [7i32, 8, 9, 10]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect(),
+        );
+        buf.set_endian(LittleEndian);
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            16,
+            16,
+            None,
+            DataPageHeader::new(4, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let data_page: FixedLengthPlainDataPageReaderV1<i32> =
+            FixedLengthPlainDataPageReaderV1::new_with_loaded_definition_levels(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i32>(),
+                2,
+                &[2, 2, 2, 2],
+                None,
+            )
+            .unwrap();
+        assert!(data_page.get_definition_levels().is_empty());
+        assert_eq!(data_page.get_value_with_def_level(3), (Some(10), 2));
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_float_data_page_bit_exact() {
+        let values = [
+            f32::from_bits(0x7f800001), // signaling NaN
+            f32::from_bits(0xffc00123), // negative quiet NaN with payload
+            -0.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            1.5,
+        ];
+        let page: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let page_size = page.len();
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(6, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(buf.read_f32_at(i * 4).unwrap().to_bits(), value.to_bits());
+        }
+
+        let data_page: FixedLengthPlainDataPageReaderV1<f32> =
+            FixedLengthPlainDataPageReaderV1::new(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<f32>(),
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, values.len());
+        let res = data_page.read(
+            RowRange::new(0, values.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        for (i, value) in values.iter().enumerate() {
+            let (valid, result) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(result.to_bits(), value.to_bits());
+        }
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_nullable_double_data_page_bit_exact() {
+        // The non-null values are copied when expanding the nulls, which must keep the bits.
+        let values = [
+            Some(f64::from_bits(0x7ff0000000000001)), // signaling NaN
+            None,
+            Some(-0.0),
+            Some(f64::INFINITY),
+            None,
+            Some(f64::from_bits(0xfff8000000000abc)), // negative quiet NaN with payload
+            Some(f64::NEG_INFINITY),
+            None,
+        ];
+        let definition_levels: Vec<u32> = values.iter().map(|v| v.is_some() as u32).collect();
+
+        let mut page = encode_levels(&definition_levels, 1);
+        page.extend(values.iter().flatten().flat_map(|v| v.to_le_bytes()));
+        let page_size = page.len();
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(
+                values.len() as i32,
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        let data_page: FixedLengthPlainDataPageReaderV1<f64> =
+            FixedLengthPlainDataPageReaderV1::new_with_definition_levels(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<f64>(),
+                1,
+                None,
+            )
+            .unwrap();
+        assert_eq!(buf.get_rpos(), page_size);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, values.len());
+        let res = data_page.read(
+            RowRange::new(0, values.len()),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        for (i, value) in values.iter().enumerate() {
+            let (valid, result) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, value.is_some());
+            if let Some(value) = value {
+                assert_eq!(result.to_bits(), value.to_bits());
+            }
+        }
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_int96_data_page() {
+        let values = [
+            Int96::new(2440588, 0),
+            Int96::new(2458850, 123456789),
+            Int96::new(2440587, 86399 * 1_000_000_000),
+        ];
+        let page: Vec<u8> = values.iter().flat_map(|v| v.0).collect();
+        let page_size = page.len();
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page_size as i32,
+            page_size as i32,
+            None,
+            DataPageHeader::new(3, Encoding::PLAIN, Encoding::RLE, Encoding::RLE, None),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+
+        let data_page: FixedLengthPlainDataPageReaderV1<Int96> =
+            FixedLengthPlainDataPageReaderV1::new(
+                &page_header,
+                &mut buf,
+                0,
+                Int96::TYPE_SIZE,
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(data_page.get_data_page_type_size(), 12);
+        assert_eq!(data_page.get_data_page_num_values(), 3);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, values.len());
+        let res = data_page.read(
+            RowRange::new(0, 3),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let expected = [0, 1_577_836_800_123_456_789, -1_000_000_000];
+        for (i, expected) in expected.iter().enumerate() {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(value, values[i]);
+            assert_eq!(value.to_unix_nanos(), *expected);
+        }
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_range_fast_path() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV1<i64>, _>, _) =
+            load_non_null_plain_data_page(100, path);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        let num_values = data_page.get_data_page_num_values();
+
+        // Read the page in the ranges of different lengths, and compare with the values read
+        // one by one.
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, num_values);
+        let mut begin = 100;
+        let mut length = 1;
+        while begin < 100 + num_values {
+            let end = (begin + length).min(100 + num_values);
+            let res = data_page.read(
+                RowRange::new(begin, end),
+                0,
+                &mut result_row_range_set,
+                &mut raw_bridge,
+            );
+            assert!(res.is_ok());
+            begin = end;
+            length = length * 3 + 1;
+        }
+        assert_eq!(raw_bridge.get_size(), num_values);
+        for i in 0..num_values {
+            assert_eq!(
+                raw_bridge
+                    .get_validity_and_value(0, 100 + i, &result_row_range_set)
+                    .unwrap(),
+                (true, data_page.get_value(i))
+            );
+        }
+
+        // The ranges outside of the page are rejected before reading.
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, num_values);
+        let res = data_page.read(
+            RowRange::new(num_values - 5, num_values + 5),
+            100,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            format!(
+                "Fixed Length Data Page Error: Reading range: [{}, {}) with offset: 100 is out of the data page: [100, {})\n",
+                num_values - 5,
+                num_values + 5,
+                num_values + 100
+            )
+        );
+        let res = data_page.read(
+            RowRange::new(0, 5),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_err());
+        assert_eq!(raw_bridge.get_size(), 0);
+        assert!(result_row_range_set.get_row_ranges().is_empty());
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
+
+    #[test]
+    fn test_read_range_with_overflowed_offset() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV1<i64>, _>, _) =
+            load_non_null_plain_data_page(100, path);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        // The wrapped range [100, 110) would be inside of the page.
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 10);
+        for to_read in [RowRange::new(101, 111), RowRange { begin: 101, end: 0 }] {
+            let res = data_page.read(
+                to_read,
+                usize::MAX,
+                &mut result_row_range_set,
+                &mut raw_bridge,
+            );
+            assert!(res.is_err());
+            assert!(res
+                .err()
+                .unwrap()
+                .to_string()
+                .starts_with("Fixed Length Data Page Error: Reading range: [101, "));
+        }
+        assert_eq!(raw_bridge.get_size(), 0);
+        assert!(result_row_range_set.get_row_ranges().is_empty());
+
+        destroy_fixed_length_plain_data_page_v1(data_page);
+    }
 }
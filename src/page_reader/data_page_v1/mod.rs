@@ -13,5 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod boolean_plain_data_page_v1;
+pub mod byte_stream_split_data_page_v1;
 pub mod data_page_base;
+pub mod delta_binary_packed_data_page_v1;
+pub mod delta_byte_array_data_page_v1;
+pub mod delta_length_byte_array_data_page_v1;
+pub mod dictionary_data_page_v1;
+pub mod fixed_len_byte_array_data_page_v1;
 pub mod fixed_length_plain_data_page_v1;
+pub mod variable_length_plain_data_page_v1;
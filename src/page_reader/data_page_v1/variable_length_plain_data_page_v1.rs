@@ -0,0 +1,509 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+// BYTE_ARRAY values are not fixed width, the type size is reported as 0.
+const VARIABLE_LENGTH_TYPE_SIZE: usize = 0;
+
+/// The Parquet Variable Length Plain Page Reader V1 Struct
+/// current_offset: the offset in the whole column
+///
+/// Each PLAIN encoded BYTE_ARRAY value is stored as a 4-byte little-endian length followed by the
+/// bytes of the value. The values are returned as slices borrowing from the DirectByteBuffer.
+pub struct VariableLengthPlainDataPageReaderV1<'a> {
+    has_null: bool,
+    num_values: usize,
+    current_offset: usize,
+    data_size: usize,
+    validity: Option<Vec<bool>>,
+    data: Vec<&'a [u8]>,
+}
+
+#[allow(dead_code)]
+impl<'a> std::fmt::Display for VariableLengthPlainDataPageReaderV1<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(|value| String::from_utf8_lossy(value).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Variable Length Plain Data Page: has_null {}, num_values {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl<'a> DataPage<&'a [u8]> for VariableLengthPlainDataPageReaderV1<'a> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        VARIABLE_LENGTH_TYPE_SIZE
+    }
+}
+
+impl<'a> VariableLengthPlainDataPageReaderV1<'a> {
+    /// The value data is read starting from the current rpos of the buffer. The buffer is
+    /// borrowed by the returned values, so the rpos is not moved. Use get_data_size() to skip the
+    /// page data.
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &'a DirectByteBuffer,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+    ) -> Result<VariableLengthPlainDataPageReaderV1<'a>, BoltReaderError> {
+        Self::new_with_options(
+            page_header,
+            buffer,
+            current_offset,
+            has_null,
+            validity,
+            false,
+        )
+    }
+
+    /// With validate_utf8, every non-null value is checked once on decode, e.g. for the STRING
+    /// logical type. The index of the InvalidUtf8 error is the value index in the column.
+    pub fn new_with_options(
+        page_header: &PageHeader,
+        buffer: &'a DirectByteBuffer,
+        current_offset: usize,
+        has_null: bool,
+        validity: Option<Vec<bool>>,
+        validate_utf8: bool,
+    ) -> Result<VariableLengthPlainDataPageReaderV1<'a>, BoltReaderError> {
+        let header = match &page_header.data_page_header {
+            Some(data_page_v1) => data_page_v1,
+            None => {
+                return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                    "Error when reading Data Page V1 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::PLAIN {
+            return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                "Plain Data Page Encoding should be PLAIN",
+            )));
+        }
+
+        let bytes = &buffer.as_bytes()[buffer.get_rpos()..];
+        let mut data: Vec<&'a [u8]> = Vec::with_capacity(num_values);
+        let mut pos = 0;
+
+        let validity_slice = match &validity {
+            Some(validity) if has_null => Some(&validity[..]),
+            _ => None,
+        };
+
+        for i in 0..num_values {
+            if let Some(validity) = validity_slice {
+                if !validity[i] {
+                    data.push(&[]);
+                    continue;
+                }
+            }
+
+            if unlikely(pos + 4 > bytes.len()) {
+                return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                    "Unable to read the length of value: {}, remaining bytes: {}",
+                    i,
+                    bytes.len() - pos
+                )));
+            }
+            let length =
+                u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+                    as usize;
+            pos += 4;
+
+            if unlikely(pos + length > bytes.len()) {
+                return Err(BoltReaderError::VariableLengthDataPageError(format!(
+                    "Value: {} with length: {} is out of the buffer, remaining bytes: {}",
+                    i,
+                    length,
+                    bytes.len() - pos
+                )));
+            }
+            let value = &bytes[pos..pos + length];
+            if validate_utf8 {
+                if let Err(e) = std::str::from_utf8(value) {
+                    return Err(BoltReaderError::InvalidUtf8 {
+                        index: current_offset + i,
+                        valid_up_to: e.valid_up_to(),
+                    });
+                }
+            }
+            data.push(value);
+            pos += length;
+        }
+
+        Ok(VariableLengthPlainDataPageReaderV1 {
+            has_null,
+            num_values,
+            current_offset,
+            data_size: pos,
+            validity,
+            data,
+        })
+    }
+
+    /// The number of bytes of the PLAIN encoded value data.
+    pub fn get_data_size(&self) -> usize {
+        self.data_size
+    }
+
+    /// The value slices are located on construction, so the random access is O(1) without
+    /// scanning the lengths of the previous values.
+    pub fn get_value(&self, index: usize) -> Option<&'a [u8]> {
+        match &self.validity {
+            Some(validity) if self.has_null && !validity[index] => None,
+            _ => Some(self.data[index]),
+        }
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<&'a [u8]>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            Some(validity) if self.has_null => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+            _ => result_bridge.append_non_null_results(&self.data[start..end])?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v1::variable_length_plain_data_page_v1::VariableLengthPlainDataPageReaderV1;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    // The l_comment column chunk in lineitem.parquet, which has a single PLAIN data page
+    const COMMENT_COLUMN_CHUNK_OFFSET: usize = 1530515;
+    const COMMENT_COLUMN_CHUNK_SIZE: usize = 341194;
+    const COMMENT_DATA_PAGE_SIZE: usize = 341113;
+
+    fn load_comment_page_buffer() -> DirectByteBuffer {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(
+            &file,
+            COMMENT_COLUMN_CHUNK_OFFSET,
+            COMMENT_COLUMN_CHUNK_SIZE,
+        );
+        assert!(res.is_ok());
+        res.unwrap()
+    }
+
+    fn create_data_page_header(num_values: usize) -> PageHeader {
+        PageHeader::new(
+            PageType::DATA_PAGE,
+            0,
+            0,
+            None,
+            DataPageHeader::new(
+                num_values as i32,
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn encode_plain_byte_array(values: &[&str]) -> Vec<u8> {
+        let mut vec = vec![];
+        for value in values {
+            vec.extend((value.len() as u32).to_le_bytes());
+            vec.extend(value.as_bytes());
+        }
+        vec
+    }
+
+    #[test]
+    fn test_read_string_column() {
+        let mut buf = load_comment_page_buffer();
+        let page_header = read_page_header(&mut buf);
+        assert!(page_header.is_ok());
+        let page_header = page_header.unwrap();
+        buf.set_rpos(buf.get_rpos() + 8);
+
+        let data_page =
+            VariableLengthPlainDataPageReaderV1::new(&page_header, &buf, 0, false, None);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        assert_eq!(data_page.get_data_page_num_values(), 11212);
+        assert_eq!(data_page.get_data_page_type_size(), 0);
+        assert!(!data_page.data_page_has_null());
+        assert_eq!(data_page.get_data_size() + 8, COMMENT_DATA_PAGE_SIZE);
+        assert_eq!(buf.get_rpos() + data_page.get_data_size(), buf.len());
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(false, 1024);
+        let res = data_page.read(
+            RowRange::new(0, 4),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let expected = [
+            "orbits: blith",
+            "nt, ironic re",
+            "regular requests nag",
+            "ular realms. slyly regular requests detect",
+        ];
+        for (i, &value) in expected.iter().enumerate() {
+            let (valid, bytes) = raw_bridge
+                .get_validity_and_value(0, i, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(std::str::from_utf8(bytes).unwrap(), value);
+        }
+
+        for i in 0..data_page.get_data_page_num_values() {
+            assert!(std::str::from_utf8(data_page.get_value(i).unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_random_access() {
+        let mut buf = load_comment_page_buffer();
+        let page_header = read_page_header(&mut buf).unwrap();
+        buf.set_rpos(buf.get_rpos() + 8);
+
+        let data_page =
+            VariableLengthPlainDataPageReaderV1::new(&page_header, &buf, 0, false, None).unwrap();
+
+        // Scan the length prefixed values from the start of the page.
+        let bytes = &buf.as_bytes()[buf.get_rpos()..];
+        let mut scanned: Vec<&[u8]> = Vec::new();
+        let mut pos = 0;
+        while scanned.len() < data_page.get_data_page_num_values() {
+            let length = u32::from_le_bytes(bytes[pos..pos + 4].try_into().unwrap()) as usize;
+            scanned.push(&bytes[pos + 4..pos + 4 + length]);
+            pos += 4 + length;
+        }
+        assert_eq!(pos, data_page.get_data_size());
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let i = rng.gen_range(0..scanned.len());
+            assert_eq!(data_page.get_value(i).unwrap(), scanned[i]);
+        }
+        assert_eq!(data_page.get_value(11211).unwrap(), scanned[11211]);
+        assert_eq!(data_page.get_value(0).unwrap(), b"orbits: blith");
+    }
+
+    #[test]
+    fn test_read_utf8_values_with_nulls() {
+        let values = ["héllo", "", "wörld", "数据"];
+        let buf = DirectByteBuffer::from_vec(encode_plain_byte_array(&values));
+        let validity = vec![true, false, true, true, false, true];
+
+        let page_header = create_data_page_header(validity.len());
+        let data_page = VariableLengthPlainDataPageReaderV1::new(
+            &page_header,
+            &buf,
+            10,
+            true,
+            Option::Some(validity.clone()),
+        );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        assert_eq!(data_page.get_data_size(), buf.len());
+        assert_eq!(data_page.to_string(), "Variable Length Plain Data Page: has_null true, num_values 6, current_offset 10\nData: héllo, , , wörld, , 数据 ...\nValidity: true, false, true, true, false, true ...\n");
+
+        let mut result_row_range_set = RowRangeSet::new(10);
+        let mut raw_bridge = RawBridge::new(true, 1024);
+        let res = data_page.read(
+            RowRange::new(0, 6),
+            10,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+
+        let expected = [
+            Some("héllo"),
+            None,
+            Some(""),
+            Some("wörld"),
+            None,
+            Some("数据"),
+        ];
+        for (i, expected) in expected.iter().enumerate() {
+            let (valid, bytes) = raw_bridge
+                .get_validity_and_value(10, i, &result_row_range_set)
+                .unwrap();
+            match expected {
+                None => assert!(!valid),
+                Some(value) => {
+                    assert!(valid);
+                    assert_eq!(std::str::from_utf8(bytes).unwrap(), *value);
+                }
+            }
+            assert_eq!(
+                data_page
+                    .get_value(i)
+                    .map(|v| std::str::from_utf8(v).unwrap()),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_utf8() {
+        let values = ["héllo", "", "wörld", "数据"];
+        let buf = DirectByteBuffer::from_vec(encode_plain_byte_array(&values));
+        let validity = vec![true, false, true, true, false, true];
+        let page_header = create_data_page_header(validity.len());
+
+        let res = VariableLengthPlainDataPageReaderV1::new_with_options(
+            &page_header,
+            &buf,
+            10,
+            true,
+            Some(validity.clone()),
+            true,
+        );
+        assert!(res.is_ok());
+        let data_page = res.unwrap();
+        assert_eq!(data_page.get_value(5).unwrap(), "数据".as_bytes());
+
+        // The third value is cut in the middle of "ö".
+        let mut bytes = vec![];
+        for value in [b"abc".as_slice(), b"", b"w\xc3", b"def"] {
+            bytes.extend((value.len() as u32).to_le_bytes());
+            bytes.extend(value);
+        }
+        let buf = DirectByteBuffer::from_vec(bytes);
+
+        let res = VariableLengthPlainDataPageReaderV1::new_with_options(
+            &page_header,
+            &buf,
+            10,
+            true,
+            Some(validity.clone()),
+            true,
+        );
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::InvalidUtf8 {
+                index: 13,
+                valid_up_to: 1
+            })
+        ));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Invalid UTF-8: value 13 has invalid bytes after 1 valid bytes\n"
+        );
+
+        // The raw bytes are returned without the validation.
+        let res =
+            VariableLengthPlainDataPageReaderV1::new(&page_header, &buf, 10, true, Some(validity));
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().get_value(3).unwrap(), b"w\xc3");
+    }
+
+    #[test]
+    fn test_read_truncated_page() {
+        let mut vec = encode_plain_byte_array(&["foo", "bar"]);
+        vec.pop();
+        let buf = DirectByteBuffer::from_vec(vec);
+
+        let page_header = create_data_page_header(2);
+        let data_page =
+            VariableLengthPlainDataPageReaderV1::new(&page_header, &buf, 0, false, None);
+        assert!(data_page.is_err());
+        assert_eq!(
+            data_page.err().unwrap().to_string(),
+            "Variable Length Data Page Error: Value: 1 with length: 3 is out of the buffer, remaining bytes: 2\n"
+        );
+    }
+}
@@ -0,0 +1,737 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::TypeId;
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::bridge::bridge_base::Bridge;
+use crate::convert_generic_vec;
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::{CompressionCodec, DataPageHeaderV2, PageHeader};
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::page_reader::decompression::decompress_page_into;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::rep_def_parser::RepDefParser;
+use crate::utils::row_range_set::{RowRange, RowRangeSet, RowRangeSetGenerator};
+
+// Currently, we display 10 pieces of data only
+// todo: Create config module to handle the default const values.
+const DEFAULT_DISPLAY_NUMBER: usize = 10;
+
+/// The Parquet Page Reader V2 Struct
+/// current_offset: the offset in the whole column
+///
+/// In Data Page V2, the repetition and definition levels are stored uncompressed ahead of the
+/// value data, and their byte lengths are recorded in the page header. Only the value data is
+/// compressed. The PLAIN encoded value data contains the non-null values only, so the values are
+/// spread out to their row positions when the page has nulls.
+pub struct FixedLengthPlainDataPageReaderV2<'a, T> {
+    has_null: bool,
+    num_values: usize,
+    num_nulls: usize,
+    num_rows: usize,
+    current_offset: usize,
+    type_size: usize,
+    #[allow(dead_code)]
+    zero_copy: bool,
+    filter: Option<&'a dyn FixedLengthRangeFilter>,
+    validity: Option<Vec<bool>>,
+    data: Vec<T>,
+}
+
+#[allow(dead_code)]
+impl<'a, T: ToString> std::fmt::Display for FixedLengthPlainDataPageReaderV2<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_str = self
+            .data
+            .iter()
+            .take(DEFAULT_DISPLAY_NUMBER)
+            .map(T::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let validity_str = match &self.validity {
+            None => "true, ".repeat(DEFAULT_DISPLAY_NUMBER - 1) + "true",
+            Some(validity) => validity
+                .iter()
+                .take(DEFAULT_DISPLAY_NUMBER)
+                .map(bool::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        writeln!(
+            f,
+            "Plain Data Page V2: has_null {}, num_values {}, num_nulls {}, num_rows {}, current_offset {}\nData: {} ...\nValidity: {} ...",
+            self.has_null, self.num_values, self.num_nulls, self.num_rows, self.current_offset, data_str, validity_str
+        )
+    }
+}
+
+impl<'a, T> DataPage<T> for FixedLengthPlainDataPageReaderV2<'a, T> {
+    fn data_page_has_null(&self) -> bool {
+        self.has_null
+    }
+
+    fn get_data_page_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    fn get_data_page_offset(&self) -> usize {
+        self.current_offset
+    }
+
+    fn get_data_page_type_size(&self) -> usize {
+        self.type_size
+    }
+}
+
+impl<'a, T: 'static + std::marker::Copy + Default> FixedLengthPlainDataPageReaderV2<'a, T> {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        type_size: usize,
+        compression_codec: CompressionCodec,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+    ) -> Result<FixedLengthPlainDataPageReaderV2<'a, T>, BoltReaderError> {
+        let header = match &page_header.data_page_header_v2 {
+            Some(data_page_v2) => data_page_v2,
+            None => {
+                return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                    "Error when reading Data Page V2 Header",
+                )))
+            }
+        };
+
+        let num_values = header.num_values as usize;
+        let num_nulls = header.num_nulls as usize;
+        let num_rows = header.num_rows as usize;
+        let encoding = header.encoding;
+
+        if encoding != parquet_metadata_thrift::Encoding::PLAIN {
+            return Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "Plain Data Page Encoding should be PLAIN",
+            )));
+        }
+
+        if num_nulls > num_values {
+            return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                "Data Page V2 num_nulls: {} cannot be larger than num_values: {}",
+                num_nulls, num_values
+            )));
+        }
+
+        // Only top level columns are supported, the repetition levels are skipped.
+        let rep_length = header.repetition_levels_byte_length as usize;
+        let def_length = header.definition_levels_byte_length as usize;
+        let is_compressed = header.is_compressed.unwrap_or(true)
+            && compression_codec != CompressionCodec::UNCOMPRESSED;
+        let values_size = get_data_page_v2_values_size(page_header, header)?;
+        buffer.set_rpos(buffer.get_rpos() + rep_length);
+
+        let has_null = num_nulls > 0;
+        let validity = if has_null {
+            let levels = RepDefParser::load_level_with_length(buffer, 1, def_length)?;
+            if levels.len() < num_values {
+                return Err(BoltReaderError::FixedLengthDataPageError(format!(
+                    "Data Page V2 definition levels: {} are fewer than num_values: {}",
+                    levels.len(),
+                    num_values
+                )));
+            }
+            Some(
+                levels
+                    .iter()
+                    .take(num_values)
+                    .map(|&x| x == 1)
+                    .collect::<Vec<bool>>(),
+            )
+        } else {
+            buffer.set_rpos(buffer.get_rpos() + def_length);
+            None
+        };
+
+        // The levels of Data Page V2 are never compressed, only the values after them are.
+        let mut decompressed_buffer;
+        let buffer: &mut dyn ByteBufferBase = if is_compressed {
+            let (compressed_size, uncompressed_size) = values_size;
+            let mut decompressed = Vec::new();
+            let rpos = buffer.get_rpos();
+            if buffer.can_create_buffer_slice(rpos, compressed_size) {
+                decompress_page_into(
+                    buffer.create_buffer_slice(rpos, compressed_size)?,
+                    compression_codec,
+                    uncompressed_size,
+                    &mut decompressed,
+                )?;
+            } else {
+                decompress_page_into(
+                    &buffer.load_bytes_to_byte_vec_deep_copy(rpos, compressed_size)?,
+                    compression_codec,
+                    uncompressed_size,
+                    &mut decompressed,
+                )?;
+            }
+            buffer.set_rpos(rpos + compressed_size);
+
+            decompressed_buffer = DirectByteBuffer::from_loaded_bytes(decompressed);
+            &mut decompressed_buffer
+        } else {
+            buffer
+        };
+
+        let data_size: usize = (num_values - num_nulls) * type_size;
+
+        let zero_copy;
+        let data: Vec<T> = if !has_null
+            && !is_compressed
+            && buffer.can_create_buffer_slice(buffer.get_rpos(), data_size)
+        {
+            zero_copy = true;
+            let res = DirectByteBuffer::convert_byte_vec(
+                buffer.load_bytes_to_byte_vec(buffer.get_rpos(), data_size)?,
+                type_size,
+            )?;
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+
+            res
+        } else {
+            zero_copy = false;
+            let non_null_data: Vec<T> = DirectByteBuffer::convert_byte_vec(
+                buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), data_size)?,
+                type_size,
+            )?;
+            buffer.set_rpos(buffer.get_rpos() + data_size);
+
+            match &validity {
+                None => non_null_data,
+                Some(validity) => {
+                    let mut data = Vec::with_capacity(num_values);
+                    let mut idx = 0;
+                    for &valid in validity {
+                        if valid {
+                            data.push(non_null_data[idx]);
+                            idx += 1;
+                        } else {
+                            data.push(T::default());
+                        }
+                    }
+                    data
+                }
+            }
+        };
+
+        Ok(FixedLengthPlainDataPageReaderV2 {
+            has_null,
+            num_values,
+            num_nulls,
+            num_rows,
+            current_offset,
+            type_size,
+            zero_copy,
+            filter,
+            validity,
+            data,
+        })
+    }
+
+    pub fn get_data_page_num_nulls(&self) -> usize {
+        self.num_nulls
+    }
+
+    pub fn get_data_page_num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn read_with_filter(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let filter = self.filter.unwrap();
+
+        if TypeId::of::<T>() == TypeId::of::<i64>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<i64>(), i64) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<i32>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<i32>(), i32) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_i32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<f64>(), f64) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_f64(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        } else if TypeId::of::<T>() == TypeId::of::<f32>() {
+            let vec = unsafe { convert_generic_vec!(&self.data[..], mem::size_of::<f32>(), f32) };
+            self.filter_values(
+                to_read,
+                offset,
+                |i| filter.check_f32(vec[i]),
+                result_row_range_set,
+                result_bridge,
+            );
+            mem::forget(vec);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the value check to every row in the range. Null rows pass the filter only if the
+    /// filter allows nulls.
+    #[inline(always)]
+    fn filter_values<F: Fn(usize) -> bool>(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        check: F,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        let null_allowed = self.filter.unwrap().get_null_allowed();
+
+        let mut generator = RowRangeSetGenerator::new(result_row_range_set);
+
+        for i in start..end {
+            let valid = match &self.validity {
+                None => true,
+                Some(validity) => validity[i],
+            };
+
+            let filter_res = if valid { check(i) } else { null_allowed };
+            generator.update(i + self.current_offset - offset, filter_res);
+            if filter_res {
+                result_bridge.append_result(valid, self.data[i]);
+            }
+        }
+        generator.finish(end + self.current_offset - offset);
+    }
+
+    pub fn read(
+        &self,
+        to_read: RowRange,
+        offset: usize,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let start = to_read.begin + offset - self.current_offset;
+        let end = to_read.end + offset - self.current_offset;
+        result_row_range_set.add_row_ranges(
+            to_read.begin + offset - result_row_range_set.get_offset(),
+            to_read.end + offset - result_row_range_set.get_offset(),
+        );
+
+        match &self.validity {
+            None => result_bridge.append_non_null_results(&self.data[start..end])?,
+            Some(validity) => {
+                result_bridge.append_results(&validity[start..end], &self.data[start..end])?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Return the compressed and uncompressed sizes of the values after the levels, which are never
+/// compressed in Data Page V2.
+pub fn get_data_page_v2_values_size(
+    page_header: &PageHeader,
+    header: &DataPageHeaderV2,
+) -> Result<(usize, usize), BoltReaderError> {
+    let levels_length =
+        header.repetition_levels_byte_length as i64 + header.definition_levels_byte_length as i64;
+    let compressed_size = page_header.compressed_page_size as i64 - levels_length;
+    let uncompressed_size = page_header.uncompressed_page_size as i64 - levels_length;
+    if header.repetition_levels_byte_length < 0
+        || header.definition_levels_byte_length < 0
+        || compressed_size < 0
+        || uncompressed_size < 0
+    {
+        return Err(BoltReaderError::FixedLengthDataPageError(format!(
+            "Data Page V2 levels size: {} bytes exceeds the page size",
+            levels_length
+        )));
+    }
+
+    Ok((compressed_size as usize, uncompressed_size as usize))
+}
+
+#[allow(dead_code)]
+#[inline(always)]
+pub fn destroy_fixed_length_plain_data_page_v2<T>(data_page: FixedLengthPlainDataPageReaderV2<T>) {
+    if data_page.zero_copy {
+        mem::forget(data_page.data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::{CompressionCodec, PageType};
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::data_page_v2::fixed_length_plain_data_page_v2::{
+        destroy_fixed_length_plain_data_page_v2, FixedLengthPlainDataPageReaderV2,
+    };
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::file_streaming_byte_buffer::{FileStreamingBuffer, StreamingByteBuffer};
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+
+    // The sample file has a single nullable INT64 column, written in one Data Page V2.
+    // The value of row i is i * 10, and every row with i % 7 == 3 is null.
+    const DATA_PAGE_V2_PATH: &str = "src/sample_files/data_page_v2.parquet";
+
+    fn expected_value(row: usize) -> Option<i64> {
+        if row % 7 == 3 {
+            None
+        } else {
+            Some(row as i64 * 10)
+        }
+    }
+
+    fn load_plain_data_page_v2<'a, T: 'static + std::marker::Copy + Default>(
+        data_page_offset: usize,
+        filter: Option<&'a (dyn FixedLengthRangeFilter + 'a)>,
+    ) -> (
+        Result<FixedLengthPlainDataPageReaderV2<'a, T>, BoltReaderError>,
+        DirectByteBuffer,
+    ) {
+        let path = String::from(DATA_PAGE_V2_PATH);
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let page_header = read_page_header(&mut buf);
+        assert!(page_header.is_ok());
+        let page_header = page_header.unwrap();
+        assert_eq!(page_header.type_, PageType::DATA_PAGE_V2);
+
+        (
+            FixedLengthPlainDataPageReaderV2::new(
+                &page_header,
+                &mut buf,
+                data_page_offset,
+                mem::size_of::<T>(),
+                CompressionCodec::UNCOMPRESSED,
+                filter,
+            ),
+            buf,
+        )
+    }
+
+    #[test]
+    fn test_create_fixed_length_plain_data_page_v2() {
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV2<i64>, _>, _) =
+            load_plain_data_page_v2(100, None);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+
+        assert!(data_page.data_page_has_null());
+        assert_eq!(data_page.get_data_page_num_values(), 1000);
+        assert_eq!(data_page.get_data_page_num_nulls(), 143);
+        assert_eq!(data_page.get_data_page_num_rows(), 1000);
+        assert_eq!(data_page.to_string(), "Plain Data Page V2: has_null true, num_values 1000, num_nulls 143, num_rows 1000, current_offset 100\nData: 0, 10, 20, 0, 40, 50, 60, 70, 80, 90 ...\nValidity: true, true, true, false, true, true, true, true, true, true ...\n");
+
+        destroy_fixed_length_plain_data_page_v2(data_page);
+    }
+
+    #[test]
+    fn test_read_data_page_v2() {
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV2<i64>, _>, _) =
+            load_plain_data_page_v2(100, None);
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        let to_read = RowRange::new(50, 1100);
+        let offset = 50;
+        let capacity = 1024;
+
+        let to_read = data_page.get_data_page_covered_range(
+            data_page.get_data_page_offset(),
+            data_page.get_data_page_offset() + data_page.get_data_page_num_values(),
+            offset,
+            &to_read,
+        );
+        assert!(to_read.is_ok());
+        let to_read = to_read.unwrap();
+        assert!(to_read.is_some());
+        let to_read = to_read.unwrap();
+        assert_eq!(to_read.end, 1050);
+
+        let mut result_row_range_set = RowRangeSet::new(offset);
+        let mut raw_bridge = RawBridge::new(true, capacity);
+        let res = data_page.read(to_read, offset, &mut result_row_range_set, &mut raw_bridge);
+        assert!(res.is_ok());
+        assert_eq!(raw_bridge.get_size(), 1000);
+
+        for row in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(offset, row + 50, &result_row_range_set)
+                .unwrap();
+            match expected_value(row) {
+                None => assert!(!valid),
+                Some(expected) => {
+                    assert!(valid);
+                    assert_eq!(value, expected);
+                }
+            }
+        }
+
+        destroy_fixed_length_plain_data_page_v2(data_page);
+    }
+
+    #[test]
+    fn test_read_data_page_v2_with_filter() {
+        let filter = IntegerRangeFilter::new(100, 5000, false);
+        let (data_page, _buffer): (Result<FixedLengthPlainDataPageReaderV2<i64>, _>, _) =
+            load_plain_data_page_v2(100, Option::Some(&filter));
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        let to_read = RowRange::new(50, 1050);
+        let offset = 50;
+        let capacity = 1024;
+
+        let mut result_row_range_set = RowRangeSet::new(offset);
+        let mut raw_bridge = RawBridge::new(true, capacity);
+        let res =
+            data_page.read_with_filter(to_read, offset, &mut result_row_range_set, &mut raw_bridge);
+        assert!(res.is_ok());
+
+        let expected_rows: Vec<usize> = (0..1000)
+            .filter(|&row| match expected_value(row) {
+                None => false,
+                Some(value) => filter.check_i64(value),
+            })
+            .collect();
+        assert_eq!(raw_bridge.get_size(), expected_rows.len());
+
+        for row in expected_rows {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(offset, row + 50, &result_row_range_set)
+                .unwrap();
+            assert!(valid);
+            assert_eq!(value, expected_value(row).unwrap());
+        }
+
+        destroy_fixed_length_plain_data_page_v2(data_page);
+    }
+
+    #[test]
+    fn test_read_data_page_v2_in_streaming_buffer() {
+        let path = String::from(DATA_PAGE_V2_PATH);
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+
+        for i in 0..16 {
+            let buffer_size = 1 << i;
+            let res =
+                StreamingByteBuffer::from_file(&file, 4, file.get_file_size() - 4, buffer_size);
+            assert!(res.is_ok());
+            let mut buf = res.unwrap();
+            let page_header = read_page_header(&mut buf);
+            assert!(page_header.is_ok());
+            let page_header = page_header.unwrap();
+
+            let data_page: Result<FixedLengthPlainDataPageReaderV2<i64>, _> =
+                FixedLengthPlainDataPageReaderV2::new(
+                    &page_header,
+                    &mut buf,
+                    0,
+                    mem::size_of::<i64>(),
+                    CompressionCodec::UNCOMPRESSED,
+                    None,
+                );
+            assert!(data_page.is_ok());
+            let data_page = data_page.unwrap();
+
+            let mut result_row_range_set = RowRangeSet::new(0);
+            let mut raw_bridge = RawBridge::new(true, 1024);
+            let res = data_page.read(
+                RowRange::new(0, 1000),
+                0,
+                &mut result_row_range_set,
+                &mut raw_bridge,
+            );
+            assert!(res.is_ok());
+
+            for row in 0..1000 {
+                let (valid, value) = raw_bridge
+                    .get_validity_and_value(0, row, &result_row_range_set)
+                    .unwrap();
+                assert_eq!(valid, expected_value(row).is_some());
+                if valid {
+                    assert_eq!(value, expected_value(row).unwrap());
+                }
+            }
+
+            destroy_fixed_length_plain_data_page_v2(data_page);
+        }
+    }
+
+    #[test]
+    fn test_read_data_page_v2_compressed_not_supported() {
+        let path = String::from(DATA_PAGE_V2_PATH);
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let page_header = read_page_header(&mut buf).unwrap();
+
+        let data_page: Result<FixedLengthPlainDataPageReaderV2<i64>, _> =
+            FixedLengthPlainDataPageReaderV2::new(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i64>(),
+                CompressionCodec::LZO,
+                None,
+            );
+        assert!(data_page.is_err());
+    }
+
+    #[test]
+    fn test_read_snappy_compressed_data_page_v2() {
+        let path = String::from(DATA_PAGE_V2_PATH);
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let mut page_header = read_page_header(&mut buf).unwrap();
+
+        // Compress the values of the sample page, the levels stay uncompressed.
+        let header = page_header.data_page_header_v2.as_mut().unwrap();
+        header.is_compressed = Some(true);
+        let levels_length =
+            (header.repetition_levels_byte_length + header.definition_levels_byte_length) as usize;
+        let body_begin = buf.get_rpos();
+        let body =
+            &buf.as_bytes()[body_begin..body_begin + page_header.compressed_page_size as usize];
+        let compressed_values = snap::raw::Encoder::new()
+            .compress_vec(&body[levels_length..])
+            .unwrap();
+        assert!(compressed_values.len() < body.len() - levels_length);
+
+        let mut page = body[..levels_length].to_vec();
+        page.extend_from_slice(&compressed_values);
+        page_header.compressed_page_size = page.len() as i32;
+        // The bytes after the page should not be read.
+        page.push(0xff);
+        let mut page_buf = DirectByteBuffer::from_loaded_bytes(page);
+
+        let data_page: Result<FixedLengthPlainDataPageReaderV2<i64>, _> =
+            FixedLengthPlainDataPageReaderV2::new(
+                &page_header,
+                &mut page_buf,
+                0,
+                mem::size_of::<i64>(),
+                CompressionCodec::SNAPPY,
+                None,
+            );
+        assert!(data_page.is_ok());
+        let data_page = data_page.unwrap();
+        assert_eq!(page_buf.get_rpos(), page_buf.len() - 1);
+
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, 1024);
+        let res = data_page.read(
+            RowRange::new(0, 1000),
+            0,
+            &mut result_row_range_set,
+            &mut raw_bridge,
+        );
+        assert!(res.is_ok());
+        assert_eq!(raw_bridge.get_size(), 1000);
+
+        for row in 0..1000 {
+            let (valid, value) = raw_bridge
+                .get_validity_and_value(0, row, &result_row_range_set)
+                .unwrap();
+            assert_eq!(valid, expected_value(row).is_some());
+            if valid {
+                assert_eq!(value, expected_value(row).unwrap());
+            }
+        }
+
+        destroy_fixed_length_plain_data_page_v2(data_page);
+    }
+
+    #[test]
+    fn test_create_data_page_v2_from_data_page_v1() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 0, file.get_file_size());
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+        let page_header = read_page_header(&mut buf).unwrap();
+
+        let data_page: Result<FixedLengthPlainDataPageReaderV2<i64>, _> =
+            FixedLengthPlainDataPageReaderV2::new(
+                &page_header,
+                &mut buf,
+                0,
+                mem::size_of::<i64>(),
+                CompressionCodec::UNCOMPRESSED,
+                None,
+            );
+        assert!(data_page.is_err());
+        assert_eq!(
+            data_page.err().unwrap().to_string(),
+            "Fixed Length Data Page Error: Error when reading Data Page V2 Header\n"
+        );
+    }
+}
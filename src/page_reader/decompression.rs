@@ -0,0 +1,713 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::sync::Arc;
+
+use flate2::read::MultiGzDecoder;
+
+use crate::metadata::compression::Compression;
+use crate::metadata::parquet_metadata_thrift::CompressionCodec;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+const LZ4_FRAME_MAGIC: [u8; 4] = [0x04, 0x22, 0x4D, 0x18];
+
+// The same as the max capacity of the DirectByteBuffer.
+const MAX_UNCOMPRESSED_PAGE_SIZE: usize = 1 << 30;
+
+/// Decompress the page body of a compression codec.
+///
+/// The decompressed bytes are appended to the output, the capacity of uncompressed_size is
+/// reserved by the caller. The decompressed length is checked by decompress_page_into.
+pub trait Decompressor: Send + Sync {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError>;
+}
+
+pub struct UncompressedDecompressor;
+
+impl Decompressor for UncompressedDecompressor {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        _uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        output.extend_from_slice(compressed);
+        Ok(())
+    }
+}
+
+pub struct SnappyDecompressor;
+
+impl Decompressor for SnappyDecompressor {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        decompress_snappy(compressed, uncompressed_size, output)
+    }
+}
+
+pub struct GzipDecompressor;
+
+impl Decompressor for GzipDecompressor {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        decompress_gzip(compressed, uncompressed_size, output)
+    }
+}
+
+pub struct ZstdDecompressor;
+
+impl Decompressor for ZstdDecompressor {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        decompress_zstd(compressed, uncompressed_size, output)
+    }
+}
+
+pub struct Lz4RawDecompressor;
+
+impl Decompressor for Lz4RawDecompressor {
+    fn decompress(
+        &self,
+        compressed: &[u8],
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        decompress_lz4_raw(compressed, uncompressed_size, output)
+    }
+}
+
+/// The decompressors of a reader, i.e. the custom decompressors registered for some compressions
+/// and the default decompressors of the other codecs. By default, no custom decompressor is
+/// registered. There are only a few codecs, so a Vec is used instead of a map.
+#[derive(Clone, Default)]
+pub struct Decompressors {
+    custom_decompressors: Vec<(Compression, Arc<dyn Decompressor>)>,
+}
+
+impl Decompressors {
+    /// Register a custom decompressor for the compression, it replaces the default decompressor
+    /// and the one registered before. The UNCOMPRESSED pages are read in place by the column
+    /// chunk readers, so a decompressor cannot be registered for them.
+    pub fn register(
+        &mut self,
+        compression: Compression,
+        decompressor: Arc<dyn Decompressor>,
+    ) -> Result<(), BoltReaderError> {
+        if unlikely(compression == Compression::Uncompressed) {
+            return Err(BoltReaderError::ReaderConfigError(String::from(
+                "A decompressor cannot be registered for the UNCOMPRESSED pages",
+            )));
+        }
+
+        self.unregister(compression);
+        self.custom_decompressors.push((compression, decompressor));
+        Ok(())
+    }
+
+    /// Remove the custom decompressor of the compression, the default decompressor is used again.
+    /// Return the removed decompressor.
+    pub fn unregister(&mut self, compression: Compression) -> Option<Arc<dyn Decompressor>> {
+        let index = self
+            .custom_decompressors
+            .iter()
+            .position(|(registered, _)| *registered == compression)?;
+        Some(self.custom_decompressors.remove(index).1)
+    }
+
+    fn get_decompressor(
+        &self,
+        codec: CompressionCodec,
+    ) -> Result<&dyn Decompressor, BoltReaderError> {
+        let compression = Compression::from(codec);
+        match self
+            .custom_decompressors
+            .iter()
+            .find(|(registered, _)| *registered == compression)
+        {
+            Some((_, decompressor)) => Ok(decompressor.as_ref()),
+            None => get_default_decompressor(codec),
+        }
+    }
+
+    /// Decompress the page body into a freshly allocated little-endian DirectByteBuffer.
+    ///
+    /// The compression codec is stored in the column chunk metadata, while uncompressed_size is
+    /// the uncompressed_page_size from the page header. The decompressed length must match it.
+    pub fn decompress_page(
+        &self,
+        compressed: &[u8],
+        codec: CompressionCodec,
+        uncompressed_size: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        let mut decompressed = Vec::new();
+        self.decompress_page_into(compressed, codec, uncompressed_size, &mut decompressed)?;
+        Ok(DirectByteBuffer::from_loaded_bytes(decompressed))
+    }
+
+    /// Decompress the page body and append it to the output, e.g. a PooledBuffer, so the
+    /// allocation of the output is reused when it has enough capacity.
+    ///
+    /// The decompressor registered for the codec is used if any, otherwise the default one.
+    pub fn decompress_page_into(
+        &self,
+        compressed: &[u8],
+        codec: CompressionCodec,
+        uncompressed_size: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BoltReaderError> {
+        let begin = output.len();
+        if unlikely(uncompressed_size > MAX_UNCOMPRESSED_PAGE_SIZE) {
+            return Err(BoltReaderError::InsufficientMemoryError(format!(
+                "Uncompressed page size: {} bytes is over the max direct buffer capacity: {} bytes",
+                uncompressed_size, MAX_UNCOMPRESSED_PAGE_SIZE
+            )));
+        }
+        output.try_reserve(uncompressed_size).map_err(|e| {
+            BoltReaderError::InsufficientMemoryError(format!(
+                "Unable to allocate {} bytes for the decompressed page: {}",
+                uncompressed_size, e
+            ))
+        })?;
+
+        self.get_decompressor(codec)?
+            .decompress(compressed, uncompressed_size, output)?;
+
+        if unlikely(output.len() - begin != uncompressed_size) {
+            return Err(BoltReaderError::DecompressionError(format!(
+                "Decompressed page size: {} does not match the uncompressed page size: {}",
+                output.len() - begin,
+                uncompressed_size
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn get_default_decompressor(
+    codec: CompressionCodec,
+) -> Result<&'static dyn Decompressor, BoltReaderError> {
+    match codec {
+        CompressionCodec::UNCOMPRESSED => Ok(&UncompressedDecompressor),
+        CompressionCodec::SNAPPY => Ok(&SnappyDecompressor),
+        CompressionCodec::GZIP => Ok(&GzipDecompressor),
+        CompressionCodec::ZSTD => Ok(&ZstdDecompressor),
+        CompressionCodec::LZ4_RAW => Ok(&Lz4RawDecompressor),
+        CompressionCodec::LZ4 => Err(BoltReaderError::DecompressionError(String::from(
+            "The deprecated LZ4 codec is not supported, only LZ4_RAW is supported",
+        ))),
+        _ => Err(BoltReaderError::NotYetImplementedError(format!(
+            "Compression codec: {} is not supported yet",
+            codec.0
+        ))),
+    }
+}
+
+/// Decompress the page body into a freshly allocated little-endian DirectByteBuffer, with the
+/// default decompressors, see Decompressors::decompress_page.
+pub fn decompress_page(
+    compressed: &[u8],
+    codec: CompressionCodec,
+    uncompressed_size: usize,
+) -> Result<DirectByteBuffer, BoltReaderError> {
+    Decompressors::default().decompress_page(compressed, codec, uncompressed_size)
+}
+
+/// Decompress the page body and append it to the output, with the default decompressors, see
+/// Decompressors::decompress_page_into.
+pub fn decompress_page_into(
+    compressed: &[u8],
+    codec: CompressionCodec,
+    uncompressed_size: usize,
+    output: &mut Vec<u8>,
+) -> Result<(), BoltReaderError> {
+    Decompressors::default().decompress_page_into(compressed, codec, uncompressed_size, output)
+}
+
+fn decompress_snappy(
+    compressed: &[u8],
+    uncompressed_size: usize,
+    output: &mut Vec<u8>,
+) -> Result<(), BoltReaderError> {
+    let decompressed_len = match snap::raw::decompress_len(compressed) {
+        Ok(len) => len,
+        Err(e) => {
+            return Err(BoltReaderError::DecompressionError(format!(
+                "Unable to read Snappy decompressed length: {}",
+                e
+            )))
+        }
+    };
+
+    if unlikely(decompressed_len != uncompressed_size) {
+        return Err(BoltReaderError::DecompressionError(format!(
+            "Snappy decompressed length: {} does not match the uncompressed page size: {}",
+            decompressed_len, uncompressed_size
+        )));
+    }
+
+    let begin = output.len();
+    output.resize(begin + uncompressed_size, 0);
+    match snap::raw::Decoder::new().decompress(compressed, &mut output[begin..]) {
+        Ok(len) => {
+            output.truncate(begin + len);
+            Ok(())
+        }
+        Err(e) => Err(BoltReaderError::DecompressionError(format!(
+            "Unable to decompress Snappy page: {}",
+            e
+        ))),
+    }
+}
+
+/// Some writers emit multi-member gzip streams, all the members are read until EOF. At most one
+/// byte more than the uncompressed size is inflated, so a gzip bomb cannot exhaust the memory,
+/// and the longer output fails the decompressed size check.
+fn decompress_gzip(
+    compressed: &[u8],
+    uncompressed_size: usize,
+    output: &mut Vec<u8>,
+) -> Result<(), BoltReaderError> {
+    match MultiGzDecoder::new(compressed)
+        .take(uncompressed_size as u64 + 1)
+        .read_to_end(output)
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(BoltReaderError::DecompressionError(format!(
+            "Unable to decompress GZIP page: {}",
+            e
+        ))),
+    }
+}
+
+fn decompress_zstd(
+    compressed: &[u8],
+    uncompressed_size: usize,
+    output: &mut Vec<u8>,
+) -> Result<(), BoltReaderError> {
+    let begin = output.len();
+    output.resize(begin + uncompressed_size, 0);
+    match zstd::bulk::decompress_to_buffer(compressed, &mut output[begin..]) {
+        Ok(len) => {
+            output.truncate(begin + len);
+            Ok(())
+        }
+        Err(e) => Err(BoltReaderError::DecompressionError(format!(
+            "Unable to decompress ZSTD page: {}",
+            e
+        ))),
+    }
+}
+
+/// LZ4_RAW pages are a single LZ4 block without the frame header, so the uncompressed size from
+/// the page header is required.
+fn decompress_lz4_raw(
+    compressed: &[u8],
+    uncompressed_size: usize,
+    output: &mut Vec<u8>,
+) -> Result<(), BoltReaderError> {
+    if unlikely(compressed.starts_with(&LZ4_FRAME_MAGIC)) {
+        return Err(BoltReaderError::DecompressionError(String::from(
+            "Found LZ4 frame format in a LZ4_RAW page",
+        )));
+    }
+
+    let begin = output.len();
+    output.resize(begin + uncompressed_size, 0);
+    match lz4_flex::block::decompress_into(compressed, &mut output[begin..]) {
+        Ok(len) => {
+            output.truncate(begin + len);
+            Ok(())
+        }
+        Err(e) => Err(BoltReaderError::DecompressionError(format!(
+            "Unable to decompress LZ4_RAW page: {}",
+            e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use crate::metadata::compression;
+    use crate::metadata::page_header::read_page_header;
+    use crate::metadata::parquet_metadata_thrift::CompressionCodec;
+    use crate::page_reader::decompression::{
+        decompress_page, decompress_page_into, Decompressor, Decompressors,
+    };
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    fn load_snappy_page() -> (Vec<u8>, usize) {
+        let path = String::from("src/sample_files/snappy_data_page_v1.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+
+        let page_header = read_page_header(&mut buf);
+        assert!(page_header.is_ok());
+        let page_header = page_header.unwrap();
+
+        let start = buf.get_rpos();
+        let compressed =
+            buf.as_bytes()[start..start + page_header.compressed_page_size as usize].to_vec();
+        (compressed, page_header.uncompressed_page_size as usize)
+    }
+
+    #[test]
+    fn test_decompress_snappy_page() {
+        let (compressed, uncompressed_size) = load_snappy_page();
+        assert_eq!(uncompressed_size, 8000);
+        assert!(compressed.len() < uncompressed_size);
+
+        let res = decompress_page(&compressed, CompressionCodec::SNAPPY, uncompressed_size);
+        assert!(res.is_ok());
+        let buf = res.unwrap();
+        assert_eq!(buf.len(), uncompressed_size);
+
+        for (i, value) in buf.as_bytes().chunks_exact(8).enumerate() {
+            assert_eq!(
+                i64::from_le_bytes(value.try_into().unwrap()),
+                (i as i64) % 100 * 3
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompress_uncompressed_page() {
+        let data: Vec<u8> = (0..100).collect();
+        let res = decompress_page(&data, CompressionCodec::UNCOMPRESSED, 100);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), &data[..]);
+
+        let res = decompress_page(&data, CompressionCodec::UNCOMPRESSED, 101);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Decompressed page size: 100 does not match the uncompressed page size: 101\n"
+        );
+    }
+
+    #[test]
+    fn test_decompress_snappy_size_mismatch() {
+        let (compressed, uncompressed_size) = load_snappy_page();
+        let res = decompress_page(&compressed, CompressionCodec::SNAPPY, uncompressed_size - 1);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Snappy decompressed length: 8000 does not match the uncompressed page size: 7999\n"
+        );
+    }
+
+    #[test]
+    fn test_decompress_corrupt_snappy_page() {
+        let (mut compressed, uncompressed_size) = load_snappy_page();
+        compressed.truncate(compressed.len() / 2);
+        let res = decompress_page(&compressed, CompressionCodec::SNAPPY, uncompressed_size);
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Decompression Error: Unable to decompress Snappy page"));
+    }
+
+    #[test]
+    fn test_decompress_zstd_page() {
+        let data: Vec<u8> = (0..10000u32)
+            .flat_map(|i| (i % 321).to_le_bytes())
+            .collect();
+        let compressed = zstd::bulk::compress(&data, 3).unwrap();
+        assert!(compressed.len() < data.len());
+
+        let res = decompress_page(&compressed, CompressionCodec::ZSTD, data.len());
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), &data[..]);
+
+        let res = decompress_page(&compressed, CompressionCodec::ZSTD, data.len() + 1);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Decompressed page size: 40000 does not match the uncompressed page size: 40001\n"
+        );
+    }
+
+    #[test]
+    fn test_decompress_corrupt_zstd_page() {
+        let data: Vec<u8> = (0..10000u32)
+            .flat_map(|i| (i % 321).to_le_bytes())
+            .collect();
+        let compressed = zstd::bulk::compress(&data, 3).unwrap();
+
+        let truncated = &compressed[..compressed.len() / 2];
+        let res = decompress_page(truncated, CompressionCodec::ZSTD, data.len());
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Decompression Error: Unable to decompress ZSTD page"));
+
+        let res = decompress_page(
+            &[0xde, 0xad, 0xbe, 0xef],
+            CompressionCodec::ZSTD,
+            data.len(),
+        );
+        assert!(res.is_err());
+
+        // The uncompressed size from the page header is smaller than the frame content.
+        let res = decompress_page(&compressed, CompressionCodec::ZSTD, data.len() - 1);
+        assert!(res.is_err());
+    }
+
+    fn compress_gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_decompress_gzip_page() {
+        let data: Vec<u8> = (0..10000u32)
+            .flat_map(|i| (i % 123).to_le_bytes())
+            .collect();
+        let compressed = compress_gzip(&data);
+        assert!(compressed.len() < data.len());
+
+        let res = decompress_page(&compressed, CompressionCodec::GZIP, data.len());
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), &data[..]);
+
+        let res = decompress_page(
+            &compressed[..compressed.len() - 10],
+            CompressionCodec::GZIP,
+            data.len(),
+        );
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Decompression Error: Unable to decompress GZIP page"));
+    }
+
+    #[test]
+    fn test_decompress_multi_member_gzip_page() {
+        let data: Vec<u8> = (0..10000u32)
+            .flat_map(|i| (i % 123).to_le_bytes())
+            .collect();
+        let (first, second) = data.split_at(12345);
+        let mut compressed = compress_gzip(first);
+        compressed.extend(compress_gzip(second));
+
+        let res = decompress_page(&compressed, CompressionCodec::GZIP, data.len());
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), &data[..]);
+
+        // Only one byte more than the uncompressed size is inflated.
+        let res = decompress_page(&compressed, CompressionCodec::GZIP, first.len());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Decompressed page size: 12346 does not match the uncompressed page size: 12345\n"
+        );
+    }
+
+    #[test]
+    fn test_decompress_gzip_bomb() {
+        // 16MB of zeros are compressed to about 16KB.
+        let data = vec![0u8; 16 << 20];
+        let compressed = compress_gzip(&data);
+        assert!(compressed.len() < 1 << 20);
+
+        let mut output = Vec::new();
+        let res = decompress_page_into(&compressed, CompressionCodec::GZIP, 1000, &mut output);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Decompressed page size: 1001 does not match the uncompressed page size: 1000\n"
+        );
+        assert_eq!(output.len(), 1001);
+    }
+
+    #[test]
+    fn test_decompress_lz4_raw_page() {
+        // A small page and a page larger than the 64KB LZ4 window.
+        for len in [1000u32, 100000] {
+            let data: Vec<u8> = (0..len).flat_map(|i| (i % 100).to_le_bytes()).collect();
+            let compressed = lz4_flex::block::compress(&data);
+            assert!(compressed.len() < data.len());
+
+            let res = decompress_page(&compressed, CompressionCodec::LZ4_RAW, data.len());
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap().as_bytes(), &data[..]);
+
+            let res = decompress_page(&compressed, CompressionCodec::LZ4_RAW, data.len() - 1);
+            assert!(res.is_err());
+            assert!(res
+                .err()
+                .unwrap()
+                .to_string()
+                .starts_with("Decompression Error: Unable to decompress LZ4_RAW page"));
+        }
+    }
+
+    #[test]
+    fn test_decompress_lz4_frame_page() {
+        let data: Vec<u8> = (0..1000u32).flat_map(|i| (i % 100).to_le_bytes()).collect();
+        let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+        encoder.write_all(&data).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let res = decompress_page(&compressed, CompressionCodec::LZ4_RAW, data.len());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Found LZ4 frame format in a LZ4_RAW page\n"
+        );
+
+        let res = decompress_page(&compressed, CompressionCodec::LZ4, data.len());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: The deprecated LZ4 codec is not supported, only LZ4_RAW is supported\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_codec() {
+        let data: Vec<u8> = vec![0; 10];
+        let res = decompress_page(&data, CompressionCodec::LZO, 10);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Not Yet Implemented: Compression codec: 3 is not supported yet\n"
+        );
+    }
+
+    struct PassThroughDecompressor {
+        num_calls: AtomicUsize,
+    }
+
+    impl Decompressor for PassThroughDecompressor {
+        fn decompress(
+            &self,
+            compressed: &[u8],
+            _uncompressed_size: usize,
+            output: &mut Vec<u8>,
+        ) -> Result<(), BoltReaderError> {
+            self.num_calls.fetch_add(1, Ordering::Relaxed);
+            output.extend_from_slice(compressed);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_register_decompressor() {
+        let data: Vec<u8> = (0..100u8).collect();
+        let mut decompressors = Decompressors::default();
+        let res = decompressors.decompress_page(&data, CompressionCodec::BROTLI, data.len());
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Not Yet Implemented: Compression codec: 4 is not supported yet\n"
+        );
+
+        let decompressor = Arc::new(PassThroughDecompressor {
+            num_calls: AtomicUsize::new(0),
+        });
+        assert!(decompressors
+            .register(compression::Compression::Brotli, decompressor.clone())
+            .is_ok());
+
+        let res = decompressors.decompress_page(&data, CompressionCodec::BROTLI, data.len());
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), &data[..]);
+        assert_eq!(decompressor.num_calls.load(Ordering::Relaxed), 1);
+
+        // The decompressed length is still checked for the custom decompressor.
+        let res = decompressors.decompress_page(&data, CompressionCodec::BROTLI, data.len() + 1);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Decompressed page size: 100 does not match the uncompressed page size: 101\n"
+        );
+        assert_eq!(decompressor.num_calls.load(Ordering::Relaxed), 2);
+
+        // The other decompressors, e.g. the default ones, are not changed.
+        assert!(decompress_page(&data, CompressionCodec::BROTLI, data.len()).is_err());
+        assert!(Decompressors::default()
+            .decompress_page(&data, CompressionCodec::BROTLI, data.len())
+            .is_err());
+        assert_eq!(decompressor.num_calls.load(Ordering::Relaxed), 2);
+
+        assert!(decompressors
+            .unregister(compression::Compression::Brotli)
+            .is_some());
+        assert!(decompressors
+            .unregister(compression::Compression::Brotli)
+            .is_none());
+        let res = decompressors.decompress_page(&data, CompressionCodec::BROTLI, data.len());
+        assert!(res.is_err());
+        assert_eq!(decompressor.num_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_register_uncompressed_decompressor() {
+        let mut decompressors = Decompressors::default();
+        let res = decompressors.register(
+            compression::Compression::Uncompressed,
+            Arc::new(PassThroughDecompressor {
+                num_calls: AtomicUsize::new(0),
+            }),
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Reader Config Error: A decompressor cannot be registered for the UNCOMPRESSED pages\n"
+        );
+    }
+}
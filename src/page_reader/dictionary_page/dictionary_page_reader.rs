@@ -0,0 +1,291 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::TypeId;
+use std::cmp::min;
+use std::fmt::Formatter;
+use std::{mem, ptr};
+
+use crate::metadata::page_header::read_page_header;
+use crate::metadata::parquet_metadata_thrift;
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::dictionary_page::dictionary_page_base::DictionaryPage;
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The Dictionary Page Reader parses the dictionary page header and materializes the PLAIN encoded
+/// dictionary values into an owned Vec. INT32, INT64, FLOAT and DOUBLE dictionaries are supported.
+///
+/// Unlike FixedLengthDictionary, the dictionary values are always copied out of the buffer, so the
+/// reader does not need to be destroyed before the buffer is released. It is Send and Sync when T
+/// is, so the decoded dictionary can be shared by Arc with the data pages decoded on other
+/// threads.
+pub struct DictionaryPageReader<T> {
+    dictionary: Vec<T>,
+    sorted: bool,
+}
+
+#[allow(dead_code)]
+impl<T: ToString> std::fmt::Display for DictionaryPageReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let dict_str = self
+            .dictionary
+            .iter()
+            .take(min(10, self.dictionary.len()))
+            .map(T::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        writeln!(
+            f,
+            "Dictionary page reader: num_values {}, sorted {}\nDictionary: {} ...",
+            self.dictionary.len(),
+            self.sorted,
+            dict_str
+        )
+    }
+}
+
+impl<T: std::marker::Copy> DictionaryPage<T> for DictionaryPageReader<T> {
+    #[inline(always)]
+    fn validate(&self, index: usize) -> bool {
+        index < self.dictionary.len()
+    }
+
+    #[inline(always)]
+    fn find(&self, index: usize) -> T {
+        self.dictionary[index]
+    }
+
+    fn get_num_values(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    #[inline(always)]
+    fn get_type_size(&self) -> usize {
+        mem::size_of::<T>()
+    }
+}
+
+impl<T: 'static + std::marker::Copy> DictionaryPageReader<T> {
+    pub fn new(buffer: &mut DirectByteBuffer) -> Result<DictionaryPageReader<T>, BoltReaderError> {
+        let page_header = read_page_header(buffer)?;
+        Self::new_with_header(&page_header, buffer)
+    }
+
+    /// Load the dictionary values after the page header has been read, e.g. when the page body
+    /// is decompressed into a separate buffer.
+    pub fn new_with_header(
+        page_header: &PageHeader,
+        buffer: &mut DirectByteBuffer,
+    ) -> Result<DictionaryPageReader<T>, BoltReaderError> {
+        if TypeId::of::<T>() != TypeId::of::<i32>()
+            && TypeId::of::<T>() != TypeId::of::<i64>()
+            && TypeId::of::<T>() != TypeId::of::<f32>()
+            && TypeId::of::<T>() != TypeId::of::<f64>()
+        {
+            return Err(BoltReaderError::DictionaryPageError(String::from(
+                "Dictionary Page Reader only supports INT32, INT64, FLOAT and DOUBLE",
+            )));
+        }
+
+        let header = match &page_header.dictionary_page_header {
+            Some(dictionary_header) => dictionary_header,
+            None => {
+                return Err(BoltReaderError::DictionaryPageError(String::from(
+                    "Error when reading Dictionary Page Header",
+                )))
+            }
+        };
+
+        let encoding = header.encoding;
+        if encoding != parquet_metadata_thrift::Encoding::PLAIN_DICTIONARY
+            && encoding != parquet_metadata_thrift::Encoding::PLAIN
+        {
+            return Err(BoltReaderError::DictionaryPageError(String::from(
+                "Dictionary Page Encoding should by either PLAIN or PLAIN_DICTIONARY",
+            )));
+        }
+
+        if unlikely(header.num_values < 0) {
+            return Err(BoltReaderError::DictionaryPageError(format!(
+                "Invalid number of dictionary values: {}",
+                header.num_values
+            )));
+        }
+
+        let num_values = header.num_values as usize;
+        let type_size = mem::size_of::<T>();
+        let dictionary_size = num_values * type_size;
+
+        let mut dictionary: Vec<T> = Vec::with_capacity(num_values);
+        if num_values > 0 {
+            let slice = buffer.create_buffer_slice(buffer.get_rpos(), dictionary_size)?;
+            for i in 0..num_values {
+                // The buffer is not guaranteed to be aligned to T.
+                dictionary.push(unsafe {
+                    ptr::read_unaligned(slice.as_ptr().add(i * type_size) as *const T)
+                });
+            }
+        }
+
+        buffer.set_rpos(buffer.get_rpos() + dictionary_size);
+
+        Ok(DictionaryPageReader {
+            dictionary,
+            sorted: header.is_sorted.unwrap_or(false),
+        })
+    }
+
+    pub fn get_value(&self, index: usize) -> Result<T, BoltReaderError> {
+        if unlikely(index >= self.dictionary.len()) {
+            return Err(BoltReaderError::DictionaryPageError(format!(
+                "Dictionary index: {} is out of bound, dictionary size: {}",
+                index,
+                self.dictionary.len()
+            )));
+        }
+
+        Ok(self.dictionary[index])
+    }
+
+    pub fn len(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionary.is_empty()
+    }
+
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use thrift::protocol::{TCompactOutputProtocol, TSerializable};
+
+    use crate::metadata::parquet_metadata_thrift::{
+        DictionaryPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::dictionary_page::dictionary_page_base::DictionaryPage;
+    use crate::page_reader::dictionary_page::dictionary_page_reader::DictionaryPageReader;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    fn encode_dictionary_page(num_values: usize, encoding: Encoding, values: &[u8]) -> Vec<u8> {
+        let header = PageHeader::new(
+            PageType::DICTIONARY_PAGE,
+            values.len() as i32,
+            values.len() as i32,
+            None,
+            None,
+            None,
+            DictionaryPageHeader::new(num_values as i32, encoding, true),
+            None,
+        );
+
+        let mut bytes: Vec<u8> = vec![];
+        let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+        header.write_to_out_protocol(&mut protocol).unwrap();
+        bytes.extend_from_slice(values);
+        bytes
+    }
+
+    #[test]
+    fn test_load_dictionary_page() {
+        let path = String::from("src/sample_files/lineitem_dictionary.parquet");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        let res = DirectByteBuffer::from_file(&file, 4, file.get_file_size() - 4);
+        assert!(res.is_ok());
+        let mut buf = res.unwrap();
+
+        let dictionary = DictionaryPageReader::<i64>::new(&mut buf);
+        assert!(dictionary.is_ok());
+        let dictionary = dictionary.unwrap();
+
+        assert_eq!(dictionary.len(), 2921);
+        assert_eq!(dictionary.get_num_values(), 2921);
+        assert_eq!(dictionary.get_type_size(), 8);
+        assert_eq!(dictionary.get_value(0).unwrap(), 429);
+        assert_eq!(dictionary.get_value(1).unwrap(), 54914);
+        assert_eq!(dictionary.get_value(2).unwrap(), 54915);
+        assert_eq!(dictionary.find(3), 54916);
+        assert_eq!(buf.get_rpos(), 19 + 2921 * 8);
+        assert_eq!(dictionary.to_string(), "Dictionary page reader: num_values 2921, sorted false\nDictionary: 429, 54914, 54915, 54916, 54917, 54918, 54919, 54944, 54945, 54946 ...\n");
+    }
+
+    #[test]
+    fn test_load_fixed_width_dictionaries() {
+        let values: Vec<u8> = [1i32, -2, 3].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut buf =
+            DirectByteBuffer::from_vec(encode_dictionary_page(3, Encoding::PLAIN, &values));
+        let dictionary = DictionaryPageReader::<i32>::new(&mut buf).unwrap();
+        assert_eq!(dictionary.len(), 3);
+        assert!(dictionary.is_sorted());
+        assert_eq!(dictionary.get_value(1).unwrap(), -2);
+
+        let values: Vec<u8> = [1.5f32, 2.5].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut buf = DirectByteBuffer::from_vec(encode_dictionary_page(
+            2,
+            Encoding::PLAIN_DICTIONARY,
+            &values,
+        ));
+        let dictionary = DictionaryPageReader::<f32>::new(&mut buf).unwrap();
+        assert_eq!(dictionary.get_value(1).unwrap(), 2.5);
+
+        let values: Vec<u8> = [0.25f64].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut buf =
+            DirectByteBuffer::from_vec(encode_dictionary_page(1, Encoding::PLAIN, &values));
+        let dictionary = DictionaryPageReader::<f64>::new(&mut buf).unwrap();
+        assert_eq!(dictionary.get_value(0).unwrap(), 0.25);
+        assert_eq!(buf.get_rpos(), buf.len());
+    }
+
+    #[test]
+    fn test_dictionary_index_out_of_bound() {
+        let values: Vec<u8> = [7i64, 8].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut buf =
+            DirectByteBuffer::from_vec(encode_dictionary_page(2, Encoding::PLAIN, &values));
+        let dictionary = DictionaryPageReader::<i64>::new(&mut buf).unwrap();
+
+        let res = dictionary.get_value(2);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Dictionary Page Error: Dictionary index: 2 is out of bound, dictionary size: 2\n"
+        );
+        assert!(!dictionary.validate(2));
+    }
+
+    #[test]
+    fn test_unsupported_dictionary() {
+        let values: Vec<u8> = vec![0; 16];
+        let mut buf = DirectByteBuffer::from_vec(encode_dictionary_page(2, Encoding::RLE, &values));
+        let dictionary = DictionaryPageReader::<i64>::new(&mut buf);
+        assert!(dictionary.is_err());
+
+        let mut buf =
+            DirectByteBuffer::from_vec(encode_dictionary_page(2, Encoding::PLAIN, &values));
+        let dictionary = DictionaryPageReader::<u8>::new(&mut buf);
+        assert!(dictionary.is_err());
+    }
+}
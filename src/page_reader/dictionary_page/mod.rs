@@ -14,5 +14,6 @@
 // limitations under the License.
 
 pub mod dictionary_page_base;
+pub mod dictionary_page_reader;
 pub mod fixed_length_dictionary_page;
 pub mod fixed_length_dictionary_page_with_filter;
@@ -0,0 +1,477 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::metadata::page_header::{check_page_size, read_page_header, DEFAULT_MAX_PAGE_SIZE};
+use crate::metadata::parquet_metadata_thrift::{
+    CompressionCodec, FieldRepetitionType, PageHeader, PageType,
+};
+use crate::metadata::row_group::ColumnChunkMetaData;
+use crate::metadata::schema::ColumnDescriptor;
+use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
+    destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
+};
+use crate::page_reader::data_page_v1::variable_length_plain_data_page_v1::VariableLengthPlainDataPageReaderV1;
+use crate::page_reader::decompression::decompress_page;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::rep_def_parser::RepDefParser;
+
+/// A list column in the Arrow layout. The list i has the values [offsets[i], offsets[i + 1]),
+/// so both the empty lists and the null lists have no values, and they are told apart by the
+/// validity. The value validity is false for the null elements.
+pub struct ListColumn<T> {
+    offsets: Vec<i32>,
+    validity: Vec<bool>,
+    values: Vec<T>,
+    value_validity: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl<T> std::fmt::Display for ListColumn<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "List Column: num_lists {}, num_null_lists {}, num_values {}, num_null_values {}",
+            self.get_num_lists(),
+            self.validity.iter().filter(|valid| !**valid).count(),
+            self.values.len(),
+            self.value_validity.iter().filter(|valid| !**valid).count()
+        )
+    }
+}
+
+impl<T> ListColumn<T> {
+    pub fn get_num_lists(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// The offsets of the lists into the values, with get_num_lists() + 1 entries.
+    pub fn get_offsets(&self) -> &[i32] {
+        &self.offsets
+    }
+
+    pub fn get_validity(&self) -> &[bool] {
+        &self.validity
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        !self.validity[index]
+    }
+
+    /// The values of all the lists. The value at a null element is T::default().
+    pub fn get_values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn get_value_validity(&self) -> &[bool] {
+        &self.value_validity
+    }
+
+    /// The values of the list at the index, which are empty for a null list.
+    pub fn get_list(&self, index: usize) -> &[T] {
+        &self.values[self.offsets[index] as usize..self.offsets[index + 1] as usize]
+    }
+}
+
+/// The List Column Reader loads a column chunk of a single level list column, e.g.
+/// optional group col (LIST) { repeated group list { optional int32 element; } }, and rebuilds
+/// the lists from the repetition and definition levels of the PLAIN encoded Data Page V1.
+///
+/// The repetition level 0 starts a new list. The definition level tells the null list, below the
+/// level of the empty list, from the empty list, below the repeated field, and from the
+/// elements, which are null below the max definition level.
+pub struct ListColumnReader<T> {
+    path: String,
+    codec: CompressionCodec,
+    num_values: usize,
+    values_read: usize,
+    max_definition_level: u32,
+    // The definition level of the repeated field. The levels below it have no element.
+    repeated_definition_level: u32,
+    buffer: DirectByteBuffer,
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[allow(dead_code)]
+impl<T> std::fmt::Display for ListColumnReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "List Column Reader: path {}, compression {}, num_values {}, values_read {}, max_definition_level {}, repeated_definition_level {}",
+            self.path,
+            self.codec.0,
+            self.num_values,
+            self.values_read,
+            self.max_definition_level,
+            self.repeated_definition_level
+        )
+    }
+}
+
+// The levels and the values of a data page, with a value at every level.
+struct PageLevels<T> {
+    repetition_levels: Vec<u32>,
+    definition_levels: Vec<u32>,
+    values: Vec<T>,
+}
+
+impl<T> ListColumnReader<T> {
+    pub fn new(
+        column_chunk: &ColumnChunkMetaData,
+        column: &ColumnDescriptor,
+        file: &dyn LoadFile,
+    ) -> Result<ListColumnReader<T>, BoltReaderError> {
+        let path = column.get_path_string();
+        if unlikely(column.get_max_repetition_level() != 1) {
+            return Err(BoltReaderError::NotYetImplementedError(format!(
+                "Column: {} with max repetition level: {} is not a single level list",
+                path,
+                column.get_max_repetition_level()
+            )));
+        }
+
+        // The optional element takes the max definition level, the repeated field takes the
+        // level below it. The legacy two-level list repeats the element itself.
+        let max_definition_level = column.get_max_definition_level() as u32;
+        let repeated_definition_level =
+            if column.get_repetition_type() == Some(FieldRepetitionType::OPTIONAL) {
+                max_definition_level - 1
+            } else {
+                max_definition_level
+            };
+
+        let buffer = DirectByteBuffer::from_file(
+            file,
+            column_chunk.column_chunk_offset(),
+            column_chunk.total_compressed_size(),
+        )?;
+
+        Ok(ListColumnReader {
+            path,
+            codec: column_chunk.compression(),
+            num_values: column_chunk.num_values(),
+            values_read: 0,
+            max_definition_level,
+            repeated_definition_level,
+            buffer,
+            _marker: Default::default(),
+        })
+    }
+
+    pub fn get_path(&self) -> &String {
+        &self.path
+    }
+
+    // Read all the data pages with read_page, which decodes the levels and the values of the page
+    // body starting from the rpos of the page buffer.
+    fn read_pages<F>(&mut self, mut read_page: F) -> Result<ListColumn<T>, BoltReaderError>
+    where
+        F: FnMut(
+            &PageHeader,
+            &mut DirectByteBuffer,
+            usize,
+            u32,
+        ) -> Result<PageLevels<T>, BoltReaderError>,
+    {
+        let mut column = ListColumn {
+            offsets: Vec::new(),
+            validity: Vec::new(),
+            values: Vec::new(),
+            value_validity: Vec::new(),
+        };
+
+        while self.values_read < self.num_values && self.buffer.get_rpos() < self.buffer.len() {
+            let page_header = read_page_header(&mut self.buffer)?;
+            check_page_size(&page_header, DEFAULT_MAX_PAGE_SIZE)?;
+            let body_begin = self.buffer.get_rpos();
+            if unlikely(
+                page_header.compressed_page_size < 0
+                    || page_header.uncompressed_page_size < 0
+                    || body_begin + page_header.compressed_page_size as usize > self.buffer.len(),
+            ) {
+                return Err(BoltReaderError::ColumnChunkError(format!(
+                    "Column chunk: {} has invalid page size: {} bytes at offset: {}, column chunk size: {} bytes",
+                    self.path,
+                    page_header.compressed_page_size,
+                    body_begin,
+                    self.buffer.len()
+                )));
+            }
+            let body_end = body_begin + page_header.compressed_page_size as usize;
+
+            match page_header.type_ {
+                PageType::DATA_PAGE => {
+                    let mut decompressed;
+                    let page_buffer: &mut DirectByteBuffer = match self.codec {
+                        CompressionCodec::UNCOMPRESSED => &mut self.buffer,
+                        _ => {
+                            decompressed = decompress_page(
+                                &self.buffer.as_bytes()[body_begin..body_end],
+                                self.codec,
+                                page_header.uncompressed_page_size as usize,
+                            )?;
+                            &mut decompressed
+                        }
+                    };
+
+                    let page_levels = read_page(
+                        &page_header,
+                        page_buffer,
+                        self.values_read,
+                        self.max_definition_level,
+                    )?;
+                    self.append_page_levels(page_levels, &mut column)?;
+                }
+                PageType::DICTIONARY_PAGE | PageType::DATA_PAGE_V2 => {
+                    return Err(BoltReaderError::NotYetImplementedError(format!(
+                        "List column: {} page of type: {}",
+                        self.path, page_header.type_.0
+                    )))
+                }
+                _ => {}
+            }
+            self.buffer.set_rpos(body_end);
+        }
+
+        column.offsets.push(column.values.len() as i32);
+        Ok(column)
+    }
+
+    fn append_page_levels(
+        &mut self,
+        page_levels: PageLevels<T>,
+        column: &mut ListColumn<T>,
+    ) -> Result<(), BoltReaderError> {
+        let num_values = page_levels.repetition_levels.len();
+        if unlikely(
+            self.values_read == 0
+                && page_levels
+                    .repetition_levels
+                    .first()
+                    .is_some_and(|l| *l != 0),
+        ) {
+            return Err(BoltReaderError::RepDefError(format!(
+                "List column: {} should start with the repetition level 0",
+                self.path
+            )));
+        }
+
+        for ((repetition_level, definition_level), value) in page_levels
+            .repetition_levels
+            .into_iter()
+            .zip(page_levels.definition_levels)
+            .zip(page_levels.values)
+        {
+            if repetition_level == 0 {
+                column.offsets.push(column.values.len() as i32);
+                column
+                    .validity
+                    .push(definition_level + 1 >= self.repeated_definition_level);
+            }
+
+            if definition_level >= self.repeated_definition_level {
+                column.values.push(value);
+                column
+                    .value_validity
+                    .push(definition_level == self.max_definition_level);
+            }
+        }
+
+        self.values_read += num_values;
+        Ok(())
+    }
+}
+
+impl<T: 'static + std::marker::Copy + Default> ListColumnReader<T> {
+    /// Read all the lists of the column chunk.
+    pub fn read_list_column(&mut self) -> Result<ListColumn<T>, BoltReaderError> {
+        self.read_pages(
+            |page_header, page_buffer, current_offset, max_definition_level| {
+                let data_page = FixedLengthPlainDataPageReaderV1::<T>::new_with_levels(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    mem::size_of::<T>(),
+                    1,
+                    max_definition_level,
+                    None,
+                )?;
+
+                let repetition_levels = data_page.get_repetition_levels();
+                let page_levels = PageLevels {
+                    repetition_levels: repetition_levels.iter().map(|l| *l as u32).collect(),
+                    definition_levels: data_page
+                        .get_definition_levels()
+                        .iter()
+                        .map(|l| *l as u32)
+                        .collect(),
+                    values: (0..repetition_levels.len())
+                        .map(|i| data_page.get_value(i))
+                        .collect(),
+                };
+                destroy_fixed_length_plain_data_page_v1(data_page);
+                Ok(page_levels)
+            },
+        )
+    }
+}
+
+impl ListColumnReader<Vec<u8>> {
+    /// Read all the lists of a BYTE_ARRAY column chunk. The value at a null element is empty.
+    pub fn read_byte_array_list_column(&mut self) -> Result<ListColumn<Vec<u8>>, BoltReaderError> {
+        self.read_pages(
+            |page_header, page_buffer, current_offset, max_definition_level| {
+                let num_values = match &page_header.data_page_header {
+                    Some(header) => header.num_values as usize,
+                    None => {
+                        return Err(BoltReaderError::VariableLengthDataPageError(String::from(
+                            "Error when reading Data Page V1 Header",
+                        )))
+                    }
+                };
+
+                let mut repetition_levels = RepDefParser::load_level(page_buffer, 1)?;
+                let mut definition_levels =
+                    RepDefParser::load_level(page_buffer, max_definition_level)?;
+                if unlikely(
+                    repetition_levels.len() < num_values || definition_levels.len() < num_values,
+                ) {
+                    return Err(BoltReaderError::RepDefError(format!(
+                        "Data page with {} values has {} repetition levels and {} definition levels",
+                        num_values,
+                        repetition_levels.len(),
+                        definition_levels.len()
+                    )));
+                }
+                repetition_levels.truncate(num_values);
+                definition_levels.truncate(num_values);
+
+                let validity: Vec<bool> = definition_levels
+                    .iter()
+                    .map(|l| *l == max_definition_level)
+                    .collect();
+                let has_null = validity.iter().any(|valid| !*valid);
+                let data_page = VariableLengthPlainDataPageReaderV1::new(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    has_null,
+                    Some(validity),
+                )?;
+                let values = (0..num_values)
+                    .map(|i| data_page.get_value(i).map(<[u8]>::to_vec).unwrap_or_default())
+                    .collect();
+
+                Ok(PageLevels {
+                    repetition_levels,
+                    definition_levels,
+                    values,
+                })
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::list_column_reader::{ListColumn, ListColumnReader};
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    fn read_list_column(path: &str) -> ListColumn<i32> {
+        let file = LocalFileLoader::new(&String::from(path)).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let column = &schema_tree.get_column_descriptors()[0];
+        let column_chunk = file_metadata.row_groups()[0].column(0).unwrap();
+
+        let res = ListColumnReader::<i32>::new(column_chunk, column, &file);
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+        assert_eq!(reader.to_string(), "List Column Reader: path col.list.element, compression 0, num_values 13, values_read 0, max_definition_level 3, repeated_definition_level 2\n");
+
+        let res = reader.read_list_column();
+        assert!(res.is_ok());
+        res.unwrap()
+    }
+
+    #[test]
+    fn test_read_list_column() {
+        // 2 data pages of the lists: [1, 2, 3], [], null, [4, null, 5] and [null], [6], null,
+        // [7, 8]
+        let column = read_list_column("src/sample_files/list_int32.parquet");
+        assert_eq!(column.get_num_lists(), 8);
+        assert_eq!(column.get_offsets(), &[0, 3, 3, 3, 6, 7, 8, 8, 10]);
+        assert_eq!(
+            column.get_validity(),
+            &[true, true, false, true, true, true, false, true]
+        );
+        assert_eq!(column.get_values(), &[1, 2, 3, 4, 0, 5, 0, 6, 7, 8]);
+        assert_eq!(
+            column.get_value_validity(),
+            &[true, true, true, true, false, true, false, true, true, true]
+        );
+        assert_eq!(
+            column.to_string(),
+            "List Column: num_lists 8, num_null_lists 2, num_values 10, num_null_values 2\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_and_null_lists() {
+        let column = read_list_column("src/sample_files/list_int32.parquet");
+
+        // The empty list and the null list both have no values.
+        assert!(column.get_list(1).is_empty());
+        assert!(!column.is_null(1));
+        assert!(column.get_list(2).is_empty());
+        assert!(column.is_null(2));
+        assert!(column.is_null(6));
+
+        // The null elements are in the lists, unlike the null lists.
+        assert_eq!(column.get_list(3), &[4, 0, 5]);
+        assert!(!column.get_value_validity()[4]);
+        assert_eq!(column.get_list(4).len(), 1);
+        assert!(!column.is_null(4));
+        assert!(!column.get_value_validity()[6]);
+        assert_eq!(column.get_list(7), &[7, 8]);
+    }
+
+    #[test]
+    fn test_read_non_list_column() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let column_chunk = file_metadata.row_groups()[0].column(0).unwrap();
+
+        let res = ListColumnReader::<i64>::new(
+            column_chunk,
+            &schema_tree.get_column_descriptors()[0],
+            &file,
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Not Yet Implemented: Column: orderkey with max repetition level: 0 is not a single level list\n"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::row_group::RowGroupMetaData;
+use crate::metadata::schema::ColumnDescriptor;
+use crate::page_reader::list_column_reader::ListColumnReader;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+/// A map column in the Arrow layout. The map i has the key value pairs
+/// [offsets[i], offsets[i + 1]), the keys are never null and the value validity is false for the
+/// null values.
+pub struct MapColumn<V> {
+    offsets: Vec<i32>,
+    validity: Vec<bool>,
+    keys: Vec<Vec<u8>>,
+    values: Vec<V>,
+    value_validity: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl<V> std::fmt::Display for MapColumn<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Map Column: num_maps {}, num_null_maps {}, num_entries {}, num_null_values {}",
+            self.get_num_maps(),
+            self.validity.iter().filter(|valid| !**valid).count(),
+            self.keys.len(),
+            self.value_validity.iter().filter(|valid| !**valid).count()
+        )
+    }
+}
+
+impl<V> MapColumn<V> {
+    pub fn get_num_maps(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// The offsets of the maps into the keys and the values, with get_num_maps() + 1 entries.
+    pub fn get_offsets(&self) -> &[i32] {
+        &self.offsets
+    }
+
+    pub fn get_validity(&self) -> &[bool] {
+        &self.validity
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        !self.validity[index]
+    }
+
+    pub fn get_keys(&self) -> &[Vec<u8>] {
+        &self.keys
+    }
+
+    /// The values of all the maps. The value at a null value is V::default().
+    pub fn get_values(&self) -> &[V] {
+        &self.values
+    }
+
+    pub fn get_value_validity(&self) -> &[bool] {
+        &self.value_validity
+    }
+
+    /// The keys and the values of the map at the index, which are empty for a null map.
+    pub fn get_map(&self, index: usize) -> (&[Vec<u8>], &[V]) {
+        let begin = self.offsets[index] as usize;
+        let end = self.offsets[index + 1] as usize;
+        (&self.keys[begin..end], &self.values[begin..end])
+    }
+}
+
+/// The Map Column Reader loads the key and the value column chunks of a map column, e.g.
+/// optional group col (MAP) { repeated group key_value { required binary key; optional int32
+/// value; } }, and rebuilds the maps from their shared repetition structure.
+///
+/// The keys are read as BYTE_ARRAY, the values as the fixed length type V.
+pub struct MapColumnReader<V> {
+    key_reader: ListColumnReader<Vec<u8>>,
+    value_reader: ListColumnReader<V>,
+}
+
+#[allow(dead_code)]
+impl<V> std::fmt::Display for MapColumnReader<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Map Column Reader\nKeys: {}Values: {}",
+            self.key_reader, self.value_reader
+        )
+    }
+}
+
+impl<V: 'static + std::marker::Copy + Default> MapColumnReader<V> {
+    pub fn new(
+        row_group: &RowGroupMetaData,
+        key_column: &ColumnDescriptor,
+        value_column: &ColumnDescriptor,
+        file: &dyn LoadFile,
+    ) -> Result<MapColumnReader<V>, BoltReaderError> {
+        let key_path = key_column.get_path();
+        let value_path = value_column.get_path();
+        if unlikely(
+            key_path.len() != value_path.len()
+                || key_path[..key_path.len() - 1] != value_path[..value_path.len() - 1],
+        ) {
+            return Err(BoltReaderError::RepDefError(format!(
+                "Columns: {} and {} are not the keys and the values of the same map",
+                key_column.get_path_string(),
+                value_column.get_path_string()
+            )));
+        }
+
+        let key_reader = ListColumnReader::new(
+            row_group.column(key_column.get_column_index())?,
+            key_column,
+            file,
+        )?;
+        let value_reader = ListColumnReader::new(
+            row_group.column(value_column.get_column_index())?,
+            value_column,
+            file,
+        )?;
+
+        Ok(MapColumnReader {
+            key_reader,
+            value_reader,
+        })
+    }
+
+    /// Read all the maps of the column chunks.
+    pub fn read_map_column(&mut self) -> Result<MapColumn<V>, BoltReaderError> {
+        let keys = self.key_reader.read_byte_array_list_column()?;
+        let values = self.value_reader.read_list_column()?;
+
+        if unlikely(
+            keys.get_offsets() != values.get_offsets()
+                || keys.get_validity() != values.get_validity(),
+        ) {
+            return Err(BoltReaderError::RepDefError(format!(
+                "Map keys: {} and values: {} have different repetition structures",
+                self.key_reader.get_path(),
+                self.value_reader.get_path()
+            )));
+        }
+        if unlikely(keys.get_value_validity().iter().any(|valid| !*valid)) {
+            return Err(BoltReaderError::RepDefError(format!(
+                "Map keys: {} should not be null",
+                self.key_reader.get_path()
+            )));
+        }
+
+        Ok(MapColumn {
+            offsets: values.get_offsets().to_vec(),
+            validity: values.get_validity().to_vec(),
+            keys: keys.get_values().to_vec(),
+            values: values.get_values().to_vec(),
+            value_validity: values.get_value_validity().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::map_column_reader::MapColumnReader;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_map_column() {
+        // The maps: {a: 1, b: 2}, {}, null, {c: null, d: 4}, {e: 5}
+        let path = String::from("src/sample_files/map_string_int32.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let columns = schema_tree.get_column_descriptors();
+
+        let res = MapColumnReader::<i32>::new(
+            &file_metadata.row_groups()[0],
+            &columns[0],
+            &columns[1],
+            &file,
+        );
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+
+        let res = reader.read_map_column();
+        assert!(res.is_ok());
+        let column = res.unwrap();
+        assert_eq!(column.get_num_maps(), 5);
+        assert_eq!(column.get_offsets(), &[0, 2, 2, 2, 4, 5]);
+        assert_eq!(column.get_validity(), &[true, true, false, true, true]);
+        assert_eq!(
+            column.to_string(),
+            "Map Column: num_maps 5, num_null_maps 1, num_entries 5, num_null_values 1\n"
+        );
+
+        let (keys, values) = column.get_map(0);
+        assert_eq!(keys, &[b"a".to_vec(), b"b".to_vec()]);
+        assert_eq!(values, &[1, 2]);
+
+        assert!(column.get_map(1).0.is_empty());
+        assert!(!column.is_null(1));
+        assert!(column.get_map(2).0.is_empty());
+        assert!(column.is_null(2));
+
+        let (keys, values) = column.get_map(3);
+        assert_eq!(keys, &[b"c".to_vec(), b"d".to_vec()]);
+        assert_eq!(values, &[0, 4]);
+        assert_eq!(
+            column.get_value_validity(),
+            &[true, true, false, true, true]
+        );
+
+        let (keys, values) = column.get_map(4);
+        assert_eq!(keys, &[b"e".to_vec()]);
+        assert_eq!(values, &[5]);
+    }
+
+    #[test]
+    fn test_read_map_column_with_unrelated_columns() {
+        let path = String::from("src/sample_files/map_string_int32.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let columns = schema_tree.get_column_descriptors();
+
+        let list_path = String::from("src/sample_files/list_int32.parquet");
+        let list_file = LocalFileLoader::new(&list_path).unwrap();
+        let list_metadata = read_file_metadata(&list_file).unwrap();
+        let list_schema_tree = SchemaTree::new(list_metadata.schema()).unwrap();
+
+        let res = MapColumnReader::<i32>::new(
+            &file_metadata.row_groups()[0],
+            &columns[0],
+            &list_schema_tree.get_column_descriptors()[0],
+            &file,
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Repetition and Definition Error: Columns: m.key_value.key and col.list.element are not the keys and the values of the same map\n"
+        );
+    }
+}
@@ -13,5 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod column_chunk_reader;
 pub mod data_page_v1;
+pub mod data_page_v2;
+pub mod decompression;
 pub mod dictionary_page;
+pub mod list_column_reader;
+pub mod map_column_reader;
+pub mod page_stream;
+#[cfg(feature = "arrow")]
+pub mod record_batch;
+pub mod repeated_primitive_reader;
+pub mod row_group_reader;
+pub mod struct_column_reader;
@@ -0,0 +1,343 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::min;
+use std::fmt::Formatter;
+
+use crate::metadata::page_header::{check_page_size, read_page_header, DEFAULT_MAX_PAGE_SIZE};
+use crate::metadata::parquet_metadata_thrift::{PageHeader, PageType};
+use crate::metadata::row_group::ColumnChunkMetaData;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+// The page header is loaded with this size first, and reloaded with the doubled size when it
+// does not fit, e.g. with large statistics.
+// todo: Create config module to handle the default const values.
+const PAGE_HEADER_SIZE_ESTIMATE: usize = 64;
+
+/// A page loaded by the PageStream, holding the page header and the compressed page body.
+pub struct PageHandle {
+    offset: usize,
+    header_size: usize,
+    page_header: PageHeader,
+    buffer: DirectByteBuffer,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for PageHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Page Handle: offset {}, header_size {}, page_type {}, compressed_page_size {}",
+            self.offset,
+            self.header_size,
+            self.page_header.type_.0,
+            self.buffer.len()
+        )
+    }
+}
+
+impl PageHandle {
+    /// The offset of the page header in the file.
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_header_size(&self) -> usize {
+        self.header_size
+    }
+
+    pub fn get_page_header(&self) -> &PageHeader {
+        &self.page_header
+    }
+
+    pub fn get_page_type(&self) -> PageType {
+        self.page_header.type_
+    }
+
+    pub fn get_compressed_page_size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// The compressed page body, with the read position at the beginning of the body.
+    pub fn get_buffer(&mut self) -> &mut DirectByteBuffer {
+        &mut self.buffer
+    }
+
+    pub fn into_buffer(self) -> DirectByteBuffer {
+        self.buffer
+    }
+}
+
+/// Iterate the pages of a column chunk without loading the whole column chunk. Each page is
+/// loaded by a range read of its header followed by a range read of its body, so the memory is
+/// bounded by the largest page rather than the column chunk size.
+///
+/// After an error, the iterator returns None.
+pub struct PageStream<'a> {
+    file: &'a dyn LoadFile,
+    offset: usize,
+    end: usize,
+    max_page_size: usize,
+    finished: bool,
+}
+
+#[allow(dead_code)]
+impl<'a> std::fmt::Display for PageStream<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Page Stream: path {}, offset {}, end {}, finished {}",
+            self.file.get_file_path(),
+            self.offset,
+            self.end,
+            self.finished
+        )
+    }
+}
+
+impl<'a> Iterator for PageStream<'a> {
+    type Item = Result<PageHandle, BoltReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.offset >= self.end {
+            return None;
+        }
+
+        let res = self.load_page();
+        match &res {
+            Ok(page) => self.offset += page.header_size + page.buffer.len(),
+            Err(_) => self.finished = true,
+        }
+        Some(res)
+    }
+}
+
+impl<'a> PageStream<'a> {
+    pub fn new(column_chunk: &ColumnChunkMetaData, file: &'a dyn LoadFile) -> PageStream<'a> {
+        let begin = column_chunk.column_chunk_offset();
+        Self::from_range(file, begin, begin + column_chunk.total_compressed_size())
+    }
+
+    /// Iterate the pages in the file range [begin, end).
+    pub fn from_range(file: &'a dyn LoadFile, begin: usize, end: usize) -> PageStream<'a> {
+        PageStream {
+            file,
+            offset: begin,
+            end,
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+            finished: false,
+        }
+    }
+
+    /// The pages larger than the max page size are rejected with PageTooLarge before their
+    /// bodies are loaded. The default is DEFAULT_MAX_PAGE_SIZE.
+    pub fn with_max_page_size(mut self, max_page_size: usize) -> PageStream<'a> {
+        self.max_page_size = max_page_size;
+        self
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    fn load_page(&self) -> Result<PageHandle, BoltReaderError> {
+        let remaining = self.end - self.offset;
+        let mut header_estimate = min(PAGE_HEADER_SIZE_ESTIMATE, remaining);
+        let (page_header, header_size, header_buffer) = loop {
+            let header_buffer = self
+                .file
+                .load_file_to_buffer(self.offset, header_estimate)?;
+            let mut bytes = header_buffer.as_bytes();
+            match read_page_header(&mut bytes) {
+                Ok(page_header) => {
+                    let header_size = header_estimate - bytes.len();
+                    break (page_header, header_size, header_buffer);
+                }
+                Err(e) => {
+                    if header_estimate == remaining {
+                        return Err(e);
+                    }
+                    header_estimate = min(header_estimate * 2, remaining);
+                }
+            }
+        };
+
+        check_page_size(&page_header, self.max_page_size)?;
+        if unlikely(
+            page_header.compressed_page_size < 0
+                || page_header.compressed_page_size as usize > remaining - header_size,
+        ) {
+            return Err(BoltReaderError::ColumnChunkError(format!(
+                "Page at offset: {} has invalid page size: {} bytes, the column chunk ends at: {}",
+                self.offset, page_header.compressed_page_size, self.end
+            )));
+        }
+
+        // A small page may be already loaded with its header.
+        let page_size = page_header.compressed_page_size as usize;
+        let buffer = if header_size + page_size <= header_buffer.len() {
+            DirectByteBuffer::from_loaded_bytes(
+                header_buffer.as_bytes()[header_size..header_size + page_size].to_vec(),
+            )
+        } else {
+            drop(header_buffer);
+            DirectByteBuffer::from_file(self.file, self.offset + header_size, page_size)?
+        };
+
+        Ok(PageHandle {
+            offset: self.offset,
+            header_size,
+            page_header,
+            buffer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::parquet_metadata_thrift::PageType;
+    use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
+        destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
+    };
+    use crate::page_reader::page_stream::{PageStream, PAGE_HEADER_SIZE_ESTIMATE};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+    use crate::utils::test_utils::RecordingFileLoader;
+
+    #[test]
+    fn test_stream_pages() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let loader = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&loader).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let file = RecordingFileLoader::new(loader);
+
+        let mut num_pages = 0;
+        let mut max_page_size = 0;
+        let mut total_size = 0;
+        for res in PageStream::new(column, &file) {
+            assert!(res.is_ok());
+            let mut page = res.unwrap();
+            assert_eq!(page.get_page_type(), PageType::DATA_PAGE);
+            assert_eq!(
+                page.get_compressed_page_size(),
+                page.get_page_header().compressed_page_size as usize
+            );
+            assert_eq!(page.get_offset(), column.column_chunk_offset() + total_size);
+            max_page_size = max_page_size.max(page.get_compressed_page_size());
+            total_size += page.get_header_size() + page.get_compressed_page_size();
+
+            if num_pages == 0 {
+                let page_header = page.get_page_header().clone();
+                let data_page =
+                    FixedLengthPlainDataPageReaderV1::<i64>::new_with_definition_levels(
+                        &page_header,
+                        page.get_buffer(),
+                        0,
+                        8,
+                        1,
+                        None,
+                    )
+                    .unwrap();
+                let mut result_row_range_set = RowRangeSet::new(0);
+                let mut raw_bridge = RawBridge::new(false, 250);
+                data_page
+                    .read(
+                        RowRange::new(0, 250),
+                        0,
+                        &mut result_row_range_set,
+                        &mut raw_bridge,
+                    )
+                    .unwrap();
+                destroy_fixed_length_plain_data_page_v1(data_page);
+                for i in 0..250 {
+                    let (valid, value) = raw_bridge
+                        .get_validity_and_value(0, i, &result_row_range_set)
+                        .unwrap();
+                    assert!(valid);
+                    assert_eq!(value, i as i64 * 2);
+                }
+            }
+            num_pages += 1;
+        }
+        assert_eq!(num_pages, 4);
+        assert_eq!(total_size, column.total_compressed_size());
+
+        // Each page is loaded by a header read and a body read, never the whole column chunk.
+        // The all null page 2 is small enough to be loaded with its header.
+        let lengths = file.get_lengths();
+        assert_eq!(lengths.len(), 7);
+        assert!(lengths
+            .iter()
+            .all(|&length| length <= PAGE_HEADER_SIZE_ESTIMATE.max(max_page_size)));
+        assert!(max_page_size * 2 < column.total_compressed_size());
+    }
+
+    #[test]
+    fn test_stream_truncated_pages() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let begin = column.column_chunk_offset();
+
+        // The range ends in the middle of the first page.
+        let mut stream = PageStream::from_range(&file, begin, begin + 100);
+        let res = stream.next().unwrap();
+        assert!(res.is_err());
+        assert!(res
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Column Chunk Error: Page at offset: 4 has invalid page size"));
+        assert!(stream.next().is_none());
+
+        // The range ends in the middle of the page header.
+        let mut stream = PageStream::from_range(&file, begin, begin + 4);
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_pages_with_max_page_size() {
+        let path = String::from("src/sample_files/page_index.parquet");
+        let loader = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&loader).unwrap();
+        let column = file_metadata.row_group(0).unwrap().column(0).unwrap();
+        let file = RecordingFileLoader::new(loader);
+
+        let mut stream = PageStream::new(column, &file).with_max_page_size(100);
+        let res = stream.next().unwrap();
+        assert!(matches!(
+            res,
+            Err(BoltReaderError::PageTooLarge {
+                max_page_size: 100,
+                ..
+            })
+        ));
+        assert!(stream.next().is_none());
+
+        // Only the page header is loaded.
+        assert_eq!(file.get_lengths(), vec![PAGE_HEADER_SIZE_ESTIMATE]);
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::types::{
+    ArrowPrimitiveType, ArrowTimestampType, Date32Type, Float32Type, Float64Type, Int32Type,
+    Int64Type, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType,
+};
+use arrow_array::{ArrayRef, PrimitiveArray, RecordBatch};
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer, ScalarBuffer};
+use arrow_schema::{Field, Schema};
+
+use crate::bridge::raw_bridge::RawBridge;
+use crate::page_reader::row_group_reader::{ColumnValues, RowGroupReader, RowGroupSelection};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::timestamp::{TimestampType, TimestampUnit};
+
+impl RowGroupReader<'_> {
+    /// Convert the selected columns to an Arrow RecordBatch, one PrimitiveArray per column in
+    /// the order of the column indices. The field is nullable if the column is optional, and the
+    /// null bitmap follows the validity decoded from the definition levels.
+    pub fn to_record_batch(
+        &self,
+        selection: &RowGroupSelection,
+    ) -> Result<RecordBatch, BoltReaderError> {
+        let mut fields = Vec::new();
+        let mut arrays: Vec<ArrayRef> = Vec::new();
+        for column_index in selection.get_column_indices() {
+            let column = &self.get_schema_tree().get_column_descriptors()[column_index];
+            let array: ArrayRef = match selection.get_column(column_index) {
+                Some(ColumnValues::Int32(bridge)) => {
+                    Arc::new(to_primitive_array::<Int32Type>(bridge))
+                }
+                Some(ColumnValues::Date32(bridge)) => {
+                    Arc::new(to_primitive_array::<Date32Type>(bridge))
+                }
+                Some(ColumnValues::Int64(bridge)) => {
+                    Arc::new(to_primitive_array::<Int64Type>(bridge))
+                }
+                Some(ColumnValues::Timestamp(bridge, timestamp_type)) => {
+                    to_timestamp_array(bridge, timestamp_type)
+                }
+                Some(ColumnValues::Time(bridge, time_type)) => match time_type.get_unit() {
+                    TimestampUnit::Micros => {
+                        Arc::new(to_primitive_array::<Time64MicrosecondType>(bridge))
+                    }
+                    TimestampUnit::Nanos => {
+                        Arc::new(to_primitive_array::<Time64NanosecondType>(bridge))
+                    }
+                    TimestampUnit::Millis => {
+                        return Err(BoltReaderError::NotYetImplementedError(format!(
+                            "Converting the INT64 TIME(MILLIS) column: {}",
+                            column.get_path_string()
+                        )))
+                    }
+                },
+                Some(ColumnValues::Float(bridge)) => {
+                    Arc::new(to_primitive_array::<Float32Type>(bridge))
+                }
+                Some(ColumnValues::Double(bridge)) => {
+                    Arc::new(to_primitive_array::<Float64Type>(bridge))
+                }
+                None => {
+                    return Err(BoltReaderError::RowGroupError(format!(
+                        "Column: {} is not selected",
+                        column_index
+                    )))
+                }
+            };
+
+            fields.push(Field::new(
+                column.get_path_string(),
+                array.data_type().clone(),
+                column.get_max_definition_level() > 0,
+            ));
+            arrays.push(array);
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).map_err(|err| {
+            BoltReaderError::RowGroupError(format!("Unable to create the record batch: {}", err))
+        })
+    }
+}
+
+fn to_primitive_array<A: ArrowPrimitiveType>(bridge: &RawBridge<A::Native>) -> PrimitiveArray<A> {
+    let values = bridge.get_raw_result();
+    let validity = bridge.get_raw_validity();
+
+    // The values after the end of the validity are not null.
+    let nulls = if validity.iter().all(|valid| *valid) {
+        None
+    } else {
+        let mut builder = BooleanBufferBuilder::new(values.len());
+        builder.append_slice(validity);
+        builder.append_n(values.len() - validity.len(), true);
+        Some(NullBuffer::new(builder.finish()))
+    };
+
+    PrimitiveArray::<A>::new(ScalarBuffer::from(values.to_vec()), nulls)
+}
+
+// The array has the unit of the timestamp type. The timestamps adjusted to UTC have the UTC
+// time zone, the local timestamps have none.
+fn to_timestamp_array(bridge: &RawBridge<i64>, timestamp_type: &TimestampType) -> ArrayRef {
+    match timestamp_type.get_unit() {
+        TimestampUnit::Millis => Arc::new(to_timestamp_unit_array::<TimestampMillisecondType>(
+            bridge,
+            timestamp_type,
+        )),
+        TimestampUnit::Micros => Arc::new(to_timestamp_unit_array::<TimestampMicrosecondType>(
+            bridge,
+            timestamp_type,
+        )),
+        TimestampUnit::Nanos => Arc::new(to_timestamp_unit_array::<TimestampNanosecondType>(
+            bridge,
+            timestamp_type,
+        )),
+    }
+}
+
+fn to_timestamp_unit_array<A: ArrowTimestampType>(
+    bridge: &RawBridge<i64>,
+    timestamp_type: &TimestampType,
+) -> PrimitiveArray<A> {
+    let array = to_primitive_array::<A>(bridge);
+    if timestamp_type.is_adjusted_to_utc() {
+        array.with_timezone("UTC")
+    } else {
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::projection_mask::ProjectionMask;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::row_group_reader::RowGroupReader;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::RowRangeSet;
+
+    #[test]
+    fn test_to_record_batch() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        reader.project(&ProjectionMask::all(&schema_tree)).unwrap();
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(290, 310);
+        ranges.add_row_ranges(995, 1000);
+        let selection = reader.read_with_selection(&ranges).unwrap();
+
+        let res = reader.to_record_batch(&selection);
+        assert!(res.is_ok());
+        let record_batch = res.unwrap();
+
+        // The id is i, and the value is i * 0.5 or null if i % 10 == 7.
+        let rows: Vec<i64> = (290..310).chain(995..1000).collect();
+        let ids: ArrayRef = Arc::new(Int64Array::from(rows.clone()));
+        let values: ArrayRef = Arc::new(Float64Array::from(
+            rows.iter()
+                .map(|i| (i % 10 != 7).then_some(*i as f64 * 0.5))
+                .collect::<Vec<Option<f64>>>(),
+        ));
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("value", DataType::Float64, true),
+        ]);
+        let expected = RecordBatch::try_new(Arc::new(schema), vec![ids, values]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 25);
+        assert_eq!(record_batch.column(0).null_count(), 0);
+        assert_eq!(record_batch.column(1).null_count(), 3);
+        assert_eq!(record_batch, expected);
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::metadata::parquet_metadata_thrift::PageHeader;
+use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
+    destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
+};
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::exceptions::BoltReaderError;
+
+/// The Repeated Primitive Reader segments the values of a PLAIN encoded Data Page V1 of a
+/// repeated primitive column, e.g. list<int32>, into the rows by the repetition levels alone,
+/// which is lighter than the ListColumnReader for the common case. The repetition level 0 starts
+/// a new row, so the max repetition level is 1.
+/// current_offset: the offset in the whole column
+///
+/// Only the levels at the max definition level have a value, so the empty lists, the null lists
+/// and the null elements are all left out of the rows. The ListColumnReader tells them apart.
+pub struct RepeatedPrimitiveReader<T> {
+    num_values: usize,
+    current_offset: usize,
+    // The first row continues the last row of the previous page.
+    continues_previous_row: bool,
+    rows: Vec<Vec<T>>,
+}
+
+#[allow(dead_code)]
+impl<T> std::fmt::Display for RepeatedPrimitiveReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Repeated Primitive Reader: num_values {}, current_offset {}, num_rows {}, continues_previous_row {}",
+            self.num_values,
+            self.current_offset,
+            self.rows.len(),
+            self.continues_previous_row
+        )
+    }
+}
+
+impl<T: 'static + std::marker::Copy + Default> RepeatedPrimitiveReader<T> {
+    pub fn new(
+        page_header: &PageHeader,
+        buffer: &mut dyn ByteBufferBase,
+        current_offset: usize,
+        max_definition_level: u32,
+    ) -> Result<RepeatedPrimitiveReader<T>, BoltReaderError> {
+        let data_page = FixedLengthPlainDataPageReaderV1::<T>::new_with_levels(
+            page_header,
+            buffer,
+            current_offset,
+            mem::size_of::<T>(),
+            1,
+            max_definition_level,
+            None,
+        )?;
+
+        let repetition_levels = data_page.get_repetition_levels();
+        let definition_levels = data_page.get_definition_levels();
+        let continues_previous_row = repetition_levels.first().is_some_and(|l| *l != 0);
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        if continues_previous_row {
+            rows.push(Vec::new());
+        }
+
+        for (i, repetition_level) in repetition_levels.iter().enumerate() {
+            if *repetition_level == 0 {
+                rows.push(Vec::new());
+            }
+
+            if definition_levels[i] as u32 == max_definition_level {
+                if let Some(row) = rows.last_mut() {
+                    row.push(data_page.get_value(i));
+                }
+            }
+        }
+
+        let num_values = repetition_levels.len();
+        destroy_fixed_length_plain_data_page_v1(data_page);
+
+        Ok(RepeatedPrimitiveReader {
+            num_values,
+            current_offset,
+            continues_previous_row,
+            rows,
+        })
+    }
+}
+
+impl<T> RepeatedPrimitiveReader<T> {
+    /// The number of the levels in the page, including the levels without a value.
+    pub fn get_num_values(&self) -> usize {
+        self.num_values
+    }
+
+    pub fn get_num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// If true, the first row has the trailing values of the last row of the previous page, as
+    /// the Data Page V1 may split a row across the pages.
+    pub fn continues_previous_row(&self) -> bool {
+        self.continues_previous_row
+    }
+
+    pub fn get_row(&self, index: usize) -> &[T] {
+        &self.rows[index]
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        self.rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytebuffer::Endian::LittleEndian;
+    use parquet2::encoding::hybrid_rle::encode_u32;
+
+    use crate::metadata::parquet_metadata_thrift::{
+        DataPageHeader, Encoding, PageHeader, PageType,
+    };
+    use crate::page_reader::repeated_primitive_reader::RepeatedPrimitiveReader;
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+
+    fn encode_levels(levels: &[u32], bit_width: u32) -> Vec<u8> {
+        let mut encoded = vec![];
+        encode_u32(&mut encoded, levels.iter().cloned(), bit_width).unwrap();
+        let mut res = (encoded.len() as u32).to_le_bytes().to_vec();
+        res.extend(encoded);
+        res
+    }
+
+    fn create_page(
+        repetition_levels: &[u32],
+        definition_levels: &[u32],
+        definition_bit_width: u32,
+        values: &[i32],
+    ) -> (PageHeader, DirectByteBuffer) {
+        let mut page = encode_levels(repetition_levels, 1);
+        page.extend(encode_levels(definition_levels, definition_bit_width));
+        page.extend(values.iter().flat_map(|v| v.to_le_bytes()));
+
+        let page_header = PageHeader::new(
+            PageType::DATA_PAGE,
+            page.len() as i32,
+            page.len() as i32,
+            None,
+            DataPageHeader::new(
+                repetition_levels.len() as i32,
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::RLE,
+                None,
+            ),
+            None,
+            None,
+            None,
+        );
+        let mut buf = DirectByteBuffer::from_vec(page);
+        buf.set_endian(LittleEndian);
+        (page_header, buf)
+    }
+
+    #[test]
+    fn test_read_repeated_primitive_page() {
+        // repeated int32 col
+        // Rows: [1, 2], [3], [], [4, 5, 6], [7]
+        let repetition_levels: [u32; 8] = [0, 1, 0, 0, 0, 1, 1, 0];
+        let definition_levels: [u32; 8] = [1, 1, 1, 0, 1, 1, 1, 1];
+        let (page_header, mut buf) = create_page(
+            &repetition_levels,
+            &definition_levels,
+            1,
+            &[1, 2, 3, 4, 5, 6, 7],
+        );
+
+        let res = RepeatedPrimitiveReader::<i32>::new(&page_header, &mut buf, 0, 1);
+        assert!(res.is_ok());
+        let reader = res.unwrap();
+        assert_eq!(buf.get_rpos(), buf.len());
+        assert_eq!(reader.get_num_values(), 8);
+        assert_eq!(reader.get_num_rows(), 5);
+        assert!(!reader.continues_previous_row());
+        assert_eq!(
+            reader.to_string(),
+            "Repeated Primitive Reader: num_values 8, current_offset 0, num_rows 5, continues_previous_row false\n"
+        );
+
+        assert_eq!(reader.get_row(0), &[1, 2]);
+        assert_eq!(reader.get_row(1), &[3]);
+        assert!(reader.get_row(2).is_empty());
+        assert_eq!(reader.get_row(3), &[4, 5, 6]);
+        assert_eq!(reader.get_row(4), &[7]);
+    }
+
+    #[test]
+    fn test_read_repeated_primitive_page_continued() {
+        // optional group col (LIST) { repeated group list { optional int32 element; } }
+        // The page starts in the middle of a row: [.., 1, 2], [3, null], null, [], [4]
+        let repetition_levels: [u32; 8] = [1, 1, 0, 1, 0, 0, 0, 0];
+        let definition_levels: [u32; 8] = [3, 3, 3, 2, 0, 1, 3, 3];
+        let (page_header, mut buf) =
+            create_page(&repetition_levels, &definition_levels, 2, &[1, 2, 3, 4, 5]);
+
+        let res = RepeatedPrimitiveReader::<i32>::new(&page_header, &mut buf, 100, 3);
+        assert!(res.is_ok());
+        let reader = res.unwrap();
+        assert!(reader.continues_previous_row());
+        assert_eq!(reader.get_num_rows(), 6);
+
+        // The null element, the null list and the empty list have no values.
+        assert_eq!(
+            reader.into_rows(),
+            vec![vec![1, 2], vec![3], vec![], vec![], vec![4], vec![5]]
+        );
+    }
+}
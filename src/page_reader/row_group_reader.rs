@@ -0,0 +1,1438 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::max;
+use std::fmt::Formatter;
+#[cfg(feature = "parallel")]
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::bridge::bridge_base::Bridge;
+use crate::bridge::raw_bridge::RawBridge;
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::metadata::offset_index::{read_offset_index, OffsetIndex};
+use crate::metadata::parquet_metadata_thrift::Type;
+use crate::metadata::projection_mask::ProjectionMask;
+use crate::metadata::row_group::RowGroupMetaData;
+use crate::metadata::schema::SchemaTree;
+use crate::page_reader::column_chunk_reader::{
+    destroy_column_chunk_data_page, ColumnChunkDataPage, ColumnChunkReader,
+};
+use crate::page_reader::data_page_v1::data_page_base::DataPage;
+use crate::page_reader::decompression::Decompressors;
+use crate::utils::cancellation_token::CancellationToken;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::read_metrics::ReadMetrics;
+use crate::utils::row_range_set::{RowRange, RowRangeSet, RowRangeSetGenerator};
+use crate::utils::timestamp::{TimeType, TimestampType};
+
+/// The logical types interpreted when the columns are read. By default, the columns are read in
+/// their physical types.
+/// read_dates: read the INT32 DATE columns as ColumnValues::Date32
+/// read_timestamps: read the INT64 TIMESTAMP columns as ColumnValues::Timestamp
+/// read_times: read the INT64 TIME columns as ColumnValues::Time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LogicalTypeOptions {
+    pub read_dates: bool,
+    pub read_timestamps: bool,
+    pub read_times: bool,
+}
+
+/// The selected values of a projected column, in the bridge of its physical type, or of its
+/// logical type enabled by the LogicalTypeOptions.
+pub enum ColumnValues {
+    Int32(RawBridge<i32>),
+    Date32(RawBridge<i32>),
+    Int64(RawBridge<i64>),
+    Timestamp(RawBridge<i64>, TimestampType),
+    Time(RawBridge<i64>, TimeType),
+    Float(RawBridge<f32>),
+    Double(RawBridge<f64>),
+}
+
+impl ColumnValues {
+    pub fn get_size(&self) -> usize {
+        match self {
+            ColumnValues::Int32(bridge) => bridge.get_size(),
+            ColumnValues::Date32(bridge) => bridge.get_size(),
+            ColumnValues::Int64(bridge) => bridge.get_size(),
+            ColumnValues::Timestamp(bridge, _) => bridge.get_size(),
+            ColumnValues::Time(bridge, _) => bridge.get_size(),
+            ColumnValues::Float(bridge) => bridge.get_size(),
+            ColumnValues::Double(bridge) => bridge.get_size(),
+        }
+    }
+
+    /// The days since the Unix epoch of the index-th selected row of a Date32 column. The value
+    /// at the null position is 0, the validity is in the bridge.
+    pub fn get_date(&self, index: usize) -> Result<i32, BoltReaderError> {
+        match self {
+            ColumnValues::Date32(bridge) => get_raw_value(bridge, index),
+            _ => Err(BoltReaderError::RowGroupError(String::from(
+                "Only the Date32 column values have dates",
+            ))),
+        }
+    }
+
+    /// The index-th selected row of a Timestamp column, scaled from its unit to nanoseconds.
+    /// The value at the null position is 0, the validity is in the bridge.
+    pub fn get_timestamp_nanos(&self, index: usize) -> Result<i64, BoltReaderError> {
+        match self {
+            ColumnValues::Timestamp(bridge, timestamp_type) => {
+                timestamp_type.to_nanos(get_raw_value(bridge, index)?)
+            }
+            _ => Err(BoltReaderError::RowGroupError(String::from(
+                "Only the Timestamp column values have timestamps",
+            ))),
+        }
+    }
+
+    /// The unit and the UTC flag of a Timestamp column, None for the other columns.
+    pub fn get_timestamp_type(&self) -> Option<TimestampType> {
+        match self {
+            ColumnValues::Timestamp(_, timestamp_type) => Some(*timestamp_type),
+            _ => None,
+        }
+    }
+
+    /// The microseconds since midnight of the index-th selected row of a Time column, the NANOS
+    /// values are truncated to microseconds. The value at the null position is 0, the validity is
+    /// in the bridge.
+    pub fn get_time_micros(&self, index: usize) -> Result<i64, BoltReaderError> {
+        match self {
+            ColumnValues::Time(bridge, time_type) => {
+                time_type.to_micros(get_raw_value(bridge, index)?)
+            }
+            _ => Err(BoltReaderError::RowGroupError(String::from(
+                "Only the Time column values have times",
+            ))),
+        }
+    }
+
+    /// The unit and the UTC flag of a Time column, None for the other columns.
+    pub fn get_time_type(&self) -> Option<TimeType> {
+        match self {
+            ColumnValues::Time(_, time_type) => Some(*time_type),
+            _ => None,
+        }
+    }
+}
+
+fn get_raw_value<T: 'static + std::marker::Copy + Default>(
+    bridge: &RawBridge<T>,
+    index: usize,
+) -> Result<T, BoltReaderError> {
+    match bridge.get_raw_result().get(index) {
+        Some(value) => Ok(*value),
+        None => Err(BoltReaderError::RowGroupError(format!(
+            "Index: {} is out of bound, number of values: {}",
+            index,
+            bridge.get_size()
+        ))),
+    }
+}
+
+/// The rows selected from a row group. All the columns hold the same rows in the same order, so
+/// the values of a row are looked up with the same index and the shared row range set.
+pub struct RowGroupSelection {
+    row_range_set: RowRangeSet,
+    columns: Vec<(usize, ColumnValues)>,
+}
+
+impl RowGroupSelection {
+    /// The selected rows of the row group, sorted and non-overlapping.
+    pub fn get_row_range_set(&self) -> &RowRangeSet {
+        &self.row_range_set
+    }
+
+    pub fn get_num_rows(&self) -> usize {
+        self.row_range_set.count_rows()
+    }
+
+    pub fn get_column_indices(&self) -> Vec<usize> {
+        self.columns.iter().map(|(index, _)| *index).collect()
+    }
+
+    pub fn get_column(&self, column_index: usize) -> Option<&ColumnValues> {
+        self.columns
+            .iter()
+            .find(|(index, _)| *index == column_index)
+            .map(|(_, values)| values)
+    }
+}
+
+/// The Row Group Reader loads the column chunks of a row group. Only the projected column
+/// chunks are loaded, so the bytes of the other columns are never read from the file.
+pub struct RowGroupReader<'a> {
+    file: &'a dyn LoadFile,
+    row_group: &'a RowGroupMetaData,
+    schema_tree: &'a SchemaTree,
+    column_chunks: Vec<Option<DirectByteBuffer>>,
+    logical_type_options: LogicalTypeOptions,
+    cancellation_token: Option<CancellationToken>,
+    read_metrics: Option<Arc<ReadMetrics>>,
+    decompressors: Decompressors,
+}
+
+#[allow(dead_code)]
+impl<'a> std::fmt::Display for RowGroupReader<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Row Group Reader: path {}, num_rows {}, num_columns {}, loaded columns {:?}",
+            self.file.get_file_path(),
+            self.row_group.num_rows(),
+            self.row_group.num_columns(),
+            self.get_loaded_column_indices()
+        )
+    }
+}
+
+impl<'a> RowGroupReader<'a> {
+    pub fn new(
+        file: &'a dyn LoadFile,
+        row_group: &'a RowGroupMetaData,
+        schema_tree: &'a SchemaTree,
+    ) -> Result<RowGroupReader<'a>, BoltReaderError> {
+        if unlikely(row_group.num_columns() != schema_tree.get_num_columns()) {
+            return Err(BoltReaderError::RowGroupError(format!(
+                "The row group has {} column chunks, but the schema has {} columns",
+                row_group.num_columns(),
+                schema_tree.get_num_columns()
+            )));
+        }
+
+        let mut column_chunks = Vec::with_capacity(row_group.num_columns());
+        column_chunks.resize_with(row_group.num_columns(), || None);
+
+        Ok(RowGroupReader {
+            file,
+            row_group,
+            schema_tree,
+            column_chunks,
+            logical_type_options: LogicalTypeOptions::default(),
+            cancellation_token: None,
+            read_metrics: None,
+            decompressors: Decompressors::default(),
+        })
+    }
+
+    pub fn get_logical_type_options(&self) -> LogicalTypeOptions {
+        self.logical_type_options
+    }
+
+    pub fn set_logical_type_options(&mut self, logical_type_options: LogicalTypeOptions) {
+        self.logical_type_options = logical_type_options;
+    }
+
+    /// Read the INT32 columns with the DATE logical type as ColumnValues::Date32, instead of
+    /// ColumnValues::Int32.
+    pub fn set_read_dates(&mut self, read_dates: bool) {
+        self.logical_type_options.read_dates = read_dates;
+    }
+
+    /// Read the INT64 columns with the TIMESTAMP logical type as ColumnValues::Timestamp, with
+    /// the unit and the UTC flag from the schema.
+    pub fn set_read_timestamps(&mut self, read_timestamps: bool) {
+        self.logical_type_options.read_timestamps = read_timestamps;
+    }
+
+    /// Read the INT64 columns with the TIME logical type as ColumnValues::Time, with the unit and
+    /// the UTC flag from the schema. The INT32 TIME(MILLIS) columns are read as INT32.
+    pub fn set_read_times(&mut self, read_times: bool) {
+        self.logical_type_options.read_times = read_times;
+    }
+
+    /// The token is passed to the column chunk readers, so the reads return Cancelled before the
+    /// next page once the token is cancelled.
+    pub fn set_cancellation_token(&mut self, cancellation_token: CancellationToken) {
+        self.cancellation_token = Some(cancellation_token);
+    }
+
+    /// The metrics are passed to the column chunk readers, which count the decoded pages, and
+    /// the pages skipped by the selection are counted as pruned.
+    pub fn set_read_metrics(&mut self, read_metrics: Arc<ReadMetrics>) {
+        self.read_metrics = Some(read_metrics);
+    }
+
+    /// The decompressors are passed to the column chunk readers, which decompress the pages with
+    /// the custom decompressors registered in them.
+    pub fn set_decompressors(&mut self, decompressors: Decompressors) {
+        self.decompressors = decompressors;
+    }
+
+    pub fn get_num_rows(&self) -> usize {
+        self.row_group.num_rows() as usize
+    }
+
+    pub fn get_row_group(&self) -> &RowGroupMetaData {
+        self.row_group
+    }
+
+    pub fn get_schema_tree(&self) -> &SchemaTree {
+        self.schema_tree
+    }
+
+    pub fn is_loaded(&self, column_index: usize) -> bool {
+        column_index < self.column_chunks.len() && self.column_chunks[column_index].is_some()
+    }
+
+    pub fn get_loaded_column_indices(&self) -> Vec<usize> {
+        (0..self.column_chunks.len())
+            .filter(|index| self.is_loaded(*index))
+            .collect()
+    }
+
+    /// Load the column chunks selected by the mask. The chunks already loaded are kept, and the
+    /// loaded chunks outside the mask are released.
+    pub fn project(&mut self, mask: &ProjectionMask) -> Result<(), BoltReaderError> {
+        if unlikely(mask.get_num_columns() != self.column_chunks.len()) {
+            return Err(BoltReaderError::RowGroupError(format!(
+                "The projection mask has {} columns, but the row group has {} columns",
+                mask.get_num_columns(),
+                self.column_chunks.len()
+            )));
+        }
+
+        for index in 0..self.column_chunks.len() {
+            if !mask.is_projected(index) {
+                self.column_chunks[index] = None;
+                continue;
+            }
+
+            if self.column_chunks[index].is_none() {
+                self.column_chunks[index] = Some(self.load_column_chunk(index)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load_column_chunk(&self, column_index: usize) -> Result<DirectByteBuffer, BoltReaderError> {
+        let column_chunk = self.row_group.column(column_index)?;
+        DirectByteBuffer::from_file(
+            self.file,
+            column_chunk.column_chunk_offset(),
+            column_chunk.total_compressed_size(),
+        )
+    }
+
+    /// Move the loaded column chunk into a ColumnChunkReader. The column needs to be projected
+    /// again to create another reader.
+    pub fn get_column_chunk_reader<T: 'static + std::marker::Copy + Default>(
+        &mut self,
+        column_index: usize,
+    ) -> Result<ColumnChunkReader<T>, BoltReaderError> {
+        if unlikely(!self.is_loaded(column_index)) {
+            return Err(BoltReaderError::RowGroupError(format!(
+                "Column: {} is not projected",
+                column_index
+            )));
+        }
+
+        let column = &self.schema_tree.get_column_descriptors()[column_index];
+        if unlikely(column.get_max_repetition_level() > 0) {
+            return Err(BoltReaderError::NotYetImplementedError(format!(
+                "Reading the repeated column: {}",
+                column.get_path_string()
+            )));
+        }
+        let buffer = self.column_chunks[column_index].take().unwrap();
+
+        let mut reader = ColumnChunkReader::from_buffer(
+            self.row_group.column(column_index)?,
+            buffer,
+            column.get_max_definition_level() as u32,
+        )?;
+        if let Some(cancellation_token) = &self.cancellation_token {
+            reader.set_cancellation_token(cancellation_token.clone());
+        }
+        if let Some(read_metrics) = &self.read_metrics {
+            reader.set_read_metrics(read_metrics.clone());
+        }
+        reader.set_decompressors(self.decompressors.clone());
+        Ok(reader)
+    }
+
+    /// Read the selected rows of all the projected columns. The ranges are row indexes in the
+    /// row group after adding the offset of the row range set. The rows beyond the row group
+    /// are ignored.
+    ///
+    /// The projected column chunks are consumed, so they need to be projected again to be read
+    /// another time.
+    pub fn read_with_selection(
+        &mut self,
+        ranges: &RowRangeSet,
+    ) -> Result<RowGroupSelection, BoltReaderError> {
+        let selection = self.get_selected_rows(ranges);
+        let num_rows = selection.count_rows();
+
+        let mut columns = Vec::new();
+        for column_index in self.get_loaded_column_indices() {
+            if let Some(cancellation_token) = &self.cancellation_token {
+                cancellation_token.check()?;
+            }
+            let column = &self.schema_tree.get_column_descriptors()[column_index];
+            let options = self.logical_type_options;
+            let timestamp_type = column
+                .get_timestamp_type()
+                .filter(|_| options.read_timestamps);
+            let time_type = column.get_time_type().filter(|_| options.read_times);
+            let values = match column.get_physical_type() {
+                Type::INT32 if options.read_dates && column.is_date() => ColumnValues::Date32(
+                    self.read_column_values(column_index, &selection, num_rows)?,
+                ),
+                Type::INT32 => ColumnValues::Int32(self.read_column_values(
+                    column_index,
+                    &selection,
+                    num_rows,
+                )?),
+                Type::INT64 => {
+                    let bridge = self.read_column_values(column_index, &selection, num_rows)?;
+                    match (timestamp_type, time_type) {
+                        (Some(timestamp_type), _) => {
+                            ColumnValues::Timestamp(bridge, timestamp_type)
+                        }
+                        (_, Some(time_type)) => ColumnValues::Time(bridge, time_type),
+                        _ => ColumnValues::Int64(bridge),
+                    }
+                }
+                Type::FLOAT => ColumnValues::Float(self.read_column_values(
+                    column_index,
+                    &selection,
+                    num_rows,
+                )?),
+                Type::DOUBLE => ColumnValues::Double(self.read_column_values(
+                    column_index,
+                    &selection,
+                    num_rows,
+                )?),
+                physical_type => {
+                    return Err(BoltReaderError::NotYetImplementedError(format!(
+                        "Reading the column: {} of physical type: {} with selection",
+                        column.get_path_string(),
+                        physical_type.0
+                    )))
+                }
+            };
+            columns.push((column_index, values));
+        }
+
+        Ok(RowGroupSelection {
+            row_range_set: selection,
+            columns,
+        })
+    }
+
+    /// Read the selected rows of all the projected columns, which pass all the filters. A filter
+    /// is a column index with the filter of its values, and the null rows pass only if the filter
+    /// allows nulls.
+    ///
+    /// The filter columns need not be projected. They are loaded transiently to evaluate the
+    /// filters and released afterwards, so only the projected columns are in the selection.
+    pub fn read_with_filters(
+        &mut self,
+        ranges: &RowRangeSet,
+        filters: &[(usize, &dyn FixedLengthRangeFilter)],
+    ) -> Result<RowGroupSelection, BoltReaderError> {
+        let mut selection = self.get_selected_rows(ranges);
+        for (column_index, filter) in filters {
+            if selection.count_rows() == 0 {
+                break;
+            }
+            selection = self.evaluate_filter(*column_index, *filter, &selection)?;
+        }
+
+        self.read_with_selection(&selection)
+    }
+
+    // Return the selected rows passing the filter. The projected column chunk is kept to be read
+    // after the filters, and the chunk loaded for the filter only is released.
+    fn evaluate_filter(
+        &mut self,
+        column_index: usize,
+        filter: &dyn FixedLengthRangeFilter,
+        selection: &RowRangeSet,
+    ) -> Result<RowRangeSet, BoltReaderError> {
+        if unlikely(column_index >= self.column_chunks.len()) {
+            return Err(BoltReaderError::RowGroupError(format!(
+                "Filter column: {} is out of bound, number of columns: {}",
+                column_index,
+                self.column_chunks.len()
+            )));
+        }
+
+        let projected = self.column_chunks[column_index].is_some();
+        if !projected {
+            self.column_chunks[column_index] = Some(self.load_column_chunk(column_index)?);
+        }
+
+        let column = &self.schema_tree.get_column_descriptors()[column_index];
+        let res = match column.get_physical_type() {
+            Type::INT32 => self.filter_column_values::<i32>(
+                column_index,
+                projected,
+                selection,
+                filter,
+                |value| filter.check_i32(value),
+            ),
+            Type::INT64 => self.filter_column_values::<i64>(
+                column_index,
+                projected,
+                selection,
+                filter,
+                |value| filter.check_i64(value),
+            ),
+            Type::FLOAT => self.filter_column_values::<f32>(
+                column_index,
+                projected,
+                selection,
+                filter,
+                |value| filter.check_f32(value),
+            ),
+            Type::DOUBLE => self.filter_column_values::<f64>(
+                column_index,
+                projected,
+                selection,
+                filter,
+                |value| filter.check_f64(value),
+            ),
+            physical_type => Err(BoltReaderError::NotYetImplementedError(format!(
+                "Filtering the column: {} of physical type: {}",
+                column.get_path_string(),
+                physical_type.0
+            ))),
+        };
+        if !projected {
+            self.column_chunks[column_index] = None;
+        }
+
+        res
+    }
+
+    // Filter the values of the loaded column chunk. The projected column chunk is taken back
+    // from the reader, so it is read again after the filters without a copy.
+    fn filter_column_values<T: 'static + std::marker::Copy + Default>(
+        &mut self,
+        column_index: usize,
+        projected: bool,
+        selection: &RowRangeSet,
+        filter: &dyn FixedLengthRangeFilter,
+        check: impl Fn(T) -> bool,
+    ) -> Result<RowRangeSet, BoltReaderError> {
+        let mut reader = self.get_column_chunk_reader::<T>(column_index)?;
+        let bridge = self
+            .read_column_offset_index(column_index)
+            .and_then(|offset_index| {
+                decode_column_values(
+                    &mut reader,
+                    offset_index.as_ref(),
+                    column_index,
+                    selection,
+                    self.get_num_rows(),
+                    selection.count_rows(),
+                )
+            });
+        if projected {
+            self.column_chunks[column_index] = Some(reader.into_buffer());
+        }
+        let bridge = bridge?;
+        let values = bridge.get_raw_result();
+        let validity = bridge.get_raw_validity();
+        let null_allowed = filter.get_null_allowed();
+
+        // The values are in the order of the selected rows, and the validity might be shorter
+        // than the values, see the comment of RawBridge.
+        let mut filtered = RowRangeSet::new(0);
+        let mut index = 0;
+        for range in selection.get_row_ranges() {
+            let mut generator = RowRangeSetGenerator::new(&mut filtered);
+            for row in range.begin..range.end {
+                let valid = validity.get(index).copied().unwrap_or(true);
+                generator.update(
+                    row,
+                    if valid {
+                        check(values[index])
+                    } else {
+                        null_allowed
+                    },
+                );
+                index += 1;
+            }
+            generator.finish(range.end);
+        }
+
+        Ok(filtered)
+    }
+
+    /// Read the selected rows of a projected column into the bridge. With the OffsetIndex of
+    /// the column chunk, the pages without any selected row are skipped without being decoded.
+    pub fn read_column_with_selection<T: 'static + std::marker::Copy + Default>(
+        &mut self,
+        column_index: usize,
+        ranges: &RowRangeSet,
+        result_row_range_set: &mut RowRangeSet,
+        result_bridge: &mut dyn Bridge<T>,
+    ) -> Result<(), BoltReaderError> {
+        let selection = self.get_selected_rows(ranges);
+        let mut reader = self.get_column_chunk_reader::<T>(column_index)?;
+        let offset_index = self.read_column_offset_index(column_index)?;
+
+        read_column_chunk_with_selection(
+            &mut reader,
+            offset_index.as_ref(),
+            column_index,
+            &selection,
+            self.get_num_rows(),
+            result_row_range_set,
+            result_bridge,
+        )
+    }
+
+    /// Read all the rows of the columns selected by the mask, and decode the columns in
+    /// parallel on the rayon global thread pool, so the number of threads does not grow with
+    /// the number of columns.
+    ///
+    /// The file loader may not be shared across threads, so the column chunks and their
+    /// OffsetIndex are loaded on the calling thread before the decoding starts.
+    #[cfg(feature = "parallel")]
+    pub fn read_columns_parallel(
+        &mut self,
+        mask: &ProjectionMask,
+    ) -> Result<RowGroupSelection, BoltReaderError> {
+        self.project(mask)?;
+        let num_rows = self.get_num_rows();
+        let mut selection = RowRangeSet::new(0);
+        selection.add_row_ranges(0, num_rows);
+
+        let mut column_indices = Vec::new();
+        let mut tasks: Vec<ColumnTask<'_>> = Vec::new();
+        for column_index in self.get_loaded_column_indices() {
+            let column = &self.schema_tree.get_column_descriptors()[column_index];
+            let offset_index = self.read_column_offset_index(column_index)?;
+            let options = self.logical_type_options;
+            let timestamp_type = column
+                .get_timestamp_type()
+                .filter(|_| options.read_timestamps);
+            let time_type = column.get_time_type().filter(|_| options.read_times);
+            let task = match column.get_physical_type() {
+                Type::INT32 if options.read_dates && column.is_date() => create_column_task(
+                    self.get_column_chunk_reader::<i32>(column_index)?,
+                    offset_index,
+                    column_index,
+                    &selection,
+                    num_rows,
+                    ColumnValues::Date32,
+                ),
+                Type::INT32 => create_column_task(
+                    self.get_column_chunk_reader::<i32>(column_index)?,
+                    offset_index,
+                    column_index,
+                    &selection,
+                    num_rows,
+                    ColumnValues::Int32,
+                ),
+                Type::INT64 => {
+                    let reader = self.get_column_chunk_reader::<i64>(column_index)?;
+                    match (timestamp_type, time_type) {
+                        (Some(timestamp_type), _) => create_column_task(
+                            reader,
+                            offset_index,
+                            column_index,
+                            &selection,
+                            num_rows,
+                            move |bridge| ColumnValues::Timestamp(bridge, timestamp_type),
+                        ),
+                        (_, Some(time_type)) => create_column_task(
+                            reader,
+                            offset_index,
+                            column_index,
+                            &selection,
+                            num_rows,
+                            move |bridge| ColumnValues::Time(bridge, time_type),
+                        ),
+                        _ => create_column_task(
+                            reader,
+                            offset_index,
+                            column_index,
+                            &selection,
+                            num_rows,
+                            ColumnValues::Int64,
+                        ),
+                    }
+                }
+                Type::FLOAT => create_column_task(
+                    self.get_column_chunk_reader::<f32>(column_index)?,
+                    offset_index,
+                    column_index,
+                    &selection,
+                    num_rows,
+                    ColumnValues::Float,
+                ),
+                Type::DOUBLE => create_column_task(
+                    self.get_column_chunk_reader::<f64>(column_index)?,
+                    offset_index,
+                    column_index,
+                    &selection,
+                    num_rows,
+                    ColumnValues::Double,
+                ),
+                physical_type => {
+                    return Err(BoltReaderError::NotYetImplementedError(format!(
+                        "Reading the column: {} of physical type: {} in parallel",
+                        column.get_path_string(),
+                        physical_type.0
+                    )))
+                }
+            };
+            column_indices.push(column_index);
+            tasks.push(task);
+        }
+
+        let results: Vec<Result<ColumnValues, BoltReaderError>> = tasks
+            .into_par_iter()
+            .map(|task| match panic::catch_unwind(AssertUnwindSafe(task)) {
+                Ok(res) => res,
+                Err(_) => Err(BoltReaderError::InternalError(String::from(
+                    "The column decoding thread panicked",
+                ))),
+            })
+            .collect();
+
+        let mut columns = Vec::with_capacity(results.len());
+        for (column_index, res) in column_indices.into_iter().zip(results) {
+            columns.push((column_index, res?));
+        }
+
+        Ok(RowGroupSelection {
+            row_range_set: selection,
+            columns,
+        })
+    }
+
+    fn read_column_values<T: 'static + std::marker::Copy + Default>(
+        &mut self,
+        column_index: usize,
+        selection: &RowRangeSet,
+        num_rows: usize,
+    ) -> Result<RawBridge<T>, BoltReaderError> {
+        let mut reader = self.get_column_chunk_reader::<T>(column_index)?;
+        let offset_index = self.read_column_offset_index(column_index)?;
+
+        decode_column_values(
+            &mut reader,
+            offset_index.as_ref(),
+            column_index,
+            selection,
+            self.get_num_rows(),
+            num_rows,
+        )
+    }
+
+    fn read_column_offset_index(
+        &self,
+        column_index: usize,
+    ) -> Result<Option<OffsetIndex>, BoltReaderError> {
+        let column_chunk = self.row_group.column(column_index)?;
+        match (
+            column_chunk.offset_index_offset(),
+            column_chunk.offset_index_length(),
+        ) {
+            (Some(offset), Some(length)) => Ok(Some(read_offset_index(self.file, offset, length)?)),
+            _ => Ok(None),
+        }
+    }
+
+    // Sort and merge the ranges with offset 0, and clip them to the rows of the row group.
+    fn get_selected_rows(&self, ranges: &RowRangeSet) -> RowRangeSet {
+        let mut row_group_range_set = RowRangeSet::new(0);
+        row_group_range_set.add_row_ranges(0, self.get_num_rows());
+        row_group_range_set.intersect(ranges)
+    }
+}
+
+#[cfg(feature = "parallel")]
+type ColumnTask<'s> = Box<dyn FnOnce() -> Result<ColumnValues, BoltReaderError> + Send + 's>;
+
+#[cfg(feature = "parallel")]
+fn create_column_task<'s, T: 'static + std::marker::Copy + Default + Send + Sync>(
+    mut reader: ColumnChunkReader<T>,
+    offset_index: Option<OffsetIndex>,
+    column_index: usize,
+    selection: &'s RowRangeSet,
+    num_rows: usize,
+    to_column_values: impl FnOnce(RawBridge<T>) -> ColumnValues + Send + 's,
+) -> ColumnTask<'s> {
+    Box::new(move || {
+        decode_column_values(
+            &mut reader,
+            offset_index.as_ref(),
+            column_index,
+            selection,
+            num_rows,
+            selection.count_rows(),
+        )
+        .map(to_column_values)
+    })
+}
+
+// Decode the selected rows of the column chunk. Every column has to produce exactly the
+// selected rows to keep the rows aligned.
+fn decode_column_values<T: 'static + std::marker::Copy + Default>(
+    reader: &mut ColumnChunkReader<T>,
+    offset_index: Option<&OffsetIndex>,
+    column_index: usize,
+    selection: &RowRangeSet,
+    row_group_num_rows: usize,
+    num_rows: usize,
+) -> Result<RawBridge<T>, BoltReaderError> {
+    let mut result_row_range_set = RowRangeSet::new(0);
+    let mut result_bridge = RawBridge::new(false, num_rows);
+    read_column_chunk_with_selection(
+        reader,
+        offset_index,
+        column_index,
+        selection,
+        row_group_num_rows,
+        &mut result_row_range_set,
+        &mut result_bridge,
+    )?;
+
+    if unlikely(result_bridge.get_size() != num_rows) {
+        return Err(BoltReaderError::RowGroupError(format!(
+            "Column: {} has read {} rows, expected {} selected rows",
+            column_index,
+            result_bridge.get_size(),
+            num_rows
+        )));
+    }
+
+    Ok(result_bridge)
+}
+
+// Read the selected rows of the column chunk, the selection has offset 0 and is clipped to the
+// row group. With the OffsetIndex, the pages without any selected row are skipped.
+fn read_column_chunk_with_selection<T: 'static + std::marker::Copy + Default>(
+    reader: &mut ColumnChunkReader<T>,
+    offset_index: Option<&OffsetIndex>,
+    column_index: usize,
+    selection: &RowRangeSet,
+    num_rows: usize,
+    result_row_range_set: &mut RowRangeSet,
+    result_bridge: &mut dyn Bridge<T>,
+) -> Result<(), BoltReaderError> {
+    match offset_index {
+        Some(offset_index) => {
+            for (page_index, location) in offset_index.page_locations().iter().enumerate() {
+                let page_range = offset_index.get_page_row_range(page_index, num_rows)?;
+                if selection
+                    .iter_for_page(page_range.begin, page_range.end, 0)
+                    .next()
+                    .is_none()
+                {
+                    if let Some(read_metrics) = reader.get_read_metrics() {
+                        read_metrics.add_pages_pruned(1);
+                    }
+                    continue;
+                }
+
+                reader.skip_to_page(location)?;
+                match reader.next_page()? {
+                    Some(data_page) => read_selected_rows(
+                        data_page,
+                        selection,
+                        result_row_range_set,
+                        result_bridge,
+                    )?,
+                    None => {
+                        return Err(BoltReaderError::RowGroupError(format!(
+                            "Column: {} has no data page at offset: {}",
+                            column_index, location.offset
+                        )))
+                    }
+                }
+            }
+        }
+        None => {
+            while let Some(data_page) = reader.next_page()? {
+                read_selected_rows(data_page, selection, result_row_range_set, result_bridge)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Read the rows of the data page covered by the selection, which has offset 0. The data page is
+// destroyed before the error is returned.
+fn read_selected_rows<T: 'static + std::marker::Copy + Default>(
+    data_page: ColumnChunkDataPage<'_, T>,
+    selection: &RowRangeSet,
+    result_row_range_set: &mut RowRangeSet,
+    result_bridge: &mut dyn Bridge<T>,
+) -> Result<(), BoltReaderError> {
+    let page_begin = data_page.get_data_page_offset();
+    let page_end = page_begin + data_page.get_data_page_num_values();
+
+    let mut res = Ok(());
+    for range in selection.get_row_ranges() {
+        if range.end <= page_begin {
+            continue;
+        }
+        if range.begin >= page_end {
+            break;
+        }
+
+        let to_read = RowRange::new(max(range.begin, page_begin), range.end);
+        res = match data_page.get_data_page_covered_range(page_begin, page_end, 0, &to_read) {
+            Ok(Some(covered)) if !covered.is_empty() => {
+                data_page.read(covered, 0, result_row_range_set, result_bridge)
+            }
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if res.is_err() {
+            break;
+        }
+    }
+
+    destroy_column_chunk_data_page(data_page);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::bridge::raw_bridge::RawBridge;
+    use crate::filters::float_point_range_filter::FloatPointRangeFilter;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::projection_mask::ProjectionMask;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::column_chunk_reader::{
+        destroy_column_chunk_data_page, ColumnChunkReader,
+    };
+    use crate::page_reader::data_page_v1::data_page_base::DataPage;
+    use crate::page_reader::row_group_reader::{ColumnValues, RowGroupReader};
+    use crate::utils::cancellation_token::CancellationToken;
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::{RowRange, RowRangeSet};
+    use crate::utils::test_utils::RecordingFileLoader;
+
+    fn read_all<T: 'static + std::marker::Copy + Default>(
+        mut reader: ColumnChunkReader<T>,
+    ) -> (RowRangeSet, RawBridge<T>) {
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut raw_bridge = RawBridge::new(true, reader.get_num_values());
+        let mut offset = 0;
+        while let Some(data_page) = reader.next_page().unwrap() {
+            let num_values = data_page.get_data_page_num_values();
+            data_page
+                .read(
+                    RowRange::new(offset, offset + num_values),
+                    0,
+                    &mut result_row_range_set,
+                    &mut raw_bridge,
+                )
+                .unwrap();
+            destroy_column_chunk_data_page(data_page);
+            offset += num_values;
+        }
+        (result_row_range_set, raw_bridge)
+    }
+
+    #[test]
+    fn test_project_row_group() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        let file = RecordingFileLoader::new(file);
+        let res = RowGroupReader::new(&file, row_group, &schema_tree);
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+        assert!(file.get_ranges().is_empty());
+
+        // Project orderkey and quantity out of the 22 columns.
+        let mask = ProjectionMask::from_paths(&schema_tree, &["orderkey", "quantity"]).unwrap();
+        assert!(reader.project(&mask).is_ok());
+        assert_eq!(reader.get_loaded_column_indices(), vec![0, 4]);
+
+        let expected: Vec<(usize, usize)> = [0, 4]
+            .iter()
+            .map(|&index| {
+                let column = row_group.column(index).unwrap();
+                (column.column_chunk_offset(), column.total_compressed_size())
+            })
+            .collect();
+        assert_eq!(file.get_ranges(), expected);
+
+        // The loaded column chunks are not loaded again.
+        assert!(reader.project(&mask).is_ok());
+        assert_eq!(file.get_ranges().len(), 2);
+
+        let res = reader.get_column_chunk_reader::<i64>(0);
+        assert!(res.is_ok());
+        let (_, raw_bridge) = read_all(res.unwrap());
+        assert_eq!(raw_bridge.get_size(), reader.get_num_rows());
+        assert!(!reader.is_loaded(0));
+
+        let (rrs, raw_bridge) = read_all(reader.get_column_chunk_reader::<f64>(4).unwrap());
+        assert_eq!(raw_bridge.get_size(), reader.get_num_rows());
+        for i in 0..raw_bridge.get_size() {
+            let (valid, value) = raw_bridge.get_validity_and_value(0, i, &rrs).unwrap();
+            if valid {
+                assert!((1.0..=50.0).contains(&value));
+            }
+        }
+
+        // Reading the file has only touched the projected column chunks.
+        assert_eq!(file.get_ranges(), expected);
+    }
+
+    #[test]
+    fn test_project_row_group_errors() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        let mask = ProjectionMask::new(&schema_tree, &[1]).unwrap();
+        assert!(reader.project(&mask).is_ok());
+
+        let res = reader.get_column_chunk_reader::<i64>(0);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Row Group Error: Column: 0 is not projected\n"
+        );
+
+        // Projecting another mask releases the column chunks outside of it.
+        let mask = ProjectionMask::new(&schema_tree, &[0]).unwrap();
+        assert!(reader.project(&mask).is_ok());
+        assert_eq!(reader.get_loaded_column_indices(), vec![0]);
+        assert_eq!(
+            reader.to_string(),
+            "Row Group Reader: path src/sample_files/lineitem.parquet, num_rows 11212, num_columns 22, loaded columns [0]\n"
+        );
+
+        let res = reader.get_column_chunk_reader::<i64>(22);
+        assert!(res.is_err());
+    }
+
+    fn get_selected_rows(row_range_set: &RowRangeSet) -> Vec<usize> {
+        row_range_set
+            .get_row_ranges()
+            .iter()
+            .flat_map(|range| range.begin..range.end)
+            .collect()
+    }
+
+    #[test]
+    fn test_read_with_selection() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        // The id pages start at the rows 0, 300 and 600, and the value pages at 0, 250, 500
+        // and 750.
+        assert!(row_group.column(0).unwrap().offset_index_offset().is_some());
+        assert!(row_group.column(1).unwrap().offset_index_offset().is_some());
+
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        reader.project(&ProjectionMask::all(&schema_tree)).unwrap();
+
+        // The ranges are unsorted, cross the page boundaries of either column, and exceed the
+        // row group.
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(498, 503);
+        ranges.add_row_ranges(5, 12);
+        ranges.add_row_ranges(295, 305);
+        ranges.add_row_ranges(8, 10);
+        ranges.add_row_ranges(995, 1005);
+
+        let res = reader.read_with_selection(&ranges);
+        assert!(res.is_ok());
+        let selection = res.unwrap();
+        assert_eq!(selection.get_column_indices(), vec![0, 1]);
+        assert_eq!(selection.get_num_rows(), 27);
+        let rows = get_selected_rows(selection.get_row_range_set());
+        assert_eq!(rows.len(), 27);
+
+        let ids = match selection.get_column(0) {
+            Some(ColumnValues::Int64(bridge)) => bridge,
+            _ => panic!("The id column should be INT64"),
+        };
+        let values = match selection.get_column(1) {
+            Some(ColumnValues::Double(bridge)) => bridge,
+            _ => panic!("The value column should be DOUBLE"),
+        };
+        assert_eq!(ids.get_size(), 27);
+        assert_eq!(values.get_size(), 27);
+
+        // The row N of both columns is the same row of the row group.
+        for row in rows {
+            let (valid, id) = ids
+                .get_validity_and_value(0, row, selection.get_row_range_set())
+                .unwrap();
+            assert!(valid);
+            assert_eq!(id, row as i64);
+
+            let (valid, value) = values
+                .get_validity_and_value(0, row, selection.get_row_range_set())
+                .unwrap();
+            assert_eq!(valid, row % 10 != 7);
+            if valid {
+                assert_eq!(value, row as f64 * 0.5);
+            }
+        }
+
+        // The column chunks are consumed by the read.
+        assert!(reader.get_loaded_column_indices().is_empty());
+    }
+
+    #[test]
+    fn test_read_with_selection_without_offset_index() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        assert!(row_group.column(0).unwrap().offset_index_offset().is_none());
+
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        let mask = ProjectionMask::from_paths(&schema_tree, &["orderkey", "quantity"]).unwrap();
+        reader.project(&mask).unwrap();
+        let (expected_rrs, expected_orderkeys) =
+            read_all(reader.get_column_chunk_reader::<i64>(0).unwrap());
+        let (_, expected_quantities) = read_all(reader.get_column_chunk_reader::<f64>(4).unwrap());
+
+        reader.project(&mask).unwrap();
+        // The ranges are relative to the offset of the row range set.
+        let mut ranges = RowRangeSet::new(100);
+        ranges.add_row_ranges(0, 1);
+        ranges.add_row_ranges(1000, 1234);
+        ranges.add_row_ranges(11000, 11200);
+        let selection = reader.read_with_selection(&ranges).unwrap();
+        assert_eq!(selection.get_num_rows(), 347);
+
+        let orderkeys = match selection.get_column(0) {
+            Some(ColumnValues::Int64(bridge)) => bridge,
+            _ => panic!("The orderkey column should be INT64"),
+        };
+        let quantities = match selection.get_column(4) {
+            Some(ColumnValues::Double(bridge)) => bridge,
+            _ => panic!("The quantity column should be DOUBLE"),
+        };
+        assert_eq!(selection.get_column(1).map(ColumnValues::get_size), None);
+
+        for row in get_selected_rows(selection.get_row_range_set()) {
+            assert_eq!(
+                orderkeys
+                    .get_validity_and_value(0, row, selection.get_row_range_set())
+                    .unwrap(),
+                expected_orderkeys
+                    .get_validity_and_value(0, row, &expected_rrs)
+                    .unwrap()
+            );
+            assert_eq!(
+                quantities
+                    .get_validity_and_value(0, row, selection.get_row_range_set())
+                    .unwrap(),
+                expected_quantities
+                    .get_validity_and_value(0, row, &expected_rrs)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_with_filters() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(0, 1000);
+
+        // Project the value column, and filter on the id column, which is not projected.
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        reader
+            .project(&ProjectionMask::new(&schema_tree, &[1]).unwrap())
+            .unwrap();
+        let filter = IntegerRangeFilter::new(100, 400, false);
+        let selection = reader.read_with_filters(&ranges, &[(0, &filter)]).unwrap();
+        assert_eq!(selection.get_column_indices(), vec![1]);
+        assert_eq!(selection.get_num_rows(), 301);
+        assert_eq!(
+            get_selected_rows(selection.get_row_range_set()),
+            (100..=400).collect::<Vec<usize>>()
+        );
+
+        let values = match selection.get_column(1) {
+            Some(ColumnValues::Double(bridge)) => bridge,
+            _ => panic!("The value column should be DOUBLE"),
+        };
+        for row in 100..=400 {
+            let (valid, value) = values
+                .get_validity_and_value(0, row, selection.get_row_range_set())
+                .unwrap();
+            assert_eq!(valid, row % 10 != 7);
+            if valid {
+                assert_eq!(value, row as f64 * 0.5);
+            }
+        }
+        assert!(reader.get_loaded_column_indices().is_empty());
+
+        // Filter on both columns, and the projected filter column is still read. The null values
+        // do not pass the filter.
+        reader.project(&ProjectionMask::all(&schema_tree)).unwrap();
+        let value_filter = FloatPointRangeFilter::new(100.0, 150.0, true, true, true, true, false);
+        let id_filter = IntegerRangeFilter::new(250, 1000, false);
+        let selection = reader
+            .read_with_filters(&ranges, &[(1, &value_filter), (0, &id_filter)])
+            .unwrap();
+        assert_eq!(selection.get_column_indices(), vec![0, 1]);
+        let rows = get_selected_rows(selection.get_row_range_set());
+        let expected: Vec<usize> = (250..=300).filter(|row| row % 10 != 7).collect();
+        assert_eq!(rows, expected);
+        assert_eq!(rows.len(), 46);
+
+        let ids = match selection.get_column(0) {
+            Some(ColumnValues::Int64(bridge)) => bridge,
+            _ => panic!("The id column should be INT64"),
+        };
+        for row in rows {
+            assert_eq!(
+                ids.get_validity_and_value(0, row, selection.get_row_range_set())
+                    .unwrap(),
+                (true, row as i64)
+            );
+        }
+
+        let res = reader.read_with_filters(&ranges, &[(2, &id_filter)]);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Row Group Error: Filter column: 2 is out of bound, number of columns: 2\n"
+        );
+    }
+
+    #[test]
+    fn test_read_with_filters_column_chunk_loads() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = RecordingFileLoader::new(LocalFileLoader::new(&path).unwrap());
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let chunk_ranges: Vec<(usize, usize)> = (0..2)
+            .map(|column_index| {
+                let column_chunk = row_group.column(column_index).unwrap();
+                (
+                    column_chunk.column_chunk_offset(),
+                    column_chunk.total_compressed_size(),
+                )
+            })
+            .collect();
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(0, 1000);
+        let id_filter = IntegerRangeFilter::new(250, 1000, false);
+        let value_filter = FloatPointRangeFilter::new(100.0, 150.0, true, true, true, true, false);
+
+        // The projected filter columns are read from the loaded column chunks.
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        reader.project(&ProjectionMask::all(&schema_tree)).unwrap();
+        let num_loads = file.get_ranges().len();
+        let selection = reader
+            .read_with_filters(&ranges, &[(1, &value_filter), (0, &id_filter)])
+            .unwrap();
+        assert_eq!(selection.get_column_indices(), vec![0, 1]);
+        assert_eq!(selection.get_num_rows(), 46);
+        let loads = &file.get_ranges()[num_loads..];
+        assert!(chunk_ranges.iter().all(|range| !loads.contains(range)));
+
+        // The filter column which is not projected is loaded once, and released afterwards.
+        reader
+            .project(&ProjectionMask::new(&schema_tree, &[1]).unwrap())
+            .unwrap();
+        let num_loads = file.get_ranges().len();
+        let selection = reader
+            .read_with_filters(&ranges, &[(0, &id_filter)])
+            .unwrap();
+        assert_eq!(selection.get_column_indices(), vec![1]);
+        assert_eq!(selection.get_num_rows(), 750);
+        let loads = &file.get_ranges()[num_loads..];
+        assert_eq!(
+            loads
+                .iter()
+                .filter(|range| **range == chunk_ranges[0])
+                .count(),
+            1
+        );
+        assert!(!loads.contains(&chunk_ranges[1]));
+        assert!(reader.get_loaded_column_indices().is_empty());
+    }
+
+    // Cancel the token when the first page is read into the bridge.
+    struct CancellingBridge {
+        bridge: RawBridge<i64>,
+        cancellation_token: CancellationToken,
+    }
+
+    impl Bridge<i64> for CancellingBridge {
+        fn get_bridge_name(&self) -> String {
+            String::from("Cancelling Bridge")
+        }
+
+        fn is_empty(&self) -> bool {
+            self.bridge.is_empty()
+        }
+
+        fn get_size(&self) -> usize {
+            self.bridge.get_size()
+        }
+
+        fn may_has_null(&self) -> bool {
+            self.bridge.may_has_null()
+        }
+
+        fn append_result(&mut self, validity: bool, result: i64) {
+            self.bridge.append_result(validity, result);
+        }
+
+        fn append_results(
+            &mut self,
+            validity: &[bool],
+            result: &[i64],
+        ) -> Result<(), BoltReaderError> {
+            self.cancellation_token.cancel();
+            self.bridge.append_results(validity, result)
+        }
+    }
+
+    #[test]
+    fn test_cancel_read_with_selection() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(0, 1000);
+
+        // The id pages start at the rows 0, 300 and 600, and the token is cancelled while the
+        // first page is read, so the later pages are not read.
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        let cancellation_token = CancellationToken::new();
+        reader.set_cancellation_token(cancellation_token.clone());
+        reader.project(&ProjectionMask::all(&schema_tree)).unwrap();
+        let mut result_row_range_set = RowRangeSet::new(0);
+        let mut result_bridge = CancellingBridge {
+            bridge: RawBridge::new(false, 1000),
+            cancellation_token,
+        };
+        let res = reader.read_column_with_selection(
+            0,
+            &ranges,
+            &mut result_row_range_set,
+            &mut result_bridge,
+        );
+        assert!(matches!(res, Err(BoltReaderError::Cancelled)));
+        assert_eq!(result_bridge.get_size(), 300);
+
+        // The read does not start with the cancelled token.
+        let res = reader.read_with_selection(&ranges);
+        assert!(matches!(res, Err(BoltReaderError::Cancelled)));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Cancelled: the read is cancelled\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_read_columns_parallel() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+        let row_group = file_metadata.row_group(0).unwrap();
+
+        // The INT64, INT32, DOUBLE and FLOAT columns.
+        let column_indices = [0, 1, 2, 3, 4, 5, 6, 7, 18, 19, 20, 21];
+        let mask = ProjectionMask::new(&schema_tree, &column_indices).unwrap();
+
+        let mut reader = RowGroupReader::new(&file, row_group, &schema_tree).unwrap();
+        reader.project(&mask).unwrap();
+        let mut ranges = RowRangeSet::new(0);
+        ranges.add_row_ranges(0, reader.get_num_rows());
+        let sequential = reader.read_with_selection(&ranges).unwrap();
+
+        let res = reader.read_columns_parallel(&mask);
+        assert!(res.is_ok());
+        let parallel = res.unwrap();
+        assert_eq!(parallel.get_column_indices(), column_indices);
+        assert_eq!(parallel.get_num_rows(), 11212);
+        assert!(reader.get_loaded_column_indices().is_empty());
+
+        for column_index in column_indices {
+            let sequential_values = sequential.get_column(column_index).unwrap();
+            let parallel_values = parallel.get_column(column_index).unwrap();
+            assert_eq!(parallel_values.get_size(), 11212);
+            match (sequential_values, parallel_values) {
+                (ColumnValues::Int32(expected), ColumnValues::Int32(actual)) => {
+                    assert_eq!(expected.get_raw_result(), actual.get_raw_result());
+                    assert_eq!(expected.get_raw_validity(), actual.get_raw_validity());
+                }
+                (ColumnValues::Int64(expected), ColumnValues::Int64(actual)) => {
+                    assert_eq!(expected.get_raw_result(), actual.get_raw_result());
+                    assert_eq!(expected.get_raw_validity(), actual.get_raw_validity());
+                }
+                (ColumnValues::Float(expected), ColumnValues::Float(actual)) => {
+                    assert_eq!(expected.get_raw_result(), actual.get_raw_result());
+                    assert_eq!(expected.get_raw_validity(), actual.get_raw_validity());
+                }
+                (ColumnValues::Double(expected), ColumnValues::Double(actual)) => {
+                    assert_eq!(expected.get_raw_result(), actual.get_raw_result());
+                    assert_eq!(expected.get_raw_validity(), actual.get_raw_validity());
+                }
+                _ => panic!("Column: {} has different physical types", column_index),
+            }
+        }
+
+        // The BYTE_ARRAY columns are not supported yet.
+        let mask = ProjectionMask::new(&schema_tree, &[0, 8]).unwrap();
+        let res = reader.read_columns_parallel(&mask);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Not Yet Implemented: Reading the column: returnflag of physical type: 6 in parallel\n"
+        );
+    }
+}
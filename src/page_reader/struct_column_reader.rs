@@ -0,0 +1,550 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::mem;
+
+use crate::metadata::page_header::{check_page_size, read_page_header, DEFAULT_MAX_PAGE_SIZE};
+use crate::metadata::parquet_metadata_thrift::{
+    CompressionCodec, FieldRepetitionType, PageHeader, PageType, Type,
+};
+use crate::metadata::row_group::RowGroupMetaData;
+use crate::metadata::schema::SchemaTree;
+use crate::page_reader::data_page_v1::fixed_length_plain_data_page_v1::{
+    destroy_fixed_length_plain_data_page_v1, FixedLengthPlainDataPageReaderV1,
+};
+use crate::page_reader::data_page_v1::variable_length_plain_data_page_v1::VariableLengthPlainDataPageReaderV1;
+use crate::page_reader::decompression::decompress_page;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::rep_def_parser::RepDefParser;
+
+/// The values of a struct field, in the type of its physical type. The value at a null position
+/// is the default value, e.g. 0 or an empty byte array.
+pub enum StructFieldValues {
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    ByteArray(Vec<Vec<u8>>),
+}
+
+impl StructFieldValues {
+    pub fn get_size(&self) -> usize {
+        match self {
+            StructFieldValues::Int32(values) => values.len(),
+            StructFieldValues::Int64(values) => values.len(),
+            StructFieldValues::Float(values) => values.len(),
+            StructFieldValues::Double(values) => values.len(),
+            StructFieldValues::ByteArray(values) => values.len(),
+        }
+    }
+}
+
+/// A field of the struct column, with a value at every row. The field is null when the struct
+/// is null.
+pub struct StructField {
+    name: String,
+    validity: Vec<bool>,
+    values: StructFieldValues,
+}
+
+impl StructField {
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_validity(&self) -> &[bool] {
+        &self.validity
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        !self.validity[index]
+    }
+
+    pub fn get_values(&self) -> &StructFieldValues {
+        &self.values
+    }
+}
+
+/// A struct column in the Arrow layout. The row N of the struct is assembled from the row N of
+/// every field.
+pub struct StructColumn {
+    validity: Vec<bool>,
+    fields: Vec<StructField>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for StructColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Struct Column: num_rows {}, num_null_rows {}, fields {:?}",
+            self.get_num_rows(),
+            self.validity.iter().filter(|valid| !**valid).count(),
+            self.fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<&str>>()
+        )
+    }
+}
+
+impl StructColumn {
+    pub fn get_num_rows(&self) -> usize {
+        self.validity.len()
+    }
+
+    pub fn get_validity(&self) -> &[bool] {
+        &self.validity
+    }
+
+    pub fn is_null(&self, index: usize) -> bool {
+        !self.validity[index]
+    }
+
+    pub fn get_fields(&self) -> &Vec<StructField> {
+        &self.fields
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<&StructField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+// The column chunk of a leaf field of the struct.
+struct StructFieldReader {
+    name: String,
+    path: String,
+    physical_type: Type,
+    codec: CompressionCodec,
+    num_values: usize,
+    max_definition_level: u32,
+    buffer: DirectByteBuffer,
+}
+
+impl StructFieldReader {
+    // Read the definition levels and the values of all the data pages. read_page decodes the
+    // values of the page body starting from the rpos of the page buffer, after the definition
+    // levels of the page.
+    fn read_pages<T, F>(&mut self, mut read_page: F) -> Result<(Vec<u32>, Vec<T>), BoltReaderError>
+    where
+        F: FnMut(
+            &PageHeader,
+            &mut DirectByteBuffer,
+            usize,
+            &[u32],
+        ) -> Result<Vec<T>, BoltReaderError>,
+    {
+        let mut definition_levels = Vec::with_capacity(self.num_values);
+        let mut values = Vec::with_capacity(self.num_values);
+
+        while definition_levels.len() < self.num_values
+            && self.buffer.get_rpos() < self.buffer.len()
+        {
+            let page_header = read_page_header(&mut self.buffer)?;
+            check_page_size(&page_header, DEFAULT_MAX_PAGE_SIZE)?;
+            let body_begin = self.buffer.get_rpos();
+            if unlikely(
+                page_header.compressed_page_size < 0
+                    || page_header.uncompressed_page_size < 0
+                    || body_begin + page_header.compressed_page_size as usize > self.buffer.len(),
+            ) {
+                return Err(BoltReaderError::ColumnChunkError(format!(
+                    "Column chunk: {} has invalid page size: {} bytes at offset: {}, column chunk size: {} bytes",
+                    self.path,
+                    page_header.compressed_page_size,
+                    body_begin,
+                    self.buffer.len()
+                )));
+            }
+            let body_end = body_begin + page_header.compressed_page_size as usize;
+
+            match page_header.type_ {
+                PageType::DATA_PAGE => {
+                    let num_values = match &page_header.data_page_header {
+                        Some(header) => header.num_values as usize,
+                        None => {
+                            return Err(BoltReaderError::ColumnChunkError(String::from(
+                                "Error when reading Data Page V1 Header",
+                            )))
+                        }
+                    };
+
+                    let mut decompressed;
+                    let page_buffer: &mut DirectByteBuffer = match self.codec {
+                        CompressionCodec::UNCOMPRESSED => &mut self.buffer,
+                        _ => {
+                            decompressed = decompress_page(
+                                &self.buffer.as_bytes()[body_begin..body_end],
+                                self.codec,
+                                page_header.uncompressed_page_size as usize,
+                            )?;
+                            &mut decompressed
+                        }
+                    };
+
+                    let mut page_levels = if self.max_definition_level == 0 {
+                        vec![0; num_values]
+                    } else {
+                        RepDefParser::load_level(page_buffer, self.max_definition_level)?
+                    };
+                    if unlikely(page_levels.len() < num_values) {
+                        return Err(BoltReaderError::RepDefError(format!(
+                            "Data page of: {} with {} values has {} definition levels",
+                            self.path,
+                            num_values,
+                            page_levels.len()
+                        )));
+                    }
+                    page_levels.truncate(num_values);
+
+                    values.append(&mut read_page(
+                        &page_header,
+                        page_buffer,
+                        definition_levels.len(),
+                        &page_levels,
+                    )?);
+                    definition_levels.append(&mut page_levels);
+                }
+                PageType::DICTIONARY_PAGE | PageType::DATA_PAGE_V2 => {
+                    return Err(BoltReaderError::NotYetImplementedError(format!(
+                        "Struct field: {} page of type: {}",
+                        self.path, page_header.type_.0
+                    )))
+                }
+                _ => {}
+            }
+            self.buffer.set_rpos(body_end);
+        }
+
+        Ok((definition_levels, values))
+    }
+
+    fn read_fixed_length<T: 'static + std::marker::Copy + Default>(
+        &mut self,
+    ) -> Result<(Vec<u32>, Vec<T>), BoltReaderError> {
+        let max_definition_level = self.max_definition_level;
+        self.read_pages(|page_header, page_buffer, current_offset, levels| {
+            let data_page =
+                FixedLengthPlainDataPageReaderV1::<T>::new_with_loaded_definition_levels(
+                    page_header,
+                    page_buffer,
+                    current_offset,
+                    mem::size_of::<T>(),
+                    max_definition_level,
+                    levels,
+                    None,
+                )?;
+            let values = (0..levels.len()).map(|i| data_page.get_value(i)).collect();
+            destroy_fixed_length_plain_data_page_v1(data_page);
+            Ok(values)
+        })
+    }
+
+    fn read_byte_array(&mut self) -> Result<(Vec<u32>, Vec<Vec<u8>>), BoltReaderError> {
+        let max_definition_level = self.max_definition_level;
+        self.read_pages(|page_header, page_buffer, current_offset, levels| {
+            let validity: Vec<bool> = levels
+                .iter()
+                .map(|level| *level == max_definition_level)
+                .collect();
+            let has_null = validity.iter().any(|valid| !*valid);
+            let data_page = VariableLengthPlainDataPageReaderV1::new(
+                page_header,
+                page_buffer,
+                current_offset,
+                has_null,
+                Some(validity),
+            )?;
+            Ok((0..levels.len())
+                .map(|i| {
+                    data_page
+                        .get_value(i)
+                        .map(<[u8]>::to_vec)
+                        .unwrap_or_default()
+                })
+                .collect())
+        })
+    }
+
+    fn read(&mut self) -> Result<(Vec<u32>, StructFieldValues), BoltReaderError> {
+        match self.physical_type {
+            Type::INT32 => {
+                let (levels, values) = self.read_fixed_length::<i32>()?;
+                Ok((levels, StructFieldValues::Int32(values)))
+            }
+            Type::INT64 => {
+                let (levels, values) = self.read_fixed_length::<i64>()?;
+                Ok((levels, StructFieldValues::Int64(values)))
+            }
+            Type::FLOAT => {
+                let (levels, values) = self.read_fixed_length::<f32>()?;
+                Ok((levels, StructFieldValues::Float(values)))
+            }
+            Type::DOUBLE => {
+                let (levels, values) = self.read_fixed_length::<f64>()?;
+                Ok((levels, StructFieldValues::Double(values)))
+            }
+            Type::BYTE_ARRAY => {
+                let (levels, values) = self.read_byte_array()?;
+                Ok((levels, StructFieldValues::ByteArray(values)))
+            }
+            physical_type => Err(BoltReaderError::NotYetImplementedError(format!(
+                "Struct field: {} of physical type: {}",
+                self.path, physical_type.0
+            ))),
+        }
+    }
+}
+
+/// The Struct Column Reader loads the column chunks of the leaf fields of a struct, e.g.
+/// optional group s { optional int32 a; optional binary b; }, and aligns them by row with the
+/// definition levels.
+///
+/// A definition level below the level of the struct nulls the whole struct, and a level below
+/// the max definition level of a field nulls the field only. The fields of nested groups and
+/// the repeated fields are not supported yet.
+pub struct StructColumnReader {
+    path: String,
+    // The definition level of the struct. The levels below it are the null structs.
+    struct_definition_level: u32,
+    fields: Vec<StructFieldReader>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for StructColumnReader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Struct Column Reader: path {}, struct_definition_level {}, fields {:?}",
+            self.path,
+            self.struct_definition_level,
+            self.fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<&str>>()
+        )
+    }
+}
+
+impl StructColumnReader {
+    /// The path is the names from the top level field to the struct, e.g. ["s"].
+    pub fn new(
+        row_group: &RowGroupMetaData,
+        schema_tree: &SchemaTree,
+        path: &[&str],
+        file: &dyn LoadFile,
+    ) -> Result<StructColumnReader, BoltReaderError> {
+        let path_string = path.join(".");
+        let mut struct_definition_level = None;
+        let mut fields = Vec::new();
+
+        for column in schema_tree.get_column_descriptors() {
+            let column_path = column.get_path();
+            if column_path.len() <= path.len()
+                || column_path.iter().zip(path.iter()).any(|(a, b)| a != b)
+            {
+                continue;
+            }
+
+            if unlikely(column_path.len() > path.len() + 1) {
+                return Err(BoltReaderError::NotYetImplementedError(format!(
+                    "Struct: {} with the nested group field: {}",
+                    path_string,
+                    column.get_path_string()
+                )));
+            }
+            if unlikely(column.get_max_repetition_level() != 0) {
+                return Err(BoltReaderError::NotYetImplementedError(format!(
+                    "Struct: {} with the repeated field: {}",
+                    path_string,
+                    column.get_path_string()
+                )));
+            }
+
+            // The optional field takes one more definition level than the struct.
+            let max_definition_level = column.get_max_definition_level() as u32;
+            if struct_definition_level.is_none() {
+                struct_definition_level =
+                    if column.get_repetition_type() == Some(FieldRepetitionType::OPTIONAL) {
+                        Some(max_definition_level - 1)
+                    } else {
+                        Some(max_definition_level)
+                    };
+            }
+
+            let column_chunk = row_group.column(column.get_column_index())?;
+            fields.push(StructFieldReader {
+                name: column_path[path.len()].clone(),
+                path: column.get_path_string(),
+                physical_type: column.get_physical_type(),
+                codec: column_chunk.compression(),
+                num_values: column_chunk.num_values(),
+                max_definition_level,
+                buffer: DirectByteBuffer::from_file(
+                    file,
+                    column_chunk.column_chunk_offset(),
+                    column_chunk.total_compressed_size(),
+                )?,
+            });
+        }
+
+        match struct_definition_level {
+            Some(struct_definition_level) => Ok(StructColumnReader {
+                path: path_string,
+                struct_definition_level,
+                fields,
+            }),
+            None => Err(BoltReaderError::MetadataError(format!(
+                "Struct: {} has no leaf fields in the schema",
+                path_string
+            ))),
+        }
+    }
+
+    /// Read all the rows of the struct column.
+    pub fn read_struct_column(&mut self) -> Result<StructColumn, BoltReaderError> {
+        let mut validity: Option<Vec<bool>> = None;
+        let mut fields = Vec::with_capacity(self.fields.len());
+
+        for field in &mut self.fields {
+            let (definition_levels, values) = field.read()?;
+            let struct_validity: Vec<bool> = definition_levels
+                .iter()
+                .map(|level| *level >= self.struct_definition_level)
+                .collect();
+
+            match &validity {
+                Some(validity) if unlikely(*validity != struct_validity) => {
+                    return Err(BoltReaderError::RepDefError(format!(
+                        "Struct: {} field: {} is not aligned with the other fields",
+                        self.path, field.name
+                    )))
+                }
+                Some(_) => {}
+                None => validity = Some(struct_validity),
+            }
+
+            fields.push(StructField {
+                name: field.name.clone(),
+                validity: definition_levels
+                    .iter()
+                    .map(|level| *level == field.max_definition_level)
+                    .collect(),
+                values,
+            });
+        }
+
+        Ok(StructColumn {
+            validity: validity.unwrap_or_default(),
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::struct_column_reader::{StructColumnReader, StructFieldValues};
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    #[test]
+    fn test_read_struct_column() {
+        // The rows of s: {a: 1, b: x}, null, {a: null, b: y}, {a: 4, b: null}, {a: 5, b: z}
+        let path = String::from("src/sample_files/struct_int32_string.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+
+        let res =
+            StructColumnReader::new(&file_metadata.row_groups()[0], &schema_tree, &["s"], &file);
+        assert!(res.is_ok());
+        let mut reader = res.unwrap();
+        assert_eq!(
+            reader.to_string(),
+            "Struct Column Reader: path s, struct_definition_level 1, fields [\"a\", \"b\"]\n"
+        );
+
+        let res = reader.read_struct_column();
+        assert!(res.is_ok());
+        let column = res.unwrap();
+        assert_eq!(column.get_num_rows(), 5);
+        assert_eq!(column.get_validity(), &[true, false, true, true, true]);
+        assert!(column.is_null(1));
+        assert_eq!(
+            column.to_string(),
+            "Struct Column: num_rows 5, num_null_rows 1, fields [\"a\", \"b\"]\n"
+        );
+
+        let a = column.get_field("a").unwrap();
+        assert_eq!(a.get_validity(), &[true, false, false, true, true]);
+        match a.get_values() {
+            StructFieldValues::Int32(values) => assert_eq!(values, &[1, 0, 0, 4, 5]),
+            _ => panic!("Field a should be Int32"),
+        }
+
+        let b = column.get_field("b").unwrap();
+        assert_eq!(b.get_validity(), &[true, false, true, false, true]);
+        assert_eq!(b.get_values().get_size(), 5);
+        match b.get_values() {
+            StructFieldValues::ByteArray(values) => assert_eq!(
+                values,
+                &[b"x".to_vec(), vec![], b"y".to_vec(), vec![], b"z".to_vec()]
+            ),
+            _ => panic!("Field b should be ByteArray"),
+        }
+
+        // The field is null in the null struct, but valid in the struct with another null field.
+        assert!(a.is_null(2) && !b.is_null(2));
+        assert!(!a.is_null(3) && b.is_null(3));
+        assert!(column.get_field("c").is_none());
+    }
+
+    #[test]
+    fn test_read_non_struct_column() {
+        let path = String::from("src/sample_files/struct_int32_string.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let file_metadata = read_file_metadata(&file).unwrap();
+        let schema_tree = SchemaTree::new(file_metadata.schema()).unwrap();
+
+        let res =
+            StructColumnReader::new(&file_metadata.row_groups()[0], &schema_tree, &["id"], &file);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet Metadata Error: Struct: id has no leaf fields in the schema\n"
+        );
+
+        let list_path = String::from("src/sample_files/list_int32.parquet");
+        let list_file = LocalFileLoader::new(&list_path).unwrap();
+        let list_metadata = read_file_metadata(&list_file).unwrap();
+        let list_schema_tree = SchemaTree::new(list_metadata.schema()).unwrap();
+        let res = StructColumnReader::new(
+            &list_metadata.row_groups()[0],
+            &list_schema_tree,
+            &["col", "list"],
+            &list_file,
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Not Yet Implemented: Struct: col.list with the repeated field: col.list.element\n"
+        );
+    }
+}
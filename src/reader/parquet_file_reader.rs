@@ -0,0 +1,488 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::OnceCell;
+use std::cmp::{max, min};
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::filters::fixed_length_filter::FixedLengthRangeFilter;
+use crate::filters::page_pruner::prune_column_chunk;
+use crate::metadata::file_metadata::{read_file_metadata, read_footer, FileMetaData};
+use crate::metadata::projection_mask::ProjectionMask;
+use crate::metadata::row_group::RowGroupMetaData;
+use crate::metadata::schema::SchemaTree;
+use crate::page_reader::decompression::Decompressors;
+use crate::page_reader::row_group_reader::{LogicalTypeOptions, RowGroupReader, RowGroupSelection};
+use crate::reader::reader_builder::ReaderBuilder;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+use crate::utils::metered_file_loader::MeteredFileLoader;
+use crate::utils::read_metrics::ReadMetrics;
+use crate::utils::row_range_set::RowRangeSet;
+
+/// The file level reader. The footer is read and the schema tree is built once when the reader
+/// is created, then each row group is read by a RowGroupReader borrowing them.
+///
+/// The reader is configured by the ReaderBuilder. The row filter is the rows of the whole file,
+/// i.e. the row range [begin, end) selects the rows begin + offset to end + offset.
+///
+/// The reader opened by open_metadata_only loads the footer bytes only, and the row group
+/// metadata needing the page data, i.e. the inferred dictionary page offsets, is completed when
+/// the row group is read.
+///
+/// The bytes loaded through the reader and the pages read by its row group readers are counted
+/// in the ReadMetrics of the reader.
+pub struct ParquetFileReader<'a> {
+    file: MeteredFileLoader<'a>,
+    read_metrics: Arc<ReadMetrics>,
+    file_metadata: FileMetaData,
+    // The row groups completed lazily, for the reader opened by open_metadata_only only.
+    lazy_row_groups: Option<Vec<OnceCell<RowGroupMetaData>>>,
+    schema_tree: SchemaTree,
+    // The index of the first row of each row group in the file.
+    row_group_offsets: Vec<usize>,
+    projection: ProjectionMask,
+    row_filter: Option<RowRangeSet>,
+    batch_size: usize,
+    logical_type_options: LogicalTypeOptions,
+    decompressors: Decompressors,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ParquetFileReader<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Parquet File Reader: path {}, num_rows {}, num_row_groups {}, num_columns {}",
+            self.file.get_file_path(),
+            self.file_metadata.num_rows(),
+            self.num_row_groups(),
+            self.schema_tree.get_num_columns()
+        )
+    }
+}
+
+impl<'a> ParquetFileReader<'a> {
+    /// Create the reader with the default configuration, see ReaderBuilder.
+    pub fn new(file: &'a dyn LoadFile) -> Result<ParquetFileReader<'a>, BoltReaderError> {
+        ReaderBuilder::new(file).build()
+    }
+
+    /// Create the reader with the default configuration, reading nothing but the footer. The
+    /// schema and the statistics are available at once, the row groups are read lazily.
+    pub fn open_metadata_only(
+        file: &'a dyn LoadFile,
+    ) -> Result<ParquetFileReader<'a>, BoltReaderError> {
+        ReaderBuilder::new(file).with_metadata_only().build()
+    }
+
+    pub(crate) fn create(
+        file: &'a dyn LoadFile,
+        projection: Option<ProjectionMask>,
+        row_filter: Option<RowRangeSet>,
+        batch_size: usize,
+        metadata_only: bool,
+        logical_type_options: LogicalTypeOptions,
+        decompressors: Decompressors,
+    ) -> Result<ParquetFileReader<'a>, BoltReaderError> {
+        if unlikely(batch_size == 0) {
+            return Err(BoltReaderError::ReaderConfigError(String::from(
+                "Batch size should be positive",
+            )));
+        }
+
+        let read_metrics = Arc::new(ReadMetrics::default());
+        let file = MeteredFileLoader::new(file, read_metrics.clone());
+        let file_metadata = if metadata_only {
+            read_footer(&file)?
+        } else {
+            read_file_metadata(&file)?
+        };
+        let schema_tree = SchemaTree::new(file_metadata.schema())?;
+        let projection = match projection {
+            Some(projection) => {
+                if unlikely(projection.get_num_columns() != schema_tree.get_num_columns()) {
+                    return Err(BoltReaderError::ReaderConfigError(format!(
+                        "Projection mask has {} columns, but the schema has {} columns",
+                        projection.get_num_columns(),
+                        schema_tree.get_num_columns()
+                    )));
+                }
+                projection
+            }
+            None => ProjectionMask::all(&schema_tree),
+        };
+
+        let mut row_group_offsets = Vec::with_capacity(file_metadata.row_groups().len());
+        let mut offset = 0;
+        for row_group in file_metadata.row_groups() {
+            row_group_offsets.push(offset);
+            offset += row_group.num_rows() as usize;
+        }
+
+        let lazy_row_groups = if metadata_only {
+            Some(
+                (0..file_metadata.row_groups().len())
+                    .map(|_| OnceCell::new())
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        Ok(ParquetFileReader {
+            file,
+            read_metrics,
+            file_metadata,
+            lazy_row_groups,
+            schema_tree,
+            row_group_offsets,
+            projection,
+            row_filter,
+            batch_size,
+            logical_type_options,
+            decompressors,
+        })
+    }
+
+    pub fn get_file_metadata(&self) -> &FileMetaData {
+        &self.file_metadata
+    }
+
+    pub fn get_schema_tree(&self) -> &SchemaTree {
+        &self.schema_tree
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.file_metadata.num_rows() as usize
+    }
+
+    pub fn num_row_groups(&self) -> usize {
+        self.file_metadata.row_groups().len()
+    }
+
+    pub fn get_projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    pub fn get_row_filter(&self) -> Option<&RowRangeSet> {
+        self.row_filter.as_ref()
+    }
+
+    pub fn get_batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// The logical types interpreted when the row groups are read, see the with_read_* options
+    /// of ReaderBuilder.
+    pub fn get_logical_type_options(&self) -> LogicalTypeOptions {
+        self.logical_type_options
+    }
+
+    pub fn is_reading_dates(&self) -> bool {
+        self.logical_type_options.read_dates
+    }
+
+    pub fn is_reading_timestamps(&self) -> bool {
+        self.logical_type_options.read_timestamps
+    }
+
+    pub fn is_reading_times(&self) -> bool {
+        self.logical_type_options.read_times
+    }
+
+    pub fn is_metadata_only(&self) -> bool {
+        self.lazy_row_groups.is_some()
+    }
+
+    /// The metrics of the scans so far, accumulated across the row groups.
+    pub fn get_read_metrics(&self) -> &ReadMetrics {
+        &self.read_metrics
+    }
+
+    /// Get the metadata of the index-th row group, with the missing dictionary page offsets
+    /// inferred from the page data.
+    pub fn get_row_group_metadata(
+        &self,
+        index: usize,
+    ) -> Result<&RowGroupMetaData, BoltReaderError> {
+        let row_group = self.file_metadata.row_group(index)?;
+        let lazy_row_group = match &self.lazy_row_groups {
+            Some(lazy_row_groups) => &lazy_row_groups[index],
+            None => return Ok(row_group),
+        };
+
+        if let Some(row_group) = lazy_row_group.get() {
+            return Ok(row_group);
+        }
+        let mut inferred = row_group.clone();
+        inferred.infer_dictionary_page_offsets(&self.file)?;
+        Ok(lazy_row_group.get_or_init(|| inferred))
+    }
+
+    /// Get the rows to read in the index-th row group, relative to the first row of the row
+    /// group. All the rows are selected without the row filter.
+    pub fn get_row_group_selection(&self, index: usize) -> Result<RowRangeSet, BoltReaderError> {
+        let num_rows = self.file_metadata.row_group(index)?.num_rows() as usize;
+        let begin = self.row_group_offsets[index];
+        let end = begin + num_rows;
+
+        let mut selection = RowRangeSet::new(0);
+        match &self.row_filter {
+            None => selection.add_row_ranges(0, num_rows),
+            Some(row_filter) => {
+                let offset = row_filter.get_offset();
+                for range in row_filter.get_row_ranges() {
+                    let range_begin = max(range.begin + offset, begin);
+                    let range_end = min(range.end + offset, end);
+                    if range_begin < range_end {
+                        selection.add_row_ranges(range_begin - begin, range_end - begin);
+                    }
+                }
+            }
+        }
+
+        Ok(selection)
+    }
+
+    /// Create the reader of the index-th row group, no column chunk is loaded until it is
+    /// projected.
+    pub fn row_group(&self, index: usize) -> Result<RowGroupReader<'_>, BoltReaderError> {
+        let row_group = self.get_row_group_metadata(index)?;
+        let mut row_group_reader = RowGroupReader::new(&self.file, row_group, &self.schema_tree)?;
+        row_group_reader.set_logical_type_options(self.logical_type_options);
+        row_group_reader.set_read_metrics(self.read_metrics.clone());
+        row_group_reader.set_decompressors(self.decompressors.clone());
+        Ok(row_group_reader)
+    }
+
+    /// Read the projected columns of the rows selected by the row filter in the index-th row
+    /// group.
+    pub fn read_row_group(&self, index: usize) -> Result<RowGroupSelection, BoltReaderError> {
+        let selection = self.get_row_group_selection(index)?;
+        let mut row_group_reader = self.row_group(index)?;
+        row_group_reader.project(&self.projection)?;
+        row_group_reader.read_with_selection(&selection)
+    }
+
+    /// Read the projected columns of the rows selected by the row filter in the index-th row
+    /// group, which pass all the filters, see RowGroupReader::read_with_filters.
+    ///
+    /// The rows are pruned first by the statistics and the page index of the filter columns, see
+    /// prune_column_chunk. If no row is left, the row group is pruned without loading any column
+    /// chunk, and None is returned.
+    pub fn read_row_group_with_filters(
+        &self,
+        index: usize,
+        filters: &[(usize, &dyn FixedLengthRangeFilter)],
+    ) -> Result<Option<RowGroupSelection>, BoltReaderError> {
+        let row_group = self.get_row_group_metadata(index)?;
+        let num_rows = row_group.num_rows() as usize;
+        let mut selection = self.get_row_group_selection(index)?;
+        for (column_index, filter) in filters {
+            if selection.count_rows() == 0 {
+                break;
+            }
+            let column = row_group.column(*column_index)?;
+            let pruned = prune_column_chunk(&self.file, column, num_rows, *filter)?;
+            selection = selection.intersect(&pruned);
+        }
+
+        if selection.count_rows() == 0 {
+            self.read_metrics.add_row_groups_pruned(1);
+            return Ok(None);
+        }
+
+        let mut row_group_reader = self.row_group(index)?;
+        row_group_reader.project(&self.projection)?;
+        row_group_reader
+            .read_with_filters(&selection, filters)
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::filters::integer_range_filter::IntegerRangeFilter;
+    use crate::metadata::projection_mask::ProjectionMask;
+    use crate::page_reader::row_group_reader::ColumnValues;
+    use crate::reader::parquet_file_reader::ParquetFileReader;
+    use crate::reader::reader_builder::ReaderBuilder;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::RowRangeSet;
+    use crate::utils::test_utils::RecordingFileLoader;
+
+    #[test]
+    fn test_read_row_groups() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let res = ParquetFileReader::new(&file);
+        assert!(res.is_ok());
+        let reader = res.unwrap();
+        assert_eq!(reader.num_row_groups(), 3);
+        assert_eq!(reader.get_schema_tree().get_num_columns(), 2);
+        assert_eq!(
+            reader.to_string(),
+            "Parquet File Reader: path src/sample_files/row_groups.parquet, num_rows 1000, num_row_groups 3, num_columns 2\n"
+        );
+
+        // Read the id column across the row groups of 400, 350 and 250 rows.
+        let mask = ProjectionMask::from_paths(reader.get_schema_tree(), &["id"]).unwrap();
+        let mut num_rows = 0;
+        for i in 0..reader.num_row_groups() {
+            let mut row_group_reader = reader.row_group(i).unwrap();
+            row_group_reader.project(&mask).unwrap();
+
+            let mut ranges = RowRangeSet::new(0);
+            ranges.add_row_ranges(0, row_group_reader.get_num_rows());
+            let selection = row_group_reader.read_with_selection(&ranges).unwrap();
+            let ids = match selection.get_column(0) {
+                Some(ColumnValues::Int64(bridge)) => bridge,
+                _ => panic!("The id column should be INT64"),
+            };
+            assert_eq!(ids.get_size(), row_group_reader.get_num_rows());
+
+            for row in 0..row_group_reader.get_num_rows() {
+                let (valid, id) = ids
+                    .get_validity_and_value(0, row, selection.get_row_range_set())
+                    .unwrap();
+                assert!(valid);
+                assert_eq!(id, (num_rows + row) as i64);
+            }
+            num_rows += row_group_reader.get_num_rows();
+        }
+        assert_eq!(num_rows, reader.num_rows());
+        assert_eq!(num_rows, 1000);
+
+        let res = reader.row_group(3);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "File Metadata Error: Row group index: 3 is out of bound, number of row groups: 3\n"
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_file() {
+        let path = String::from("src/sample_files/linitem_plain_data_page");
+        let file = LocalFileLoader::new(&path).unwrap();
+        assert!(ParquetFileReader::new(&file).is_err());
+    }
+
+    #[test]
+    fn test_open_metadata_only() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = RecordingFileLoader::new(LocalFileLoader::new(&path).unwrap());
+        let file_size = file.get_file_size();
+
+        let res = ParquetFileReader::open_metadata_only(&file);
+        assert!(res.is_ok());
+        let reader = res.unwrap();
+        assert!(reader.is_metadata_only());
+
+        // Only the footer length and magic code, then the footer itself are loaded.
+        assert_eq!(
+            file.get_ranges(),
+            vec![(file_size - 8, 8), (file_size - 8 - 527, 527)]
+        );
+
+        assert_eq!(reader.num_row_groups(), 3);
+        assert_eq!(reader.get_schema_tree().get_num_columns(), 2);
+        let statistics = reader
+            .get_file_metadata()
+            .row_group(1)
+            .unwrap()
+            .column(0)
+            .unwrap()
+            .statistics()
+            .unwrap();
+        assert_eq!(statistics.min_i64().unwrap(), Some(400));
+        assert_eq!(statistics.max_i64().unwrap(), Some(749));
+        assert_eq!(file.get_ranges().len(), 2);
+
+        // The column chunk is loaded when the row group is read.
+        let selection = reader.read_row_group(1).unwrap();
+        assert_eq!(selection.get_num_rows(), 350);
+        let ranges = file.get_ranges();
+        assert!(ranges.len() > 2);
+        assert!(ranges[2..]
+            .iter()
+            .all(|(offset, _)| *offset < file_size - 8 - 527));
+    }
+
+    #[test]
+    fn test_read_metrics() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        let reader = ParquetFileReader::new(&file).unwrap();
+        let footer_bytes = reader.get_read_metrics().bytes_read();
+        assert!(footer_bytes > 0);
+        for (i, num_rows) in [400, 350, 250].iter().enumerate() {
+            assert_eq!(reader.read_row_group(i).unwrap().get_num_rows(), *num_rows);
+        }
+        let full_scan = reader.get_read_metrics();
+        assert!(full_scan.bytes_read() > footer_bytes);
+        assert!(full_scan.pages_decoded() >= 6);
+        assert_eq!(full_scan.row_groups_pruned(), 0);
+
+        // The row groups of 400, 350 and 250 rows have the ids [0, 400), [400, 750) and
+        // [750, 1000), and only the second row group passes the filter.
+        let mask = ProjectionMask::from_paths(reader.get_schema_tree(), &["id"]).unwrap();
+        let reader = ReaderBuilder::new(&file)
+            .with_projection(mask)
+            .build()
+            .unwrap();
+        let filter = IntegerRangeFilter::new(450, 500, false);
+        let mut num_rows = Vec::new();
+        for i in 0..reader.num_row_groups() {
+            let res = reader.read_row_group_with_filters(i, &[(0, &filter)]);
+            assert!(res.is_ok());
+            num_rows.push(res.unwrap().map(|selection| selection.get_num_rows()));
+        }
+        assert_eq!(num_rows, vec![None, Some(51), None]);
+
+        let filtered_scan = reader.get_read_metrics();
+        assert_eq!(filtered_scan.row_groups_pruned(), 2);
+        assert!(filtered_scan.pages_decoded() > 0);
+        assert!(filtered_scan.bytes_read() < full_scan.bytes_read());
+    }
+
+    #[test]
+    fn test_read_metrics_pages_pruned() {
+        let path = String::from("src/sample_files/row_selection.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let mut row_filter = RowRangeSet::new(0);
+        row_filter.add_row_ranges(0, 10);
+        let reader = ReaderBuilder::new(&file)
+            .with_row_filter(row_filter)
+            .build()
+            .unwrap();
+
+        // The id pages start at the rows 0, 300 and 600, and the value pages at 0, 250, 500
+        // and 750, so only the first page of each column is decoded.
+        let selection = reader.read_row_group(0).unwrap();
+        assert_eq!(selection.get_num_rows(), 10);
+        let read_metrics = reader.get_read_metrics();
+        assert_eq!(read_metrics.pages_decoded(), 2);
+        assert_eq!(read_metrics.pages_pruned(), 5);
+        assert_eq!(read_metrics.row_groups_pruned(), 0);
+    }
+}
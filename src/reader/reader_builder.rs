@@ -0,0 +1,503 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::projection_mask::ProjectionMask;
+use crate::page_reader::decompression::Decompressors;
+use crate::page_reader::row_group_reader::LogicalTypeOptions;
+use crate::reader::parquet_file_reader::ParquetFileReader;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::row_range_set::RowRangeSet;
+
+pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// Configure and create a ParquetFileReader. By default, all the columns are projected, all the
+/// rows are selected and the batch size is DEFAULT_BATCH_SIZE.
+pub struct ReaderBuilder<'a> {
+    file: &'a dyn LoadFile,
+    projection: Option<ProjectionMask>,
+    row_filter: Option<RowRangeSet>,
+    batch_size: usize,
+    metadata_only: bool,
+    logical_type_options: LogicalTypeOptions,
+    decompressors: Decompressors,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ReaderBuilder<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Reader Builder: path {}, projection {:?}, row_filter {}, batch_size {}, metadata_only {}, read_dates {}, read_timestamps {}, read_times {}",
+            self.file.get_file_path(),
+            self.projection
+                .as_ref()
+                .map(|projection| projection.get_column_indices()),
+            self.row_filter.is_some(),
+            self.batch_size,
+            self.metadata_only,
+            self.logical_type_options.read_dates,
+            self.logical_type_options.read_timestamps,
+            self.logical_type_options.read_times
+        )
+    }
+}
+
+impl<'a> ReaderBuilder<'a> {
+    pub fn new(file: &'a dyn LoadFile) -> ReaderBuilder<'a> {
+        ReaderBuilder {
+            file,
+            projection: None,
+            row_filter: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            metadata_only: false,
+            logical_type_options: LogicalTypeOptions::default(),
+            decompressors: Decompressors::default(),
+        }
+    }
+
+    /// The mask should be created from the schema of the file.
+    pub fn with_projection(mut self, projection: ProjectionMask) -> ReaderBuilder<'a> {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// The rows to read in the whole file.
+    pub fn with_row_filter(mut self, row_filter: RowRangeSet) -> ReaderBuilder<'a> {
+        self.row_filter = Some(row_filter);
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> ReaderBuilder<'a> {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Read the footer bytes only when the reader is built, see
+    /// ParquetFileReader::open_metadata_only.
+    pub fn with_metadata_only(mut self) -> ReaderBuilder<'a> {
+        self.metadata_only = true;
+        self
+    }
+
+    /// Read the INT32 columns with the DATE logical type as ColumnValues::Date32, so the days
+    /// since the Unix epoch are exposed by ColumnValues::get_date.
+    pub fn with_read_dates(mut self) -> ReaderBuilder<'a> {
+        self.logical_type_options.read_dates = true;
+        self
+    }
+
+    /// Read the INT64 columns with the TIMESTAMP logical type as ColumnValues::Timestamp, so the
+    /// values scaled to nanoseconds are exposed by ColumnValues::get_timestamp_nanos.
+    pub fn with_read_timestamps(mut self) -> ReaderBuilder<'a> {
+        self.logical_type_options.read_timestamps = true;
+        self
+    }
+
+    /// Read the INT64 columns with the TIME logical type as ColumnValues::Time, so the values
+    /// converted to microseconds since midnight are exposed by ColumnValues::get_time_micros.
+    pub fn with_read_times(mut self) -> ReaderBuilder<'a> {
+        self.logical_type_options.read_times = true;
+        self
+    }
+
+    /// Decompress the pages of the reader with the decompressors, e.g. with a custom decompressor
+    /// registered for a codec. The other readers are not affected.
+    pub fn with_decompressors(mut self, decompressors: Decompressors) -> ReaderBuilder<'a> {
+        self.decompressors = decompressors;
+        self
+    }
+
+    /// Read the footer and validate the configuration against the schema of the file.
+    pub fn build(self) -> Result<ParquetFileReader<'a>, BoltReaderError> {
+        ParquetFileReader::create(
+            self.file,
+            self.projection,
+            self.row_filter,
+            self.batch_size,
+            self.metadata_only,
+            self.logical_type_options,
+            self.decompressors,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::bridge::bridge_base::Bridge;
+    use crate::metadata::compression::Compression;
+    use crate::metadata::file_metadata::read_file_metadata;
+    use crate::metadata::projection_mask::ProjectionMask;
+    use crate::metadata::schema::SchemaTree;
+    use crate::page_reader::decompression::{Decompressor, Decompressors};
+    use crate::page_reader::row_group_reader::{ColumnValues, LogicalTypeOptions};
+    use crate::reader::reader_builder::{ReaderBuilder, DEFAULT_BATCH_SIZE};
+    use crate::utils::date::Date32;
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::row_range_set::RowRangeSet;
+    use crate::utils::timestamp::{TimeType, TimestampType, TimestampUnit};
+
+    fn get_ranges(row_range_set: &RowRangeSet) -> Vec<(usize, usize)> {
+        assert_eq!(row_range_set.get_offset(), 0);
+        row_range_set
+            .get_row_ranges()
+            .iter()
+            .map(|range| (range.begin, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_build_default_reader() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let builder = ReaderBuilder::new(&file);
+        assert_eq!(builder.to_string(), "Reader Builder: path src/sample_files/row_groups.parquet, projection None, row_filter false, batch_size 1024, metadata_only false, read_dates false, read_timestamps false, read_times false\n");
+
+        let res = builder.build();
+        assert!(res.is_ok());
+        let reader = res.unwrap();
+        assert_eq!(reader.get_projection().get_column_indices(), vec![0, 1]);
+        assert!(reader.get_row_filter().is_none());
+        assert_eq!(reader.get_batch_size(), DEFAULT_BATCH_SIZE);
+        assert_eq!(
+            get_ranges(&reader.get_row_group_selection(1).unwrap()),
+            vec![(0, 350)]
+        );
+
+        let selection = reader.read_row_group(2).unwrap();
+        assert_eq!(selection.get_column_indices(), vec![0, 1]);
+        assert_eq!(selection.get_num_rows(), 250);
+    }
+
+    #[test]
+    fn test_build_configured_reader() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let schema_tree = SchemaTree::new(read_file_metadata(&file).unwrap().schema()).unwrap();
+
+        // The row groups have 400, 350 and 250 rows, the filter crosses their boundaries.
+        let mut row_filter = RowRangeSet::new(10);
+        row_filter.add_row_ranges(380, 400);
+        row_filter.add_row_ranges(730, 745);
+        row_filter.add_row_ranges(980, 1000);
+
+        let reader = ReaderBuilder::new(&file)
+            .with_projection(ProjectionMask::from_paths(&schema_tree, &["id"]).unwrap())
+            .with_row_filter(row_filter)
+            .with_batch_size(100)
+            .build()
+            .unwrap();
+        assert_eq!(reader.get_projection().get_column_indices(), vec![0]);
+        assert_eq!(reader.get_row_filter().unwrap().get_offset(), 10);
+        assert_eq!(reader.get_batch_size(), 100);
+
+        assert_eq!(
+            get_ranges(&reader.get_row_group_selection(0).unwrap()),
+            vec![(390, 400)]
+        );
+        assert_eq!(
+            get_ranges(&reader.get_row_group_selection(1).unwrap()),
+            vec![(0, 10), (340, 350)]
+        );
+        assert_eq!(
+            get_ranges(&reader.get_row_group_selection(2).unwrap()),
+            vec![(0, 5), (240, 250)]
+        );
+
+        let selection = reader.read_row_group(1).unwrap();
+        assert_eq!(selection.get_column_indices(), vec![0]);
+        assert_eq!(selection.get_num_rows(), 20);
+        let ids = match selection.get_column(0) {
+            Some(ColumnValues::Int64(bridge)) => bridge,
+            _ => panic!("The id column should be INT64"),
+        };
+        for row in (0..10).chain(340..350) {
+            let (valid, id) = ids
+                .get_validity_and_value(0, row, selection.get_row_range_set())
+                .unwrap();
+            assert!(valid);
+            assert_eq!(id, 400 + row as i64);
+        }
+    }
+
+    #[test]
+    fn test_build_reader_with_dates() {
+        let path = String::from("src/sample_files/date_int32.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        // The date column is read as INT32 by default.
+        let reader = ReaderBuilder::new(&file).build().unwrap();
+        assert!(!reader.is_reading_dates());
+        let column = &reader.get_schema_tree().get_column_descriptors()[1];
+        assert_eq!(column.get_path_string(), "date");
+        assert!(column.is_date());
+        assert!(!reader.get_schema_tree().get_column_descriptors()[0].is_date());
+        let selection = reader.read_row_group(0).unwrap();
+        assert!(matches!(
+            selection.get_column(1),
+            Some(ColumnValues::Int32(_))
+        ));
+
+        let reader = ReaderBuilder::new(&file).with_read_dates().build().unwrap();
+        assert!(reader.is_reading_dates());
+        let selection = reader.read_row_group(0).unwrap();
+        assert_eq!(selection.get_num_rows(), 8);
+        let ids = selection.get_column(0).unwrap();
+        assert!(matches!(ids, ColumnValues::Int32(_)));
+        assert!(ids.get_date(0).is_err());
+
+        let dates = selection.get_column(1).unwrap();
+        let validity = match dates {
+            ColumnValues::Date32(bridge) => bridge.get_raw_validity(),
+            _ => panic!("The date column should be Date32"),
+        };
+        let expected = [
+            Some("1970-01-01"),
+            Some("1969-12-31"),
+            None,
+            Some("2020-01-01"),
+            Some("2023-06-15"),
+            Some("0001-01-01"),
+            Some("2000-02-29"),
+            Some("1900-01-01"),
+        ];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(validity[i], expected.is_some());
+            if let Some(expected) = expected {
+                let days = dates.get_date(i).unwrap();
+                assert_eq!(Date32::new(days).to_string(), *expected);
+            }
+        }
+        assert_eq!(dates.get_date(3).unwrap(), 18262);
+        assert_eq!(dates.get_date(5).unwrap(), -719162);
+
+        let res = dates.get_date(8);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Row Group Error: Index: 8 is out of bound, number of values: 8\n"
+        );
+    }
+
+    #[test]
+    fn test_build_reader_with_timestamps() {
+        let path = String::from("src/sample_files/timestamp_int64.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        // The timestamp columns are read as INT64 by default.
+        let reader = ReaderBuilder::new(&file).build().unwrap();
+        assert!(!reader.is_reading_timestamps());
+        let selection = reader.read_row_group(0).unwrap();
+        assert!(matches!(
+            selection.get_column(0),
+            Some(ColumnValues::Int64(_))
+        ));
+        assert!(selection
+            .get_column(0)
+            .unwrap()
+            .get_timestamp_type()
+            .is_none());
+
+        let reader = ReaderBuilder::new(&file)
+            .with_read_timestamps()
+            .build()
+            .unwrap();
+        assert!(reader.is_reading_timestamps());
+        let selection = reader.read_row_group(0).unwrap();
+        assert_eq!(selection.get_num_rows(), 4);
+
+        // The same instants in MILLIS, MICROS, NANOS and the legacy TIMESTAMP_MICROS.
+        let expected_types = [
+            (TimestampUnit::Millis, true),
+            (TimestampUnit::Micros, false),
+            (TimestampUnit::Nanos, true),
+            (TimestampUnit::Micros, true),
+        ];
+        let expected_nanos = [0, 1577836800123000000, -1500000000];
+        for (column_index, (unit, is_adjusted_to_utc)) in expected_types.iter().enumerate() {
+            let column = &reader.get_schema_tree().get_column_descriptors()[column_index];
+            let timestamp_type = TimestampType::new(*unit, *is_adjusted_to_utc);
+            assert_eq!(column.get_timestamp_type(), Some(timestamp_type));
+
+            let values = selection.get_column(column_index).unwrap();
+            assert_eq!(values.get_timestamp_type(), Some(timestamp_type));
+            let validity = match values {
+                ColumnValues::Timestamp(bridge, _) => bridge.get_raw_validity(),
+                _ => panic!(
+                    "The {} column should be Timestamp",
+                    column.get_path_string()
+                ),
+            };
+            assert_eq!(validity, [true, true, true, false]);
+
+            for (row, nanos) in expected_nanos.iter().enumerate() {
+                let res = values.get_timestamp_nanos(row).unwrap();
+                // The micros and nanos values keep the sub-millisecond digits.
+                assert_eq!(res - res % unit.get_nanos_per_unit(), res);
+                assert_eq!(res / 1_000_000, nanos / 1_000_000);
+            }
+        }
+
+        let nanos = selection.get_column(2).unwrap();
+        assert_eq!(nanos.get_timestamp_nanos(1).unwrap(), 1577836800123456789);
+        let micros = selection.get_column(1).unwrap();
+        assert_eq!(micros.get_timestamp_nanos(1).unwrap(), 1577836800123456000);
+        assert!(micros.get_date(0).is_err());
+        let res = micros.get_timestamp_nanos(4);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Row Group Error: Index: 4 is out of bound, number of values: 4\n"
+        );
+    }
+
+    #[test]
+    fn test_build_reader_with_times() {
+        let path = String::from("src/sample_files/time_int64.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        let reader = ReaderBuilder::new(&file).with_read_times().build().unwrap();
+        assert!(reader.is_reading_times());
+        assert!(!reader.is_reading_timestamps());
+        let selection = reader.read_row_group(0).unwrap();
+        assert_eq!(selection.get_num_rows(), 6);
+
+        let column = &reader.get_schema_tree().get_column_descriptors()[0];
+        let micros_type = TimeType::new(TimestampUnit::Micros, true);
+        assert_eq!(column.get_time_type(), Some(micros_type));
+        assert!(column.get_timestamp_type().is_none());
+        let micros = selection.get_column(0).unwrap();
+        assert_eq!(micros.get_time_type(), Some(micros_type));
+
+        let nanos_type = TimeType::new(TimestampUnit::Nanos, false);
+        let nanos = selection.get_column(1).unwrap();
+        assert_eq!(nanos.get_time_type(), Some(nanos_type));
+        assert!(!nanos.get_time_type().unwrap().is_adjusted_to_utc());
+
+        // The midnight, the noon and one microsecond before the midnight.
+        for (row, expected) in [0, 43_200_000_000, 86_399_999_999].iter().enumerate() {
+            assert_eq!(micros.get_time_micros(row).unwrap(), *expected);
+            assert_eq!(nanos.get_time_micros(row).unwrap(), *expected);
+        }
+        assert_eq!(nanos.get_time_micros(4).unwrap(), 1);
+
+        // The null value is read as 0.
+        let validity = match micros {
+            ColumnValues::Time(bridge, _) => bridge.get_raw_validity(),
+            _ => panic!("The time_micros column should be Time"),
+        };
+        assert!(!validity[3]);
+        assert_eq!(micros.get_time_micros(3).unwrap(), 0);
+
+        let res = micros.get_time_micros(4);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Time: 86400000000 MICROS is out of the range of a day: [0, 86400000000)\n"
+        );
+        assert!(micros.get_time_micros(5).is_err());
+        assert!(nanos.get_time_micros(5).is_err());
+        assert!(micros.get_timestamp_nanos(0).is_err());
+
+        // The options of the reader are set on the row group readers.
+        let row_group = reader.row_group(0).unwrap();
+        assert_eq!(
+            row_group.get_logical_type_options(),
+            LogicalTypeOptions {
+                read_dates: false,
+                read_timestamps: false,
+                read_times: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_invalid_reader() {
+        let path = String::from("src/sample_files/row_groups.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        let res = ReaderBuilder::new(&file).with_batch_size(0).build();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Reader Config Error: Batch size should be positive\n"
+        );
+
+        // The projection mask of another schema.
+        let lineitem_path = String::from("src/sample_files/lineitem.parquet");
+        let lineitem = LocalFileLoader::new(&lineitem_path).unwrap();
+        let schema_tree = SchemaTree::new(read_file_metadata(&lineitem).unwrap().schema()).unwrap();
+        let res = ReaderBuilder::new(&file)
+            .with_projection(ProjectionMask::all(&schema_tree))
+            .build();
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Reader Config Error: Projection mask has 22 columns, but the schema has 2 columns\n"
+        );
+    }
+
+    struct FailingDecompressor;
+
+    impl Decompressor for FailingDecompressor {
+        fn decompress(
+            &self,
+            _compressed: &[u8],
+            _uncompressed_size: usize,
+            _output: &mut Vec<u8>,
+        ) -> Result<(), BoltReaderError> {
+            Err(BoltReaderError::DecompressionError(String::from(
+                "Failing decompressor",
+            )))
+        }
+    }
+
+    #[test]
+    fn test_build_reader_with_decompressors() {
+        let path = String::from("src/sample_files/snappy_data_page_v1.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+
+        let mut decompressors = Decompressors::default();
+        decompressors
+            .register(Compression::Snappy, Arc::new(FailingDecompressor))
+            .unwrap();
+        let reader = ReaderBuilder::new(&file)
+            .with_decompressors(decompressors)
+            .build()
+            .unwrap();
+        let res = reader.read_row_group(0);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Decompression Error: Failing decompressor\n"
+        );
+
+        // The decompressors of a reader are not used by the other readers. The sample page is
+        // written without the definition levels of its optional column, which fails after the
+        // page is decompressed by the default decompressor.
+        let reader = ReaderBuilder::new(&file).build().unwrap();
+        let res = reader.read_row_group(0);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Fixed Length Data Page Error: Data Page V1 definition levels: 0 are fewer than num_values: 1000\n"
+        );
+    }
+}
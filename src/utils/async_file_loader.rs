@@ -0,0 +1,169 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::utils::exceptions::BoltReaderError;
+
+/// The number of threads running the blocking loads of LoadBytesFuture. The loads beyond it are
+/// queued, so prefetching the column chunks of a wide row group does not start a thread for each
+/// of them.
+pub const NUM_IO_THREADS: usize = 8;
+
+/// The async counterpart of LoadFile. The byte ranges of a row group can be requested
+/// concurrently, then fed to DirectByteBuffer::from_loaded_bytes.
+///
+/// The local files are loaded by the AsyncLocalFileLoader on Unix, and by the TokioFileLoader
+/// with the tokio feature.
+pub trait AsyncLoadFile {
+    fn get_file_path(&self) -> &String;
+
+    fn get_file_size(&self) -> usize;
+
+    /// Load the byte range [begin, end) of the file.
+    fn load_bytes(
+        &self,
+        begin: usize,
+        end: usize,
+    ) -> impl Future<Output = Result<Vec<u8>, BoltReaderError>> + Send;
+}
+
+#[derive(Default)]
+struct LoadState {
+    result: Option<Result<Vec<u8>, BoltReaderError>>,
+    waker: Option<Waker>,
+}
+
+/// The future of a byte range loaded on the IO thread pool. It does not depend on a specific
+/// runtime, so it can be awaited from Tokio without blocking the worker threads.
+pub struct LoadBytesFuture {
+    state: Arc<Mutex<LoadState>>,
+}
+
+/// The pool of NUM_IO_THREADS threads, separate from the global rayon pool decoding the columns,
+/// so the blocking reads do not hold the decoding threads.
+fn io_thread_pool() -> &'static ThreadPool {
+    static IO_THREAD_POOL: OnceLock<ThreadPool> = OnceLock::new();
+    IO_THREAD_POOL.get_or_init(|| {
+        ThreadPoolBuilder::new()
+            .num_threads(NUM_IO_THREADS)
+            .thread_name(|index| format!("bolt-io-{}", index))
+            .build()
+            .expect("Failed to create the IO thread pool")
+    })
+}
+
+impl LoadBytesFuture {
+    /// A future resolved to the given result, e.g. the error of an invalid range.
+    pub fn ready(result: Result<Vec<u8>, BoltReaderError>) -> LoadBytesFuture {
+        LoadBytesFuture {
+            state: Arc::new(Mutex::new(LoadState {
+                result: Some(result),
+                waker: None,
+            })),
+        }
+    }
+
+    /// Run the blocking load on the IO thread pool.
+    pub fn spawn<F>(load: F) -> LoadBytesFuture
+    where
+        F: FnOnce() -> Result<Vec<u8>, BoltReaderError> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(LoadState::default()));
+        let thread_state = state.clone();
+        io_thread_pool().spawn(move || {
+            let result = load();
+            let mut state = thread_state.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        LoadBytesFuture { state }
+    }
+}
+
+impl Future for LoadBytesFuture {
+    type Output = Result<Vec<u8>, BoltReaderError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use crate::utils::async_file_loader::{LoadBytesFuture, NUM_IO_THREADS};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::test_utils::block_on;
+
+    #[test]
+    fn test_load_bytes_future_ready() {
+        let res = block_on(LoadBytesFuture::ready(Ok(vec![1, 2, 3])));
+        assert_eq!(res.unwrap(), vec![1, 2, 3]);
+
+        let res = block_on(LoadBytesFuture::ready(Err(BoltReaderError::InternalError(
+            String::from("Failed to load"),
+        ))));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Failed to load\n"
+        );
+    }
+
+    #[test]
+    fn test_load_bytes_future_bounded_threads() {
+        let thread_names = Arc::new(Mutex::new(HashSet::new()));
+
+        // Request many more loads than the IO threads before awaiting any of them.
+        let futures: Vec<_> = (0..NUM_IO_THREADS * 16)
+            .map(|i| {
+                let thread_names = thread_names.clone();
+                LoadBytesFuture::spawn(move || {
+                    let name = thread::current().name().map(String::from);
+                    thread_names.lock().unwrap().insert(name);
+                    Ok(vec![i as u8])
+                })
+            })
+            .collect();
+
+        for (i, future) in futures.into_iter().enumerate() {
+            assert_eq!(block_on(future).unwrap(), vec![i as u8]);
+        }
+
+        let thread_names = thread_names.lock().unwrap();
+        assert!(thread_names.len() <= NUM_IO_THREADS);
+        for name in thread_names.iter() {
+            assert!(name.as_ref().unwrap().starts_with("bolt-io-"));
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::fs::File;
+use std::future::Future;
+use std::os::unix::fs::FileExt;
+use std::sync::Arc;
+
+use crate::utils::async_file_loader::{AsyncLoadFile, LoadBytesFuture};
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// Load a local file asynchronously. Each request is read on the IO thread pool with a positioned
+/// read, so multiple ranges are loaded concurrently without sharing a file cursor.
+pub struct AsyncLocalFileLoader {
+    path: String,
+    size: usize,
+    file: Arc<File>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for AsyncLocalFileLoader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Async Local File Loader, path: {}, size: {}",
+            self.path, self.size
+        )
+    }
+}
+
+impl AsyncLoadFile for AsyncLocalFileLoader {
+    fn get_file_path(&self) -> &String {
+        &self.path
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.size
+    }
+
+    fn load_bytes(
+        &self,
+        begin: usize,
+        end: usize,
+    ) -> impl Future<Output = Result<Vec<u8>, BoltReaderError>> + Send {
+        if unlikely(begin > end || end > self.size) {
+            return LoadBytesFuture::ready(Err(BoltReaderError::InternalError(format!(
+                "Reading range exceeds the file size: {} bytes. \nReading range [{}, {}) ",
+                self.size, begin, end
+            ))));
+        }
+
+        let file = self.file.clone();
+        LoadBytesFuture::spawn(move || {
+            let mut v = DirectByteBuffer::allocate_vec_for_buffer(end - begin)?;
+            file.read_exact_at(&mut v, begin as u64)?;
+            Ok(v)
+        })
+    }
+}
+
+#[allow(dead_code)]
+impl AsyncLocalFileLoader {
+    pub fn new(path: &String) -> Result<AsyncLocalFileLoader, BoltReaderError> {
+        let file = File::open(path)?;
+        Ok(AsyncLocalFileLoader {
+            path: path.clone(),
+            size: file.metadata()?.len() as usize,
+            file: Arc::new(file),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::async_file_loader::AsyncLoadFile;
+    use crate::utils::async_local_file_loader::AsyncLocalFileLoader;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::test_utils::block_on;
+
+    #[test]
+    fn test_async_local_file_loader() {
+        let path = String::from("not_existing_file");
+        assert!(AsyncLocalFileLoader::new(&path).is_err());
+
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let res = AsyncLocalFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        assert_eq!(file.get_file_size(), 2065982);
+        assert_eq!(file.get_file_path(), "src/sample_files/lineitem.parquet");
+
+        let res = block_on(file.load_bytes(0, 4));
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), vec![80, 65, 82, 49]);
+
+        let res = block_on(file.load_bytes(file.get_file_size(), file.get_file_size() + 1));
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().to_string(), "Internal Error: Reading range exceeds the file size: 2065982 bytes. \nReading range [2065982, 2065983) \n");
+    }
+
+    #[test]
+    fn test_async_local_file_loader_concurrent_ranges() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = AsyncLocalFileLoader::new(&path).unwrap();
+        let local_file = LocalFileLoader::new(&path).unwrap();
+
+        // Request all the ranges first, so they are loaded concurrently.
+        let ranges = [(4, 89775), (1530515, 1871709), (2065978, 2065982)];
+        let futures: Vec<_> = ranges
+            .iter()
+            .map(|(begin, end)| file.load_bytes(*begin, *end))
+            .collect();
+
+        for ((begin, end), future) in ranges.iter().zip(futures) {
+            let mut buffer = DirectByteBuffer::from_loaded_bytes(block_on(future).unwrap());
+            let mut expected = local_file.load_file_to_buffer(*begin, end - begin).unwrap();
+            assert_eq!(buffer.as_bytes(), expected.as_bytes());
+            assert_eq!(buffer.read_u32().unwrap(), expected.read_u32().unwrap());
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+
+// todo: Create config module to handle the default const values.
+pub const DEFAULT_MAX_FREE_BUFFERS: usize = 4;
+
+/// A pool of the reusable byte buffers, e.g. for the decompressed pages of a scan. A buffer is
+/// handed out as a PooledBuffer guard and reclaimed by the pool when the guard is dropped, so the
+/// pages of similar sizes share the same allocations.
+///
+/// At most max_free_buffers buffers are kept by the pool, the extra buffers are freed on drop.
+pub struct BufferPool {
+    free_buffers: Mutex<Vec<Vec<u8>>>,
+    max_free_buffers: usize,
+    num_allocations: AtomicUsize,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for BufferPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Buffer Pool: num_free_buffers {}, max_free_buffers {}, num_allocations {}",
+            self.get_num_free_buffers(),
+            self.max_free_buffers,
+            self.get_num_allocations()
+        )
+    }
+}
+
+impl BufferPool {
+    pub fn new(max_free_buffers: usize) -> Arc<BufferPool> {
+        Arc::new(BufferPool {
+            free_buffers: Mutex::new(Vec::new()),
+            max_free_buffers,
+            num_allocations: AtomicUsize::new(0),
+        })
+    }
+
+    /// Hand out an empty buffer with the capacity of at least size bytes. The smallest free
+    /// buffer that fits is reused, otherwise a free buffer is grown or a new one is allocated.
+    pub fn acquire(self: &Arc<Self>, size: usize) -> Result<PooledBuffer, BoltReaderError> {
+        let buffer = {
+            let mut free_buffers = self.free_buffers.lock().unwrap();
+            let best_fit = free_buffers
+                .iter()
+                .enumerate()
+                .filter(|(_, buffer)| buffer.capacity() >= size)
+                .min_by_key(|(_, buffer)| buffer.capacity())
+                .map(|(index, _)| index);
+            match best_fit {
+                Some(index) => Some(free_buffers.swap_remove(index)),
+                None => free_buffers.pop(),
+            }
+        };
+
+        let mut buffer = buffer.unwrap_or_default();
+        if buffer.capacity() < size {
+            buffer.try_reserve_exact(size).map_err(|e| {
+                BoltReaderError::InsufficientMemoryError(format!(
+                    "Unable to allocate a pooled buffer of {} bytes: {}",
+                    size, e
+                ))
+            })?;
+            self.num_allocations.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(PooledBuffer {
+            pool: Arc::clone(self),
+            buffer: Some(DirectByteBuffer::from_loaded_bytes(buffer)),
+        })
+    }
+
+    pub fn get_num_free_buffers(&self) -> usize {
+        self.free_buffers.lock().unwrap().len()
+    }
+
+    /// The number of the buffers allocated or grown by acquire.
+    pub fn get_num_allocations(&self) -> usize {
+        self.num_allocations.load(Ordering::Relaxed)
+    }
+
+    fn release(&self, mut buffer: Vec<u8>) {
+        let mut free_buffers = self.free_buffers.lock().unwrap();
+        if free_buffers.len() < self.max_free_buffers {
+            buffer.clear();
+            free_buffers.push(buffer);
+        }
+    }
+}
+
+/// The buffer handed out by the BufferPool, which is returned to the pool when dropped. It
+/// dereferences to a little-endian DirectByteBuffer.
+pub struct PooledBuffer {
+    pool: Arc<BufferPool>,
+    buffer: Option<DirectByteBuffer>,
+}
+
+impl PooledBuffer {
+    /// Replace the content with the bytes appended by fill to the pooled allocation, and reset
+    /// the read position.
+    pub fn fill<F>(&mut self, fill: F) -> Result<(), BoltReaderError>
+    where
+        F: FnOnce(&mut Vec<u8>) -> Result<(), BoltReaderError>,
+    {
+        let mut bytes = self.buffer.take().unwrap_or_default().into_vec();
+        bytes.clear();
+        let res = fill(&mut bytes);
+        self.buffer = Some(DirectByteBuffer::from_loaded_bytes(bytes));
+        res
+    }
+}
+
+impl Deref for PooledBuffer {
+    type Target = DirectByteBuffer;
+
+    fn deref(&self) -> &DirectByteBuffer {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for PooledBuffer {
+    fn deref_mut(&mut self) -> &mut DirectByteBuffer {
+        self.buffer.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            self.pool.release(buffer.into_vec());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::buffer_pool::BufferPool;
+    use crate::utils::exceptions::BoltReaderError;
+
+    #[test]
+    fn test_reuse_pooled_buffer() {
+        let pool = BufferPool::new(2);
+        let mut buffer = pool.acquire(100).unwrap();
+        buffer
+            .fill(|bytes| {
+                bytes.extend_from_slice(&[1, 2, 3]);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(buffer.as_bytes(), &[1, 2, 3]);
+        let ptr = buffer.as_bytes().as_ptr();
+        assert_eq!(pool.get_num_free_buffers(), 0);
+        drop(buffer);
+        assert_eq!(pool.get_num_free_buffers(), 1);
+
+        // The smaller request reuses the same allocation, which is emptied.
+        let mut buffer = pool.acquire(50).unwrap();
+        assert!(buffer.as_bytes().is_empty());
+        buffer
+            .fill(|bytes| {
+                bytes.resize(50, 7);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(buffer.as_bytes().as_ptr(), ptr);
+        assert_eq!(buffer.get_rpos(), 0);
+        assert_eq!(pool.get_num_allocations(), 1);
+
+        // The buffer in use is not handed out twice.
+        let other = pool.acquire(50).unwrap();
+        assert_eq!(pool.get_num_allocations(), 2);
+        drop(buffer);
+        drop(other);
+        assert_eq!(pool.get_num_free_buffers(), 2);
+        assert_eq!(
+            pool.to_string(),
+            "Buffer Pool: num_free_buffers 2, max_free_buffers 2, num_allocations 2\n"
+        );
+
+        // The larger request grows a free buffer.
+        let buffer = pool.acquire(1000).unwrap();
+        assert_eq!(pool.get_num_allocations(), 3);
+        assert_eq!(pool.get_num_free_buffers(), 1);
+        drop(buffer);
+    }
+
+    #[test]
+    fn test_max_free_buffers() {
+        let pool = BufferPool::new(1);
+        let first = pool.acquire(10).unwrap();
+        let second = pool.acquire(10).unwrap();
+        drop(first);
+        drop(second);
+        assert_eq!(pool.get_num_free_buffers(), 1);
+
+        // The failed fill keeps the buffer in the pool.
+        let mut buffer = pool.acquire(10).unwrap();
+        let res = buffer.fill(|_| Err(BoltReaderError::InternalError(String::from("fill"))));
+        assert!(res.is_err());
+        drop(buffer);
+        assert_eq!(pool.get_num_free_buffers(), 1);
+        assert_eq!(pool.get_num_allocations(), 2);
+    }
+}
@@ -13,11 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
 use std::mem;
 
 use crate::convert_generic_vec;
 use crate::utils::direct_byte_buffer::{ByteBufferSlice, DirectByteBuffer};
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
 
 pub trait ByteBufferBase {
     fn can_create_buffer_slice(&self, start: usize, len: usize) -> bool;
@@ -58,4 +60,35 @@ pub trait ByteBufferBase {
         start: usize,
         length: usize,
     ) -> Result<Vec<u8>, BoltReaderError>;
+
+    /// Read the 4-byte little-endian length prefix of a RLE/BP level section in Data Page V1,
+    /// and return the section starting at the RLE data. The read position is advanced past the
+    /// section. The section borrows the memory of the buffer if it can be sliced, otherwise it is
+    /// copied.
+    fn read_rle_section(&mut self) -> Result<Cow<'_, [u8]>, BoltReaderError> {
+        let rpos = self.get_rpos();
+        let length = self.read_u32()? as usize;
+        let begin = rpos + 4;
+        if unlikely(begin > self.len() || self.len() - begin < length) {
+            self.set_rpos(rpos);
+            return Err(BoltReaderError::BufferError(format!(
+                "RLE section of {} bytes at offset: {} exceeds the buffer size: {} bytes",
+                length,
+                begin,
+                self.len()
+            )));
+        }
+
+        self.set_rpos(begin + length);
+        if length == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+        if self.can_create_buffer_slice(begin, length) {
+            return Ok(Cow::Borrowed(self.create_buffer_slice(begin, length)?));
+        }
+
+        let section = self.load_bytes_to_byte_vec_deep_copy(begin, length)?;
+        self.set_rpos(begin + length);
+        Ok(Cow::Owned(section))
+    }
 }
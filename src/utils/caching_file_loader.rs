@@ -0,0 +1,261 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Formatter;
+use std::sync::Mutex;
+
+use bytebuffer::Endian::LittleEndian;
+
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+
+struct RangeCache {
+    capacity: usize,
+    size: usize,
+    ranges: HashMap<(usize, usize), Vec<u8>>,
+    // The least recently used range is at the front.
+    lru: VecDeque<(usize, usize)>,
+}
+
+impl RangeCache {
+    fn get(&mut self, key: (usize, usize)) -> Option<&Vec<u8>> {
+        if self.ranges.contains_key(&key) {
+            self.touch(key);
+        }
+        self.ranges.get(&key)
+    }
+
+    fn put(&mut self, key: (usize, usize), bytes: Vec<u8>) {
+        // A range larger than the whole budget is not cached.
+        if bytes.len() > self.capacity || self.ranges.contains_key(&key) {
+            return;
+        }
+
+        while self.size + bytes.len() > self.capacity {
+            match self.lru.pop_front() {
+                Some(evicted) => {
+                    if let Some(evicted_bytes) = self.ranges.remove(&evicted) {
+                        self.size -= evicted_bytes.len();
+                    }
+                }
+                None => break,
+            }
+        }
+
+        self.size += bytes.len();
+        self.ranges.insert(key, bytes);
+        self.lru.push_back(key);
+    }
+
+    fn touch(&mut self, key: (usize, usize)) {
+        if let Some(pos) = self.lru.iter().position(|k| *k == key) {
+            self.lru.remove(pos);
+        }
+        self.lru.push_back(key);
+    }
+}
+
+/// Wrap a LoadFile and keep the recently loaded byte ranges in a LRU cache, keyed by
+/// [begin, end). The cached ranges take at most capacity bytes in total.
+pub struct CachingFileLoader<L: LoadFile> {
+    loader: L,
+    cache: Mutex<RangeCache>,
+}
+
+#[allow(dead_code)]
+impl<L: LoadFile> std::fmt::Display for CachingFileLoader<L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cache = self.cache.lock().unwrap();
+        writeln!(
+            f,
+            "Caching File Loader, path: {}, cached ranges: {}, cached size: {}, capacity: {}",
+            self.loader.get_file_path(),
+            cache.ranges.len(),
+            cache.size,
+            cache.capacity
+        )
+    }
+}
+
+impl<L: LoadFile> LoadFile for CachingFileLoader<L> {
+    fn get_file_path(&self) -> &String {
+        self.loader.get_file_path()
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.loader.get_file_size()
+    }
+
+    fn load_file_to_buffer(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        let key = (offset, offset + length);
+        if let Some(bytes) = self.cache.lock().unwrap().get(key) {
+            let mut buffer = DirectByteBuffer::from_vec(bytes.clone());
+            buffer.set_endian(LittleEndian);
+            return Ok(buffer);
+        }
+
+        // The lock is not held while loading, so the other ranges can be loaded concurrently.
+        let buffer = self.loader.load_file_to_buffer(offset, length)?;
+        self.cache
+            .lock()
+            .unwrap()
+            .put(key, buffer.as_bytes().to_vec());
+
+        Ok(buffer)
+    }
+}
+
+#[allow(dead_code)]
+impl<L: LoadFile> CachingFileLoader<L> {
+    pub fn new(loader: L, capacity: usize) -> CachingFileLoader<L> {
+        CachingFileLoader {
+            loader,
+            cache: Mutex::new(RangeCache {
+                capacity,
+                size: 0,
+                ranges: HashMap::new(),
+                lru: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub fn get_loader(&self) -> &L {
+        &self.loader
+    }
+
+    pub fn get_cached_size(&self) -> usize {
+        self.cache.lock().unwrap().size
+    }
+
+    pub fn is_cached(&self, offset: usize, length: usize) -> bool {
+        self.cache
+            .lock()
+            .unwrap()
+            .ranges
+            .contains_key(&(offset, offset + length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::utils::caching_file_loader::CachingFileLoader;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+
+    struct CountingFileLoader {
+        loader: LocalFileLoader,
+        loads: AtomicUsize,
+    }
+
+    impl LoadFile for CountingFileLoader {
+        fn get_file_path(&self) -> &String {
+            self.loader.get_file_path()
+        }
+
+        fn get_file_size(&self) -> usize {
+            self.loader.get_file_size()
+        }
+
+        fn load_file_to_buffer(
+            &self,
+            offset: usize,
+            length: usize,
+        ) -> Result<DirectByteBuffer, BoltReaderError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            self.loader.load_file_to_buffer(offset, length)
+        }
+    }
+
+    fn create_caching_file_loader(capacity: usize) -> CachingFileLoader<CountingFileLoader> {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let loader = CountingFileLoader {
+            loader: LocalFileLoader::new(&path).unwrap(),
+            loads: AtomicUsize::new(0),
+        };
+        CachingFileLoader::new(loader, capacity)
+    }
+
+    fn get_loads(file: &CachingFileLoader<CountingFileLoader>) -> usize {
+        file.get_loader().loads.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn test_caching_file_loader_hit() {
+        let file = create_caching_file_loader(1024);
+        assert_eq!(file.get_file_size(), 2065982);
+
+        let res = DirectByteBuffer::from_file(&file, 0, 4);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().as_bytes(), vec![80, 65, 82, 49]);
+        assert_eq!(get_loads(&file), 1);
+
+        let res = DirectByteBuffer::from_file(&file, 0, 4);
+        assert!(res.is_ok());
+        let mut buffer = res.unwrap();
+        assert_eq!(buffer.as_bytes(), vec![80, 65, 82, 49]);
+        assert_eq!(buffer.read_u32().unwrap(), 0x31524150);
+        assert_eq!(get_loads(&file), 1);
+
+        // A different range is loaded from the underlying loader.
+        let res = DirectByteBuffer::from_file(&file, 0, 8);
+        assert!(res.is_ok());
+        assert_eq!(get_loads(&file), 2);
+        assert_eq!(file.get_cached_size(), 12);
+
+        // The errors are not cached.
+        assert!(DirectByteBuffer::from_file(&file, file.get_file_size(), 1).is_err());
+        assert_eq!(file.get_cached_size(), 12);
+    }
+
+    #[test]
+    fn test_caching_file_loader_eviction() {
+        let file = create_caching_file_loader(250);
+
+        DirectByteBuffer::from_file(&file, 0, 100).unwrap();
+        DirectByteBuffer::from_file(&file, 100, 100).unwrap();
+        assert_eq!(file.get_cached_size(), 200);
+
+        // Touch the first range, so the second range is the least recently used.
+        DirectByteBuffer::from_file(&file, 0, 100).unwrap();
+        assert_eq!(get_loads(&file), 2);
+
+        DirectByteBuffer::from_file(&file, 200, 100).unwrap();
+        assert_eq!(get_loads(&file), 3);
+        assert_eq!(file.get_cached_size(), 200);
+        assert!(file.is_cached(0, 100));
+        assert!(!file.is_cached(100, 100));
+        assert!(file.is_cached(200, 100));
+
+        DirectByteBuffer::from_file(&file, 100, 100).unwrap();
+        assert_eq!(get_loads(&file), 4);
+        assert!(!file.is_cached(0, 100));
+
+        // A range larger than the capacity is never cached.
+        DirectByteBuffer::from_file(&file, 0, 300).unwrap();
+        DirectByteBuffer::from_file(&file, 0, 300).unwrap();
+        assert_eq!(get_loads(&file), 6);
+        assert_eq!(file.get_cached_size(), 200);
+    }
+}
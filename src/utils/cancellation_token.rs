@@ -0,0 +1,81 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::utils::exceptions::BoltReaderError;
+
+/// The Cancellation Token aborts a scan from another thread, e.g. when the client disconnects.
+/// The clones share the same flag, and the readers check it between the pages, so the read
+/// returns Cancelled before the next page is decoded.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for CancellationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Cancellation Token: cancelled {}", self.is_cancelled())
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return Cancelled once the token is cancelled.
+    pub fn check(&self) -> Result<(), BoltReaderError> {
+        if self.is_cancelled() {
+            return Err(BoltReaderError::Cancelled);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::cancellation_token::CancellationToken;
+    use crate::utils::exceptions::BoltReaderError;
+
+    #[test]
+    fn test_cancel_shared_token() {
+        let token = CancellationToken::new();
+        let shared = token.clone();
+        assert!(!shared.is_cancelled());
+        assert!(shared.check().is_ok());
+
+        // The token is cancelled from another thread.
+        std::thread::spawn(move || token.cancel()).join().unwrap();
+        assert!(shared.is_cancelled());
+        assert!(matches!(shared.check(), Err(BoltReaderError::Cancelled)));
+        assert_eq!(
+            shared.check().unwrap_err().to_string(),
+            "Cancelled: the read is cancelled\n"
+        );
+    }
+}
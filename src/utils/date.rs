@@ -0,0 +1,140 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+// The days from 0000-03-01 to 1970-01-01 in the proleptic Gregorian calendar.
+const DAYS_FROM_CIVIL_EPOCH: i64 = 719468;
+const DAYS_PER_ERA: i64 = 146097;
+
+/// The DATE value, stored as the INT32 days since the Unix epoch 1970-01-01. The days before
+/// the epoch are negative.
+///
+/// It has the layout of the i32, so it can be read by FixedLengthPlainDataPageReaderV1 with the
+/// type size Date32::TYPE_SIZE.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date32(pub i32);
+
+#[allow(dead_code)]
+impl std::fmt::Display for Date32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.to_ymd();
+        if year < 0 {
+            write!(f, "-{:04}-{:02}-{:02}", -year, month, day)
+        } else {
+            write!(f, "{:04}-{:02}-{:02}", year, month, day)
+        }
+    }
+}
+
+impl From<i32> for Date32 {
+    fn from(days: i32) -> Self {
+        Date32(days)
+    }
+}
+
+impl Date32 {
+    pub const TYPE_SIZE: usize = 4;
+
+    pub fn new(days: i32) -> Date32 {
+        Date32(days)
+    }
+
+    /// The days since the Unix epoch.
+    pub fn get_days(&self) -> i32 {
+        self.0
+    }
+
+    /// Convert to the (year, month, day) in the proleptic Gregorian calendar, e.g. 0 is
+    /// (1970, 1, 1) and -1 is (1969, 12, 31).
+    ///
+    /// The calendar is split into the 400 years eras starting on March 1st, so the leap day is
+    /// the last day of the year and the days of an era are mapped with the same arithmetic.
+    pub fn to_ymd(&self) -> (i32, u32, u32) {
+        let days = self.0 as i64 + DAYS_FROM_CIVIL_EPOCH;
+        let era = days.div_euclid(DAYS_PER_ERA);
+        let day_of_era = days.rem_euclid(DAYS_PER_ERA);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        // The month starting from March.
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        (year as i32, month as u32, day as u32)
+    }
+
+    /// Convert to the chrono NaiveDate, None if the date is out of the range of NaiveDate.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?
+            .checked_add_signed(chrono::Duration::days(self.0 as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use crate::utils::date::Date32;
+
+    #[test]
+    fn test_date32_to_ymd() {
+        assert_eq!(mem::size_of::<Date32>(), Date32::TYPE_SIZE);
+
+        assert_eq!(Date32::new(0).to_ymd(), (1970, 1, 1));
+        assert_eq!(Date32::new(18262).to_ymd(), (2020, 1, 1));
+        assert_eq!(Date32::new(19523).to_ymd(), (2023, 6, 15));
+        assert_eq!(Date32::new(11016).to_ymd(), (2000, 2, 29));
+        assert_eq!(Date32::new(11017).to_ymd(), (2000, 3, 1));
+        assert_eq!(Date32::new(2932896).to_ymd(), (9999, 12, 31));
+        assert_eq!(Date32::from(19523).to_string(), "2023-06-15");
+    }
+
+    #[test]
+    fn test_date32_before_epoch() {
+        assert_eq!(Date32::new(-1).to_ymd(), (1969, 12, 31));
+        assert_eq!(Date32::new(-365).to_ymd(), (1969, 1, 1));
+        assert_eq!(Date32::new(-25567).to_ymd(), (1900, 1, 1));
+        assert_eq!(Date32::new(-719162).to_ymd(), (1, 1, 1));
+        assert_eq!(Date32::new(-719163).to_ymd(), (0, 12, 31));
+        assert_eq!(Date32::new(-1).to_string(), "1969-12-31");
+        assert_eq!(Date32::new(-719162).to_string(), "0001-01-01");
+        assert!(Date32::new(i32::MIN).to_string().starts_with('-'));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date32_to_naive_date() {
+        use chrono::NaiveDate;
+
+        assert_eq!(
+            Date32::new(19523).to_naive_date(),
+            NaiveDate::from_ymd_opt(2023, 6, 15)
+        );
+        assert_eq!(
+            Date32::new(-25567).to_naive_date(),
+            NaiveDate::from_ymd_opt(1900, 1, 1)
+        );
+        assert_eq!(Date32::new(i32::MAX).to_naive_date(), None);
+    }
+}
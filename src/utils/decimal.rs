@@ -0,0 +1,142 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The DECIMAL value with up to 38 digits. The value is unscaled_value * 10^(-scale), e.g.
+/// the unscaled value -1234567 with scale 2 is -12345.67.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Decimal128 {
+    unscaled_value: i128,
+    scale: i32,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for Decimal128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.scale <= 0 && self.unscaled_value == 0 {
+            return write!(f, "0");
+        }
+
+        if self.scale <= 0 {
+            return write!(
+                f,
+                "{}{}",
+                self.unscaled_value,
+                "0".repeat(self.scale.unsigned_abs() as usize)
+            );
+        }
+
+        let scale = self.scale as usize;
+        let digits = self.unscaled_value.unsigned_abs().to_string();
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign = if self.unscaled_value < 0 { "-" } else { "" };
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl Decimal128 {
+    /// The max size of the FIXED_LEN_BYTE_ARRAY holding a Decimal128.
+    pub const MAX_TYPE_SIZE: usize = 16;
+
+    pub fn new(unscaled_value: i128, scale: i32) -> Decimal128 {
+        Decimal128 {
+            unscaled_value,
+            scale,
+        }
+    }
+
+    /// Decode the big-endian two's complement unscaled value of 1 to 16 bytes, which is the
+    /// layout of the FIXED_LEN_BYTE_ARRAY and BYTE_ARRAY decimals.
+    pub fn from_be_bytes(bytes: &[u8], scale: i32) -> Result<Decimal128, BoltReaderError> {
+        if unlikely(bytes.is_empty() || bytes.len() > Self::MAX_TYPE_SIZE) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Can not decode {} bytes as a decimal, the length should be 1 to {}",
+                bytes.len(),
+                Self::MAX_TYPE_SIZE
+            )));
+        }
+
+        // Sign extend the most significant byte, then shift in the remaining bytes.
+        let mut unscaled_value = bytes[0] as i8 as i128;
+        for byte in &bytes[1..] {
+            unscaled_value = (unscaled_value << 8) | *byte as i128;
+        }
+
+        Ok(Decimal128::new(unscaled_value, scale))
+    }
+
+    pub fn get_unscaled_value(&self) -> i128 {
+        self.unscaled_value
+    }
+
+    pub fn get_scale(&self) -> i32 {
+        self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::decimal::Decimal128;
+
+    #[test]
+    fn test_decimal_from_be_bytes() {
+        let value = Decimal128::from_be_bytes(&[0x00, 0x7f], 0).unwrap();
+        assert_eq!(value.get_unscaled_value(), 127);
+
+        // The high bit is the sign bit.
+        let value = Decimal128::from_be_bytes(&[0xff, 0x80], 0).unwrap();
+        assert_eq!(value.get_unscaled_value(), -128);
+        let value = Decimal128::from_be_bytes(&[0xff; 16], 9).unwrap();
+        assert_eq!(value.get_unscaled_value(), -1);
+        assert_eq!(value.get_scale(), 9);
+
+        let mut bytes = [0u8; 16];
+        bytes[0] = 0x80;
+        assert_eq!(
+            Decimal128::from_be_bytes(&bytes, 0)
+                .unwrap()
+                .get_unscaled_value(),
+            i128::MIN
+        );
+
+        assert!(Decimal128::from_be_bytes(&[], 0).is_err());
+        let res = Decimal128::from_be_bytes(&[0u8; 17], 0);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Can not decode 17 bytes as a decimal, the length should be 1 to 16\n"
+        );
+    }
+
+    #[test]
+    fn test_decimal_to_string() {
+        assert_eq!(Decimal128::new(-1234567, 2).to_string(), "-12345.67");
+        assert_eq!(
+            Decimal128::new(123456789012, 9).to_string(),
+            "123.456789012"
+        );
+        assert_eq!(Decimal128::new(5, 3).to_string(), "0.005");
+        assert_eq!(Decimal128::new(-5, 3).to_string(), "-0.005");
+        assert_eq!(Decimal128::new(0, 2).to_string(), "0.00");
+        assert_eq!(Decimal128::new(42, 0).to_string(), "42");
+        assert_eq!(Decimal128::new(42, -2).to_string(), "4200");
+        assert_eq!(Decimal128::new(0, -2).to_string(), "0");
+    }
+}
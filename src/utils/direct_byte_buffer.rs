@@ -16,10 +16,14 @@
 use std::mem;
 
 use bytebuffer::ByteBuffer;
+use bytebuffer::Endian::LittleEndian;
 
 use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::encoding::delta_binary_packed::DeltaBinaryPackedDecoder;
+use crate::utils::encoding::varint::get_max_varint_length_64_bit;
 use crate::utils::exceptions::BoltReaderError;
 use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
 
 // Currently, we use 1GB as the max capacity.
 // todo: Create config module to handle the default const values.
@@ -37,6 +41,10 @@ pub trait Buffer {
         length: usize,
     ) -> Result<DirectByteBuffer, BoltReaderError>;
 
+    /// Wrap the bytes already loaded from a file, e.g. by an AsyncLoadFile. The buffer reads
+    /// little-endian values, the same as the buffers created by from_file.
+    fn from_loaded_bytes(bytes: Vec<u8>) -> DirectByteBuffer;
+
     fn convert_byte_vec<T>(input: Vec<u8>, type_size: usize) -> Result<Vec<T>, BoltReaderError> {
         if input.len() % type_size != 0 {
             return Err(BoltReaderError::InternalError(format!(
@@ -57,6 +65,70 @@ pub trait Buffer {
 
         Ok(vec)
     }
+
+    /// Return the bytes [begin, end) of this buffer as a slice borrowing its memory, regardless of
+    /// the read position.
+    fn slice(&self, begin: usize, end: usize) -> Result<ByteBufferSlice<'_>, BoltReaderError>;
+
+    /// Read a little-endian u32 at the absolute offset, without moving the read position.
+    fn read_u32_at(&self, offset: usize) -> Result<u32, BoltReaderError>;
+
+    /// Read a little-endian u64 at the absolute offset, without moving the read position.
+    fn read_u64_at(&self, offset: usize) -> Result<u64, BoltReaderError>;
+
+    /// Read a little-endian i64 at the absolute offset, without moving the read position.
+    fn read_i64_at(&self, offset: usize) -> Result<i64, BoltReaderError>;
+
+    /// Read a little-endian f32 at the absolute offset, without moving the read position. The
+    /// bit pattern is kept as is, including the NaN payload.
+    fn read_f32_at(&self, offset: usize) -> Result<f32, BoltReaderError>;
+
+    /// Read a little-endian f64 at the absolute offset, without moving the read position. The
+    /// bit pattern is kept as is, including the NaN payload.
+    fn read_f64_at(&self, offset: usize) -> Result<f64, BoltReaderError>;
+
+    /// Return the byte at the read position, without moving the read position.
+    fn peek_u8(&self) -> Result<u8, BoltReaderError>;
+
+    /// Return the len bytes from the read position, without moving the read position.
+    fn peek_bytes(&self, len: usize) -> Result<ByteBufferSlice<'_>, BoltReaderError>;
+
+    /// Read a big-endian i32 at the read position and advance the read position.
+    fn read_i32_be(&mut self) -> Result<i32, BoltReaderError>;
+
+    /// Read a big-endian i64 at the read position and advance the read position.
+    fn read_i64_be(&mut self) -> Result<i64, BoltReaderError>;
+
+    /// Read len (1 to 16) bytes as a big-endian two's complement integer, e.g. the unscaled value
+    /// of a FIXED_LEN_BYTE_ARRAY decimal, and advance the read position.
+    fn read_bytes_be(&mut self, len: usize) -> Result<i128, BoltReaderError>;
+
+    /// Read a ULEB128 varint at the read position and advance the read position. The read
+    /// position is not moved if the varint is truncated or longer than 10 bytes.
+    fn read_varint_u64(&mut self) -> Result<u64, BoltReaderError>;
+
+    /// Read a zigzag encoded ULEB128 varint, e.g. a delta of DELTA_BINARY_PACKED, at the read
+    /// position and advance the read position.
+    fn read_varint_i64(&mut self) -> Result<i64, BoltReaderError>;
+}
+
+fn read_bytes_at<const N: usize>(
+    buffer: &DirectByteBuffer,
+    offset: usize,
+) -> Result<[u8; N], BoltReaderError> {
+    let bytes = buffer.as_bytes();
+    if unlikely(offset > bytes.len() || bytes.len() - offset < N) {
+        return Err(BoltReaderError::BufferError(format!(
+            "Can not read {} bytes at offset: {}, buffer size: {} bytes",
+            N,
+            offset,
+            bytes.len()
+        )));
+    }
+
+    let mut res = [0u8; N];
+    res.copy_from_slice(&bytes[offset..offset + N]);
+    Ok(res)
 }
 
 impl Buffer for DirectByteBuffer {
@@ -78,6 +150,143 @@ impl Buffer for DirectByteBuffer {
     ) -> Result<DirectByteBuffer, BoltReaderError> {
         source.load_file_to_buffer(offset, length)
     }
+
+    fn from_loaded_bytes(bytes: Vec<u8>) -> DirectByteBuffer {
+        let mut buffer = DirectByteBuffer::from_vec(bytes);
+        buffer.set_endian(LittleEndian);
+        buffer
+    }
+
+    fn slice(&self, begin: usize, end: usize) -> Result<ByteBufferSlice<'_>, BoltReaderError> {
+        if unlikely(begin > end || end > self.len()) {
+            return Err(BoltReaderError::BufferError(format!(
+                "Can not slice [{}, {}) out of buffer size: {} bytes",
+                begin,
+                end,
+                self.len()
+            )));
+        }
+
+        Ok(&self.as_bytes()[begin..end])
+    }
+
+    fn read_u32_at(&self, offset: usize) -> Result<u32, BoltReaderError> {
+        Ok(u32::from_le_bytes(read_bytes_at(self, offset)?))
+    }
+
+    fn read_u64_at(&self, offset: usize) -> Result<u64, BoltReaderError> {
+        Ok(u64::from_le_bytes(read_bytes_at(self, offset)?))
+    }
+
+    fn read_i64_at(&self, offset: usize) -> Result<i64, BoltReaderError> {
+        Ok(i64::from_le_bytes(read_bytes_at(self, offset)?))
+    }
+
+    fn read_f32_at(&self, offset: usize) -> Result<f32, BoltReaderError> {
+        Ok(f32::from_le_bytes(read_bytes_at(self, offset)?))
+    }
+
+    fn read_f64_at(&self, offset: usize) -> Result<f64, BoltReaderError> {
+        Ok(f64::from_le_bytes(read_bytes_at(self, offset)?))
+    }
+
+    fn peek_u8(&self) -> Result<u8, BoltReaderError> {
+        Ok(read_bytes_at::<1>(self, self.get_rpos())?[0])
+    }
+
+    fn peek_bytes(&self, len: usize) -> Result<ByteBufferSlice<'_>, BoltReaderError> {
+        let rpos = self.get_rpos();
+        let bytes = self.as_bytes();
+        if unlikely(rpos > bytes.len() || bytes.len() - rpos < len) {
+            return Err(BoltReaderError::BufferError(format!(
+                "Can not read {} bytes at offset: {}, buffer size: {} bytes",
+                len,
+                rpos,
+                bytes.len()
+            )));
+        }
+
+        Ok(&bytes[rpos..rpos + len])
+    }
+
+    fn read_i32_be(&mut self) -> Result<i32, BoltReaderError> {
+        let res = i32::from_be_bytes(read_bytes_at(self, self.get_rpos())?);
+        self.set_rpos(self.get_rpos() + 4);
+        Ok(res)
+    }
+
+    fn read_i64_be(&mut self) -> Result<i64, BoltReaderError> {
+        let res = i64::from_be_bytes(read_bytes_at(self, self.get_rpos())?);
+        self.set_rpos(self.get_rpos() + 8);
+        Ok(res)
+    }
+
+    fn read_bytes_be(&mut self, len: usize) -> Result<i128, BoltReaderError> {
+        if unlikely(len == 0 || len > 16) {
+            return Err(BoltReaderError::BufferError(format!(
+                "Can not read {} bytes as a big-endian integer, the length should be 1 to 16",
+                len
+            )));
+        }
+
+        let rpos = self.get_rpos();
+        let bytes = self.as_bytes();
+        if unlikely(rpos > bytes.len() || bytes.len() - rpos < len) {
+            return Err(BoltReaderError::BufferError(format!(
+                "Can not read {} bytes at offset: {}, buffer size: {} bytes",
+                len,
+                rpos,
+                bytes.len()
+            )));
+        }
+
+        // Sign extend the most significant byte, then shift in the remaining bytes.
+        let mut res = bytes[rpos] as i8 as i128;
+        for byte in &bytes[rpos + 1..rpos + len] {
+            res = (res << 8) | *byte as i128;
+        }
+
+        self.set_rpos(rpos + len);
+        Ok(res)
+    }
+
+    fn read_varint_u64(&mut self) -> Result<u64, BoltReaderError> {
+        let rpos = self.get_rpos();
+        let bytes = &self.as_bytes()[rpos.min(self.len())..];
+
+        let mut res: u64 = 0;
+        for (i, byte) in bytes
+            .iter()
+            .take(get_max_varint_length_64_bit())
+            .enumerate()
+        {
+            // The 10th byte can only hold the most significant bit of u64, without continuation.
+            if unlikely(i == get_max_varint_length_64_bit() - 1 && *byte > 1) {
+                return Err(BoltReaderError::VarintDecodingError(format!(
+                    "Varint at offset: {} is longer than 10 bytes or overflows u64",
+                    rpos
+                )));
+            }
+
+            res |= ((byte & 0x7f) as u64) << (i * 7);
+            if byte & 0x80 == 0 {
+                self.set_rpos(rpos + i + 1);
+                return Ok(res);
+            }
+        }
+
+        Err(BoltReaderError::VarintDecodingError(format!(
+            "Varint at offset: {} is truncated at the buffer size: {} bytes",
+            rpos,
+            self.len()
+        )))
+    }
+
+    fn read_varint_i64(&mut self) -> Result<i64, BoltReaderError> {
+        Ok(DeltaBinaryPackedDecoder::decode_zigzag(
+            self.read_varint_u64()?,
+        ))
+    }
 }
 
 impl ByteBufferBase for DirectByteBuffer {
@@ -164,6 +373,8 @@ impl ByteBufferBase for DirectByteBuffer {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use crate::utils::direct_byte_buffer::*;
 
     #[test]
@@ -254,6 +465,114 @@ mod tests {
         mem::forget(vec2);
     }
 
+    #[test]
+    fn test_slice_zero_copy() {
+        let buffer = DirectByteBuffer::from_loaded_bytes((0..16).collect());
+
+        let res = buffer.slice(4, 12);
+        assert!(res.is_ok());
+        let slice = res.unwrap();
+        assert_eq!(slice.len(), 8);
+        assert_eq!(slice, &[4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(slice.as_ptr(), buffer.as_bytes()[4..].as_ptr());
+
+        // The bytes after the slice are not readable.
+        assert!(slice.get(8).is_none());
+
+        let slice = buffer.slice(16, 16).unwrap();
+        assert!(slice.is_empty());
+
+        let res = buffer.slice(8, 17);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Buffer Error: Can not slice [8, 17) out of buffer size: 16 bytes\n"
+        );
+        assert!(buffer.slice(9, 8).is_err());
+    }
+
+    #[test]
+    fn test_read_rle_section() {
+        // A 3 bytes RLE section, followed by an empty section and the data.
+        let mut buffer =
+            DirectByteBuffer::from_loaded_bytes(vec![3, 0, 0, 0, 8, 1, 2, 0, 0, 0, 0, 42]);
+
+        let res = buffer.read_rle_section();
+        assert!(res.is_ok());
+        let section = res.unwrap();
+        assert!(matches!(section, Cow::Borrowed(_)));
+        assert_eq!(section.as_ref(), &[8, 1, 2]);
+        assert_eq!(buffer.get_rpos(), 7);
+
+        let section = buffer.read_rle_section().unwrap();
+        assert!(section.is_empty());
+        assert_eq!(buffer.get_rpos(), 11);
+        assert_eq!(buffer.read_u8().unwrap(), 42);
+
+        // The length prefix exceeds the buffer, and the read position is not moved.
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(vec![5, 0, 0, 0, 1, 2]);
+        let res = buffer.read_rle_section();
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Buffer Error: RLE section of 5 bytes at offset: 4 exceeds the buffer size: 6 bytes\n"
+        );
+        assert_eq!(buffer.get_rpos(), 0);
+
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(vec![1, 0]);
+        assert!(buffer.read_rle_section().is_err());
+    }
+
+    #[test]
+    fn test_read_varint() {
+        let mut bytes = vec![0x00, 0x7f, 0xac, 0x02, 0xc0, 0x84, 0x3d];
+        bytes.extend([0xff; 9]);
+        bytes.push(0x01);
+        // The zigzag values -1, 1, -64
+        bytes.extend([0x01, 0x02, 0x7f]);
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(bytes);
+
+        assert_eq!(buffer.read_varint_u64().unwrap(), 0);
+        assert_eq!(buffer.read_varint_u64().unwrap(), 127);
+        assert_eq!(buffer.read_varint_u64().unwrap(), 300);
+        assert_eq!(buffer.get_rpos(), 4);
+        assert_eq!(buffer.read_varint_u64().unwrap(), 1000000);
+        assert_eq!(buffer.read_varint_u64().unwrap(), u64::MAX);
+        assert_eq!(buffer.get_rpos(), 17);
+
+        assert_eq!(buffer.read_varint_i64().unwrap(), -1);
+        assert_eq!(buffer.read_varint_i64().unwrap(), 1);
+        assert_eq!(buffer.read_varint_i64().unwrap(), -64);
+
+        // Nothing is left to read.
+        let res = buffer.read_varint_u64();
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Varint Decoding Error: Varint at offset: 20 is truncated at the buffer size: 20 bytes\n"
+        );
+        assert_eq!(buffer.get_rpos(), 20);
+
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(vec![0x80, 0x80]);
+        assert!(buffer.read_varint_i64().is_err());
+        assert_eq!(buffer.get_rpos(), 0);
+
+        // The 10th byte has the continuation bit.
+        let mut buffer = DirectByteBuffer::from_loaded_bytes([0xff; 11].to_vec());
+        let res = buffer.read_varint_u64();
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Varint Decoding Error: Varint at offset: 0 is longer than 10 bytes or overflows u64\n"
+        );
+        assert_eq!(buffer.get_rpos(), 0);
+
+        let mut bytes = vec![0x80; 9];
+        bytes.push(0x02);
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(bytes);
+        assert!(buffer.read_varint_u64().is_err());
+    }
+
     #[test]
     fn test_create_vec_from_deep_copy() {
         let res = DirectByteBuffer::allocate_vec_for_buffer(10);
@@ -290,4 +609,162 @@ mod tests {
         assert_eq!(vec2, Vec::from([506097522914230528]));
         assert_ne!(buffer.as_bytes().as_ptr(), vec2.as_ptr() as *mut u8);
     }
+
+    #[test]
+    fn test_read_at() {
+        let vec: Vec<u8> = (0..16).collect();
+        let mut buffer = DirectByteBuffer::from_vec(vec);
+        buffer.set_rpos(3);
+
+        assert_eq!(buffer.read_u32_at(0).unwrap(), 0x03020100);
+        assert_eq!(buffer.read_u32_at(12).unwrap(), 0x0f0e0d0c);
+        assert_eq!(buffer.read_u64_at(8).unwrap(), 0x0f0e0d0c0b0a0908);
+        assert_eq!(buffer.read_i64_at(1).unwrap(), 0x0807060504030201);
+
+        let negative = DirectByteBuffer::from_vec(vec![0xff; 8]);
+        assert_eq!(negative.read_i64_at(0).unwrap(), -1);
+        assert_eq!(negative.read_u64_at(0).unwrap(), u64::MAX);
+
+        // The read position is not changed.
+        assert_eq!(buffer.get_rpos(), 3);
+    }
+
+    #[test]
+    fn test_read_at_out_of_bound() {
+        let vec: Vec<u8> = (0..16).collect();
+        let buffer = DirectByteBuffer::from_vec(vec);
+
+        let res = buffer.read_u32_at(13);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Buffer Error: Can not read 4 bytes at offset: 13, buffer size: 16 bytes\n"
+        );
+
+        assert!(buffer.read_u64_at(9).is_err());
+        assert!(buffer.read_i64_at(16).is_err());
+        assert!(buffer.read_i64_at(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_peek() {
+        let vec: Vec<u8> = (0..16).collect();
+        let mut buffer = DirectByteBuffer::from_loaded_bytes(vec);
+        buffer.set_rpos(3);
+
+        assert_eq!(buffer.peek_u8().unwrap(), 3);
+        assert_eq!(buffer.peek_bytes(4).unwrap(), &[3, 4, 5, 6]);
+        assert!(buffer.peek_bytes(0).unwrap().is_empty());
+        assert_eq!(buffer.get_rpos(), 3);
+
+        // The peeked bytes are the same as the bytes read next.
+        let peeked = buffer.peek_bytes(5).unwrap().to_vec();
+        assert_eq!(buffer.read_u8().unwrap(), peeked[0]);
+        assert_eq!(buffer.read_u32().unwrap(), u32::from_le_bytes([4, 5, 6, 7]));
+        assert_eq!(buffer.get_rpos(), 8);
+
+        buffer.set_rpos(15);
+        assert_eq!(buffer.peek_u8().unwrap(), 15);
+        let res = buffer.peek_bytes(2);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Buffer Error: Can not read 2 bytes at offset: 15, buffer size: 16 bytes\n"
+        );
+
+        buffer.set_rpos(16);
+        assert!(buffer.peek_u8().is_err());
+        assert_eq!(buffer.get_rpos(), 16);
+    }
+
+    #[test]
+    fn test_read_float_at() {
+        let f32_values = [
+            f32::from_bits(0x7f800001), // signaling NaN
+            f32::from_bits(0xffc00123), // negative quiet NaN with payload
+            -0.0f32,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+        let f64_values = [
+            f64::from_bits(0x7ff0000000000001), // signaling NaN
+            -0.0f64,
+            f64::INFINITY,
+        ];
+
+        let mut vec: Vec<u8> = f32_values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        vec.extend(f64_values.iter().flat_map(|v| v.to_le_bytes()));
+        let buffer = DirectByteBuffer::from_vec(vec);
+
+        for (i, value) in f32_values.iter().enumerate() {
+            assert_eq!(
+                buffer.read_f32_at(i * 4).unwrap().to_bits(),
+                value.to_bits()
+            );
+        }
+        for (i, value) in f64_values.iter().enumerate() {
+            assert_eq!(
+                buffer.read_f64_at(20 + i * 8).unwrap().to_bits(),
+                value.to_bits()
+            );
+        }
+
+        assert!(buffer.read_f32_at(41).is_err());
+        assert!(buffer.read_f64_at(37).is_err());
+    }
+
+    #[test]
+    fn test_read_big_endian() {
+        let mut buffer = DirectByteBuffer::from_vec(vec![
+            0x00, 0x00, 0x01, 0x02, 0xff, 0xff, 0xff, 0xfe, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+            0x07, 0x08,
+        ]);
+
+        assert_eq!(buffer.read_i32_be().unwrap(), 0x0102);
+        assert_eq!(buffer.read_i32_be().unwrap(), -2);
+        assert_eq!(buffer.read_i64_be().unwrap(), 0x0102030405060708);
+        assert_eq!(buffer.get_rpos(), 16);
+        assert!(buffer.read_i32_be().is_err());
+        assert_eq!(buffer.get_rpos(), 16);
+
+        // The little-endian readers are not affected.
+        assert_eq!(buffer.read_u32_at(0).unwrap(), 0x02010000);
+    }
+
+    #[test]
+    fn test_read_big_endian_decimal() {
+        // Unscaled values of a FIXED_LEN_BYTE_ARRAY(2) decimal around the sign boundary.
+        let mut buffer = DirectByteBuffer::from_vec(vec![
+            0x7f, 0xff, // 32767
+            0x80, 0x00, // -32768
+            0xff, 0xff, // -1
+            0x00, 0x00, // 0
+            0x00, 0x01, // 1
+        ]);
+        assert_eq!(buffer.read_bytes_be(2).unwrap(), 32767);
+        assert_eq!(buffer.read_bytes_be(2).unwrap(), -32768);
+        assert_eq!(buffer.read_bytes_be(2).unwrap(), -1);
+        assert_eq!(buffer.read_bytes_be(2).unwrap(), 0);
+        assert_eq!(buffer.read_bytes_be(2).unwrap(), 1);
+        assert!(buffer.read_bytes_be(2).is_err());
+
+        // decimal(38, 2) value -12345.67 stored in 16 bytes.
+        let unscaled: i128 = -1234567;
+        let mut buffer = DirectByteBuffer::from_vec(unscaled.to_be_bytes().to_vec());
+        assert_eq!(buffer.read_bytes_be(16).unwrap(), -1234567);
+
+        // 5 bytes decimal, 0xfffffffffe is -2 and 0x00ffffffff is 4294967295.
+        let mut buffer = DirectByteBuffer::from_vec(vec![
+            0xff, 0xff, 0xff, 0xff, 0xfe, 0x00, 0xff, 0xff, 0xff, 0xff,
+        ]);
+        assert_eq!(buffer.read_bytes_be(5).unwrap(), -2);
+        assert_eq!(buffer.read_bytes_be(5).unwrap(), 4294967295);
+
+        let res = buffer.read_bytes_be(17);
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Buffer Error: Can not read 17 bytes as a big-endian integer, the length should be 1 to 16\n"
+        );
+    }
 }
@@ -0,0 +1,128 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+const BASE64_PADDING: u8 = b'=';
+
+#[inline(always)]
+fn decode_base64_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decode the padded base64 of the standard alphabet, e.g. the ARROW:schema value in the
+/// key-value metadata. The padding is only allowed at the end of the last 4 characters.
+pub fn decode_base64(input: &str) -> Result<Vec<u8>, BoltReaderError> {
+    let input = input.as_bytes();
+    if unlikely(!input.len().is_multiple_of(4)) {
+        return Err(BoltReaderError::InternalError(format!(
+            "Base64 length: {} should be a multiple of 4",
+            input.len()
+        )));
+    }
+
+    let mut res = Vec::with_capacity(input.len() / 4 * 3);
+    for (chunk_index, chunk) in input.chunks_exact(4).enumerate() {
+        let is_last = (chunk_index + 1) * 4 == input.len();
+        let num_padding = if is_last {
+            chunk
+                .iter()
+                .rev()
+                .take_while(|c| **c == BASE64_PADDING)
+                .count()
+        } else {
+            0
+        };
+        if unlikely(num_padding > 2) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Base64 has invalid padding at offset: {}",
+                chunk_index * 4
+            )));
+        }
+
+        let mut bits: u32 = 0;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = if i >= 4 - num_padding {
+                0
+            } else {
+                match decode_base64_char(*c) {
+                    Some(value) => value,
+                    None => {
+                        return Err(BoltReaderError::InternalError(format!(
+                            "Invalid base64 character: {:?} at offset: {}",
+                            *c as char,
+                            chunk_index * 4 + i
+                        )))
+                    }
+                }
+            };
+            bits = (bits << 6) | value as u32;
+        }
+
+        let bytes = bits.to_be_bytes();
+        res.extend_from_slice(&bytes[1..4 - num_padding]);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::encoding::base64::decode_base64;
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("").unwrap(), b"");
+        assert_eq!(decode_base64("Zg==").unwrap(), b"f");
+        assert_eq!(decode_base64("Zm8=").unwrap(), b"fo");
+        assert_eq!(decode_base64("Zm9v").unwrap(), b"foo");
+        assert_eq!(decode_base64("Zm9vYmFy").unwrap(), b"foobar");
+        assert_eq!(decode_base64("/////w==").unwrap(), vec![0xff; 4]);
+        assert_eq!(
+            decode_base64("AAEC+/38").unwrap(),
+            vec![0x00, 0x01, 0x02, 0xfb, 0xfd, 0xfc]
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_base64() {
+        let res = decode_base64("Zm9");
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Internal Error: Base64 length: 3 should be a multiple of 4\n"
+        );
+
+        let res = decode_base64("Zm9vYm-y");
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Internal Error: Invalid base64 character: '-' at offset: 6\n"
+        );
+
+        // The padding is only allowed at the end.
+        assert!(decode_base64("Zg==Zm9v").is_err());
+        assert!(decode_base64("Zm=v").is_err());
+        assert!(decode_base64("Z===").is_err());
+    }
+}
@@ -0,0 +1,313 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::byte_buffer_base::ByteBufferBase;
+use crate::utils::encoding::varint::decode_varint;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+// The writers use a few miniblocks per block, e.g. 4 in parquet-mr. A larger number in the header
+// is corrupt, and would allocate the miniblock bit widths of that size.
+const MAX_NUM_MINI_BLOCKS: usize = 1024;
+
+/// Native DELTA_BINARY_PACKED Decoder.
+///
+/// The encoded data starts with the header:
+/// <block size in values> <number of miniblocks in a block> <total value count> <first value>
+///
+/// Each block contains:
+/// <min delta> <list of bitwidths of miniblocks> <miniblocks>
+///
+/// All the header values are ULEB128 varints, the first value and the min delta are zigzag
+/// encoded. The values are decoded as i64 with wrapping arithmetic, which is also correct for
+/// INT32 columns after truncation.
+pub struct DeltaBinaryPackedDecoder {}
+
+impl DeltaBinaryPackedDecoder {
+    #[inline(always)]
+    pub fn decode_zigzag(value: u64) -> i64 {
+        ((value >> 1) as i64) ^ -((value & 1) as i64)
+    }
+
+    /// Return (block_size, num_mini_blocks, total_count, first_value)
+    pub fn read_header(
+        buffer: &mut dyn ByteBufferBase,
+    ) -> Result<(usize, usize, usize, i64), BoltReaderError> {
+        let block_size = decode_varint(buffer)? as usize;
+        let num_mini_blocks = decode_varint(buffer)? as usize;
+        let total_count = decode_varint(buffer)? as usize;
+        let first_value = Self::decode_zigzag(decode_varint(buffer)?);
+
+        if unlikely(block_size == 0 || !block_size.is_multiple_of(128)) {
+            return Err(BoltReaderError::DeltaBinaryPackedDecodingError(format!(
+                "Block size: {} should be a positive multiple of 128",
+                block_size
+            )));
+        }
+
+        if unlikely(num_mini_blocks == 0 || num_mini_blocks > MAX_NUM_MINI_BLOCKS) {
+            return Err(BoltReaderError::DeltaBinaryPackedDecodingError(format!(
+                "Number of miniblocks: {} should be 1 to {}",
+                num_mini_blocks, MAX_NUM_MINI_BLOCKS
+            )));
+        }
+
+        // A miniblock holds at least 32 values, the quotient is 0 if there are more miniblocks
+        // than values in a block.
+        if unlikely(
+            num_mini_blocks > block_size / 32 || !(block_size / num_mini_blocks).is_multiple_of(32),
+        ) {
+            return Err(BoltReaderError::DeltaBinaryPackedDecodingError(format!(
+                "Number of values in a miniblock should be a multiple of 32. Block size: {}, number of miniblocks: {}",
+                block_size, num_mini_blocks
+            )));
+        }
+
+        Ok((block_size, num_mini_blocks, total_count, first_value))
+    }
+
+    /// Decode all the values. The total value count in the header is untrusted, it must not be
+    /// more than expected_count, e.g. the number of non-null values of the page, so a corrupt
+    /// header cannot allocate or decode more values than the page holds.
+    pub fn decode(
+        buffer: &mut dyn ByteBufferBase,
+        expected_count: usize,
+    ) -> Result<Vec<i64>, BoltReaderError> {
+        let (block_size, num_mini_blocks, total_count, first_value) = Self::read_header(buffer)?;
+        let mini_block_size = block_size / num_mini_blocks;
+
+        if unlikely(total_count > expected_count) {
+            return Err(BoltReaderError::DeltaBinaryPackedDecodingError(format!(
+                "Total value count: {} is more than the expected value count: {}",
+                total_count, expected_count
+            )));
+        }
+
+        let mut result: Vec<i64> = Vec::with_capacity(total_count);
+        if total_count == 0 {
+            return Ok(result);
+        }
+        result.push(first_value);
+
+        let mut last_value = first_value;
+        let mut bit_widths: Vec<u8> = vec![0; num_mini_blocks];
+
+        while result.len() < total_count {
+            let min_delta = Self::decode_zigzag(decode_varint(buffer)?);
+            for bit_width in bit_widths.iter_mut() {
+                *bit_width = buffer.read_u8()?;
+            }
+
+            for &bit_width in bit_widths.iter() {
+                if result.len() >= total_count {
+                    // The miniblocks without values are not stored.
+                    break;
+                }
+
+                if unlikely(bit_width > 64) {
+                    return Err(BoltReaderError::DeltaBinaryPackedDecodingError(format!(
+                        "Invalid miniblock bit width: {}",
+                        bit_width
+                    )));
+                }
+
+                let bit_width = bit_width as usize;
+                let num_bytes = mini_block_size * bit_width / 8;
+                let to_decode = (total_count - result.len()).min(mini_block_size);
+
+                if bit_width == 0 {
+                    for _ in 0..to_decode {
+                        last_value = last_value.wrapping_add(min_delta);
+                        result.push(last_value);
+                    }
+                    continue;
+                }
+
+                let packed =
+                    buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), num_bytes)?;
+                buffer.set_rpos(buffer.get_rpos() + num_bytes);
+
+                for i in 0..to_decode {
+                    let delta = Self::unpack(&packed, i * bit_width, bit_width);
+                    last_value = last_value
+                        .wrapping_add(min_delta)
+                        .wrapping_add(delta as i64);
+                    result.push(last_value);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Read bit_width bits starting at bit_offset, the values are packed from the least
+    /// significant bit.
+    #[inline(always)]
+    fn unpack(packed: &[u8], bit_offset: usize, bit_width: usize) -> u64 {
+        let mut value: u64 = 0;
+        let mut read = 0;
+        let mut byte_idx = bit_offset / 8;
+        let mut bit_idx = bit_offset % 8;
+
+        while read < bit_width {
+            let available = 8 - bit_idx;
+            let to_read = available.min(bit_width - read);
+            let bits = ((packed[byte_idx] >> bit_idx) as u64) & ((1u64 << to_read) - 1);
+            value |= bits << read;
+
+            read += to_read;
+            bit_idx += to_read;
+            if bit_idx == 8 {
+                bit_idx = 0;
+                byte_idx += 1;
+            }
+        }
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::encoding::delta_bitpacked::encode;
+    use rand::{thread_rng, Rng};
+
+    use crate::utils::direct_byte_buffer::DirectByteBuffer;
+    use crate::utils::encoding::delta_binary_packed::DeltaBinaryPackedDecoder;
+
+    fn encode_delta_binary_packed(data: &[i64]) -> DirectByteBuffer {
+        let mut vec = vec![];
+        encode(data.iter().cloned(), &mut vec);
+        DirectByteBuffer::from_vec(vec)
+    }
+
+    #[test]
+    fn test_decode_zigzag() {
+        assert_eq!(DeltaBinaryPackedDecoder::decode_zigzag(0), 0);
+        assert_eq!(DeltaBinaryPackedDecoder::decode_zigzag(1), -1);
+        assert_eq!(DeltaBinaryPackedDecoder::decode_zigzag(2), 1);
+        assert_eq!(DeltaBinaryPackedDecoder::decode_zigzag(3), -2);
+        assert_eq!(DeltaBinaryPackedDecoder::decode_zigzag(u64::MAX), i64::MIN);
+    }
+
+    #[test]
+    fn test_decode_increasing_sequence() {
+        let data: Vec<i64> = (0..1000).map(|i| i * 3 + 7).collect();
+        let mut buf = encode_delta_binary_packed(&data);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, data.len());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), data);
+    }
+
+    #[test]
+    fn test_decode_negative_deltas() {
+        let data: Vec<i64> = (0..1000)
+            .map(|i| if i % 2 == 0 { -i * 100 } else { i * 50 })
+            .collect();
+        let mut buf = encode_delta_binary_packed(&data);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, data.len());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), data);
+    }
+
+    #[test]
+    fn test_decode_random() {
+        let mut rng = thread_rng();
+        for len in [0, 1, 2, 31, 128, 129, 1000] {
+            let data: Vec<i64> = (0..len).map(|_| rng.gen::<i32>() as i64).collect();
+            let mut buf = encode_delta_binary_packed(&data);
+            let result = DeltaBinaryPackedDecoder::decode(&mut buf, data.len());
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_multiple_miniblocks() {
+        // block size 128, 4 miniblocks, 40 values, first value 5 (zigzag 10)
+        let mut vec: Vec<u8> = vec![0x80, 0x01, 4, 40, 10];
+        // min delta -1 (zigzag 1), bit widths 1, 0, x, x
+        vec.extend([1, 1, 0, 7, 7]);
+        // miniblock 1: 32 deltas, alternating 1 and 0 on top of min delta -1
+        vec.extend([0x55; 4]);
+
+        let mut buf = DirectByteBuffer::from_vec(vec);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 40);
+        assert!(result.is_ok());
+
+        let mut expected = vec![5i64];
+        for i in 0..39 {
+            let last = *expected.last().unwrap();
+            expected.push(if i < 32 && i % 2 == 0 { last } else { last - 1 });
+        }
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_decode_invalid_header() {
+        let mut buf = DirectByteBuffer::from_vec(vec![100, 1, 4, 0]);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 4);
+        assert!(result.is_err());
+
+        let mut buf = DirectByteBuffer::from_vec(vec![0x80, 0x01, 3, 4, 0]);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 4);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_corrupt_total_count() {
+        // block size 128, 4 miniblocks, total count u64::MAX >> 1, first value 0, and a block
+        // of zero bit width miniblocks that would repeat the min delta without reading more.
+        let mut vec: Vec<u8> = vec![0x80, 0x01, 4];
+        vec.extend([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]);
+        vec.extend([0, 0, 0, 0, 0, 0]);
+
+        let mut buf = DirectByteBuffer::from_vec(vec);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 1000);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Delta Binary Packed Decoding Error: Total value count: 9223372036854775807 is more than the expected value count: 1000\n"
+        );
+
+        // The total count should not be more than the non-null values of the page.
+        let data: Vec<i64> = (0..100).collect();
+        let mut buf = encode_delta_binary_packed(&data);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 99);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_corrupt_num_mini_blocks() {
+        // block size 128, 2^40 miniblocks, total count 1, first value 0.
+        let mut buf =
+            DirectByteBuffer::from_vec(vec![0x80, 0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x20, 1, 0]);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Delta Binary Packed Decoding Error: Number of miniblocks: 1099511627776 should be 1 to 1024\n"
+        );
+
+        // block size 128, 256 miniblocks of 0 values each.
+        let mut buf = DirectByteBuffer::from_vec(vec![0x80, 0x01, 0x80, 0x02, 1, 0]);
+        let result = DeltaBinaryPackedDecoder::decode(&mut buf, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Delta Binary Packed Decoding Error: Number of values in a miniblock should be a multiple of 32. Block size: 128, number of miniblocks: 256\n"
+        );
+    }
+}
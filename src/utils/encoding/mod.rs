@@ -13,5 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod base64;
+pub mod delta_binary_packed;
 pub mod rle_bp;
 pub mod varint;
@@ -13,18 +13,180 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::mem;
-
-use parquet2::encoding::hybrid_rle::HybridRleDecoder;
-
 use crate::utils::byte_buffer_base::ByteBufferBase;
-use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
 use crate::utils::encoding::varint::decode_varint;
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The native RLE/BP hybrid decoder over a byte slice, yielding num_values decoded values. It is
+/// shared by the dictionary indices, the definition levels and the repetition levels.
+///
+/// Bit-packed runs are padded to a multiple of 8 values, the padding values after num_values are
+/// not yielded. A bit width of 0 yields num_values zeros without reading the data.
+pub struct RleBitPackedDecoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bit_width: usize,
+    num_values_left: usize,
+    rle_value: u64,
+    rle_values_left: usize,
+    // The bit position of the next value of the current bit-packed run.
+    bit_pos: usize,
+    bit_packed_values_left: usize,
+}
+
+impl<'a> RleBitPackedDecoder<'a> {
+    pub fn new(
+        data: &'a [u8],
+        bit_width: usize,
+        num_values: usize,
+    ) -> Result<RleBitPackedDecoder<'a>, BoltReaderError> {
+        if unlikely(bit_width > 64) {
+            return Err(BoltReaderError::RleBpDecodingError(format!(
+                "RLE/BP bit width: {} should not be larger than 64",
+                bit_width
+            )));
+        }
+
+        Ok(RleBitPackedDecoder {
+            data,
+            pos: 0,
+            bit_width,
+            num_values_left: num_values,
+            rle_value: 0,
+            rle_values_left: 0,
+            bit_pos: 0,
+            bit_packed_values_left: 0,
+        })
+    }
+
+    /// The number of bytes of the runs started so far.
+    pub fn get_consumed_bytes(&self) -> usize {
+        self.pos
+    }
+
+    fn read_run_header(&mut self) -> Result<(), BoltReaderError> {
+        let header;
+        (header, self.pos) = decode_run_header(self.data, self.pos)?;
+
+        let num_run_values = header >> 1;
+        let num_left_bytes = self.data.len() - self.pos;
+        if header & 1 == 1 {
+            // Each group of 8 values takes bit_width bytes. Some writers truncate the bytes of
+            // the last group after the final value, so the run is read up to the end of data.
+            let num_bytes = num_run_values
+                .saturating_mul(self.bit_width as u64)
+                .min(num_left_bytes as u64) as usize;
+            self.bit_pos = self.pos * 8;
+            self.bit_packed_values_left = num_run_values
+                .saturating_mul(8)
+                .min((num_bytes * 8 / self.bit_width) as u64)
+                as usize;
+            self.pos += num_bytes;
+        } else {
+            let num_bytes = self.bit_width.div_ceil(8);
+            if unlikely(num_bytes > num_left_bytes) {
+                return Err(BoltReaderError::RleBpDecodingError(format!(
+                    "RLE run value of {} bytes at byte: {} is out of the data size: {} bytes",
+                    num_bytes,
+                    self.pos,
+                    self.data.len()
+                )));
+            }
+            self.rle_value = self.data[self.pos..self.pos + num_bytes]
+                .iter()
+                .rev()
+                .fold(0, |value, byte| (value << 8) | *byte as u64);
+            self.rle_values_left = num_run_values as usize;
+            self.pos += num_bytes;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn read_bit_packed_value(&mut self) -> u64 {
+        let begin = self.bit_pos / 8;
+        let end = (self.bit_pos + self.bit_width).div_ceil(8);
+        let bits = self.data[begin..end]
+            .iter()
+            .rev()
+            .fold(0u128, |bits, byte| (bits << 8) | *byte as u128);
+        let value = (bits >> (self.bit_pos % 8)) as u64;
+        self.bit_pos += self.bit_width;
+
+        if self.bit_width == 64 {
+            value
+        } else {
+            value & ((1 << self.bit_width) - 1)
+        }
+    }
+}
+
+impl Iterator for RleBitPackedDecoder<'_> {
+    type Item = Result<u64, BoltReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_values_left == 0 {
+            return None;
+        }
+        self.num_values_left -= 1;
+
+        if self.bit_width == 0 {
+            return Some(Ok(0));
+        }
+
+        while self.rle_values_left == 0 && self.bit_packed_values_left == 0 {
+            if let Err(e) = self.read_run_header() {
+                // Stop at the first error.
+                self.num_values_left = 0;
+                return Some(Err(e));
+            }
+        }
+
+        if self.rle_values_left > 0 {
+            self.rle_values_left -= 1;
+            Some(Ok(self.rle_value))
+        } else {
+            self.bit_packed_values_left -= 1;
+            Some(Ok(self.read_bit_packed_value()))
+        }
+    }
 
-/// This RLE/BP Decoder is currently based on Rust Arrow2 Parquet.
-/// TODO: Implement a native RLE/BP Decoder, supporting filter push down.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.num_values_left))
+    }
+}
+
+/// Decode the ULEB128 header of the RLE/BP run at pos, and return the header and the position of
+/// the run data after it.
+pub fn decode_run_header(data: &[u8], mut pos: usize) -> Result<(u64, usize), BoltReaderError> {
+    let mut header: u64 = 0;
+    let mut shift = 0;
+    loop {
+        if unlikely(pos >= data.len()) {
+            return Err(BoltReaderError::RleBpDecodingError(format!(
+                "RLE/BP run header at byte: {} is out of the data size: {} bytes",
+                pos,
+                data.len()
+            )));
+        }
+        let byte = data[pos];
+        pos += 1;
+        header |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((header, pos));
+        }
 
+        shift += 7;
+        if unlikely(shift >= 64) {
+            return Err(BoltReaderError::RleBpDecodingError(String::from(
+                "RLE/BP run header contains too many bytes",
+            )));
+        }
+    }
+}
+
+/// Decode the RLE/BP runs of a ByteBufferBase, one run at a time.
 pub struct RleBpDecoder {}
 
 impl RleBpDecoder {
@@ -68,33 +230,71 @@ impl RleBpDecoder {
             header_length + RleBpDecoder::ceil_dividing(bit_width, 8)
         };
 
-        let zero_copy;
-
-        let encoded_data: Vec<u8> = if buffer.can_create_buffer_slice(buffer.get_rpos(), length) {
-            zero_copy = true;
-            DirectByteBuffer::convert_byte_vec(
-                buffer.load_bytes_to_byte_vec(buffer.get_rpos(), length)?,
-                mem::size_of::<u8>(),
+        let rpos = buffer.get_rpos();
+        let result = if buffer.can_create_buffer_slice(rpos, length) {
+            Self::decode_run(
+                buffer.create_buffer_slice(rpos, length)?,
+                bit_width,
+                num_values,
             )?
         } else {
-            zero_copy = false;
-            DirectByteBuffer::convert_byte_vec(
-                buffer.load_bytes_to_byte_vec_deep_copy(buffer.get_rpos(), length)?,
-                mem::size_of::<u8>(),
-            )?
+            let encoded_data = buffer.load_bytes_to_byte_vec_deep_copy(rpos, length)?;
+            Self::decode_run(&encoded_data, bit_width, num_values)?
         };
 
-        let decoder = HybridRleDecoder::try_new(&encoded_data, bit_width as u32, num_values)?;
+        buffer.set_rpos(buffer.get_rpos() + length);
 
-        let result = decoder.collect::<Result<Vec<_>, _>>()?;
+        Ok(result)
+    }
 
-        if zero_copy {
-            mem::forget(encoded_data);
+    /// Decode all the RLE/BP runs of data, e.g. a level section of which the number of values is
+    /// not known. The padding values of the bit-packed runs are included.
+    pub fn decode_runs(data: &[u8], bit_width: usize) -> Result<Vec<u32>, BoltReaderError> {
+        let mut res: Vec<u32> = vec![];
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (header, run_data_pos) = decode_run_header(data, pos)?;
+            let num_run_values = header >> 1;
+            let (num_values, num_bytes) = if header & 1 == 1 {
+                (
+                    num_run_values.saturating_mul(8),
+                    num_run_values.saturating_mul(bit_width as u64),
+                )
+            } else {
+                (num_run_values, bit_width.div_ceil(8) as u64)
+            };
+
+            // The last bit-packed run may be truncated after the final value, the same as
+            // RleBitPackedDecoder.
+            let end = (run_data_pos as u64)
+                .saturating_add(num_bytes)
+                .min(data.len() as u64) as usize;
+            let num_values = if header & 1 == 1 && bit_width > 0 {
+                num_values.min(((end - run_data_pos) * 8 / bit_width) as u64)
+            } else {
+                num_values
+            };
+
+            res.append(&mut Self::decode_run(
+                &data[pos..end],
+                bit_width,
+                num_values as usize,
+            )?);
+            pos = end;
         }
 
-        buffer.set_rpos(buffer.get_rpos() + length);
+        Ok(res)
+    }
 
-        Ok(result)
+    fn decode_run(
+        encoded_data: &[u8],
+        bit_width: usize,
+        num_values: usize,
+    ) -> Result<Vec<u32>, BoltReaderError> {
+        RleBitPackedDecoder::new(encoded_data, bit_width, num_values)?
+            .map(|value| value.map(|value| value as u32))
+            .collect()
     }
 }
 
@@ -106,7 +306,7 @@ mod tests {
     use rand::{thread_rng, Rng};
 
     use crate::utils::direct_byte_buffer::DirectByteBuffer;
-    use crate::utils::encoding::rle_bp::RleBpDecoder;
+    use crate::utils::encoding::rle_bp::{RleBitPackedDecoder, RleBpDecoder};
     use crate::utils::encoding::varint::encode_varint;
     use crate::utils::exceptions::BoltReaderError;
 
@@ -237,4 +437,93 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_rle_bit_packed_decoder_rle_run() {
+        // 300 values of 0x1234 with the bit width 13, followed by 5 values of 7.
+        let data = [0xd8, 0x04, 0x34, 0x12, 0x0a, 0x07, 0x00];
+        let decoder = RleBitPackedDecoder::new(&data, 13, 305).unwrap();
+        let result: Vec<u64> = decoder.collect::<Result<_, _>>().unwrap();
+        assert_eq!(result[..300], vec![0x1234; 300]);
+        assert_eq!(result[300..], vec![7; 5]);
+
+        let mut decoder = RleBitPackedDecoder::new(&data, 13, 10).unwrap();
+        assert_eq!(decoder.by_ref().count(), 10);
+        assert_eq!(decoder.get_consumed_bytes(), 4);
+
+        // The second run is missing.
+        let decoder = RleBitPackedDecoder::new(&data[..4], 13, 301).unwrap();
+        let result: Vec<_> = decoder.collect();
+        assert_eq!(result.len(), 301);
+        assert!(result[299].is_ok());
+        assert_eq!(
+            result[300].as_ref().unwrap_err().to_string(),
+            "Rle Bp Decoding Error: RLE/BP run header at byte: 4 is out of the data size: 4 bytes\n"
+        );
+    }
+
+    #[test]
+    fn test_rle_bit_packed_decoder_bit_packed_run() {
+        for bit_width in [1, 3, 8, 17, 32] {
+            let data: Vec<u32> = (0..100).map(|i| (i * 7919) % (1 << bit_width)).collect();
+            let mut vec = vec![];
+            encode_u32(&mut vec, data.iter().cloned(), bit_width as u32).unwrap();
+
+            // The padding values of the last group are not yielded.
+            let decoder = RleBitPackedDecoder::new(&vec, bit_width, data.len()).unwrap();
+            let result: Vec<u64> = decoder.collect::<Result<_, _>>().unwrap();
+            assert_eq!(result, data.iter().map(|v| *v as u64).collect::<Vec<_>>());
+
+            let decoder = RleBitPackedDecoder::new(&vec[..vec.len() - 1], bit_width, data.len());
+            assert!(decoder.unwrap().any(|v| v.is_err()));
+        }
+
+        // The 64-bit values, packed by hand.
+        let values = [u64::MAX, 1, 0, 1 << 63, 42, 0x0123456789abcdef, 7, 8];
+        let mut vec = vec![0x03];
+        values
+            .iter()
+            .for_each(|v| vec.extend_from_slice(&v.to_le_bytes()));
+        let decoder = RleBitPackedDecoder::new(&vec, 64, 8).unwrap();
+        let result: Vec<u64> = decoder.collect::<Result<_, _>>().unwrap();
+        assert_eq!(result, values);
+
+        assert!(RleBitPackedDecoder::new(&vec, 65, 8).is_err());
+    }
+
+    #[test]
+    fn test_rle_bit_packed_decoder_zero_bit_width() {
+        let decoder = RleBitPackedDecoder::new(&[], 0, 10).unwrap();
+        let result: Vec<u64> = decoder.collect::<Result<_, _>>().unwrap();
+        assert_eq!(result, vec![0; 10]);
+
+        let mut buf = DirectByteBuffer::from_vec(vec![8]);
+        let result = RleBpDecoder::decode(&mut buf, 0);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![0; 4]);
+    }
+
+    #[test]
+    fn test_decode_runs() {
+        let bit_width = 3;
+
+        // A RLE run of three 5s, followed by a bit-packed run of 0 to 7.
+        let mut vec = vec![0x06, 0x05];
+        encode_u32(&mut vec, 0..8, bit_width as u32).unwrap();
+
+        let result = RleBpDecoder::decode_runs(&vec, bit_width);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![5, 5, 5, 0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // The bit-packed run truncated after the 5th value.
+        let result = RleBpDecoder::decode_runs(&vec[..vec.len() - 1], bit_width);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![5, 5, 5, 0, 1, 2, 3, 4]);
+
+        assert_eq!(RleBpDecoder::decode_runs(&[], bit_width).unwrap(), vec![]);
+
+        // The RLE run value is missing.
+        assert!(RleBpDecoder::decode_runs(&[0x06], bit_width).is_err());
+        assert!(RleBpDecoder::decode_runs(&[0x86], bit_width).is_err());
+    }
 }
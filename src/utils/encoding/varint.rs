@@ -13,11 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::intrinsics::unlikely;
-
 use crate::utils::byte_buffer_base::ByteBufferBase;
 use crate::utils::direct_byte_buffer::DirectByteBuffer;
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
 
 const MAX_VARINT_LENGTH_32_BIT: usize = 5;
 const MAX_VARINT_LENGTH_64_BIT: usize = 10;
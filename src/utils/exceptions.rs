@@ -17,6 +17,8 @@ use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::{io, result};
 
+use crate::metadata::parquet_metadata_thrift::Encoding;
+
 /// Result type that could result in an [BoltReaderError]
 pub type Result<T, E = BoltReaderError> = result::Result<T, E>;
 
@@ -28,15 +30,54 @@ pub enum BoltReaderError {
     InternalError(String),
     FileFormatError(String),
     MetadataError(String),
+    FileMetadataError(String),
     VarintDecodingError(String),
     RleBpDecodingError(String),
+    DeltaBinaryPackedDecodingError(String),
     FixedLengthDictionaryPageError(String),
     FixedLengthDataPageError(String),
+    VariableLengthDataPageError(String),
     BridgeError(String),
     RepDefError(String),
+    DictionaryPageError(String),
+    DecompressionError(String),
+    BufferError(String),
+    ColumnChunkError(String),
+    RowGroupError(String),
+    ReaderConfigError(String),
+    UnsupportedEncoding(Encoding),
+    CrcMismatch {
+        expected: u32,
+        computed: u32,
+    },
+    PageTooLarge {
+        page_size: usize,
+        max_page_size: usize,
+    },
+    InvalidUtf8 {
+        index: usize,
+        valid_up_to: usize,
+    },
+    UnsupportedFormatVersion {
+        version: i32,
+        max_version: i32,
+    },
+    TruncatedPage {
+        page_index: usize,
+        page_size: usize,
+        available: usize,
+    },
+    Cancelled,
 }
 
-impl Error for BoltReaderError {}
+impl Error for BoltReaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoltReaderError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl Display for BoltReaderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -59,24 +100,97 @@ impl Display for BoltReaderError {
             BoltReaderError::MetadataError(e) => {
                 writeln!(f, "Parquet Metadata Error: {e}")
             }
+            BoltReaderError::FileMetadataError(e) => {
+                writeln!(f, "File Metadata Error: {e}")
+            }
             BoltReaderError::VarintDecodingError(e) => {
                 writeln!(f, "Varint Decoding Error: {e}")
             }
             BoltReaderError::RleBpDecodingError(e) => {
                 writeln!(f, "Rle Bp Decoding Error: {e}")
             }
+            BoltReaderError::DeltaBinaryPackedDecodingError(e) => {
+                writeln!(f, "Delta Binary Packed Decoding Error: {e}")
+            }
             BoltReaderError::FixedLengthDictionaryPageError(e) => {
                 writeln!(f, "Dictionary Page Loading Error: {e}")
             }
             BoltReaderError::FixedLengthDataPageError(e) => {
                 writeln!(f, "Fixed Length Data Page Error: {e}")
             }
+            BoltReaderError::VariableLengthDataPageError(e) => {
+                writeln!(f, "Variable Length Data Page Error: {e}")
+            }
             BoltReaderError::BridgeError(e) => {
                 writeln!(f, "Bridge Error: {e}")
             }
             BoltReaderError::RepDefError(e) => {
                 writeln!(f, "Repetition and Definition Error: {e}")
             }
+            BoltReaderError::DictionaryPageError(e) => {
+                writeln!(f, "Dictionary Page Error: {e}")
+            }
+            BoltReaderError::DecompressionError(e) => {
+                writeln!(f, "Decompression Error: {e}")
+            }
+            BoltReaderError::BufferError(e) => {
+                writeln!(f, "Buffer Error: {e}")
+            }
+            BoltReaderError::ColumnChunkError(e) => {
+                writeln!(f, "Column Chunk Error: {e}")
+            }
+            BoltReaderError::RowGroupError(e) => {
+                writeln!(f, "Row Group Error: {e}")
+            }
+            BoltReaderError::ReaderConfigError(e) => {
+                writeln!(f, "Reader Config Error: {e}")
+            }
+            BoltReaderError::UnsupportedEncoding(e) => {
+                writeln!(f, "Unsupported Encoding: {}", e.0)
+            }
+            BoltReaderError::CrcMismatch { expected, computed } => {
+                writeln!(
+                    f,
+                    "CRC Mismatch: expected {expected:#010x}, computed {computed:#010x}"
+                )
+            }
+            BoltReaderError::PageTooLarge {
+                page_size,
+                max_page_size,
+            } => {
+                writeln!(
+                    f,
+                    "Page Too Large: page size {page_size} bytes exceeds the max page size {max_page_size} bytes"
+                )
+            }
+            BoltReaderError::InvalidUtf8 { index, valid_up_to } => {
+                writeln!(
+                    f,
+                    "Invalid UTF-8: value {index} has invalid bytes after {valid_up_to} valid bytes"
+                )
+            }
+            BoltReaderError::UnsupportedFormatVersion {
+                version,
+                max_version,
+            } => {
+                writeln!(
+                    f,
+                    "Unsupported Format Version: version {version} is newer than the max supported version {max_version}"
+                )
+            }
+            BoltReaderError::TruncatedPage {
+                page_index,
+                page_size,
+                available,
+            } => {
+                writeln!(
+                    f,
+                    "Truncated Page: page {page_index} of {page_size} bytes has only {available} bytes available"
+                )
+            }
+            BoltReaderError::Cancelled => {
+                writeln!(f, "Cancelled: the read is cancelled")
+            }
         }
     }
 }
@@ -95,6 +209,7 @@ impl From<parquet2::error::Error> for BoltReaderError {
 
 #[cfg(test)]
 mod tests {
+    use std::error::Error;
     use std::fs::File;
 
     use crate::utils::exceptions::BoltReaderError;
@@ -146,4 +261,33 @@ mod tests {
         let error: BoltReaderError = BoltReaderError::MetadataError(String::from("foo"));
         assert_eq!(error.to_string(), "Parquet Metadata Error: foo\n");
     }
+
+    #[test]
+    fn test_boxed_error() {
+        fn read_page() -> Result<(), Box<dyn Error>> {
+            Err(BoltReaderError::FixedLengthDataPageError(String::from(
+                "foo",
+            )))?;
+            Ok(())
+        }
+
+        let error = read_page().unwrap_err();
+        assert_eq!(error.to_string(), "Fixed Length Data Page Error: foo\n");
+        assert!(error.source().is_none());
+        assert!(matches!(
+            error.downcast_ref::<BoltReaderError>(),
+            Some(BoltReaderError::FixedLengthDataPageError(_))
+        ));
+    }
+
+    #[test]
+    fn test_io_error_source() {
+        let error = open_file_internal(String::from("not_exist.txt")).unwrap_err();
+        let source = error.source();
+        assert!(source.is_some());
+        assert_eq!(
+            source.unwrap().to_string(),
+            "No such file or directory (os error 2)"
+        );
+    }
 }
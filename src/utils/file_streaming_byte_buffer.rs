@@ -14,7 +14,6 @@
 // limitations under the License.
 
 use std::cmp::min;
-use std::intrinsics::unlikely;
 use std::io::Read;
 use std::mem;
 
@@ -22,6 +21,7 @@ use crate::utils::byte_buffer_base::ByteBufferBase;
 use crate::utils::direct_byte_buffer::{Buffer, ByteBufferSlice, DirectByteBuffer};
 use crate::utils::exceptions::BoltReaderError;
 use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
 
 pub struct StreamingByteBuffer<'a> {
     buffer: DirectByteBuffer,
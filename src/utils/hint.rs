@@ -0,0 +1,43 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The stable replacement of std::intrinsics::unlikely. The call to the cold function in the
+/// taken branch hints the compiler that the branch is unlikely, so the error handling stays off
+/// the hot path.
+#[inline(always)]
+pub fn unlikely(b: bool) -> bool {
+    if b {
+        cold();
+    }
+    b
+}
+
+#[cold]
+#[inline(never)]
+fn cold() {}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::hint::unlikely;
+
+    #[test]
+    fn test_unlikely() {
+        assert!(unlikely(true));
+        assert!(!unlikely(false));
+
+        let values = [1, 2, 3];
+        assert_eq!(values.iter().filter(|v| unlikely(**v > 2)).count(), 1);
+    }
+}
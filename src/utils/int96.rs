@@ -0,0 +1,104 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+const JULIAN_DAY_OF_UNIX_EPOCH: i64 = 2440588;
+const NANOS_PER_DAY: i64 = 86400 * 1_000_000_000;
+
+/// The legacy INT96 timestamp, written by Spark and Impala. The first 8 bytes are the
+/// little-endian nanoseconds of the day, and the last 4 bytes are the little-endian Julian day.
+///
+/// It is a plain 12 bytes value without padding, so it can be read by
+/// FixedLengthPlainDataPageReaderV1 with the type size Int96::TYPE_SIZE.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Int96(pub [u8; 12]);
+
+#[allow(dead_code)]
+impl std::fmt::Display for Int96 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_unix_nanos())
+    }
+}
+
+impl Int96 {
+    pub const TYPE_SIZE: usize = 12;
+
+    pub fn new(julian_day: u32, nanos_of_day: u64) -> Int96 {
+        let mut bytes = [0u8; 12];
+        bytes[..8].copy_from_slice(&nanos_of_day.to_le_bytes());
+        bytes[8..].copy_from_slice(&julian_day.to_le_bytes());
+        Int96(bytes)
+    }
+
+    pub fn get_nanos_of_day(&self) -> u64 {
+        u64::from_le_bytes(self.0[..8].try_into().unwrap())
+    }
+
+    pub fn get_julian_day(&self) -> u32 {
+        u32::from_le_bytes(self.0[8..].try_into().unwrap())
+    }
+
+    /// Convert to the nanoseconds since the Unix epoch. Like the other nanosecond timestamps,
+    /// the i64 range covers the years 1677 to 2262 and the values outside wrap around.
+    pub fn to_unix_nanos(&self) -> i64 {
+        (self.get_julian_day() as i64 - JULIAN_DAY_OF_UNIX_EPOCH)
+            .wrapping_mul(NANOS_PER_DAY)
+            .wrapping_add(self.get_nanos_of_day() as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use crate::utils::int96::Int96;
+
+    #[test]
+    fn test_int96_layout() {
+        assert_eq!(mem::size_of::<Int96>(), Int96::TYPE_SIZE);
+        assert_eq!(mem::align_of::<Int96>(), 1);
+
+        let value = Int96::new(2458850, 123456789);
+        assert_eq!(value.get_julian_day(), 2458850);
+        assert_eq!(value.get_nanos_of_day(), 123456789);
+        assert_eq!(value.0[..8], 123456789u64.to_le_bytes());
+        assert_eq!(value.0[8..], 2458850u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_int96_to_unix_nanos() {
+        // 1970-01-01T00:00:00Z
+        assert_eq!(Int96::new(2440588, 0).to_unix_nanos(), 0);
+
+        // 2020-01-01T00:00:00.123456789Z
+        let value = Int96::new(2458850, 123456789);
+        assert_eq!(value.to_unix_nanos(), 1_577_836_800_123_456_789);
+        assert_eq!(value.to_string(), "1577836800123456789");
+
+        // 2023-06-15T12:34:56Z, as the raw bytes in the page.
+        let bytes = [
+            0x00, 0x60, 0xfd, 0x4b, 0x32, 0x29, 0x00, 0x00, 0xcf, 0x89, 0x25, 0x00,
+        ];
+        let value = Int96(bytes);
+        assert_eq!(value.get_julian_day(), 2460111);
+        assert_eq!(value.to_unix_nanos(), 1_686_832_496_000_000_000);
+
+        // 1969-12-31T23:59:59Z
+        let value = Int96::new(2440587, 86399 * 1_000_000_000);
+        assert_eq!(value.to_unix_nanos(), -1_000_000_000);
+    }
+}
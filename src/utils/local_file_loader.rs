@@ -87,7 +87,10 @@ impl LocalFileLoader {
 mod tests {
     extern crate bytebuffer;
 
+    use std::io::ErrorKind;
+
     use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::exceptions::BoltReaderError;
     use crate::utils::file_loader::LoadFile;
     use crate::utils::local_file_loader::LocalFileLoader;
 
@@ -140,4 +143,17 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(res.unwrap_err().to_string(), "Internal Error: Reading range exceeds the file size: 2065982 bytes. \nReading range [2065982, 2065983) \n");
     }
+
+    #[test]
+    fn test_open_not_existing_file() {
+        let path = String::from("not_existing_file");
+        let res = LocalFileLoader::new(&path);
+        assert!(res.is_err());
+        let error = res.err().unwrap();
+        assert!(matches!(&error, BoltReaderError::IoError(e) if e.kind() == ErrorKind::NotFound));
+        assert_eq!(
+            error.to_string(),
+            "I/O Error: No such file or directory (os error 2)\n"
+        );
+    }
 }
@@ -0,0 +1,104 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::read_metrics::ReadMetrics;
+
+/// Wrap a LoadFile and count the loaded bytes into the bytes_read of the ReadMetrics.
+pub struct MeteredFileLoader<'a> {
+    loader: &'a dyn LoadFile,
+    read_metrics: Arc<ReadMetrics>,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for MeteredFileLoader<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Metered File Loader, path: {}, bytes_read: {}",
+            self.loader.get_file_path(),
+            self.read_metrics.bytes_read()
+        )
+    }
+}
+
+impl LoadFile for MeteredFileLoader<'_> {
+    fn get_file_path(&self) -> &String {
+        self.loader.get_file_path()
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.loader.get_file_size()
+    }
+
+    fn load_file_to_buffer(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        let buffer = self.loader.load_file_to_buffer(offset, length)?;
+        self.read_metrics.add_bytes_read(length);
+        Ok(buffer)
+    }
+}
+
+impl<'a> MeteredFileLoader<'a> {
+    pub fn new(loader: &'a dyn LoadFile, read_metrics: Arc<ReadMetrics>) -> MeteredFileLoader<'a> {
+        MeteredFileLoader {
+            loader,
+            read_metrics,
+        }
+    }
+
+    pub fn get_read_metrics(&self) -> &Arc<ReadMetrics> {
+        &self.read_metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::metered_file_loader::MeteredFileLoader;
+    use crate::utils::read_metrics::ReadMetrics;
+
+    #[test]
+    fn test_count_bytes_read() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let loader = LocalFileLoader::new(&path).unwrap();
+        let read_metrics = Arc::new(ReadMetrics::default());
+        let file = MeteredFileLoader::new(&loader, read_metrics.clone());
+        assert_eq!(file.get_file_size(), 2065982);
+
+        assert!(file.load_file_to_buffer(0, 4).is_ok());
+        assert!(file.load_file_to_buffer(2065978, 4).is_ok());
+        assert_eq!(read_metrics.bytes_read(), 8);
+
+        // The failed loads are not counted.
+        assert!(file.load_file_to_buffer(2065978, 10).is_err());
+        assert_eq!(file.get_read_metrics().bytes_read(), 8);
+        assert_eq!(
+            read_metrics.to_string(),
+            "Read Metrics: bytes_read 8, pages_decoded 0, pages_pruned 0, row_groups_pruned 0\n"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::fs::File;
+use std::os::unix::io::AsRawFd;
+use std::{io, ptr, slice};
+
+use bytebuffer::Endian::LittleEndian;
+
+use crate::utils::direct_byte_buffer::DirectByteBuffer;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::hint::unlikely;
+
+/// A read only memory mapping of the whole file. The pages are loaded lazily by the OS, so only
+/// the touched byte ranges (e.g. the footer and the selected row groups) are read from the disk.
+///
+/// All the unsafe code of the mapping is here. The mapped bytes are kept as a slice, which is
+/// valid from map to drop: the mapping is read only, private to the process, never remapped, and
+/// unmapped only by drop. The slice is only borrowed through as_slice, bounded by the Mmap, and a
+/// shared slice is Send and Sync, so is the Mmap.
+struct Mmap {
+    bytes: &'static [u8],
+}
+
+impl Mmap {
+    fn map(file: &File, len: usize) -> Result<Mmap, BoltReaderError> {
+        // mmap does not accept a zero length, an empty file is mapped to an empty slice.
+        if len == 0 {
+            return Ok(Mmap { bytes: &[] });
+        }
+
+        let bytes = unsafe {
+            let ptr = libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            );
+            if unlikely(ptr == libc::MAP_FAILED) {
+                return Err(BoltReaderError::IoError(io::Error::last_os_error()));
+            }
+            slice::from_raw_parts(ptr as *const u8, len)
+        };
+
+        Ok(Mmap { bytes })
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if !self.bytes.is_empty() {
+            unsafe {
+                libc::munmap(self.bytes.as_ptr() as *mut libc::c_void, self.bytes.len());
+            }
+        }
+    }
+}
+
+/// Load the byte ranges of a memory mapped file. The DirectByteBuffer owns its bytes, so
+/// load_file_to_buffer copies the range out of the mapping, the same as a pread. The only zero
+/// copy access is get_bytes, which borrows the range from the mapping.
+pub struct MmapFileLoader {
+    path: String,
+    mmap: Mmap,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for MmapFileLoader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Mmap File Loader, path: {}, size: {}",
+            self.path,
+            self.mmap.len()
+        )
+    }
+}
+
+impl LoadFile for MmapFileLoader {
+    fn get_file_path(&self) -> &String {
+        &self.path
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.mmap.len()
+    }
+
+    fn load_file_to_buffer(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        let mut buffer = DirectByteBuffer::from_bytes(self.get_bytes(offset, length)?);
+        buffer.set_endian(LittleEndian);
+
+        Ok(buffer)
+    }
+}
+
+#[allow(dead_code)]
+impl MmapFileLoader {
+    pub fn new(path: &String) -> Result<MmapFileLoader, BoltReaderError> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len() as usize;
+        Ok(MmapFileLoader {
+            path: path.clone(),
+            mmap: Mmap::map(&file, size)?,
+        })
+    }
+
+    /// Borrow the byte range [offset, offset + length) from the mapping without copying.
+    pub fn get_bytes(&self, offset: usize, length: usize) -> Result<&[u8], BoltReaderError> {
+        if unlikely(offset > self.mmap.len() || length > self.mmap.len() - offset) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Reading range exceeds the file size: {} bytes. \nReading range [{}, {}) ",
+                self.mmap.len(),
+                offset,
+                offset + length
+            )));
+        }
+
+        Ok(&self.mmap.as_slice()[offset..offset + length])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::mmap_file_loader::MmapFileLoader;
+
+    #[test]
+    fn test_create_mmap_file_loader() {
+        let path = String::from("not_existing_file");
+        let res = MmapFileLoader::new(&path);
+        assert!(res.is_err());
+
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let res = MmapFileLoader::new(&path);
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        assert_eq!(file.get_file_size(), 2065982);
+        assert_eq!(file.get_file_path(), "src/sample_files/lineitem.parquet");
+
+        let res = DirectByteBuffer::from_file(&file, file.get_file_size() - 4, 4);
+        assert!(res.is_ok());
+        let buffer = res.unwrap();
+        assert_eq!(buffer.as_bytes(), vec![80, 65, 82, 49]);
+
+        let res = DirectByteBuffer::from_file(&file, file.get_file_size(), 1);
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().to_string(), "Internal Error: Reading range exceeds the file size: 2065982 bytes. \nReading range [2065982, 2065983) \n");
+    }
+
+    #[test]
+    fn test_mmap_file_loader_matches_local_file_loader() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let mmap_file = MmapFileLoader::new(&path).unwrap();
+        let local_file = LocalFileLoader::new(&path).unwrap();
+
+        for (offset, length) in [(0, 4), (89775, 12345), (1530515, 341194), (2065000, 982)] {
+            let mut mmap_buffer = DirectByteBuffer::from_file(&mmap_file, offset, length).unwrap();
+            let mut local_buffer =
+                DirectByteBuffer::from_file(&local_file, offset, length).unwrap();
+            assert_eq!(mmap_buffer.as_bytes(), local_buffer.as_bytes());
+            assert_eq!(
+                mmap_buffer.read_u32().unwrap(),
+                local_buffer.read_u32().unwrap()
+            );
+
+            assert_eq!(
+                mmap_file.get_bytes(offset, length).unwrap(),
+                local_buffer.as_bytes()
+            );
+        }
+    }
+}
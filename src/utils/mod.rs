@@ -13,13 +13,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod async_file_loader;
+#[cfg(unix)]
+pub mod async_local_file_loader;
+pub mod buffer_pool;
 pub mod byte_buffer_base;
+pub mod caching_file_loader;
+pub mod cancellation_token;
+pub mod date;
+pub mod decimal;
 pub mod direct_byte_buffer;
 pub mod encoding;
 pub mod exceptions;
 pub mod file_loader;
 pub mod file_streaming_byte_buffer;
+pub mod hint;
+pub mod int96;
 pub mod local_file_loader;
 pub mod macros;
+pub mod metered_file_loader;
+#[cfg(unix)]
+pub mod mmap_file_loader;
+pub mod plain_values;
+pub mod read_metrics;
 pub mod rep_def_parser;
 pub mod row_range_set;
+#[cfg(feature = "s3")]
+pub mod s3_file_loader;
+#[cfg(test)]
+pub mod test_utils;
+pub mod timestamp;
+#[cfg(feature = "tokio")]
+pub mod tokio_file_loader;
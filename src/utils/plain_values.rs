@@ -0,0 +1,179 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+use std::simd::Simd;
+#[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+use std::slice;
+
+#[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+const LANES: usize = 64;
+
+/// Expand the non-null plain values of a nullable page to one value per row. The null positions
+/// are filled with T::default().
+///
+/// With the simd_null_expansion feature on the little-endian targets, the runs of valid values
+/// are bulk copied. Both paths produce the same values.
+#[inline(always)]
+pub fn expand_non_null_values<T: Copy + Default>(
+    non_null_values: &[T],
+    validity: &[bool],
+) -> Vec<T> {
+    #[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+    return expand_non_null_values_simd(non_null_values, validity);
+
+    #[cfg(not(all(feature = "simd_null_expansion", target_endian = "little")))]
+    expand_non_null_values_scalar(non_null_values, validity)
+}
+
+pub fn expand_non_null_values_scalar<T: Copy + Default>(
+    non_null_values: &[T],
+    validity: &[bool],
+) -> Vec<T> {
+    let mut data = Vec::with_capacity(validity.len());
+    expand_chunk(non_null_values, 0, validity, &mut data);
+    data
+}
+
+/// Check the validity 64 rows at a time. The values of the all valid chunks are copied with one
+/// slice copy, and the all null chunks are filled directly.
+#[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+pub fn expand_non_null_values_simd<T: Copy + Default>(
+    non_null_values: &[T],
+    validity: &[bool],
+) -> Vec<T> {
+    let all_valid = Simd::<u8, LANES>::splat(1);
+    let all_null = Simd::<u8, LANES>::splat(0);
+
+    let mut data = Vec::with_capacity(validity.len());
+    let mut idx = 0;
+    let mut chunks = validity.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        // A bool is a single byte of 0 or 1.
+        let bytes = unsafe { slice::from_raw_parts(chunk.as_ptr() as *const u8, LANES) };
+        let lanes = Simd::<u8, LANES>::from_slice(bytes);
+        if lanes == all_valid {
+            data.extend_from_slice(&non_null_values[idx..idx + LANES]);
+            idx += LANES;
+        } else if lanes == all_null {
+            data.resize(data.len() + LANES, T::default());
+        } else {
+            idx = expand_chunk(non_null_values, idx, chunk, &mut data);
+        }
+    }
+    expand_chunk(non_null_values, idx, chunks.remainder(), &mut data);
+
+    data
+}
+
+// Append the values of the chunk, and return the index of the next non-null value.
+#[inline(always)]
+fn expand_chunk<T: Copy + Default>(
+    non_null_values: &[T],
+    mut idx: usize,
+    validity: &[bool],
+    data: &mut Vec<T>,
+) -> usize {
+    for &valid in validity {
+        if valid {
+            data.push(non_null_values[idx]);
+            idx += 1;
+        } else {
+            data.push(T::default());
+        }
+    }
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::utils::plain_values::{expand_non_null_values, expand_non_null_values_scalar};
+
+    // Random validity with the long runs of valid and null rows.
+    fn create_validity(num_values: usize) -> Vec<bool> {
+        let mut rng = rand::thread_rng();
+        let mut validity = Vec::with_capacity(num_values);
+        while validity.len() < num_values {
+            let run = rng.gen_range(1..300).min(num_values - validity.len());
+            match rng.gen_range(0..3) {
+                0 => validity.resize(validity.len() + run, true),
+                1 => validity.resize(validity.len() + run, false),
+                _ => validity.extend((0..run).map(|_| rng.gen_bool(0.7))),
+            }
+        }
+        validity
+    }
+
+    #[test]
+    fn test_expand_non_null_values() {
+        let validity = vec![true, false, false, true, true, false];
+        let res = expand_non_null_values(&[1i32, 2, 3], &validity);
+        assert_eq!(res, vec![1, 0, 0, 2, 3, 0]);
+        assert_eq!(res, expand_non_null_values_scalar(&[1i32, 2, 3], &validity));
+
+        assert!(expand_non_null_values::<i64>(&[], &[]).is_empty());
+        assert_eq!(
+            expand_non_null_values::<i64>(&[], &[false; 100]),
+            vec![0; 100]
+        );
+    }
+
+    #[test]
+    fn test_expand_large_page() {
+        let num_values = 1 << 20;
+        let validity = create_validity(num_values);
+        let num_non_null = validity.iter().filter(|valid| **valid).count();
+
+        let int64_values: Vec<i64> = (0..num_non_null as i64).map(|i| i * 7 - 3).collect();
+        let expected = expand_non_null_values_scalar(&int64_values, &validity);
+        assert_eq!(expected.len(), num_values);
+        assert_eq!(expand_non_null_values(&int64_values, &validity), expected);
+
+        let int32_values: Vec<i32> = (0..num_non_null as i32).map(|i| -i).collect();
+        let expected = expand_non_null_values_scalar(&int32_values, &validity);
+        assert_eq!(expand_non_null_values(&int32_values, &validity), expected);
+
+        // The floating point values are compared bit by bit.
+        let double_values: Vec<f64> = (0..num_non_null).map(|i| i as f64 * 0.1).collect();
+        let expected: Vec<u64> = expand_non_null_values_scalar(&double_values, &validity)
+            .iter()
+            .map(|v| v.to_bits())
+            .collect();
+        let res: Vec<u64> = expand_non_null_values(&double_values, &validity)
+            .iter()
+            .map(|v| v.to_bits())
+            .collect();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    #[cfg(all(feature = "simd_null_expansion", target_endian = "little"))]
+    fn test_expand_simd_matches_scalar() {
+        use crate::utils::plain_values::expand_non_null_values_simd;
+
+        // Not a multiple of the lanes, with the all valid, all null and mixed chunks.
+        for num_values in [0, 1, 63, 64, 65, 1000, 100_003] {
+            let validity = create_validity(num_values);
+            let num_non_null = validity.iter().filter(|valid| **valid).count();
+            let values: Vec<i64> = (0..num_non_null as i64).collect();
+            assert_eq!(
+                expand_non_null_values_simd(&values, &validity),
+                expand_non_null_values_scalar(&values, &validity)
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The metrics of a scan, accumulated by the file loader and the readers sharing it, e.g. the
+/// column chunk readers decoding on other threads.
+/// bytes_read: the bytes loaded from the file, including the footer and the page index
+/// pages_decoded: the data pages created by the column chunk readers
+/// pages_pruned: the data pages skipped without being decoded, as no row is selected
+/// row_groups_pruned: the row groups skipped without loading any column chunk
+#[derive(Default)]
+pub struct ReadMetrics {
+    bytes_read: AtomicUsize,
+    pages_decoded: AtomicUsize,
+    pages_pruned: AtomicUsize,
+    row_groups_pruned: AtomicUsize,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for ReadMetrics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Read Metrics: bytes_read {}, pages_decoded {}, pages_pruned {}, row_groups_pruned {}",
+            self.bytes_read(),
+            self.pages_decoded(),
+            self.pages_pruned(),
+            self.row_groups_pruned()
+        )
+    }
+}
+
+impl ReadMetrics {
+    pub fn bytes_read(&self) -> usize {
+        self.bytes_read.load(Ordering::Relaxed)
+    }
+
+    pub fn pages_decoded(&self) -> usize {
+        self.pages_decoded.load(Ordering::Relaxed)
+    }
+
+    pub fn pages_pruned(&self) -> usize {
+        self.pages_pruned.load(Ordering::Relaxed)
+    }
+
+    pub fn row_groups_pruned(&self) -> usize {
+        self.row_groups_pruned.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_bytes_read(&self, bytes_read: usize) {
+        self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_pages_decoded(&self, pages_decoded: usize) {
+        self.pages_decoded
+            .fetch_add(pages_decoded, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_pages_pruned(&self, pages_pruned: usize) {
+        self.pages_pruned.fetch_add(pages_pruned, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_row_groups_pruned(&self, row_groups_pruned: usize) {
+        self.row_groups_pruned
+            .fetch_add(row_groups_pruned, Ordering::Relaxed);
+    }
+}
@@ -13,11 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::intrinsics::unlikely;
-
 use crate::utils::byte_buffer_base::ByteBufferBase;
-use crate::utils::encoding::rle_bp::RleBpDecoder;
+use crate::utils::encoding::rle_bp::{decode_run_header, RleBpDecoder};
 use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
 
 /// This implementation is based on Rust Arrow2 Parquet reader's RLE/BP Decoder
 ///
@@ -101,20 +100,21 @@ impl RepDefParser {
         buf: &mut dyn ByteBufferBase,
         max_value: u32,
     ) -> Result<bool, BoltReaderError> {
-        let length = buf.read_u32()?;
-        let (_, header_length, is_bit_packing) = RleBpDecoder::read_header(buf)?;
+        let rpos = buf.get_rpos();
         let bit_width = RleBpDecoder::get_minimum_required_bits(max_value);
-
-        let res = if !is_bit_packing && bit_width + header_length as u32 == length && bit_width == 1
-        {
-            buf.set_rpos(buf.get_rpos() + header_length);
-            buf.read_u8()? != 1
-        } else {
-            buf.set_rpos(buf.get_rpos() - 4);
-            return Ok(true);
+        let section = buf.read_rle_section()?;
+
+        // The page is null free if the section is a single RLE run of the 1-bit level 1.
+        let res = match decode_run_header(&section, 0) {
+            Ok((header, value_pos))
+                if header & 1 == 0 && bit_width == 1 && value_pos + 1 == section.len() =>
+            {
+                section[value_pos] != 1
+            }
+            _ => true,
         };
 
-        buf.set_rpos(buf.get_rpos() - bit_width as usize - header_length - 4);
+        buf.set_rpos(rpos);
         Ok(res)
     }
 
@@ -122,7 +122,19 @@ impl RepDefParser {
         buf: &mut dyn ByteBufferBase,
         max_value: u32,
     ) -> Result<Vec<u32>, BoltReaderError> {
-        let length = buf.read_u32()? as usize;
+        let bit_width = RleBpDecoder::get_minimum_required_bits(max_value);
+        RleBpDecoder::decode_runs(&buf.read_rle_section()?, bit_width as usize)
+    }
+
+    /// Load the RLE/BP encoded level data of the given byte length.
+    ///
+    /// Data Page V2 stores the level data without the 4-byte length prefix, the length is
+    /// recorded in the page header instead.
+    pub fn load_level_with_length(
+        buf: &mut dyn ByteBufferBase,
+        max_value: u32,
+        length: usize,
+    ) -> Result<Vec<u32>, BoltReaderError> {
         let bit_width = RleBpDecoder::get_minimum_required_bits(max_value);
 
         let mut res: Vec<u32> = vec![];
@@ -13,8 +13,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::{max, min};
 use std::fmt::Formatter;
 
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
 pub struct RowRange {
     pub begin: usize,
     pub end: usize,
@@ -29,8 +33,25 @@ impl std::fmt::Display for RowRange {
 
 impl RowRange {
     pub fn new(begin: usize, end: usize) -> RowRange {
+        debug_assert!(
+            begin <= end,
+            "RowRange begin: {} should not be larger than end: {}",
+            begin,
+            end
+        );
         RowRange { begin, end }
     }
+
+    /// The fields are public, so a range built without new may have begin > end, and it is
+    /// treated as empty.
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.begin)
+    }
+
+    /// The range [begin, end) is empty if begin >= end.
+    pub fn is_empty(&self) -> bool {
+        self.begin >= self.end
+    }
 }
 
 pub struct RowRangeSet {
@@ -65,6 +86,41 @@ impl RowRangeSet {
         }
     }
 
+    /// Coalesce the consecutive selected rows of the mask into ranges, with the offset 0.
+    pub fn from_bitmap(mask: &[bool]) -> RowRangeSet {
+        let mut row_range_set = RowRangeSet::new(0);
+        let mut generator = RowRangeSetGenerator::new(&mut row_range_set);
+        for (i, selected) in mask.iter().enumerate() {
+            generator.update(i, *selected);
+        }
+        generator.finish(mask.len());
+
+        row_range_set
+    }
+
+    /// The dense mask of total_rows rows, true for the rows in any range. The ranges are taken
+    /// as is, without the offset, which is the inverse of from_bitmap.
+    pub fn to_bitmap(&self, total_rows: usize) -> Result<Vec<bool>, BoltReaderError> {
+        let mut mask = vec![false; total_rows];
+        for row_range in &self.row_ranges {
+            if unlikely(row_range.end > total_rows) {
+                return Err(BoltReaderError::InternalError(format!(
+                    "Row range: [{}, {}) exceeds the total rows: {}",
+                    row_range.begin, row_range.end, total_rows
+                )));
+            }
+            mask[row_range.begin..row_range.end].fill(true);
+        }
+
+        Ok(mask)
+    }
+
+    /// The number of the selected rows. The ranges should not overlap, as the results of
+    /// intersect and union.
+    pub fn count_rows(&self) -> usize {
+        self.row_ranges.iter().map(RowRange::len).sum()
+    }
+
     pub fn get_offset(&self) -> usize {
         self.offset
     }
@@ -76,6 +132,120 @@ impl RowRangeSet {
     pub fn add_row_ranges(&mut self, begin: usize, end: usize) {
         self.row_ranges.push(RowRange::new(begin, end));
     }
+
+    /// Intersect two row range sets. The result uses the offset of self, and its ranges are
+    /// sorted and non-overlapping. Ranges touching at a boundary are kept separate.
+    pub fn intersect(&self, other: &RowRangeSet) -> RowRangeSet {
+        let mut result = RowRangeSet::new(self.offset);
+        let left = self.get_sorted_row_ranges(self.offset);
+        let right = other.get_sorted_row_ranges(self.offset);
+
+        let mut i = 0;
+        let mut j = 0;
+        while i < left.len() && j < right.len() {
+            let begin = max(left[i].begin, right[j].begin);
+            let end = min(left[i].end, right[j].end);
+            if begin < end {
+                result.add_row_ranges(begin, end);
+            }
+
+            if left[i].end < right[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Union two row range sets. The result uses the smaller offset of the two sets, and the
+    /// overlapping or contiguous ranges are coalesced, e.g. [1, 5) and [5, 9) become [1, 9).
+    pub fn union(&self, other: &RowRangeSet) -> RowRangeSet {
+        let offset = min(self.offset, other.offset);
+        let mut ranges = self.get_sorted_row_ranges(offset);
+        ranges.append(&mut other.get_sorted_row_ranges(offset));
+        ranges.sort_by_key(|range| range.begin);
+
+        let mut result = RowRangeSet::new(offset);
+        for range in ranges {
+            match result.row_ranges.last_mut() {
+                Some(last) if range.begin <= last.end => last.end = max(last.end, range.end),
+                _ => result.row_ranges.push(range),
+            }
+        }
+
+        result
+    }
+
+    /// Iterate the ranges clipped to the data page [page_begin, page_end). Following
+    /// DataPage::get_data_page_covered_range, the page boundaries are absolute row indexes and the
+    /// yielded ranges are relative to the given offset, i.e. range.begin + offset is the first row
+    /// to read in the page. Ranges not overlapping the page are skipped.
+    pub fn iter_for_page(
+        &self,
+        page_begin: usize,
+        page_end: usize,
+        offset: usize,
+    ) -> impl Iterator<Item = RowRange> + '_ {
+        let page_begin = max(page_begin, offset);
+        self.row_ranges.iter().filter_map(move |range| {
+            let begin = max(range.begin + self.offset, page_begin);
+            let end = min(range.end + self.offset, page_end);
+            if begin < end {
+                Some(RowRange::new(begin - offset, end - offset))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Return the non-empty ranges relative to the given offset, sorted by begin with the
+    /// overlapping ranges merged. Ranges before the offset are clipped.
+    fn get_sorted_row_ranges(&self, offset: usize) -> Vec<RowRange> {
+        let mut ranges: Vec<RowRange> = self
+            .row_ranges
+            .iter()
+            .filter(|range| !range.is_empty() && range.end + self.offset > offset)
+            .map(|range| {
+                RowRange::new(
+                    (range.begin + self.offset).saturating_sub(offset),
+                    range.end + self.offset - offset,
+                )
+            })
+            .collect();
+        ranges.sort_by_key(|range| range.begin);
+
+        let mut merged: Vec<RowRange> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.begin < last.end => last.end = max(last.end, range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+}
+
+/// Distribute the ranges over the pages, where the page i covers the absolute rows
+/// [page_boundaries[i], page_boundaries[i + 1]), so n pages have n + 1 boundaries. Like
+/// RowRangeSet::iter_for_page, the returned ranges are relative to the given offset. They are
+/// ordered by the page index, and the ranges in the same page follow the order of the set.
+pub fn split_range_across_pages(
+    ranges: &RowRangeSet,
+    page_boundaries: &[usize],
+    offset: usize,
+) -> Vec<(usize, RowRange)> {
+    page_boundaries
+        .windows(2)
+        .enumerate()
+        .flat_map(|(page_index, page)| {
+            ranges
+                .iter_for_page(page[0], page[1], offset)
+                .map(move |range| (page_index, range))
+        })
+        .collect()
 }
 
 pub struct RowRangeSetGenerator<'a> {
@@ -115,7 +285,34 @@ impl<'a> RowRangeSetGenerator<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::row_range_set::{RowRangeSet, RowRangeSetGenerator};
+    use crate::utils::row_range_set::{
+        split_range_across_pages, RowRange, RowRangeSet, RowRangeSetGenerator,
+    };
+
+    #[test]
+    fn test_row_range_len() {
+        let row_range = RowRange::new(3, 10);
+        assert_eq!(row_range.len(), 7);
+        assert!(!row_range.is_empty());
+
+        let row_range = RowRange::new(5, 5);
+        assert_eq!(row_range.len(), 0);
+        assert!(row_range.is_empty());
+    }
+
+    #[test]
+    fn test_inverted_row_range_len() {
+        let row_range = RowRange { begin: 10, end: 3 };
+        assert_eq!(row_range.len(), 0);
+        assert!(row_range.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "RowRange begin: 10 should not be larger than end: 3")]
+    fn test_invalid_row_range() {
+        RowRange::new(10, 3);
+    }
 
     #[test]
     fn test_create_row_range_set() {
@@ -133,6 +330,187 @@ mod tests {
         assert_eq!(row_range_set.get_row_ranges()[1].end, 20);
     }
 
+    fn to_pairs(row_range_set: &RowRangeSet) -> Vec<(usize, usize)> {
+        row_range_set
+            .get_row_ranges()
+            .iter()
+            .map(|range| (range.begin, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_intersect_row_range_set() {
+        // Disjoint
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(0, 10);
+        left.add_row_ranges(20, 30);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(10, 20);
+        right.add_row_ranges(30, 40);
+        let result = left.intersect(&right);
+        assert_eq!(result.get_offset(), 0);
+        assert_eq!(result.get_row_ranges().len(), 0);
+
+        // Overlapping, with unsorted input
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(20, 30);
+        left.add_row_ranges(0, 10);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(5, 25);
+        let result = left.intersect(&right);
+        assert_eq!(to_pairs(&result), vec![(5, 10), (20, 25)]);
+        assert_eq!(to_pairs(&result), to_pairs(&right.intersect(&left)));
+
+        // Nested
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(0, 100);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(10, 20);
+        right.add_row_ranges(30, 40);
+        let result = left.intersect(&right);
+        assert_eq!(to_pairs(&result), vec![(10, 20), (30, 40)]);
+    }
+
+    #[test]
+    fn test_intersect_row_range_set_edge_cases() {
+        // Touching ranges are not merged
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(0, 5);
+        left.add_row_ranges(5, 10);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(0, 10);
+        let result = left.intersect(&right);
+        assert_eq!(to_pairs(&result), vec![(0, 5), (5, 10)]);
+
+        // Empty input
+        let empty = RowRangeSet::new(0);
+        assert_eq!(left.intersect(&empty).get_row_ranges().len(), 0);
+        assert_eq!(empty.intersect(&left).get_row_ranges().len(), 0);
+
+        // Different offsets, the result is relative to the offset of self
+        let mut left = RowRangeSet::new(100);
+        left.add_row_ranges(0, 50);
+        let mut right = RowRangeSet::new(80);
+        right.add_row_ranges(0, 10);
+        right.add_row_ranges(30, 40);
+        let result = left.intersect(&right);
+        assert_eq!(result.get_offset(), 100);
+        assert_eq!(to_pairs(&result), vec![(10, 20)]);
+    }
+
+    #[test]
+    fn test_union_row_range_set() {
+        // Contiguous ranges are coalesced
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(1, 5);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(5, 9);
+        let result = left.union(&right);
+        assert_eq!(to_pairs(&result), vec![(1, 9)]);
+
+        // Overlapping and disjoint ranges, the result is sorted by begin
+        let mut left = RowRangeSet::new(0);
+        left.add_row_ranges(30, 40);
+        left.add_row_ranges(0, 10);
+        let mut right = RowRangeSet::new(0);
+        right.add_row_ranges(8, 12);
+        right.add_row_ranges(50, 60);
+        let result = left.union(&right);
+        assert_eq!(to_pairs(&result), vec![(0, 12), (30, 40), (50, 60)]);
+
+        // Different offsets, the result uses the smaller offset
+        let mut left = RowRangeSet::new(100);
+        left.add_row_ranges(0, 10);
+        let mut right = RowRangeSet::new(80);
+        right.add_row_ranges(0, 10);
+        right.add_row_ranges(25, 30);
+        let result = left.union(&right);
+        assert_eq!(result.get_offset(), 80);
+        assert_eq!(to_pairs(&result), vec![(0, 10), (20, 30)]);
+
+        // Empty input
+        let empty = RowRangeSet::new(0);
+        assert_eq!(to_pairs(&empty.union(&right)), vec![(80, 90), (105, 110)]);
+        assert_eq!(empty.union(&empty).get_row_ranges().len(), 0);
+    }
+
+    #[test]
+    fn test_union_row_range_set_idempotent() {
+        let mut row_range_set = RowRangeSet::new(3);
+        row_range_set.add_row_ranges(0, 10);
+        row_range_set.add_row_ranges(15, 20);
+        row_range_set.add_row_ranges(20, 25);
+
+        let result = row_range_set.union(&row_range_set);
+        assert_eq!(result.get_offset(), 3);
+        assert_eq!(to_pairs(&result), vec![(0, 10), (15, 25)]);
+        assert_eq!(to_pairs(&result.union(&result)), to_pairs(&result));
+    }
+
+    #[test]
+    fn test_iter_for_page() {
+        let offset = 50;
+        let mut row_range_set = RowRangeSet::new(offset);
+        row_range_set.add_row_ranges(10, 60);
+        row_range_set.add_row_ranges(120, 130);
+        row_range_set.add_row_ranges(180, 260);
+
+        // Three pages with 100 rows each: [50, 150), [150, 250) and [250, 350)
+        let pages = [(50, 150), (150, 250), (250, 350)];
+        let covered: Vec<Vec<(usize, usize)>> = pages
+            .iter()
+            .map(|(page_begin, page_end)| {
+                row_range_set
+                    .iter_for_page(*page_begin, *page_end, offset)
+                    .map(|range| (range.begin, range.end))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(covered[0], vec![(10, 60)]);
+        assert_eq!(covered[1], vec![(120, 130), (180, 200)]);
+        assert_eq!(covered[2], vec![(200, 260)]);
+
+        // A page after all the ranges
+        assert_eq!(row_range_set.iter_for_page(350, 450, offset).count(), 0);
+
+        // The yielded ranges are relative to the given offset
+        let ranges: Vec<(usize, usize)> = row_range_set
+            .iter_for_page(150, 250, 0)
+            .map(|range| (range.begin, range.end))
+            .collect();
+        assert_eq!(ranges, vec![(170, 180), (230, 250)]);
+    }
+
+    #[test]
+    fn test_split_range_across_pages() {
+        let offset = 50;
+        let mut row_range_set = RowRangeSet::new(offset);
+        row_range_set.add_row_ranges(80, 230);
+
+        // Three pages with 100 rows each: [50, 150), [150, 250) and [250, 350)
+        let page_boundaries = [50, 150, 250, 350];
+        let split: Vec<(usize, usize, usize)> =
+            split_range_across_pages(&row_range_set, &page_boundaries, offset)
+                .iter()
+                .map(|(page_index, range)| (*page_index, range.begin, range.end))
+                .collect();
+        assert_eq!(split, vec![(0, 80, 100), (1, 100, 200), (2, 200, 230)]);
+
+        // Relative to the offset 0, with a range before the first page and a page without any
+        // range.
+        row_range_set.add_row_ranges(0, 10);
+        let split: Vec<(usize, usize, usize)> =
+            split_range_across_pages(&row_range_set, &[100, 150, 250, 300, 350], 0)
+                .iter()
+                .map(|(page_index, range)| (*page_index, range.begin, range.end))
+                .collect();
+        assert_eq!(split, vec![(0, 130, 150), (1, 150, 250), (2, 250, 280)]);
+
+        assert!(split_range_across_pages(&row_range_set, &[50], offset).is_empty());
+        assert!(split_range_across_pages(&row_range_set, &[], offset).is_empty());
+    }
+
     #[test]
     fn test_row_range_set_generator() {
         let offset = 3;
@@ -210,4 +588,116 @@ mod tests {
         assert_eq!(row_range_set.get_row_ranges()[0].begin, 4);
         assert_eq!(row_range_set.get_row_ranges()[0].end, 7);
     }
+
+    fn get_ranges(row_range_set: &RowRangeSet) -> Vec<(usize, usize)> {
+        row_range_set
+            .get_row_ranges()
+            .iter()
+            .map(|range| (range.begin, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_row_range_set_from_bitmap() {
+        let mask = [true, true, false, true, true, true, false, false, true];
+        let row_range_set = RowRangeSet::from_bitmap(&mask);
+        assert_eq!(row_range_set.get_offset(), 0);
+        assert_eq!(get_ranges(&row_range_set), vec![(0, 2), (3, 6), (8, 9)]);
+
+        assert!(RowRangeSet::from_bitmap(&[]).get_row_ranges().is_empty());
+        assert!(RowRangeSet::from_bitmap(&[false; 10])
+            .get_row_ranges()
+            .is_empty());
+        assert_eq!(
+            get_ranges(&RowRangeSet::from_bitmap(&[true; 10])),
+            vec![(0, 10)]
+        );
+    }
+
+    #[test]
+    fn test_row_range_set_from_alternating_bitmap() {
+        let mask: Vec<bool> = (0..1001).map(|i| i % 2 == 0).collect();
+        let ranges = get_ranges(&RowRangeSet::from_bitmap(&mask));
+        assert_eq!(ranges.len(), 501);
+        for (i, range) in ranges.iter().enumerate() {
+            assert_eq!(*range, (i * 2, i * 2 + 1));
+        }
+
+        let mask: Vec<bool> = (0..1000).map(|i| i % 2 == 1).collect();
+        let ranges = get_ranges(&RowRangeSet::from_bitmap(&mask));
+        assert_eq!(ranges.len(), 500);
+        assert_eq!(ranges[0], (1, 2));
+        assert_eq!(ranges[499], (999, 1000));
+    }
+
+    #[test]
+    fn test_row_range_set_to_bitmap() {
+        let mut row_range_set = RowRangeSet::new(0);
+        row_range_set.add_row_ranges(5, 8);
+        row_range_set.add_row_ranges(1, 3);
+        // The overlapping ranges are merged in the mask.
+        row_range_set.add_row_ranges(6, 9);
+
+        let res = row_range_set.to_bitmap(10);
+        assert!(res.is_ok());
+        let mask = res.unwrap();
+        assert_eq!(
+            mask,
+            vec![false, true, true, false, false, true, true, true, true, false]
+        );
+
+        // Round trip, the ranges are coalesced and sorted.
+        let round_trip = RowRangeSet::from_bitmap(&mask);
+        assert_eq!(get_ranges(&round_trip), vec![(1, 3), (5, 9)]);
+        assert_eq!(round_trip.to_bitmap(10).unwrap(), mask);
+        assert_eq!(
+            get_ranges(&RowRangeSet::from_bitmap(
+                &round_trip.to_bitmap(10).unwrap()
+            )),
+            get_ranges(&round_trip)
+        );
+
+        let mask: Vec<bool> = (0..100).map(|i| i % 3 != 0 && i % 7 != 0).collect();
+        assert_eq!(
+            RowRangeSet::from_bitmap(&mask).to_bitmap(100).unwrap(),
+            mask
+        );
+
+        assert_eq!(RowRangeSet::new(0).to_bitmap(3).unwrap(), vec![false; 3]);
+
+        let res = row_range_set.to_bitmap(8);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Row range: [6, 9) exceeds the total rows: 8\n"
+        );
+    }
+
+    #[test]
+    fn test_count_rows() {
+        assert_eq!(RowRangeSet::new(0).count_rows(), 0);
+
+        let mut row_range_set = RowRangeSet::new(100);
+        let ranges = [(0, 10), (15, 16), (20, 20), (30, 105)];
+        for (begin, end) in ranges {
+            row_range_set.add_row_ranges(begin, end);
+        }
+        let expected: usize = ranges.iter().map(|(begin, end)| end - begin).sum();
+        assert_eq!(expected, 86);
+        assert_eq!(row_range_set.count_rows(), expected);
+        assert_eq!(
+            row_range_set.count_rows(),
+            row_range_set
+                .to_bitmap(105)
+                .unwrap()
+                .iter()
+                .filter(|v| **v)
+                .count()
+        );
+
+        let mut other = RowRangeSet::new(100);
+        other.add_row_ranges(5, 40);
+        assert_eq!(row_range_set.intersect(&other).count_rows(), 5 + 1 + 10);
+        assert_eq!(row_range_set.union(&other).count_rows(), 105);
+    }
 }
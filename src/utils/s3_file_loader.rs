@@ -0,0 +1,235 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::future::Future;
+
+use crate::utils::async_file_loader::AsyncLoadFile;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// The S3 operations used by the S3FileLoader. It is implemented on top of the aws-sdk-s3 client
+/// by the caller, which keeps the SDK and its runtime out of the reader.
+pub trait S3Client: Sync {
+    /// Return the content length of the object from HeadObject.
+    fn head_object(
+        &self,
+        bucket: &str,
+        key: &str,
+    ) -> impl Future<Output = Result<usize, BoltReaderError>> + Send;
+
+    /// Return the body of GetObject sent with the given Range header, e.g. "bytes=0-99".
+    fn get_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        range: &str,
+    ) -> impl Future<Output = Result<Vec<u8>, BoltReaderError>> + Send;
+}
+
+/// Load a Parquet file on S3 with HTTP range requests. The object size is fetched once by
+/// HeadObject when the loader is created.
+pub struct S3FileLoader<C: S3Client> {
+    path: String,
+    bucket: String,
+    key: String,
+    size: usize,
+    client: C,
+}
+
+#[allow(dead_code)]
+impl<C: S3Client> std::fmt::Display for S3FileLoader<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "S3 File Loader, path: {}, size: {}",
+            self.path, self.size
+        )
+    }
+}
+
+impl<C: S3Client> AsyncLoadFile for S3FileLoader<C> {
+    fn get_file_path(&self) -> &String {
+        &self.path
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.size
+    }
+
+    async fn load_bytes(&self, begin: usize, end: usize) -> Result<Vec<u8>, BoltReaderError> {
+        if unlikely(begin > end || end > self.size) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Reading range exceeds the file size: {} bytes. \nReading range [{}, {}) ",
+                self.size, begin, end
+            )));
+        }
+
+        // The HTTP range is inclusive and cannot be empty.
+        if begin == end {
+            return Ok(Vec::new());
+        }
+
+        let range = get_range_header(begin, end);
+        let bytes = self
+            .client
+            .get_object(&self.bucket, &self.key, &range)
+            .await?;
+        if unlikely(bytes.len() != end - begin) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Loaded {} bytes from {} with range: {}, expected {} bytes",
+                bytes.len(),
+                self.path,
+                range,
+                end - begin
+            )));
+        }
+
+        Ok(bytes)
+    }
+}
+
+#[allow(dead_code)]
+impl<C: S3Client> S3FileLoader<C> {
+    pub async fn new(
+        client: C,
+        bucket: &str,
+        key: &str,
+    ) -> Result<S3FileLoader<C>, BoltReaderError> {
+        let size = client.head_object(bucket, key).await?;
+        Ok(S3FileLoader {
+            path: format!("s3://{}/{}", bucket, key),
+            bucket: String::from(bucket),
+            key: String::from(key),
+            size,
+            client,
+        })
+    }
+
+    pub fn get_client(&self) -> &C {
+        &self.client
+    }
+}
+
+/// Convert the byte range [begin, end) to the inclusive HTTP Range header value.
+fn get_range_header(begin: usize, end: usize) -> String {
+    format!("bytes={}-{}", begin, end - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Mutex;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::utils::async_file_loader::AsyncLoadFile;
+    use crate::utils::exceptions::BoltReaderError;
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::s3_file_loader::{S3Client, S3FileLoader};
+
+    struct StubS3Client {
+        data: Vec<u8>,
+        ranges: Mutex<Vec<String>>,
+    }
+
+    impl S3Client for StubS3Client {
+        async fn head_object(&self, bucket: &str, key: &str) -> Result<usize, BoltReaderError> {
+            assert_eq!(bucket, "bucket");
+            assert_eq!(key, "lineitem.parquet");
+            Ok(self.data.len())
+        }
+
+        async fn get_object(
+            &self,
+            _bucket: &str,
+            _key: &str,
+            range: &str,
+        ) -> Result<Vec<u8>, BoltReaderError> {
+            self.ranges.lock().unwrap().push(String::from(range));
+            let (begin, end) = range
+                .strip_prefix("bytes=")
+                .and_then(|range| range.split_once('-'))
+                .unwrap();
+            let begin: usize = begin.parse().unwrap();
+            let end: usize = end.parse().unwrap();
+            Ok(self.data[begin..end + 1].to_vec())
+        }
+    }
+
+    // The stub client never returns pending, so the futures can be polled once.
+    fn poll_ready<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(res) => res,
+            Poll::Pending => panic!("The stub S3 client should not be pending"),
+        }
+    }
+
+    fn create_s3_file_loader() -> S3FileLoader<StubS3Client> {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = LocalFileLoader::new(&path).unwrap();
+        let data = file
+            .load_file_to_buffer(0, file.get_file_size())
+            .unwrap()
+            .into_vec();
+        let client = StubS3Client {
+            data,
+            ranges: Mutex::new(Vec::new()),
+        };
+
+        poll_ready(S3FileLoader::new(client, "bucket", "lineitem.parquet")).unwrap()
+    }
+
+    #[test]
+    fn test_s3_file_loader_range_header() {
+        let file = create_s3_file_loader();
+        assert_eq!(file.get_file_size(), 2065982);
+        assert_eq!(file.get_file_path(), "s3://bucket/lineitem.parquet");
+
+        let res = poll_ready(file.load_bytes(0, 4));
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), vec![80, 65, 82, 49]);
+
+        let res = poll_ready(file.load_bytes(2065978, 2065982));
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), vec![80, 65, 82, 49]);
+
+        assert_eq!(
+            *file.get_client().ranges.lock().unwrap(),
+            vec!["bytes=0-3", "bytes=2065978-2065981"]
+        );
+    }
+
+    #[test]
+    fn test_s3_file_loader_invalid_range() {
+        let file = create_s3_file_loader();
+
+        let res = poll_ready(file.load_bytes(2065982, 2065983));
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().to_string(), "Internal Error: Reading range exceeds the file size: 2065982 bytes. \nReading range [2065982, 2065983) \n");
+
+        let res = poll_ready(file.load_bytes(10, 10));
+        assert!(res.is_ok());
+        assert!(res.unwrap().is_empty());
+
+        // No request is sent for the invalid or empty ranges.
+        assert!(file.get_client().ranges.lock().unwrap().is_empty());
+    }
+}
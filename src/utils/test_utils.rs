@@ -0,0 +1,123 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::file_loader::LoadFile;
+use crate::utils::local_file_loader::LocalFileLoader;
+
+/// A file of the bytes in memory, e.g. a sample file rewritten or truncated by the test. The
+/// loaded buffers read little-endian values, the same as the LocalFileLoader.
+pub struct InMemoryFile {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+impl LoadFile for InMemoryFile {
+    fn get_file_path(&self) -> &String {
+        &self.path
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn load_file_to_buffer(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        Ok(DirectByteBuffer::from_loaded_bytes(
+            self.data[offset..offset + length].to_vec(),
+        ))
+    }
+}
+
+/// A LocalFileLoader recording the (offset, length) of every load, to check the I/O pattern of
+/// the readers.
+pub struct RecordingFileLoader {
+    loader: LocalFileLoader,
+    ranges: Mutex<Vec<(usize, usize)>>,
+}
+
+impl LoadFile for RecordingFileLoader {
+    fn get_file_path(&self) -> &String {
+        self.loader.get_file_path()
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.loader.get_file_size()
+    }
+
+    fn load_file_to_buffer(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> Result<DirectByteBuffer, BoltReaderError> {
+        self.ranges.lock().unwrap().push((offset, length));
+        self.loader.load_file_to_buffer(offset, length)
+    }
+}
+
+impl RecordingFileLoader {
+    pub fn new(loader: LocalFileLoader) -> RecordingFileLoader {
+        RecordingFileLoader {
+            loader,
+            ranges: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn get_loader(&self) -> &LocalFileLoader {
+        &self.loader
+    }
+
+    pub fn get_ranges(&self) -> Vec<(usize, usize)> {
+        self.ranges.lock().unwrap().clone()
+    }
+
+    pub fn get_lengths(&self) -> Vec<usize> {
+        self.get_ranges()
+            .into_iter()
+            .map(|(_, length)| length)
+            .collect()
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Poll the future on the current thread until it is ready, parking the thread in between.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(res) => return res,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
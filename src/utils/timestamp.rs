@@ -0,0 +1,246 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use crate::metadata::parquet_metadata_thrift::TimeUnit;
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// The unit of the INT64 timestamp values since the Unix epoch, and of the time values since
+/// midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampUnit {
+    Millis,
+    Micros,
+    Nanos,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for TimestampUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampUnit::Millis => write!(f, "MILLIS"),
+            TimestampUnit::Micros => write!(f, "MICROS"),
+            TimestampUnit::Nanos => write!(f, "NANOS"),
+        }
+    }
+}
+
+impl TimestampUnit {
+    pub fn from_thrift(unit: &TimeUnit) -> TimestampUnit {
+        match unit {
+            TimeUnit::MILLIS(_) => TimestampUnit::Millis,
+            TimeUnit::MICROS(_) => TimestampUnit::Micros,
+            TimeUnit::NANOS(_) => TimestampUnit::Nanos,
+        }
+    }
+
+    pub fn get_nanos_per_unit(&self) -> i64 {
+        match self {
+            TimestampUnit::Millis => 1_000_000,
+            TimestampUnit::Micros => 1_000,
+            TimestampUnit::Nanos => 1,
+        }
+    }
+}
+
+/// The TIMESTAMP logical type of an INT64 column.
+/// is_adjusted_to_utc: the values are the instants since the Unix epoch in UTC, otherwise they
+/// are the local date times without a time zone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimestampType {
+    unit: TimestampUnit,
+    is_adjusted_to_utc: bool,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for TimestampType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Timestamp({}, {})",
+            self.unit,
+            if self.is_adjusted_to_utc {
+                "UTC"
+            } else {
+                "local"
+            }
+        )
+    }
+}
+
+impl TimestampType {
+    pub fn new(unit: TimestampUnit, is_adjusted_to_utc: bool) -> TimestampType {
+        TimestampType {
+            unit,
+            is_adjusted_to_utc,
+        }
+    }
+
+    pub fn get_unit(&self) -> TimestampUnit {
+        self.unit
+    }
+
+    pub fn is_adjusted_to_utc(&self) -> bool {
+        self.is_adjusted_to_utc
+    }
+
+    /// Scale the raw value to nanoseconds. The i64 nanoseconds cover the years 1677 to 2262, so
+    /// the millis and micros values outside are rejected.
+    pub fn to_nanos(&self, value: i64) -> Result<i64, BoltReaderError> {
+        match value.checked_mul(self.unit.get_nanos_per_unit()) {
+            Some(nanos) => Ok(nanos),
+            None => Err(BoltReaderError::InternalError(format!(
+                "Timestamp: {} {} is out of the range of the nanoseconds",
+                value, self.unit
+            ))),
+        }
+    }
+}
+
+/// The TIME logical type of an INT64 column, the values are the MICROS or NANOS since midnight.
+/// is_adjusted_to_utc: the values are the times of the day in UTC, otherwise they are the local
+/// times without a time zone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeType {
+    unit: TimestampUnit,
+    is_adjusted_to_utc: bool,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for TimeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Time({}, {})",
+            self.unit,
+            if self.is_adjusted_to_utc {
+                "UTC"
+            } else {
+                "local"
+            }
+        )
+    }
+}
+
+impl TimeType {
+    pub fn new(unit: TimestampUnit, is_adjusted_to_utc: bool) -> TimeType {
+        TimeType {
+            unit,
+            is_adjusted_to_utc,
+        }
+    }
+
+    pub fn get_unit(&self) -> TimestampUnit {
+        self.unit
+    }
+
+    pub fn is_adjusted_to_utc(&self) -> bool {
+        self.is_adjusted_to_utc
+    }
+
+    /// The number of the values in a day, the valid values are [0, get_units_per_day()).
+    pub fn get_units_per_day(&self) -> i64 {
+        SECONDS_PER_DAY * (1_000_000_000 / self.unit.get_nanos_per_unit())
+    }
+
+    /// Convert the raw value to the microseconds since midnight, the NANOS values are truncated.
+    /// The values outside of a day are rejected.
+    pub fn to_micros(&self, value: i64) -> Result<i64, BoltReaderError> {
+        if unlikely(value < 0 || value >= self.get_units_per_day()) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Time: {} {} is out of the range of a day: [0, {})",
+                value,
+                self.unit,
+                self.get_units_per_day()
+            )));
+        }
+
+        Ok(match self.unit {
+            TimestampUnit::Millis => value * 1_000,
+            TimestampUnit::Micros => value,
+            TimestampUnit::Nanos => value / 1_000,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::parquet_metadata_thrift::{MicroSeconds, TimeUnit};
+    use crate::utils::timestamp::{TimeType, TimestampType, TimestampUnit};
+
+    #[test]
+    fn test_timestamp_to_nanos() {
+        let millis = TimestampType::new(TimestampUnit::Millis, true);
+        assert_eq!(millis.to_nanos(1577836800123).unwrap(), 1577836800123000000);
+        assert_eq!(millis.to_nanos(-1500).unwrap(), -1500000000);
+        assert_eq!(millis.to_string(), "Timestamp(MILLIS, UTC)");
+
+        let micros = TimestampType::new(
+            TimestampUnit::from_thrift(&TimeUnit::MICROS(MicroSeconds::new())),
+            false,
+        );
+        assert_eq!(micros.get_unit(), TimestampUnit::Micros);
+        assert!(!micros.is_adjusted_to_utc());
+        assert_eq!(
+            micros.to_nanos(1577836800123456).unwrap(),
+            1577836800123456000
+        );
+        assert_eq!(micros.to_string(), "Timestamp(MICROS, local)");
+
+        let nanos = TimestampType::new(TimestampUnit::Nanos, true);
+        assert_eq!(nanos.to_nanos(i64::MIN).unwrap(), i64::MIN);
+
+        let res = millis.to_nanos(i64::MAX / 1000);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Timestamp: 9223372036854775 MILLIS is out of the range of the nanoseconds\n"
+        );
+    }
+
+    #[test]
+    fn test_time_to_micros() {
+        let micros = TimeType::new(TimestampUnit::Micros, true);
+        assert_eq!(micros.get_units_per_day(), 86_400_000_000);
+        assert_eq!(micros.to_string(), "Time(MICROS, UTC)");
+
+        // The midnight, the noon and one microsecond before the midnight.
+        assert_eq!(micros.to_micros(0).unwrap(), 0);
+        assert_eq!(micros.to_micros(43_200_000_000).unwrap(), 43_200_000_000);
+        assert_eq!(micros.to_micros(86_399_999_999).unwrap(), 86_399_999_999);
+
+        let res = micros.to_micros(86_400_000_000);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Internal Error: Time: 86400000000 MICROS is out of the range of a day: [0, 86400000000)\n"
+        );
+        assert!(micros.to_micros(-1).is_err());
+
+        let millis = TimeType::new(TimestampUnit::Millis, false);
+        assert_eq!(millis.to_micros(86_399_999).unwrap(), 86_399_999_000);
+        assert!(millis.to_micros(86_400_000).is_err());
+
+        let nanos = TimeType::new(TimestampUnit::Nanos, false);
+        assert_eq!(nanos.to_micros(86_399_999_999_999).unwrap(), 86_399_999_999);
+        assert_eq!(nanos.to_micros(1_999).unwrap(), 1);
+        assert!(nanos.to_micros(i64::MAX).is_err());
+        assert_eq!(nanos.to_string(), "Time(NANOS, local)");
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) ByteDance, Inc. and its affiliates.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+use std::io::SeekFrom;
+
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::utils::async_file_loader::AsyncLoadFile;
+use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+use crate::utils::exceptions::BoltReaderError;
+use crate::utils::hint::unlikely;
+
+/// Load a local file with tokio::fs, so the reads run on the blocking pool of the Tokio runtime
+/// instead of the worker threads. Each request opens the file, so multiple ranges are loaded
+/// concurrently without sharing a file cursor. It must be awaited inside a Tokio runtime.
+pub struct TokioFileLoader {
+    path: String,
+    size: usize,
+}
+
+#[allow(dead_code)]
+impl std::fmt::Display for TokioFileLoader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Tokio File Loader, path: {}, size: {}",
+            self.path, self.size
+        )
+    }
+}
+
+impl AsyncLoadFile for TokioFileLoader {
+    fn get_file_path(&self) -> &String {
+        &self.path
+    }
+
+    fn get_file_size(&self) -> usize {
+        self.size
+    }
+
+    async fn load_bytes(&self, begin: usize, end: usize) -> Result<Vec<u8>, BoltReaderError> {
+        if unlikely(begin > end || end > self.size) {
+            return Err(BoltReaderError::InternalError(format!(
+                "Reading range exceeds the file size: {} bytes. \nReading range [{}, {}) ",
+                self.size, begin, end
+            )));
+        }
+
+        let mut file = File::open(&self.path).await?;
+        file.seek(SeekFrom::Start(begin as u64)).await?;
+        let mut v = DirectByteBuffer::allocate_vec_for_buffer(end - begin)?;
+        file.read_exact(&mut v).await?;
+        Ok(v)
+    }
+}
+
+#[allow(dead_code)]
+impl TokioFileLoader {
+    pub async fn new(path: &String) -> Result<TokioFileLoader, BoltReaderError> {
+        let size = tokio::fs::metadata(path).await?.len() as usize;
+        Ok(TokioFileLoader {
+            path: path.clone(),
+            size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::async_file_loader::AsyncLoadFile;
+    use crate::utils::direct_byte_buffer::{Buffer, DirectByteBuffer};
+    use crate::utils::file_loader::LoadFile;
+    use crate::utils::local_file_loader::LocalFileLoader;
+    use crate::utils::tokio_file_loader::TokioFileLoader;
+
+    #[tokio::test]
+    async fn test_tokio_file_loader() {
+        let path = String::from("not_existing_file");
+        assert!(TokioFileLoader::new(&path).await.is_err());
+
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let res = TokioFileLoader::new(&path).await;
+        assert!(res.is_ok());
+        let file = res.unwrap();
+        assert_eq!(file.get_file_size(), 2065982);
+        assert_eq!(file.get_file_path(), "src/sample_files/lineitem.parquet");
+
+        let res = file.load_bytes(0, 4).await;
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), vec![80, 65, 82, 49]);
+
+        let res = file
+            .load_bytes(file.get_file_size(), file.get_file_size() + 1)
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().to_string(), "Internal Error: Reading range exceeds the file size: 2065982 bytes. \nReading range [2065982, 2065983) \n");
+    }
+
+    #[tokio::test]
+    async fn test_tokio_file_loader_concurrent_ranges() {
+        let path = String::from("src/sample_files/lineitem.parquet");
+        let file = TokioFileLoader::new(&path).await.unwrap();
+        let local_file = LocalFileLoader::new(&path).unwrap();
+
+        let ranges = [(4, 89775), (1530515, 1871709), (2065978, 2065982)];
+        let loaded = tokio::join!(
+            file.load_bytes(ranges[0].0, ranges[0].1),
+            file.load_bytes(ranges[1].0, ranges[1].1),
+            file.load_bytes(ranges[2].0, ranges[2].1)
+        );
+
+        for ((begin, end), bytes) in ranges.iter().zip([loaded.0, loaded.1, loaded.2]) {
+            let mut buffer = DirectByteBuffer::from_loaded_bytes(bytes.unwrap());
+            let mut expected = local_file.load_file_to_buffer(*begin, end - begin).unwrap();
+            assert_eq!(buffer.as_bytes(), expected.as_bytes());
+            assert_eq!(buffer.read_u32().unwrap(), expected.read_u32().unwrap());
+        }
+    }
+}